@@ -110,6 +110,9 @@ impl DataLoader for ArchetypeLoader {
                 entity_path,
                 contents.into_owned(),
             )?);
+        } else if crate::SUPPORTED_VECTOR_GRAPHIC_EXTENSIONS.contains(&extension.as_str()) {
+            re_log::debug!(?filepath, loader = self.name(), "Loading vector graphic…",);
+            rows.extend(load_svg(timepoint, entity_path, contents.into_owned())?);
         }
 
         for row in rows {
@@ -208,3 +211,21 @@ fn load_text_document(
 
     Ok(rows.into_iter())
 }
+
+fn load_svg(
+    timepoint: TimePoint,
+    entity_path: EntityPath,
+    contents: Vec<u8>,
+) -> Result<impl ExactSizeIterator<Item = DataRow>, DataLoaderError> {
+    re_tracing::profile_function!();
+
+    let rows = [
+        {
+            let arch = re_types::archetypes::SvgOverlay::new(contents);
+            DataRow::from_archetype(RowId::new(), timepoint, entity_path, &arch)?
+        },
+        //
+    ];
+
+    Ok(rows.into_iter())
+}
@@ -28,6 +28,7 @@ use re_log_types::{ArrowMsg, DataRow, LogMsg};
 ///     - [Images]
 ///     - [Point clouds]
 ///     - [Text files]
+///     - [Vector graphics]
 /// - [`DirectoryLoader`] for recursively loading folders.
 /// - [`ExternalLoader`], which looks for user-defined data loaders in $PATH.
 ///
@@ -55,6 +56,7 @@ use re_log_types::{ArrowMsg, DataRow, LogMsg};
 /// [Images]: crate::SUPPORTED_IMAGE_EXTENSIONS
 /// [Point clouds]: crate::SUPPORTED_POINT_CLOUD_EXTENSIONS
 /// [Text files]: crate::SUPPORTED_TEXT_EXTENSIONS
+/// [Vector graphics]: crate::SUPPORTED_VECTOR_GRAPHIC_EXTENSIONS
 //
 // TODO(#4525): `DataLoader`s should support arbitrary URIs
 // TODO(#4526): `DataLoader`s should be exposed to the SDKs
@@ -60,6 +60,8 @@ pub const SUPPORTED_RERUN_EXTENSIONS: &[&str] = &["rrd"];
 // TODO(#4555): Add catch-all builtin `DataLoader` for text files
 pub const SUPPORTED_TEXT_EXTENSIONS: &[&str] = &["txt", "md"];
 
+pub const SUPPORTED_VECTOR_GRAPHIC_EXTENSIONS: &[&str] = &["svg"];
+
 /// All file extension supported by our builtin [`DataLoader`]s.
 pub fn supported_extensions() -> impl Iterator<Item = &'static str> {
     SUPPORTED_RERUN_EXTENSIONS
@@ -68,6 +70,7 @@ pub fn supported_extensions() -> impl Iterator<Item = &'static str> {
         .chain(SUPPORTED_MESH_EXTENSIONS)
         .chain(SUPPORTED_POINT_CLOUD_EXTENSIONS)
         .chain(SUPPORTED_TEXT_EXTENSIONS)
+        .chain(SUPPORTED_VECTOR_GRAPHIC_EXTENSIONS)
         .copied()
 }
 
@@ -78,4 +81,5 @@ pub fn is_supported_file_extension(extension: &str) -> bool {
         || SUPPORTED_POINT_CLOUD_EXTENSIONS.contains(&extension)
         || SUPPORTED_RERUN_EXTENSIONS.contains(&extension)
         || SUPPORTED_TEXT_EXTENSIONS.contains(&extension)
+        || SUPPORTED_VECTOR_GRAPHIC_EXTENSIONS.contains(&extension)
 }
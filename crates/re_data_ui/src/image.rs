@@ -8,8 +8,8 @@ use re_types::datatypes::{TensorBuffer, TensorData, TensorDimension};
 use re_types::tensor_data::{DecodedTensor, TensorDataMeaning, TensorElement};
 use re_ui::ReUi;
 use re_viewer_context::{
-    gpu_bridge, Annotations, TensorDecodeCache, TensorStats, TensorStatsCache, UiVerbosity,
-    ViewerContext,
+    gpu_bridge, Annotations, DepthUnit, TensorDecodeCache, TensorStats, TensorStatsCache,
+    UiVerbosity, ViewerContext,
 };
 
 use crate::image_meaning_for_entity;
@@ -103,6 +103,7 @@ pub fn tensor_ui(
         meaning,
         &tensor_stats,
         annotations,
+        &Default::default(),
     )
     .ok();
 
@@ -214,6 +215,7 @@ pub fn tensor_ui(
                             annotations,
                             meaning,
                             meter,
+                            ctx.app_options.depth_unit,
                             &debug_name,
                             image_rect,
                             pointer_pos,
@@ -452,6 +454,7 @@ fn show_zoomed_image_region_tooltip(
     annotations: &Annotations,
     meaning: TensorDataMeaning,
     meter: Option<f32>,
+    depth_unit: DepthUnit,
     debug_name: &str,
     image_rect: egui::Rect,
     pointer_pos: egui::Pos2,
@@ -475,6 +478,7 @@ fn show_zoomed_image_region_tooltip(
                         tensor,
                         center_texel,
                         image_rect,
+                        ZoomedImageRegionOptions::default(),
                     );
                     show_zoomed_image_region(
                         render_ctx,
@@ -485,16 +489,52 @@ fn show_zoomed_image_region_tooltip(
                         annotations,
                         meaning,
                         meter,
+                        depth_unit,
                         debug_name,
                         center_texel,
+                        ZoomedImageRegionOptions::default(),
                     );
                 }
             });
         })
 }
 
-// Show the surrounding pixels:
-const ZOOMED_IMAGE_TEXEL_RADIUS: isize = 10;
+/// How [`show_zoomed_image_region`] magnifies the texels surrounding the hovered one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZoomedImageRegionOptions {
+    /// How many texels are shown on each side of the hovered texel.
+    pub texel_radius: u32,
+
+    /// Size of each magnified texel, in ui points.
+    pub points_per_texel: f32,
+
+    /// Print the value of each texel into it, if there is enough room for the text.
+    pub show_texel_values: bool,
+}
+
+impl ZoomedImageRegionOptions {
+    /// Smallest font size texel values are printed with.
+    ///
+    /// With smaller texels, the values aren't shown at all.
+    const MIN_VALUE_FONT_SIZE: f32 = 6.0;
+
+    /// Largest font size texel values are printed with.
+    const MAX_VALUE_FONT_SIZE: f32 = 12.0;
+
+    fn texel_radius(&self) -> isize {
+        self.texel_radius as isize
+    }
+}
+
+impl Default for ZoomedImageRegionOptions {
+    fn default() -> Self {
+        Self {
+            texel_radius: 10,
+            points_per_texel: 5.0,
+            show_texel_values: false,
+        }
+    }
+}
 
 pub fn show_zoomed_image_region_area_outline(
     egui_ctx: &egui::Context,
@@ -502,6 +542,7 @@ pub fn show_zoomed_image_region_area_outline(
     tensor: &TensorData,
     [center_x, center_y]: [isize; 2],
     image_rect: egui::Rect,
+    options: ZoomedImageRegionOptions,
 ) {
     use egui::{pos2, remap, Rect};
 
@@ -513,12 +554,13 @@ pub fn show_zoomed_image_region_area_outline(
     let height = height as f32;
 
     // Show where on the original image the zoomed-in region is at:
-    // The area shown is ZOOMED_IMAGE_TEXEL_RADIUS _surrounding_ the center.
+    // The area shown is the texel radius _surrounding_ the center.
     // Since the center is the top-left/rounded-down, coordinate, we need to add 1 to right/bottom.
-    let left = (center_x - ZOOMED_IMAGE_TEXEL_RADIUS) as f32;
-    let right = (center_x + ZOOMED_IMAGE_TEXEL_RADIUS + 1) as f32;
-    let top = (center_y - ZOOMED_IMAGE_TEXEL_RADIUS) as f32;
-    let bottom = (center_y + ZOOMED_IMAGE_TEXEL_RADIUS + 1) as f32;
+    let texel_radius = options.texel_radius();
+    let left = (center_x - texel_radius) as f32;
+    let right = (center_x + texel_radius + 1) as f32;
+    let top = (center_y - texel_radius) as f32;
+    let bottom = (center_y + texel_radius + 1) as f32;
 
     let left = remap(left, 0.0..=width, image_rect.x_range());
     let right = remap(right, 0.0..=width, image_rect.x_range());
@@ -543,8 +585,10 @@ pub fn show_zoomed_image_region(
     annotations: &Annotations,
     meaning: TensorDataMeaning,
     meter: Option<f32>,
+    depth_unit: DepthUnit,
     debug_name: &str,
     center_texel: [isize; 2],
+    options: ZoomedImageRegionOptions,
 ) {
     if let Err(err) = try_show_zoomed_image_region(
         render_ctx,
@@ -555,8 +599,10 @@ pub fn show_zoomed_image_region(
         annotations,
         meaning,
         meter,
+        depth_unit,
         debug_name,
         center_texel,
+        options,
     ) {
         ui.label(format!("Error: {err}"));
     }
@@ -573,8 +619,10 @@ fn try_show_zoomed_image_region(
     annotations: &Annotations,
     meaning: TensorDataMeaning,
     meter: Option<f32>,
+    depth_unit: DepthUnit,
     debug_name: &str,
     center_texel: [isize; 2],
+    options: ZoomedImageRegionOptions,
 ) -> anyhow::Result<()> {
     let Some([height, width, _]) = tensor.image_height_width_channels() else {
         return Ok(());
@@ -588,10 +636,12 @@ fn try_show_zoomed_image_region(
         meaning,
         tensor_stats,
         annotations,
+        // Show the true classes under the cursor, even those hidden in the view.
+        &Default::default(),
     )?;
 
-    const POINTS_PER_TEXEL: f32 = 5.0;
-    let size = Vec2::splat(((ZOOMED_IMAGE_TEXEL_RADIUS * 2 + 1) as f32) * POINTS_PER_TEXEL);
+    let points_per_texel = options.points_per_texel;
+    let size = Vec2::splat(((options.texel_radius() * 2 + 1) as f32) * points_per_texel);
 
     let (_id, zoom_rect) = ui.allocate_space(size);
     let painter = ui.painter();
@@ -602,12 +652,12 @@ fn try_show_zoomed_image_region(
     {
         let image_rect_on_screen = egui::Rect::from_min_size(
             zoom_rect.center()
-                - POINTS_PER_TEXEL
+                - points_per_texel
                     * egui::vec2(
                         (center_texel[0] as f32) + 0.5,
                         (center_texel[1] as f32) + 0.5,
                     ),
-            POINTS_PER_TEXEL * egui::vec2(width as f32, height as f32),
+            points_per_texel * egui::vec2(width as f32, height as f32),
         );
 
         gpu_bridge::render_image(
@@ -618,12 +668,22 @@ fn try_show_zoomed_image_region(
             egui::TextureOptions::NEAREST,
             debug_name,
         )?;
+
+        if options.show_texel_values {
+            paint_texel_values(
+                &painter.with_clip_rect(zoom_rect),
+                tensor,
+                image_rect_on_screen.min,
+                center_texel,
+                options,
+            );
+        }
     }
 
     // Outline the center texel, to indicate which texel we're printing the values of:
     {
         let center_texel_rect =
-            egui::Rect::from_center_size(zoom_rect.center(), Vec2::splat(POINTS_PER_TEXEL));
+            egui::Rect::from_center_size(zoom_rect.center(), Vec2::splat(points_per_texel));
         painter.rect_stroke(center_texel_rect.expand(1.0), 0.0, (1.0, Color32::BLACK));
         painter.rect_stroke(center_texel_rect, 0.0, (1.0, Color32::WHITE));
     }
@@ -633,7 +693,15 @@ fn try_show_zoomed_image_region(
         ui.separator();
 
         ui.vertical(|ui| {
-            tensor_pixel_value_ui(ui, tensor, annotations, [x as _, y as _], meaning, meter);
+            tensor_pixel_value_ui(
+                ui,
+                tensor,
+                annotations,
+                [x as _, y as _],
+                meaning,
+                meter,
+                depth_unit,
+            );
 
             // Show a big sample of the color of the middle texel:
             let (rect, _) =
@@ -663,6 +731,72 @@ fn try_show_zoomed_image_region(
     Ok(())
 }
 
+/// Prints the values of the magnified texels into them, one line per channel.
+///
+/// `image_min` is where the top left corner of the image would be on screen.
+fn paint_texel_values(
+    painter: &egui::Painter,
+    tensor: &TensorData,
+    image_min: egui::Pos2,
+    [center_x, center_y]: [isize; 2],
+    options: ZoomedImageRegionOptions,
+) {
+    let Some([height, width, num_channels]) = tensor.image_height_width_channels() else {
+        return;
+    };
+    let font_size = (options.points_per_texel / (num_channels as f32 + 1.0))
+        .min(ZoomedImageRegionOptions::MAX_VALUE_FONT_SIZE);
+    if font_size < ZoomedImageRegionOptions::MIN_VALUE_FONT_SIZE {
+        return;
+    }
+    let font_id = egui::FontId::monospace(font_size);
+
+    let texel_radius = options.texel_radius();
+    let x_range =
+        (center_x - texel_radius).max(0)..=(center_x + texel_radius).min(width as isize - 1);
+    let y_range =
+        (center_y - texel_radius).max(0)..=(center_y + texel_radius).min(height as isize - 1);
+    for y in y_range {
+        for x in x_range.clone() {
+            let Some(text) = texel_value_text(tensor, [x as u64, y as u64], num_channels) else {
+                continue;
+            };
+            let texel_center =
+                image_min + options.points_per_texel * egui::vec2(x as f32 + 0.5, y as f32 + 0.5);
+            // Dark shadow, to keep the text readable on light texels.
+            for (offset, color) in [
+                (Vec2::splat(1.0), Color32::BLACK),
+                (Vec2::ZERO, Color32::WHITE),
+            ] {
+                painter.text(
+                    texel_center + offset,
+                    egui::Align2::CENTER_CENTER,
+                    &text,
+                    font_id.clone(),
+                    color,
+                );
+            }
+        }
+    }
+}
+
+/// The values of all channels of a texel, one line per channel.
+fn texel_value_text(tensor: &TensorData, [x, y]: [u64; 2], num_channels: u64) -> Option<String> {
+    (0..num_channels)
+        .map(|channel| {
+            tensor
+                .get_with_image_coords(x, y, channel)
+                .map(|value| match value {
+                    TensorElement::F16(_) | TensorElement::F32(_) | TensorElement::F64(_) => {
+                        format!("{:.2}", value.as_f64())
+                    }
+                    _ => value.to_string(),
+                })
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(|lines| lines.join("\n"))
+}
+
 fn tensor_pixel_value_ui(
     ui: &mut egui::Ui,
     tensor: &TensorData,
@@ -670,6 +804,7 @@ fn tensor_pixel_value_ui(
     [x, y]: [u64; 2],
     meaning: TensorDataMeaning,
     meter: Option<f32>,
+    depth_unit: DepthUnit,
 ) {
     egui::Grid::new("hovered pixel properties").show(ui, |ui| {
         ui.label("Position:");
@@ -688,7 +823,10 @@ fn tensor_pixel_value_ui(
                             .resolved_class_description(Some(ClassId::from(u16_val)))
                             .annotation_info()
                             .label(None)
-                            .unwrap_or_else(|| u16_val.to_string()),
+                            .map_or_else(
+                                || u16_val.to_string(),
+                                |label| format!("{label} (class {u16_val})"),
+                            ),
                     );
                     ui.end_row();
                 };
@@ -700,71 +838,32 @@ fn tensor_pixel_value_ui(
                 let raw_value = raw_value.as_f64();
                 let meters = raw_value / (meter as f64);
                 ui.label("Depth:");
-                if meters < 1.0 {
-                    ui.monospace(format!("{:.1} mm", meters * 1e3));
-                } else {
-                    ui.monospace(format!("{meters:.3} m"));
-                }
+                ui.monospace(depth_unit.format(meters));
             }
         }
     });
 
     let text = if let Some([_, _, channel]) = tensor.image_height_width_channels() {
-        match channel {
-            1 => tensor
-                .get_with_image_coords(x, y, 0)
-                .map(|v| format!("Val: {v}")),
-            3 => {
-                // TODO(jleibs): Track RGB ordering somehow -- don't just assume it
-                if let Some([r, g, b]) = match &tensor.buffer {
-                    TensorBuffer::Nv12(_) => tensor.get_nv12_pixel(x, y),
-                    TensorBuffer::Yuy2(_) => tensor.get_yuy2_pixel(x, y),
-                    _ => {
-                        if let [Some(r), Some(g), Some(b)] = [
-                            tensor.get_with_image_coords(x, y, 0),
-                            tensor.get_with_image_coords(x, y, 1),
-                            tensor.get_with_image_coords(x, y, 2),
-                        ] {
-                            Some([r, g, b])
-                        } else {
-                            None
-                        }
-                    }
-                } {
-                    match (r, g, b) {
-                        (TensorElement::U8(r), TensorElement::U8(g), TensorElement::U8(b)) => {
-                            Some(format!("R: {r}, G: {g}, B: {b}, #{r:02X}{g:02X}{b:02X}"))
-                        }
-                        _ => Some(format!("R: {r}, G: {g}, B: {b}")),
-                    }
-                } else {
-                    None
-                }
-            }
-            4 => {
-                // TODO(jleibs): Track RGB ordering somehow -- don't just assume it
-                if let (Some(r), Some(g), Some(b), Some(a)) = (
-                    tensor.get_with_image_coords(x, y, 0),
-                    tensor.get_with_image_coords(x, y, 1),
-                    tensor.get_with_image_coords(x, y, 2),
-                    tensor.get_with_image_coords(x, y, 3),
-                ) {
-                    match (r, g, b, a) {
-                        (
-                            TensorElement::U8(r),
-                            TensorElement::U8(g),
-                            TensorElement::U8(b),
-                            TensorElement::U8(a),
-                        ) => Some(format!(
-                            "R: {r}, G: {g}, B: {b}, A: {a}, #{r:02X}{g:02X}{b:02X}{a:02X}"
-                        )),
-                        _ => Some(format!("R: {r}, G: {g}, B: {b}, A: {a}")),
-                    }
-                } else {
-                    None
+        // TODO(jleibs): Track RGB ordering somehow -- don't just assume it
+        match (channel, tensor.get_pixel_with_image_coords(x, y).as_deref()) {
+            (1, Some([v])) => Some(format!("Val: {v}")),
+            (3, Some([r, g, b])) => Some(match (r, g, b) {
+                (TensorElement::U8(r), TensorElement::U8(g), TensorElement::U8(b)) => {
+                    format!("R: {r}, G: {g}, B: {b}, #{r:02X}{g:02X}{b:02X}")
                 }
-            }
-            channel => Some(format!("Cannot preview {channel}-size channel image")),
+                _ => format!("R: {r}, G: {g}, B: {b}"),
+            }),
+            (4, Some([r, g, b, a])) => Some(match (r, g, b, a) {
+                (
+                    TensorElement::U8(r),
+                    TensorElement::U8(g),
+                    TensorElement::U8(b),
+                    TensorElement::U8(a),
+                ) => format!("R: {r}, G: {g}, B: {b}, A: {a}, #{r:02X}{g:02X}{b:02X}{a:02X}"),
+                _ => format!("R: {r}, G: {g}, B: {b}, A: {a}"),
+            }),
+            (1 | 3 | 4, _) => None,
+            (channel, _) => Some(format!("Cannot preview {channel}-size channel image")),
         }
     } else {
         Some(format!(
@@ -31,7 +31,7 @@ pub mod item_ui;
 
 pub use crate::image::{
     show_zoomed_image_region, show_zoomed_image_region_area_outline,
-    tensor_summary_ui_grid_contents,
+    tensor_summary_ui_grid_contents, ZoomedImageRegionOptions,
 };
 pub use component::EntityComponentWithInstances;
 pub use component_ui_registry::{add_to_registry, create_component_ui_registry};
@@ -98,6 +98,68 @@ pub struct EntityProperties {
     pub visible_history: re_query::ExtraQueryHistory,
     pub interactive: bool,
 
+    /// Opacity applied on top of the colors of points, lines & boxes.
+    ///
+    /// 255 leaves colors unchanged, 0 makes them fully transparent.
+    pub alpha: u8,
+
+    /// Color to fill the interior of 2D boxes with, as unmultiplied sRGBA.
+    ///
+    /// If `None`, only the box outlines are drawn.
+    pub box_fill_color: Option<[u8; 4]>,
+
+    /// Label generated for 2D boxes that were logged without one.
+    pub box_auto_label: BoxAutoLabel,
+
+    /// Overrides the draw order of the entity, which determines what is drawn on top in 2D.
+    ///
+    /// If `None`, the logged draw order or the default of the entity's kind is used.
+    pub draw_order: Option<f32>,
+
+    /// Previews non-maximum suppression of overlapping 2D boxes with this IoU threshold.
+    ///
+    /// Kept boxes are highlighted and the boxes they suppress are faded out.
+    /// Boxes are assumed to be logged in order of descending score.
+    pub box_nms_iou_threshold: Option<f32>,
+
+    /// Opacity of the fill of 2D polygons, in `0.0..=1.0` relative to their outline color.
+    ///
+    /// If `None`, only the polygon outlines are drawn.
+    pub polygon_fill_opacity: Option<f32>,
+
+    /// Length of the heads of 2D arrows in ui points.
+    ///
+    /// If set, heads are drawn as two short lines that keep their size on screen when zooming.
+    /// If `None`, heads are triangles that scale with the arrow radius.
+    pub arrow_head_size: Option<f32>,
+
+    /// Connect the 2D points of the entity in the order they were logged, e.g. to show a trajectory.
+    pub connect_points: EditableAutoValue<bool>,
+
+    /// Which tensor channels of a color image to show as red, green and blue.
+    ///
+    /// Allows showing images with more than 4 channels, e.g. hyperspectral images.
+    /// If `None`, images are shown as is.
+    pub channel_selector: Option<[usize; 3]>,
+
+    /// How the values of an image are mapped to the displayed range.
+    pub image_normalization: NormalizationMode,
+
+    /// How an image is filtered when zoomed in so far that its pixels cover several screen pixels.
+    ///
+    /// If `None`, images are magnified sharply.
+    pub image_magnification: Option<ImageMagnification>,
+
+    /// How an image is rotated or mirrored in 2D views, e.g. for camera sensors mounted sideways.
+    ///
+    /// If `None`, images are shown as stored.
+    pub image_rotation: Option<ImageRotation>,
+
+    /// Opacity of a segmentation image drawn on top of other images, in `0.0..=1.0`.
+    ///
+    /// If `None`, all images stacked in the same plane share the opacity evenly.
+    pub segmentation_opacity: Option<f32>,
+
     /// What kind of color mapping should be applied (none, map, texture, transfer..)?
     pub color_mapper: EditableAutoValue<ColorMapper>,
 
@@ -147,6 +209,19 @@ impl Default for EntityProperties {
             visible: true,
             visible_history: re_query::ExtraQueryHistory::default(),
             interactive: true,
+            alpha: 255,
+            box_fill_color: None,
+            box_auto_label: BoxAutoLabel::default(),
+            draw_order: None,
+            box_nms_iou_threshold: None,
+            polygon_fill_opacity: None,
+            arrow_head_size: None,
+            connect_points: EditableAutoValue::Auto(false),
+            channel_selector: None,
+            image_normalization: NormalizationMode::default(),
+            image_magnification: None,
+            image_rotation: None,
+            segmentation_opacity: None,
             color_mapper: EditableAutoValue::default(),
             pinhole_image_plane_distance: EditableAutoValue::default(),
             backproject_depth: EditableAutoValue::Auto(true),
@@ -169,6 +244,19 @@ impl EntityProperties {
             visible: self.visible && child.visible,
             visible_history: self.visible_history.with_child(&child.visible_history),
             interactive: self.interactive && child.interactive,
+            alpha: ((self.alpha as u16 * child.alpha as u16) / 255) as u8,
+            box_fill_color: child.box_fill_color.or(self.box_fill_color),
+            box_auto_label: child.box_auto_label.or(&self.box_auto_label).clone(),
+            draw_order: child.draw_order.or(self.draw_order),
+            box_nms_iou_threshold: child.box_nms_iou_threshold.or(self.box_nms_iou_threshold),
+            polygon_fill_opacity: child.polygon_fill_opacity.or(self.polygon_fill_opacity),
+            arrow_head_size: child.arrow_head_size.or(self.arrow_head_size),
+            connect_points: self.connect_points.or(&child.connect_points).clone(),
+            channel_selector: child.channel_selector.or(self.channel_selector),
+            image_normalization: child.image_normalization.or(self.image_normalization),
+            image_magnification: child.image_magnification.or(self.image_magnification),
+            image_rotation: child.image_rotation.or(self.image_rotation),
+            segmentation_opacity: child.segmentation_opacity.or(self.segmentation_opacity),
 
             color_mapper: self.color_mapper.or(&child.color_mapper).clone(),
 
@@ -214,6 +302,19 @@ impl EntityProperties {
             visible: other.visible,
             visible_history: self.visible_history.with_child(&other.visible_history),
             interactive: other.interactive,
+            alpha: other.alpha,
+            box_fill_color: other.box_fill_color,
+            box_auto_label: other.box_auto_label.clone(),
+            draw_order: other.draw_order,
+            box_nms_iou_threshold: other.box_nms_iou_threshold,
+            polygon_fill_opacity: other.polygon_fill_opacity,
+            arrow_head_size: other.arrow_head_size,
+            connect_points: other.connect_points.or(&self.connect_points).clone(),
+            channel_selector: other.channel_selector,
+            image_normalization: other.image_normalization,
+            image_magnification: other.image_magnification,
+            image_rotation: other.image_rotation,
+            segmentation_opacity: other.segmentation_opacity,
 
             color_mapper: other.color_mapper.or(&self.color_mapper).clone(),
 
@@ -253,6 +354,19 @@ impl EntityProperties {
             visible,
             visible_history,
             interactive,
+            alpha,
+            box_fill_color,
+            box_auto_label,
+            draw_order,
+            box_nms_iou_threshold,
+            polygon_fill_opacity,
+            arrow_head_size,
+            connect_points,
+            channel_selector,
+            image_normalization,
+            image_magnification,
+            image_rotation,
+            segmentation_opacity,
             color_mapper,
             pinhole_image_plane_distance,
             backproject_depth,
@@ -268,6 +382,19 @@ impl EntityProperties {
         visible != &other.visible
             || visible_history != &other.visible_history
             || interactive != &other.interactive
+            || alpha != &other.alpha
+            || box_fill_color != &other.box_fill_color
+            || box_auto_label != &other.box_auto_label
+            || draw_order != &other.draw_order
+            || box_nms_iou_threshold != &other.box_nms_iou_threshold
+            || polygon_fill_opacity != &other.polygon_fill_opacity
+            || arrow_head_size != &other.arrow_head_size
+            || connect_points.has_edits(&other.connect_points)
+            || channel_selector != &other.channel_selector
+            || image_normalization != &other.image_normalization
+            || image_magnification != &other.image_magnification
+            || image_rotation != &other.image_rotation
+            || segmentation_opacity != &other.segmentation_opacity
             || color_mapper.has_edits(&other.color_mapper)
             || pinhole_image_plane_distance.has_edits(&other.pinhole_image_plane_distance)
             || backproject_depth.has_edits(&other.backproject_depth)
@@ -283,6 +410,207 @@ impl EntityProperties {
 
 // ----------------------------------------------------------------------------
 
+/// What the label generated for an unlabeled 2D box shows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum AutoLabelMode {
+    /// Don't generate labels.
+    #[default]
+    None,
+
+    /// Width and height of the box, e.g. `w=1.23 h=0.45`.
+    Dimensions,
+
+    /// Area of the box, e.g. `A=0.55`.
+    Area,
+}
+
+impl std::fmt::Display for AutoLabelMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::None => "None",
+            Self::Dimensions => "Dimensions",
+            Self::Area => "Area",
+        })
+    }
+}
+
+/// Label generated for 2D boxes that were logged without one, along with its number format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct BoxAutoLabel {
+    pub mode: AutoLabelMode,
+
+    /// Number of decimal places of the shown numbers.
+    pub decimals: u8,
+
+    /// Unit appended to the shown numbers, e.g. `m` or `px`.
+    ///
+    /// Areas are shown in the squared unit.
+    pub unit_suffix: String,
+}
+
+impl Default for BoxAutoLabel {
+    fn default() -> Self {
+        Self {
+            mode: AutoLabelMode::None,
+            decimals: 2,
+            unit_suffix: String::new(),
+        }
+    }
+}
+
+impl BoxAutoLabel {
+    /// Returns `self` unless its mode is [`AutoLabelMode::None`], in which case `other` is returned.
+    #[inline]
+    pub fn or<'a>(&'a self, other: &'a Self) -> &'a Self {
+        if self.mode == AutoLabelMode::None {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// The label of a box with the given size, `None` if no labels should be generated.
+    pub fn label(&self, width: f32, height: f32) -> Option<String> {
+        let decimals = self.decimals as usize;
+        let unit = &self.unit_suffix;
+        match self.mode {
+            AutoLabelMode::None => None,
+            AutoLabelMode::Dimensions => Some(format!(
+                "w={width:.decimals$}{unit} h={height:.decimals$}{unit}"
+            )),
+            AutoLabelMode::Area => {
+                let area = width * height;
+                let squared = if unit.is_empty() { "" } else { "²" };
+                Some(format!("A={area:.decimals$}{unit}{squared}"))
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How an image is filtered when its pixels cover several screen pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ImageMagnification {
+    /// Show crisp individual pixels (nearest neighbor).
+    #[default]
+    Sharp,
+
+    /// Blend between neighboring pixels (bilinear).
+    Smooth,
+}
+
+impl std::fmt::Display for ImageMagnification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Sharp => "Sharp",
+            Self::Smooth => "Smooth",
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How an image is rotated or mirrored for display.
+///
+/// Rotations are clockwise, as seen in a 2D view.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ImageRotation {
+    /// Show the image as stored.
+    #[default]
+    None,
+
+    /// Rotate by a quarter turn clockwise.
+    Rot90,
+
+    /// Rotate by a half turn, i.e. show it upside down.
+    Rot180,
+
+    /// Rotate by a quarter turn counter-clockwise.
+    Rot270,
+
+    /// Mirror left and right.
+    FlipH,
+
+    /// Mirror top and bottom.
+    FlipV,
+}
+
+impl ImageRotation {
+    pub const ALL: [Self; 6] = [
+        Self::None,
+        Self::Rot90,
+        Self::Rot180,
+        Self::Rot270,
+        Self::FlipH,
+        Self::FlipV,
+    ];
+}
+
+impl std::fmt::Display for ImageRotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::None => "None",
+            Self::Rot90 => "90°",
+            Self::Rot180 => "180°",
+            Self::Rot270 => "270°",
+            Self::FlipH => "Flip horizontally",
+            Self::FlipV => "Flip vertically",
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How the values of an image are mapped to the range that is displayed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum NormalizationMode {
+    /// Guess the range from the data type and the values, e.g. `0-255` for most 8 bit images.
+    #[default]
+    None,
+
+    /// Stretch the smallest to the largest finite value over the full range.
+    MinMax,
+
+    /// Stretch the values between two percentiles over the full range, clamping outliers.
+    ///
+    /// Both percentiles are in `0.0..=100.0`.
+    Percentile(f32, f32),
+}
+
+impl NormalizationMode {
+    /// Default percentiles used when switching to [`Self::Percentile`].
+    pub const DEFAULT_PERCENTILE: Self = Self::Percentile(2.0, 98.0);
+
+    /// Returns `self` unless it is [`Self::None`], in which case `other` is returned.
+    #[inline]
+    pub fn or(self, other: Self) -> Self {
+        if self == Self::None {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl std::fmt::Display for NormalizationMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("Automatic"),
+            Self::MinMax => f.write_str("Min-max"),
+            Self::Percentile(low, high) => write!(f, "Percentile {low}-{high}"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Colormap {
@@ -438,3 +766,25 @@ impl TimeSeriesAggregator {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoLabelMode, BoxAutoLabel};
+
+    #[test]
+    fn box_auto_label() {
+        let mut auto_label = BoxAutoLabel::default();
+        assert_eq!(auto_label.label(1.234, 0.45), None);
+
+        auto_label.mode = AutoLabelMode::Dimensions;
+        assert_eq!(
+            auto_label.label(1.234, 0.45).as_deref(),
+            Some("w=1.23 h=0.45")
+        );
+
+        auto_label.mode = AutoLabelMode::Area;
+        auto_label.decimals = 1;
+        auto_label.unit_suffix = "m".to_owned();
+        assert_eq!(auto_label.label(2.0, 1.5).as_deref(), Some("A=3.0m²"));
+    }
+}
@@ -18,6 +18,9 @@ pub struct PointCloudBuilder {
     // Size of `point`/color` must be equal.
     pub vertices: Vec<PositionRadius>,
 
+    /// Cpu side copy of the colors in `color_buffer`, used for exporting the points.
+    pub colors: Vec<Color32>,
+
     pub(crate) color_buffer: CpuWriteGpuReadBuffer<Color32>,
     pub(crate) picking_instance_ids_buffer: CpuWriteGpuReadBuffer<PickingLayerInstanceId>,
 
@@ -52,6 +55,7 @@ impl PointCloudBuilder {
 
         Self {
             vertices: Vec::with_capacity(RESERVE_SIZE),
+            colors: Vec::with_capacity(RESERVE_SIZE),
             color_buffer,
             picking_instance_ids_buffer,
             batches: Vec::with_capacity(16),
@@ -103,6 +107,30 @@ impl PointCloudBuilder {
         })
     }
 
+    // Iterate over all batches, yielding the batch info and an iterator over point vertices and their colors.
+    pub fn iter_vertices_and_colors_by_batch(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            &PointCloudBatchInfo,
+            impl Iterator<Item = (&PositionRadius, &Color32)>,
+        ),
+    > {
+        let mut vertex_offset = 0;
+        self.batches.iter().map(move |batch| {
+            let out = (
+                batch,
+                self.vertices
+                    .iter()
+                    .zip(self.colors.iter())
+                    .skip(vertex_offset)
+                    .take(batch.point_count as usize),
+            );
+            vertex_offset += batch.point_count as usize;
+            out
+        })
+    }
+
     /// Finalizes the builder and returns a point cloud draw data with all the points added so far.
     pub fn into_draw_data(
         self,
@@ -177,6 +205,7 @@ impl<'a> PointCloudBatchBuilder<'a> {
         let mut num_points = positions.len();
 
         debug_assert_eq!(self.0.vertices.len(), self.0.color_buffer.num_written());
+        debug_assert_eq!(self.0.vertices.len(), self.0.colors.len());
         debug_assert_eq!(
             self.0.vertices.len(),
             self.0.picking_instance_ids_buffer.num_written()
@@ -225,6 +254,9 @@ impl<'a> PointCloudBatchBuilder<'a> {
                 .color_buffer
                 .fill_n(Color32::WHITE, num_points.saturating_sub(colors.len()))
                 .ok_or_log_error();
+
+            self.0.colors.extend_from_slice(colors);
+            self.0.colors.resize(self.0.vertices.len(), Color32::WHITE);
         }
         {
             re_tracing::profile_scope!("picking_ids");
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use crate::{
     context::Renderers,
     draw_phases::DrawPhase,
@@ -28,9 +30,13 @@ type DrawFn = dyn for<'a, 'b> Fn(
     + Send;
 
 /// Type erased draw data that can be submitted directly to the view builder.
+///
+/// Cloning is cheap and shares the underlying draw data,
+/// which allows drawing the same data with several view builders.
+#[derive(Clone)]
 pub struct QueueableDrawData {
-    pub(crate) draw_func: Box<DrawFn>,
-    pub(crate) draw_data: Box<dyn std::any::Any + std::marker::Send + std::marker::Sync>,
+    pub(crate) draw_func: Arc<DrawFn>,
+    pub(crate) draw_data: Arc<dyn std::any::Any + std::marker::Send + std::marker::Sync>,
     pub(crate) renderer_name: &'static str,
     pub(crate) participated_phases: &'static [DrawPhase],
 }
@@ -38,7 +44,7 @@ pub struct QueueableDrawData {
 impl<D: DrawData + Sync + Send + 'static> From<D> for QueueableDrawData {
     fn from(draw_data: D) -> Self {
         QueueableDrawData {
-            draw_func: Box::new(move |renderers, gpu_resources, phase, pass, draw_data| {
+            draw_func: Arc::new(move |renderers, gpu_resources, phase, pass, draw_data| {
                 let renderer = renderers.get::<D::Renderer>().ok_or(
                     QueueableDrawDataError::FailedToRetrieveRenderer(std::any::type_name::<
                         D::Renderer,
@@ -51,7 +57,7 @@ impl<D: DrawData + Sync + Send + 'static> From<D> for QueueableDrawData {
                     .draw(gpu_resources, phase, pass, draw_data)
                     .map_err(QueueableDrawDataError::from)
             }),
-            draw_data: Box::new(draw_data),
+            draw_data: Arc::new(draw_data),
             renderer_name: std::any::type_name::<D::Renderer>(),
             participated_phases: D::Renderer::participated_phases(),
         }
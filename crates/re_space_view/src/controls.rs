@@ -13,6 +13,49 @@ pub const HORIZONTAL_SCROLL_MODIFIER: egui::Modifiers = egui::Modifiers::SHIFT;
 /// Which mouse button to drag for panning a 2D view.
 pub const DRAG_PAN2D_BUTTON: egui::PointerButton = egui::PointerButton::Primary;
 
+/// Alternative mouse button to drag for panning a 2D view.
+///
+/// Unlike [`DRAG_PAN2D_BUTTON`] this always pans, even while a marquee selection is active.
+pub const DRAG_PAN2D_ALT_BUTTON: egui::PointerButton = egui::PointerButton::Middle;
+
+/// Modifier to hold while clicking to add or remove an instance from the selection in a 2D view.
+pub const TOGGLE_SELECTION_2D_MODIFIER: egui::Modifiers = egui::Modifiers::SHIFT;
+
+/// Modifier to hold while dragging to select all instances inside a rectangle in a 2D view.
+pub const MARQUEE_SELECT_2D_MODIFIER: egui::Modifiers = egui::Modifiers::COMMAND;
+
+/// Modifier to hold while hovering an instance in a 2D view to highlight its entire entity instead,
+/// or only the instance if whole entities are highlighted by default.
+pub const HIGHLIGHT_WHOLE_ENTITY_2D_MODIFIER: egui::Modifiers = egui::Modifiers::ALT;
+
+/// Modifier to hold while hovering a 2D view to hover all instances close to the pointer instead of only the closest one.
+///
+/// Useful to tell apart tight clusters of points. Since it includes [`TOGGLE_SELECTION_2D_MODIFIER`],
+/// clicking while holding it adds or removes all of them from the selection,
+/// whereas clicking with only [`TOGGLE_SELECTION_2D_MODIFIER`] affects just the closest instance.
+/// Takes precedence over [`HIGHLIGHT_WHOLE_ENTITY_2D_MODIFIER`].
+pub const RADIUS_HOVER_2D_MODIFIER: egui::Modifiers =
+    egui::Modifiers::ALT.plus(egui::Modifiers::SHIFT);
+
+/// Key to zoom a 2D view to the current selection.
+pub const ZOOM_TO_SELECTION_KEY: egui::Key = egui::Key::F;
+
+/// Key to select the next instance of a 2D view and zoom to it.
+pub const SELECT_NEXT_INSTANCE_KEY: egui::Key = egui::Key::CloseBracket;
+
+/// Key to select the previous instance of a 2D view and zoom to it.
+pub const SELECT_PREVIOUS_INSTANCE_KEY: egui::Key = egui::Key::OpenBracket;
+
+/// Key to pin the tooltip of the hovered image of a 2D view into a floating window.
+pub const PIN_TOOLTIP_KEY: egui::Key = egui::Key::P;
+
+/// Modifier to hold while clicking an image of a 2D view to show the value of the clicked pixel in a popup.
+pub const PIXEL_QUERY_2D_MODIFIER: egui::Modifiers = egui::Modifiers::COMMAND;
+
+/// Key to toggle the inspector mode of a 2D view, in which clicking an instance shows its details
+/// in a floating window instead of selecting it.
+pub const INSPECT_MODE_2D_KEY: egui::Key = egui::Key::I;
+
 /// Rectangles drawn with this mouse button zoom in 2D views.
 pub const SELECTION_RECT_ZOOM_BUTTON: egui::PointerButton = egui::PointerButton::Secondary;
 
@@ -6,4 +6,9 @@ pub enum ScreenshotMode {
 
     /// The screenshot will be copied to the clipboard.
     CopyToClipboard,
+
+    /// The screenshot will only be saved to disk.
+    ///
+    /// Used for exports, which may be too large for the clipboard.
+    SaveToDisk,
 }
@@ -1,9 +1,11 @@
-//! High-level benchmark of the CPU-side of our `Points3D` rendering.
+//! High-level benchmark of the CPU-side of our `Points3D` and `Points2D` rendering.
 
 use re_data_store::{DataStore, LatestAtQuery};
 use re_log_types::{DataRow, EntityPath, RowId, TimeInt, TimePoint, Timeline};
 use re_query_cache::Caches;
-use re_space_view_spatial::{LoadedPoints, Points3DComponentData};
+use re_space_view_spatial::{
+    LoadedPoints, Points2DVisualizer, Points3DComponentData, ViewportCulling,
+};
 use re_types::{
     archetypes::Points3D,
     components::{ClassId, Color, InstanceKey, KeypointId, Position3D, Radius, Text},
@@ -15,7 +17,7 @@ use re_viewer_context::Annotations;
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
 criterion::criterion_main!(benches);
-criterion::criterion_group!(benches, bench_points);
+criterion::criterion_group!(benches, bench_points, bench_points_2d_culling);
 
 // ---
 
@@ -191,3 +193,58 @@ fn bench_points(c: &mut criterion::Criterion) {
     )
     .unwrap();
 }
+
+/// Culling of 2D points against the region of the view that was visible in the previous frame.
+fn bench_points_2d_culling(c: &mut criterion::Criterion) {
+    // Spread the points over a square of 1000x1000 scene units.
+    let side = (NUM_POINTS as f32).sqrt().ceil() as usize;
+    let positions = (0..NUM_POINTS)
+        .map(|i| {
+            let (x, y) = (i % side, i / side);
+            glam::vec3(x as f32, y as f32, 0.0) * (1000.0 / side as f32)
+        })
+        .collect::<Vec<_>>();
+    let radii = vec![re_renderer::Size::new_scene(0.5); NUM_POINTS];
+    let world_from_entity = glam::Affine3A::IDENTITY;
+
+    let culling_for = |visible_rect: egui::Rect| {
+        ViewportCulling::from_visible_region(Some(visible_rect), Some(1.0), 1.0)
+    };
+    let cases = [
+        (
+            "unculled",
+            culling_for(egui::Rect::from_min_max(
+                egui::pos2(0.0, 0.0),
+                egui::pos2(1000.0, 1000.0),
+            )),
+            NUM_POINTS,
+        ),
+        (
+            "culled",
+            culling_for(egui::Rect::from_min_max(
+                egui::pos2(5000.0, 5000.0),
+                egui::pos2(6000.0, 6000.0),
+            )),
+            0,
+        ),
+    ];
+
+    let mut group = c.benchmark_group("Points2D");
+    group.throughput(criterion::Throughput::Elements(NUM_POINTS as _));
+    for (name, culling, num_visible) in cases {
+        group.bench_function(format!("cull_points/{name}"), |b| {
+            b.iter(|| {
+                let mut indices = (0..NUM_POINTS).collect::<Vec<_>>();
+                Points2DVisualizer::cull_points(
+                    &positions,
+                    &radii,
+                    world_from_entity,
+                    &culling,
+                    &mut indices,
+                );
+                assert_eq!(indices.len(), num_visible);
+                indices
+            });
+        });
+    }
+}
@@ -0,0 +1,142 @@
+use re_log_types::RowId;
+use re_types::{
+    datatypes::{TensorBuffer, TensorData},
+    tensor_data::TensorElement,
+};
+use re_viewer_context::Cache;
+
+// ----------------------------------------------------------------------------
+
+/// Caches the pixel-space bounding rectangles covered by the class ids of a segmentation image.
+///
+/// Keyed on the [`RowId`] of the `TensorData`, so that hovering over the same image does not
+/// need to scan the entire tensor every frame.
+/// The regions of all classes of an image are found in a single scan.
+/// Images that weren't used for [`Self::MAX_UNUSED_FRAMES`] frames are evicted.
+#[derive(Default)]
+pub struct ClassRegionCache {
+    regions: ahash::HashMap<RowId, CachedClassRegions>,
+    frame: u64,
+}
+
+struct CachedClassRegions {
+    regions: ahash::HashMap<u16, egui::Rect>,
+    last_used_frame: u64,
+}
+
+impl ClassRegionCache {
+    /// Number of frames an image may go unused before its regions are evicted.
+    const MAX_UNUSED_FRAMES: u64 = 60;
+
+    /// Returns the rectangle in pixel coordinates enclosing all pixels with the given class id.
+    ///
+    /// The key should be the `RowId` of the `TensorData`.
+    pub fn entry(&mut self, key: RowId, tensor: &TensorData, class_id: u16) -> Option<egui::Rect> {
+        re_tracing::profile_function!();
+
+        let cached = self
+            .regions
+            .entry(key)
+            .or_insert_with(|| CachedClassRegions {
+                regions: class_regions(tensor),
+                last_used_frame: self.frame,
+            });
+        cached.last_used_frame = self.frame;
+        cached.regions.get(&class_id).copied()
+    }
+}
+
+/// Rectangles in pixel coordinates enclosing all pixels of each class id in the image.
+fn class_regions(tensor: &TensorData) -> ahash::HashMap<u16, egui::Rect> {
+    re_tracing::profile_function!();
+
+    let Some([height, width, channels]) = tensor.image_height_width_channels() else {
+        return Default::default();
+    };
+    let (width, num_pixels, channels) = (
+        width as usize,
+        (height * width) as usize,
+        channels.max(1) as usize,
+    );
+
+    match &tensor.buffer {
+        TensorBuffer::U8(buf) => {
+            regions_in_buffer(buf, width, num_pixels, channels, TensorElement::U8)
+        }
+        TensorBuffer::U16(buf) => {
+            regions_in_buffer(buf, width, num_pixels, channels, TensorElement::U16)
+        }
+        TensorBuffer::U32(buf) => {
+            regions_in_buffer(buf, width, num_pixels, channels, TensorElement::U32)
+        }
+        TensorBuffer::U64(buf) => {
+            regions_in_buffer(buf, width, num_pixels, channels, TensorElement::U64)
+        }
+        TensorBuffer::I8(buf) => {
+            regions_in_buffer(buf, width, num_pixels, channels, TensorElement::I8)
+        }
+        TensorBuffer::I16(buf) => {
+            regions_in_buffer(buf, width, num_pixels, channels, TensorElement::I16)
+        }
+        TensorBuffer::I32(buf) => {
+            regions_in_buffer(buf, width, num_pixels, channels, TensorElement::I32)
+        }
+        TensorBuffer::I64(buf) => {
+            regions_in_buffer(buf, width, num_pixels, channels, TensorElement::I64)
+        }
+        TensorBuffer::F16(buf) => {
+            regions_in_buffer(buf, width, num_pixels, channels, TensorElement::F16)
+        }
+        TensorBuffer::F32(buf) => {
+            regions_in_buffer(buf, width, num_pixels, channels, TensorElement::F32)
+        }
+        TensorBuffer::F64(buf) => {
+            regions_in_buffer(buf, width, num_pixels, channels, TensorElement::F64)
+        }
+        // Encoded images never hold class ids.
+        TensorBuffer::Jpeg(_) | TensorBuffer::Nv12(_) | TensorBuffer::Yuy2(_) => Default::default(),
+    }
+}
+
+/// Scans the first channel of a row-major image buffer.
+fn regions_in_buffer<T: Copy>(
+    buf: &[T],
+    width: usize,
+    num_pixels: usize,
+    channels: usize,
+    element: impl Fn(T) -> TensorElement,
+) -> ahash::HashMap<u16, egui::Rect> {
+    let mut regions = ahash::HashMap::<u16, egui::Rect>::default();
+    for (index, value) in buf.iter().step_by(channels).take(num_pixels).enumerate() {
+        let Some(class_id) = element(*value).try_as_u16() else {
+            continue;
+        };
+        let pixel = egui::Rect::from_min_size(
+            egui::pos2((index % width) as f32, (index / width) as f32),
+            egui::Vec2::splat(1.0),
+        );
+        regions
+            .entry(class_id)
+            .and_modify(|region| *region = region.union(pixel))
+            .or_insert(pixel);
+    }
+    regions
+}
+
+impl Cache for ClassRegionCache {
+    fn begin_frame(&mut self) {
+        self.frame += 1;
+
+        let frame = self.frame;
+        self.regions
+            .retain(|_, cached| frame - cached.last_used_frame <= Self::MAX_UNUSED_FRAMES);
+    }
+
+    fn purge_memory(&mut self) {
+        self.regions.clear();
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use nohash_hasher::IntMap;
+use nohash_hasher::{IntMap, IntSet};
 
 use re_log_types::EntityPathHash;
 use re_types::{components::DrawOrder, ComponentNameSet, Loggable as _};
@@ -48,12 +48,26 @@ impl ViewContextSystem for EntityDepthOffsets {
 
         // Use a BTreeSet for entity hashes to get a stable order.
         let mut entities_per_draw_order = BTreeMap::<DrawOrder, BTreeSet<DrawOrderTarget>>::new();
-        for data_result in query.iter_visible_data_results(Self::identifier()) {
-            if let Some(draw_order) = store
-                .query_latest_component::<DrawOrder>(&data_result.entity_path, &ctx.current_query())
-            {
+        // Not only entities with a logged draw order, since any entity can override it via its properties.
+        let mut seen = IntSet::<EntityPathHash>::default();
+        for data_result in query.iter_all_data_results() {
+            let properties = data_result.accumulated_properties();
+            // The same entity shows up once for every visualizer it is part of.
+            if !properties.visible || !seen.insert(data_result.entity_path.hash()) {
+                continue;
+            }
+
+            let draw_order = properties.draw_order.map(DrawOrder).or_else(|| {
+                store
+                    .query_latest_component::<DrawOrder>(
+                        &data_result.entity_path,
+                        &ctx.current_query(),
+                    )
+                    .map(|draw_order| draw_order.value)
+            });
+            if let Some(draw_order) = draw_order {
                 entities_per_draw_order
-                    .entry(draw_order.value)
+                    .entry(draw_order)
                     .or_default()
                     .insert(DrawOrderTarget::Entity(data_result.entity_path.hash()));
             }
@@ -0,0 +1,77 @@
+use std::collections::BTreeSet;
+
+use re_types::{components::ClassId, ComponentNameSet};
+use re_viewer_context::{IdentifiedViewSystem, SpaceViewId, ViewContextSystem};
+
+/// Classes the user hid in a 2D view's class legend.
+///
+/// Pixels of hidden classes are transparent in segmentation images,
+/// points and boxes with a hidden class aren't drawn at all.
+///
+/// Classes are hidden in the view's ui, i.e. after all visualizers ran, so this uses the
+/// classes that were hidden as of the previous frame.
+#[derive(Clone, Default)]
+pub struct HiddenClasses(pub BTreeSet<ClassId>);
+
+impl HiddenClasses {
+    fn hidden_classes_id(space_view_id: SpaceViewId) -> egui::Id {
+        egui::Id::new(space_view_id).with("hidden_classes")
+    }
+
+    /// Remember the classes hidden in the given 2D view for the visualizers of the next frame.
+    pub fn remember_hidden_classes(
+        egui_ctx: &egui::Context,
+        space_view_id: SpaceViewId,
+        hidden_class_ids: &BTreeSet<u16>,
+    ) {
+        egui_ctx.data_mut(|data| {
+            data.insert_temp(
+                Self::hidden_classes_id(space_view_id),
+                hidden_class_ids.clone(),
+            );
+        });
+    }
+
+    /// Whether the instance at `index` of a batch with the given class ids is hidden.
+    #[inline]
+    pub fn is_instance_hidden(&self, class_ids: Option<&[Option<ClassId>]>, index: usize) -> bool {
+        if self.0.is_empty() {
+            return false;
+        }
+        class_ids
+            .and_then(|class_ids| class_ids.get(index).copied().flatten())
+            .map_or(false, |class_id| self.0.contains(&class_id))
+    }
+}
+
+impl IdentifiedViewSystem for HiddenClasses {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "HiddenClasses".into()
+    }
+}
+
+impl ViewContextSystem for HiddenClasses {
+    fn compatible_component_sets(&self) -> Vec<ComponentNameSet> {
+        Vec::new()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &re_viewer_context::ViewerContext<'_>,
+        query: &re_viewer_context::ViewQuery<'_>,
+    ) {
+        let hidden_class_ids = ctx.re_ui.egui_ctx.data(|data| {
+            data.get_temp::<BTreeSet<u16>>(Self::hidden_classes_id(query.space_view_id))
+        });
+
+        self.0 = hidden_class_ids
+            .unwrap_or_default()
+            .into_iter()
+            .map(ClassId::from)
+            .collect();
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -1,16 +1,20 @@
 mod annotation_context;
 mod depth_offsets;
+mod hidden_classes;
 mod non_interactive_entities;
 mod shared_render_builders;
 mod transform_context;
+mod viewport_culling;
 
 use std::sync::atomic::AtomicUsize;
 
 pub use annotation_context::AnnotationSceneContext;
 pub use depth_offsets::EntityDepthOffsets;
+pub use hidden_classes::HiddenClasses;
 pub use non_interactive_entities::NonInteractiveEntities;
 pub use shared_render_builders::SharedRenderBuilders;
 pub use transform_context::TransformContext;
+pub use viewport_culling::ViewportCulling;
 
 // -----------------------------------------------------------------------------
 
@@ -27,6 +31,15 @@ pub struct SpatialSceneEntityContext<'a> {
     pub annotations: std::sync::Arc<Annotations>,
     pub shared_render_builders: &'a SharedRenderBuilders,
 
+    /// Opacity all colors of the entity are multiplied with, see [`re_entity_db::EntityProperties::alpha`].
+    pub alpha: u8,
+
+    /// Which parts of the scene may be visible, primitives outside of it don't need to be drawn.
+    pub culling: ViewportCulling,
+
+    /// Classes whose points, boxes and segmentation pixels shouldn't be drawn.
+    pub hidden_classes: &'a HiddenClasses,
+
     pub highlight: &'a re_viewer_context::SpaceViewOutlineMasks, // Not part of the context, but convenient to have here.
     pub space_view_class_identifier: SpaceViewClassIdentifier,
 }
@@ -68,5 +81,7 @@ pub fn register_spatial_contexts(
     system_registry.register_context_system::<SharedRenderBuilders>()?;
     system_registry.register_context_system::<NonInteractiveEntities>()?;
     system_registry.register_context_system::<PrimitiveCounter>()?;
+    system_registry.register_context_system::<ViewportCulling>()?;
+    system_registry.register_context_system::<HiddenClasses>()?;
     Ok(())
 }
@@ -30,36 +30,36 @@ impl SharedRenderBuilders {
         MutexGuard::map(self.points.lock(), |l| l.as_mut().unwrap())
     }
 
+    /// Draw data of the lines and points built so far, along with why some of it couldn't be built.
+    ///
+    /// Failures are logged as well.
     pub fn queuable_draw_data(
         &self,
         render_ctx: &RenderContext,
-    ) -> Vec<re_renderer::QueueableDrawData> {
+    ) -> (Vec<re_renderer::QueueableDrawData>, Vec<String>) {
         let mut result = Vec::new();
-        result.extend(
-            self.lines
-                .lock()
-                .take()
-                .and_then(|l| match l.into_draw_data(render_ctx) {
-                    Ok(d) => Some(d.into()),
-                    Err(err) => {
-                        re_log::error_once!("Failed to build line strip draw data: {err}");
-                        None
-                    }
-                }),
-        );
-        result.extend(
-            self.points
-                .lock()
-                .take()
-                .and_then(|l| match l.into_draw_data(render_ctx) {
-                    Ok(d) => Some(d.into()),
-                    Err(err) => {
-                        re_log::error_once!("Failed to build point draw data: {err}");
-                        None
-                    }
-                }),
-        );
-        result
+        let mut errors = Vec::new();
+        if let Some(lines) = self.lines.lock().take() {
+            match lines.into_draw_data(render_ctx) {
+                Ok(draw_data) => result.push(draw_data.into()),
+                Err(err) => {
+                    let error = format!("Failed to build line strip draw data: {err}");
+                    re_log::error_once!("{error}");
+                    errors.push(error);
+                }
+            }
+        }
+        if let Some(points) = self.points.lock().take() {
+            match points.into_draw_data(render_ctx) {
+                Ok(draw_data) => result.push(draw_data.into()),
+                Err(err) => {
+                    let error = format!("Failed to build point draw data: {err}");
+                    re_log::error_once!("{error}");
+                    errors.push(error);
+                }
+            }
+        }
+        (result, errors)
     }
 }
 
@@ -10,6 +10,7 @@ use re_viewer_context::{IdentifiedViewSystem, ViewContextSystem};
 
 use crate::{
     query_pinhole,
+    view_orientation::ViewOrientation,
     visualizers::{image_view_coordinates, CamerasVisualizer},
 };
 
@@ -124,19 +125,23 @@ impl ViewContextSystem for TransformContext {
 
         let time_query = ctx.current_query();
 
+        // 2D views may be flipped or rotated as a whole, which we treat as the transform of the reference itself.
+        let view_from_space =
+            ViewOrientation::recall(&ctx.re_ui.egui_ctx, query.space_view_id).view_from_space();
+
         // Child transforms of this space
         self.gather_descendants_transforms(
             current_tree,
             data_store,
             &time_query,
             &entity_prop_map,
-            glam::Affine3A::IDENTITY,
+            view_from_space,
             &None, // Ignore potential pinhole camera at the root of the space view, since it regarded as being "above" this root.
         );
 
         // Walk up from the reference to the highest reachable parent.
         let mut encountered_pinhole = None;
-        let mut reference_from_ancestor = glam::Affine3A::IDENTITY;
+        let mut reference_from_ancestor = view_from_space;
         while let Some(parent_path) = current_tree.path.parent() {
             let Some(parent_tree) = entity_tree.subtree(&parent_path) else {
                 // Unlike not having the space path in the hierarchy, this should be impossible.
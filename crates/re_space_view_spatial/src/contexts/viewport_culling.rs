@@ -0,0 +1,164 @@
+use re_types::ComponentNameSet;
+use re_viewer_context::{IdentifiedViewSystem, SpaceViewId, ViewContextSystem};
+
+/// Region of a 2D view's scene that may be visible, used to skip uploading primitives which are
+/// entirely off screen, as well as the resolution at which it is shown.
+///
+/// The visible region is only known once the view's ui has been laid out, i.e. after all
+/// visualizers ran, so this uses the region of the previous frame, padded to cover the
+/// pan & zoom changes that may have happened since.
+#[derive(Clone, Copy, Default)]
+pub struct ViewportCulling {
+    /// Scene rect outside of which nothing needs to be drawn, in the space view's world coordinates.
+    ///
+    /// `None` if everything should be drawn, e.g. in 3D views.
+    cull_rect: Option<egui::Rect>,
+
+    /// Scene rect that was visible in the previous frame, in the space view's world coordinates.
+    visible_rect: Option<egui::Rect>,
+
+    /// Size of a single device pixel in world units.
+    ///
+    /// `None` if unknown, e.g. in 3D views.
+    scene_units_per_pixel: Option<f32>,
+
+    /// Number of device pixels per ui point.
+    pixels_per_point: f32,
+}
+
+/// What a 2D view remembers about the previous frame.
+#[derive(Clone, Copy)]
+struct VisibleRegion {
+    rect: egui::Rect,
+    scene_units_per_pixel: f32,
+}
+
+impl ViewportCulling {
+    /// Fraction of the visible size added on each side of the previously visible rect.
+    const PADDING: f32 = 0.5;
+
+    /// Culling for a 2D view that showed `visible_rect` in the previous frame.
+    ///
+    /// Ignores a region that is degenerate or not finite, in which case everything is drawn.
+    pub fn from_visible_region(
+        visible_rect: Option<egui::Rect>,
+        scene_units_per_pixel: Option<f32>,
+        pixels_per_point: f32,
+    ) -> Self {
+        let visible_rect = visible_rect.filter(|rect| rect.is_finite() && rect.is_positive());
+        Self {
+            cull_rect: visible_rect.map(|rect| rect.expand2(rect.size() * Self::PADDING)),
+            visible_rect,
+            scene_units_per_pixel: scene_units_per_pixel
+                .filter(|size| size.is_finite() && *size > 0.0),
+            pixels_per_point,
+        }
+    }
+
+    fn visible_region_id(space_view_id: SpaceViewId) -> egui::Id {
+        egui::Id::new(space_view_id).with("viewport_culling_visible_region")
+    }
+
+    /// Remember the scene rect visible in the given 2D view and the number of ui points per scene unit
+    /// for culling in the next frame.
+    pub fn remember_visible_rect(
+        egui_ctx: &egui::Context,
+        space_view_id: SpaceViewId,
+        visible_rect: egui::Rect,
+        points_per_scene_unit: f32,
+    ) {
+        let region = VisibleRegion {
+            rect: visible_rect,
+            scene_units_per_pixel: 1.0 / (points_per_scene_unit * egui_ctx.pixels_per_point()),
+        };
+        egui_ctx.data_mut(|data| {
+            data.insert_temp(Self::visible_region_id(space_view_id), region);
+        });
+    }
+
+    /// Scene rect that was visible in the previous frame, if known.
+    #[inline]
+    pub fn visible_rect(&self) -> Option<egui::Rect> {
+        self.visible_rect
+    }
+
+    /// Size of a single device pixel in world units, if known.
+    #[inline]
+    pub fn scene_units_per_pixel(&self) -> Option<f32> {
+        self.scene_units_per_pixel
+    }
+
+    /// Size of a single ui point in world units, if known.
+    #[inline]
+    pub fn scene_units_per_point(&self) -> Option<f32> {
+        self.scene_units_per_pixel
+            .map(|scene_units_per_pixel| scene_units_per_pixel * self.pixels_per_point)
+    }
+
+    /// Whether any part of a point with the given radius in world coordinates may be visible.
+    #[inline]
+    pub fn is_point_visible(&self, world_pos: glam::Vec3, world_radius: f32) -> bool {
+        self.cull_rect.map_or(true, |rect| {
+            rect.expand(world_radius)
+                .contains(egui::pos2(world_pos.x, world_pos.y))
+        })
+    }
+
+    /// Radius of a point in world units.
+    ///
+    /// Radii given in ui points are converted with the scale of the previous frame. If that is unknown,
+    /// or the radius is automatic, the point is treated as having no extent.
+    #[inline]
+    pub fn world_radius(&self, radius: re_renderer::Size, world_from_entity_scale: f32) -> f32 {
+        if radius.is_auto() {
+            0.0
+        } else if let Some(radius) = radius.scene() {
+            radius * world_from_entity_scale
+        } else {
+            radius.points().unwrap_or(0.0) * self.scene_units_per_point().unwrap_or(0.0)
+        }
+    }
+
+    /// Whether any part of a bounding box in world coordinates may be visible.
+    #[inline]
+    pub fn is_bbox_visible(&self, world_bbox: &macaw::BoundingBox) -> bool {
+        self.cull_rect.map_or(true, |rect| {
+            rect.intersects(egui::Rect::from_min_max(
+                egui::pos2(world_bbox.min.x, world_bbox.min.y),
+                egui::pos2(world_bbox.max.x, world_bbox.max.y),
+            ))
+        })
+    }
+}
+
+impl IdentifiedViewSystem for ViewportCulling {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "ViewportCulling".into()
+    }
+}
+
+impl ViewContextSystem for ViewportCulling {
+    fn compatible_component_sets(&self) -> Vec<ComponentNameSet> {
+        Vec::new()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &re_viewer_context::ViewerContext<'_>,
+        query: &re_viewer_context::ViewQuery<'_>,
+    ) {
+        let region = ctx.re_ui.egui_ctx.data(|data| {
+            data.get_temp::<VisibleRegion>(Self::visible_region_id(query.space_view_id))
+        });
+
+        *self = Self::from_visible_region(
+            region.map(|region| region.rect),
+            region.map(|region| region.scene_units_per_pixel),
+            ctx.re_ui.egui_ctx.pixels_per_point(),
+        );
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,255 @@
+//! Comparing the points and boxes of a 2D view against a reference time.
+//!
+//! Instances are matched by their [`InstancePathHash`]: an instance that only exists at the
+//! current time is drawn in [`ADDED_COLOR`], one that only existed at the reference time in
+//! [`REMOVED_COLOR`]. Instances that exist at both times are left untouched.
+
+use ahash::HashMap;
+use itertools::Itertools as _;
+
+use re_data_store::{DataStore, LatestAtQuery};
+use re_entity_db::{EntityPath, InstancePathHash};
+use re_log_types::{TimeInt, Timeline};
+use re_renderer::{Color32, LineStripSeriesBuilder, PointCloudBuilder, Size};
+use re_types::{
+    archetypes::{Boxes2D, Points2D},
+    components::{HalfSizes2D, Position2D, Radius, Rotation2D},
+};
+use re_viewer_context::{IdentifiedViewSystem as _, ViewQuery};
+
+use crate::{
+    contexts::TransformContext,
+    visualizers::{process_radius_slice, BoxGeometry, Boxes2DVisualizer, Points2DVisualizer},
+};
+
+/// Color of instances that exist at the current time but not at the reference time.
+pub const ADDED_COLOR: Color32 = Color32::from_rgb(80, 220, 80);
+
+/// Color of instances that existed at the reference time but not at the current time.
+pub const REMOVED_COLOR: Color32 = Color32::from_rgb(230, 70, 70);
+
+/// Reference time of a 2D view's frame comparison.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FrameDiffReference {
+    /// Timeline the reference time is on, the comparison is paused while another timeline is active.
+    pub timeline: Timeline,
+    pub time: TimeInt,
+}
+
+/// Whether an instance was added or removed since the reference time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffKind {
+    Added,
+    Removed,
+}
+
+impl DiffKind {
+    pub fn color(self) -> Color32 {
+        match self {
+            Self::Added => ADDED_COLOR,
+            Self::Removed => REMOVED_COLOR,
+        }
+    }
+}
+
+/// A point or box in entity space, as it is logged at one of the compared times.
+#[derive(Clone, Copy)]
+pub enum DiffShape {
+    Point { position: glam::Vec2, radius: Size },
+    Box { geometry: BoxGeometry, radius: Size },
+}
+
+/// A shape that only exists at one of the two compared times.
+pub struct DiffEntry {
+    pub kind: DiffKind,
+    pub entity_path: EntityPath,
+    pub shape: DiffShape,
+}
+
+/// Collects all points and boxes that differ between the current time of the view and `reference_time`.
+pub fn diff_against_reference(
+    store: &DataStore,
+    query: &ViewQuery<'_>,
+    reference_time: TimeInt,
+) -> Vec<DiffEntry> {
+    re_tracing::profile_function!();
+
+    let current_query = LatestAtQuery::new(query.timeline, query.latest_at);
+    let reference_query = LatestAtQuery::new(query.timeline, reference_time);
+
+    let entity_paths = query
+        .iter_visible_data_results(Points2DVisualizer::identifier())
+        .chain(query.iter_visible_data_results(Boxes2DVisualizer::identifier()))
+        .map(|data_result| &data_result.entity_path)
+        .collect::<std::collections::BTreeSet<_>>();
+
+    let mut entries = Vec::new();
+    for entity_path in entity_paths {
+        let current = shapes_at(store, &current_query, entity_path);
+        let reference = shapes_at(store, &reference_query, entity_path);
+        entries.extend(
+            diff_shapes(&current, &reference)
+                .into_iter()
+                .map(|(kind, shape)| DiffEntry {
+                    kind,
+                    entity_path: entity_path.clone(),
+                    shape,
+                }),
+        );
+    }
+    entries
+}
+
+/// Shapes that are in `current` but not in `reference` and vice versa.
+fn diff_shapes<T: Copy>(
+    current: &HashMap<InstancePathHash, T>,
+    reference: &HashMap<InstancePathHash, T>,
+) -> Vec<(DiffKind, T)> {
+    let added = current
+        .iter()
+        .filter(|(instance, _)| !reference.contains_key(instance))
+        .map(|(_, shape)| (DiffKind::Added, *shape));
+    let removed = reference
+        .iter()
+        .filter(|(instance, _)| !current.contains_key(instance))
+        .map(|(_, shape)| (DiffKind::Removed, *shape));
+    added.chain(removed).collect()
+}
+
+/// All points and boxes logged to `entity_path` as of `query`, by instance.
+fn shapes_at(
+    store: &DataStore,
+    query: &LatestAtQuery,
+    entity_path: &EntityPath,
+) -> HashMap<InstancePathHash, DiffShape> {
+    let mut shapes = HashMap::default();
+
+    if let Ok(points) = re_query::query_archetype::<Points2D>(store, query, entity_path) {
+        if let (Ok(positions), Ok(radii)) = (
+            points.iter_required_component::<Position2D>(),
+            points.iter_optional_component::<Radius>(),
+        ) {
+            let radii = radii.collect::<Vec<_>>();
+            let radii = process_radius_slice(Some(&radii), points.num_instances(), entity_path);
+            for (instance_key, position, radius) in
+                itertools::izip!(points.iter_instance_keys(), positions, radii)
+            {
+                shapes.insert(
+                    InstancePathHash::instance(entity_path, instance_key),
+                    DiffShape::Point {
+                        position: position.into(),
+                        radius,
+                    },
+                );
+            }
+        }
+    }
+
+    if let Ok(boxes) = re_query::query_archetype::<Boxes2D>(store, query, entity_path) {
+        if let (Ok(half_sizes), Ok(centers), Ok(rotations), Ok(radii)) = (
+            boxes.iter_required_component::<HalfSizes2D>(),
+            boxes.iter_optional_component::<Position2D>(),
+            boxes.iter_optional_component::<Rotation2D>(),
+            boxes.iter_optional_component::<Radius>(),
+        ) {
+            let radii = radii.collect::<Vec<_>>();
+            let radii = process_radius_slice(Some(&radii), boxes.num_instances(), entity_path);
+            for (instance_key, half_size, center, rotation, radius) in itertools::izip!(
+                boxes.iter_instance_keys(),
+                half_sizes,
+                centers,
+                rotations,
+                radii
+            ) {
+                shapes.insert(
+                    InstancePathHash::instance(entity_path, instance_key),
+                    DiffShape::Box {
+                        geometry: BoxGeometry::new(
+                            center.unwrap_or(Position2D::ZERO),
+                            half_size,
+                            rotation,
+                        ),
+                        radius,
+                    },
+                );
+            }
+        }
+    }
+
+    shapes
+}
+
+/// Draws all differing shapes on top of the scene, colored by whether they were added or removed.
+pub fn add_diff_to_builders(
+    entries: &[DiffEntry],
+    transforms: &TransformContext,
+    line_builder: &mut LineStripSeriesBuilder,
+    point_builder: &mut PointCloudBuilder,
+) {
+    re_tracing::profile_function!();
+
+    // Entries of the same entity are next to each other, see `diff_against_reference`.
+    for (entity_path, entries) in &entries.iter().group_by(|entry| &entry.entity_path) {
+        // Entities that are no longer part of the view's transform tree can't be placed.
+        let Some(world_from_entity) = transforms.reference_from_entity(entity_path) else {
+            continue;
+        };
+
+        let mut positions = Vec::new();
+        let mut radii = Vec::new();
+        let mut colors = Vec::new();
+        let mut line_batch = line_builder
+            .batch("frame diff boxes")
+            .depth_offset(re_renderer::DepthOffset::MAX)
+            .world_from_obj(world_from_entity);
+
+        for entry in entries {
+            match entry.shape {
+                DiffShape::Point { position, radius } => {
+                    positions.push(position.extend(0.0));
+                    radii.push(radius);
+                    colors.push(entry.kind.color());
+                }
+                DiffShape::Box { geometry, radius } => {
+                    line_batch
+                        .add_rectangle_outline_2d(
+                            geometry.corner,
+                            geometry.extent_u,
+                            geometry.extent_v,
+                        )
+                        .color(entry.kind.color())
+                        .radius(radius);
+                }
+            }
+        }
+
+        if !positions.is_empty() {
+            let picking_ids = vec![re_renderer::PickingLayerInstanceId::default(); positions.len()];
+            point_builder
+                .batch("frame diff points")
+                .depth_offset(re_renderer::DepthOffset::MAX)
+                .world_from_obj(world_from_entity)
+                .add_points_2d(&positions, &radii, &colors, &picking_ids);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::components::InstanceKey;
+
+    use super::*;
+
+    #[test]
+    fn diff_matches_instances() {
+        let entity_path = EntityPath::from("points");
+        let instance = |key| InstancePathHash::instance(&entity_path, InstanceKey(key));
+
+        let current = [(instance(0), 0), (instance(1), 1)].into_iter().collect();
+        let reference = [(instance(1), 10), (instance(2), 20)].into_iter().collect();
+
+        let mut diff = diff_shapes(&current, &reference);
+        diff.sort_by_key(|(_, shape)| *shape);
+        assert_eq!(diff, vec![(DiffKind::Added, 0), (DiffKind::Removed, 20)]);
+    }
+}
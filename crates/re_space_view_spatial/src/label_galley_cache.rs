@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use re_viewer_context::Cache;
+
+// ----------------------------------------------------------------------------
+
+/// Caches the laid out text of scene labels across frames.
+///
+/// Least recently used galleys are evicted once there are more than [`Self::CAPACITY`] of them,
+/// and everything is thrown away when the font, the pixels per point or the font atlas change.
+#[derive(Default)]
+pub struct LabelGalleyCache {
+    galleys: ahash::HashMap<LabelGalleyKey, CachedGalley>,
+    frame: u64,
+    pixels_per_point: f32,
+    font_id: Option<egui::FontId>,
+
+    /// Fill ratio of egui's font atlas, which drops when the atlas is recreated.
+    font_atlas_fill_ratio: f32,
+}
+
+#[derive(Hash, PartialEq, Eq)]
+struct LabelGalleyKey {
+    text: String,
+    wrap_width_bits: u32,
+    max_rows: usize,
+    font_size_bits: u32,
+    color: egui::Color32,
+}
+
+struct CachedGalley {
+    galley: Arc<egui::Galley>,
+    last_used_frame: u64,
+}
+
+impl LabelGalleyCache {
+    /// Maximum number of galleys kept around.
+    const CAPACITY: usize = 4096;
+
+    /// Returns the galley for a centered label, laying it out only if it isn't cached yet.
+    ///
+    /// `font_size` overrides the size of `font_id` if set.
+    /// Text that doesn't fit into `max_rows` rows is cut short with an ellipsis, see [`egui::Galley::elided`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn entry(
+        &mut self,
+        ui: &egui::Ui,
+        font_id: &egui::FontId,
+        font_size: Option<f32>,
+        text: &str,
+        wrap_width: f32,
+        max_rows: usize,
+        color: egui::Color32,
+    ) -> Arc<egui::Galley> {
+        let pixels_per_point = ui.ctx().pixels_per_point();
+        let font_atlas_fill_ratio = ui.fonts(|fonts| fonts.font_atlas_fill_ratio());
+        if self.pixels_per_point != pixels_per_point
+            || self.font_id.as_ref() != Some(font_id)
+            || font_atlas_fill_ratio < self.font_atlas_fill_ratio
+        {
+            self.galleys.clear();
+            self.pixels_per_point = pixels_per_point;
+            self.font_id = Some(font_id.clone());
+        }
+        self.font_atlas_fill_ratio = font_atlas_fill_ratio;
+
+        let font_id = egui::FontId::new(font_size.unwrap_or(font_id.size), font_id.family.clone());
+        let key = LabelGalleyKey {
+            text: text.to_owned(),
+            wrap_width_bits: wrap_width.to_bits(),
+            max_rows,
+            font_size_bits: font_id.size.to_bits(),
+            color,
+        };
+        if let Some(cached) = self.galleys.get_mut(&key) {
+            cached.last_used_frame = self.frame;
+            return cached.galley.clone();
+        }
+
+        if self.galleys.len() >= Self::CAPACITY {
+            self.evict_least_recently_used();
+        }
+
+        let galley = ui.fonts(|fonts| {
+            fonts.layout_job(egui::text::LayoutJob {
+                sections: vec![egui::text::LayoutSection {
+                    leading_space: 0.0,
+                    byte_range: 0..text.len(),
+                    format: egui::TextFormat::simple(font_id, color),
+                }],
+                text: text.to_owned(),
+                wrap: egui::text::TextWrapping {
+                    max_width: wrap_width,
+                    max_rows,
+                    ..Default::default()
+                },
+                break_on_newline: true,
+                halign: egui::Align::Center,
+                ..Default::default()
+            })
+        });
+        self.galleys.insert(
+            key,
+            CachedGalley {
+                galley: galley.clone(),
+                last_used_frame: self.frame,
+            },
+        );
+        galley
+    }
+
+    /// Removes the least recently used quarter of the cache.
+    fn evict_least_recently_used(&mut self) {
+        re_tracing::profile_function!();
+
+        let mut last_used_frames = self
+            .galleys
+            .values()
+            .map(|cached| cached.last_used_frame)
+            .collect::<Vec<_>>();
+        let num_to_evict = (Self::CAPACITY / 4).max(1);
+        let (_, &mut oldest_kept_frame, _) = last_used_frames.select_nth_unstable(num_to_evict);
+        self.galleys
+            .retain(|_, cached| cached.last_used_frame >= oldest_kept_frame);
+
+        // Many galleys may have been used in the same frame, make sure there's room either way.
+        if self.galleys.len() >= Self::CAPACITY {
+            self.galleys.clear();
+        }
+    }
+}
+
+impl Cache for LabelGalleyCache {
+    fn begin_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    fn purge_memory(&mut self) {
+        self.galleys.clear();
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -2,30 +2,44 @@
 //!
 //! Space Views that show entities in a 2D or 3D spatial relationship.
 
+mod class_region_cache;
 mod contexts;
 mod eye;
+mod frame_diff;
 mod heuristics;
 mod instance_hash_conversions;
+mod label_galley_cache;
 mod max_image_dimension_subscriber;
 mod mesh_cache;
 mod mesh_loader;
 mod picking;
+mod point_density;
 mod scene_bounding_boxes;
+mod scene_stats;
 mod space_camera_3d;
 mod space_view_2d;
 mod space_view_3d;
 mod spatial_topology;
+mod svg_cache;
+#[cfg(not(target_arch = "wasm32"))]
+mod svg_export;
+mod trails;
 mod ui;
 mod ui_2d;
 mod ui_3d;
+mod view_orientation;
 mod visualizers;
 
 use re_types::components::{Resolution, TensorData};
 pub use space_view_2d::SpatialSpaceView2D;
 pub use space_view_3d::SpatialSpaceView3D;
+pub use ui::SpatialSpaceViewState;
+pub use ui_2d::ViewTransform2D;
 
 #[doc(hidden)] // Public for benchmarks
-pub use visualizers::{LoadedPoints, Points3DComponentData};
+pub use contexts::ViewportCulling;
+#[doc(hidden)] // Public for benchmarks
+pub use visualizers::{LoadedPoints, Points2DVisualizer, Points3DComponentData};
 
 // ---
 
@@ -5,7 +5,7 @@ use re_entity_db::InstancePathHash;
 use re_renderer::PickingLayerProcessor;
 use re_types::components::InstanceKey;
 
-use crate::visualizers::ViewerImage;
+use crate::visualizers::{ViewerImage, ViewerPolygon};
 use crate::{eye::Eye, instance_hash_conversions::instance_path_hash_from_picking_layer_id};
 
 #[derive(Clone, PartialEq, Eq)]
@@ -13,6 +13,9 @@ pub enum PickingHitType {
     /// The hit was a textured rect.
     TexturedRect,
 
+    /// The hit was the fill or the outline of a polygon.
+    Polygon,
+
     /// The result came from GPU based picking.
     GpuPickingResult,
 
@@ -79,7 +82,9 @@ impl PickingContext {
     /// Radius in which cursor interactions may snap to the nearest object even if the cursor
     /// does not hover it directly.
     ///
-    /// Note that this needs to be scaled when zooming is applied by the virtual->visible ui rect transform.
+    /// Given in ui points, so it needs to be scaled by the pixels per point of the display for device pixels,
+    /// and by the virtual->visible ui rect transform when zooming is applied.
+    /// Large primitives can still be hovered anywhere on their rendered extent, in addition to this radius.
     pub const UI_INTERACTION_RADIUS: f32 = 5.0;
 
     pub fn new(
@@ -102,25 +107,33 @@ impl PickingContext {
     }
 
     /// Performs picking for a given scene.
+    ///
+    /// If `radius_hover_in_pixels` is set, all objects within that radius of the cursor are picked
+    /// on the gpu instead of only the closest one.
+    #[allow(clippy::too_many_arguments)]
     pub fn pick(
         &self,
         render_ctx: &re_renderer::RenderContext,
         gpu_readback_identifier: re_renderer::GpuReadbackIdentifier,
         previous_picking_result: &Option<PickingResult>,
         images: &[ViewerImage],
+        polygons: &[ViewerPolygon],
         ui_rects: &[PickableUiRect],
+        radius_hover_in_pixels: Option<f32>,
     ) -> PickingResult {
         re_tracing::profile_function!();
 
         // Gather picking results from different sources.
-        let gpu_pick = picking_gpu(
+        let gpu_picks = picking_gpu(
             render_ctx,
             gpu_readback_identifier,
             self,
             previous_picking_result,
+            radius_hover_in_pixels,
         );
         let mut rect_hits = picking_textured_rects(self, images);
         rect_hits.sort_by(|a, b| b.depth_offset.cmp(&a.depth_offset));
+        let polygon_hits = picking_polygons(self, polygons);
         let ui_rect_hits = picking_ui_rects(self, ui_rects);
 
         let mut hits = Vec::new();
@@ -131,7 +144,7 @@ impl PickingContext {
         // Textured rect picks also know where on the rect, making this the better source!
         // Note that whenever this happens, it means that the same object path has a textured rect and something else
         // e.g. a camera.
-        if let Some(gpu_pick) = gpu_pick {
+        for gpu_pick in gpu_picks {
             if rect_hits.iter().all(|rect_hit| {
                 rect_hit.instance_path_hash.entity_path_hash
                     != gpu_pick.instance_path_hash.entity_path_hash
@@ -143,6 +156,16 @@ impl PickingContext {
         // We never throw away any textured rects, even if they're behind other objects.
         hits.extend(rect_hits);
 
+        // Polygon outlines are usually picked on the gpu already, their fills never are.
+        let polygon_hits = polygon_hits
+            .into_iter()
+            .filter(|polygon_hit| {
+                hits.iter()
+                    .all(|hit| hit.instance_path_hash != polygon_hit.instance_path_hash)
+            })
+            .collect::<Vec<_>>();
+        hits.extend(polygon_hits);
+
         // UI rects are overlaid on top, but we don't let them hide other picking results either.
         // Give any other previous hits precedence.
         let previously_hit_objects: HashSet<_> = hits
@@ -159,12 +182,14 @@ impl PickingContext {
     }
 }
 
+/// Picks the object closest to the cursor, or all objects within `radius_hover_in_pixels` of it if set, closest first.
 fn picking_gpu(
     render_ctx: &re_renderer::RenderContext,
     gpu_readback_identifier: u64,
     context: &PickingContext,
     previous_picking_result: &Option<PickingResult>,
-) -> Option<PickingRayHit> {
+    radius_hover_in_pixels: Option<f32>,
+) -> Vec<PickingRayHit> {
     re_tracing::profile_function!();
 
     // Only look at newest available result, discard everything else.
@@ -186,58 +211,84 @@ fn picking_gpu(
             (gpu_picking_result.rect.extent - glam::UVec2::ONE).as_vec2(),
         );
 
-        // Find closest non-zero pixel to the cursor.
-        let mut picked_id = re_renderer::PickingLayerId::default();
-        let mut picked_on_picking_rect = glam::Vec2::ZERO;
-        let mut closest_rect_distance_sq = f32::INFINITY;
-
-        for (i, id) in gpu_picking_result.picking_id_data.iter().enumerate() {
-            if id.object.0 != 0 {
-                let current_pos_on_picking_rect = glam::uvec2(
-                    i as u32 % gpu_picking_result.rect.extent.x,
-                    i as u32 / gpu_picking_result.rect.extent.x,
-                )
-                .as_vec2()
-                    + glam::vec2(0.5, 0.5); // Use pixel center for distances.
-                let distance_sq =
-                    current_pos_on_picking_rect.distance_squared(pointer_on_picking_rect);
-                if distance_sq < closest_rect_distance_sq {
-                    picked_on_picking_rect = current_pos_on_picking_rect;
-                    closest_rect_distance_sq = distance_sq;
-                    picked_id = *id;
-                }
+        closest_picked_pixels(
+            &gpu_picking_result.picking_id_data,
+            gpu_picking_result.rect.extent.x,
+            pointer_on_picking_rect,
+            radius_hover_in_pixels,
+        )
+        .into_iter()
+        .map(|(picked_id, picked_on_picking_rect)| {
+            let picked_world_position =
+                gpu_picking_result.picked_world_position(picked_on_picking_rect.as_uvec2());
+
+            PickingRayHit {
+                instance_path_hash: instance_path_hash_from_picking_layer_id(picked_id),
+                space_position: picked_world_position,
+                depth_offset: 1,
+                hit_type: PickingHitType::GpuPickingResult,
             }
-        }
-        if picked_id == re_renderer::PickingLayerId::default() {
-            // Nothing found.
-            return None;
-        }
-
-        let picked_world_position =
-            gpu_picking_result.picked_world_position(picked_on_picking_rect.as_uvec2());
-
-        Some(PickingRayHit {
-            instance_path_hash: instance_path_hash_from_picking_layer_id(picked_id),
-            space_position: picked_world_position,
-            depth_offset: 1,
-            hit_type: PickingHitType::GpuPickingResult,
         })
+        .collect()
     } else {
         // It is possible that some frames we don't get a picking result and the frame after we get several.
         // We need to cache the last picking result and use it until we get a new one or the mouse leaves the screen.
         // (Andreas: On my mac this *actually* happens in very simple scenes, I get occasional frames with 0 and then with 2 picking results!)
-        if let Some(PickingResult { hits }) = previous_picking_result {
-            for previous_opaque_hit in hits {
-                if matches!(
-                    previous_opaque_hit.hit_type,
-                    PickingHitType::GpuPickingResult
-                ) {
-                    return Some(previous_opaque_hit.clone());
+        let Some(PickingResult { hits }) = previous_picking_result else {
+            return Vec::new();
+        };
+        let previous_opaque_hits = hits
+            .iter()
+            .filter(|hit| matches!(hit.hit_type, PickingHitType::GpuPickingResult));
+        if radius_hover_in_pixels.is_some() {
+            previous_opaque_hits.cloned().collect()
+        } else {
+            previous_opaque_hits.take(1).cloned().collect()
+        }
+    }
+}
+
+/// The pixel closest to the cursor of every picked object in the picking rect, closest object first.
+///
+/// With a `radius`, all objects with a pixel within it are returned, otherwise only the closest object.
+fn closest_picked_pixels(
+    picking_id_data: &[re_renderer::PickingLayerId],
+    rect_width: u32,
+    pointer_on_picking_rect: glam::Vec2,
+    radius: Option<f32>,
+) -> Vec<(re_renderer::PickingLayerId, glam::Vec2)> {
+    // Picked id, pixel and squared distance of that pixel to the cursor.
+    let mut closest: Vec<(re_renderer::PickingLayerId, glam::Vec2, f32)> = Vec::new();
+
+    for (i, id) in picking_id_data.iter().enumerate() {
+        if id.object.0 == 0 {
+            continue;
+        }
+        let current_pos_on_picking_rect = glam::uvec2(i as u32 % rect_width, i as u32 / rect_width)
+            .as_vec2()
+            + glam::vec2(0.5, 0.5); // Use pixel center for distances.
+        let distance_sq = current_pos_on_picking_rect.distance_squared(pointer_on_picking_rect);
+        if radius.map_or(false, |radius| distance_sq > radius * radius) {
+            continue;
+        }
+
+        let previous = if radius.is_some() {
+            closest.iter_mut().find(|(closest_id, ..)| closest_id == id)
+        } else {
+            closest.first_mut()
+        };
+        match previous {
+            Some(previous) => {
+                if distance_sq < previous.2 {
+                    *previous = (*id, current_pos_on_picking_rect, distance_sq);
                 }
             }
+            None => closest.push((*id, current_pos_on_picking_rect, distance_sq)),
         }
-        None
     }
+
+    closest.sort_by(|a, b| a.2.total_cmp(&b.2));
+    closest.into_iter().map(|(id, pos, _)| (id, pos)).collect()
 }
 
 fn picking_textured_rects(context: &PickingContext, images: &[ViewerImage]) -> Vec<PickingRayHit> {
@@ -283,9 +334,50 @@ fn picking_textured_rects(context: &PickingContext, images: &[ViewerImage]) -> V
     hits
 }
 
+fn picking_polygons(context: &PickingContext, polygons: &[ViewerPolygon]) -> Vec<PickingRayHit> {
+    re_tracing::profile_function!();
+
+    let mut hits = Vec::new();
+
+    for polygon in polygons {
+        let polygon_plane = macaw::Plane3::from_normal_point(
+            polygon
+                .world_from_entity
+                .transform_vector3(glam::Vec3::Z)
+                .normalize(),
+            polygon.world_from_entity.translation.into(),
+        );
+
+        let (intersect, t) =
+            polygon_plane.intersect_ray(context.ray_in_world.origin, context.ray_in_world.dir);
+        if !intersect {
+            continue;
+        }
+        let intersection_world = context.ray_in_world.point_along(t);
+        let intersection_in_entity = polygon
+            .world_from_entity
+            .inverse()
+            .transform_point3(intersection_world);
+
+        if polygon.is_hovered_at(intersection_in_entity.truncate()) {
+            hits.push(PickingRayHit {
+                instance_path_hash: polygon.instance_path_hash,
+                space_position: intersection_world,
+                hit_type: PickingHitType::Polygon,
+                depth_offset: 0,
+            });
+        }
+    }
+
+    hits
+}
+
 pub struct PickableUiRect {
     pub rect: egui::Rect,
     pub instance_hash: InstancePathHash,
+
+    /// The full text of a label that was cut short, shown when hovering it.
+    pub full_text: Option<String>,
 }
 
 fn picking_ui_rects(
@@ -308,3 +400,33 @@ fn picking_ui_rects(
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use re_renderer::{PickingLayerId, PickingLayerInstanceId, PickingLayerObjectId};
+
+    use super::closest_picked_pixels;
+
+    #[test]
+    fn radius_hover_picks_all_close_objects() {
+        let id = |instance| PickingLayerId {
+            object: PickingLayerObjectId(1),
+            instance: PickingLayerInstanceId(instance),
+        };
+        // A 4x1 picking rect with the cursor on the first pixel.
+        let picking_id_data = [id(1), id(2), id(2), id(3)];
+        let pointer = glam::vec2(0.5, 0.5);
+
+        let closest = closest_picked_pixels(&picking_id_data, 4, pointer, None);
+        assert_eq!(closest, vec![(id(1), glam::vec2(0.5, 0.5))]);
+
+        let within_radius = closest_picked_pixels(&picking_id_data, 4, pointer, Some(2.0));
+        assert_eq!(
+            within_radius,
+            vec![(id(1), glam::vec2(0.5, 0.5)), (id(2), glam::vec2(1.5, 0.5))]
+        );
+
+        let empty = [PickingLayerId::default(); 4];
+        assert!(closest_picked_pixels(&empty, 4, pointer, Some(2.0)).is_empty());
+    }
+}
@@ -0,0 +1,187 @@
+use re_renderer::renderer::{
+    ColormappedTexture, RectangleOptions, TextureFilterMag, TextureFilterMin, TexturedRect,
+};
+use re_viewer_context::{gpu_bridge, SpaceViewId};
+
+// ----------------------------------------------------------------------------
+
+/// Width and height of a density cell, in device pixels.
+const CELL_SIZE_IN_PIXELS: f32 = 2.0;
+
+/// Largest number of cells along either axis.
+const MAX_RESOLUTION: u32 = 4096;
+
+fn enabled_id(space_view_id: SpaceViewId) -> egui::Id {
+    egui::Id::new(space_view_id).with("point_density_enabled")
+}
+
+/// Remember whether the 2D points of the given view are shown as a density heatmap in the next frame.
+pub fn remember_enabled(egui_ctx: &egui::Context, space_view_id: SpaceViewId, enabled: bool) {
+    egui_ctx.data_mut(|data| data.insert_temp(enabled_id(space_view_id), enabled));
+}
+
+/// Whether the 2D points of the given view are shown as a density heatmap instead of markers.
+pub fn is_enabled(egui_ctx: &egui::Context, space_view_id: SpaceViewId) -> bool {
+    egui_ctx
+        .data(|data| data.get_temp::<bool>(enabled_id(space_view_id)))
+        .unwrap_or(false)
+}
+
+/// Number of 2D points falling into each cell of a grid covering the visible region of a view.
+pub struct PointDensityGrid {
+    /// Region covered by the grid in world coordinates.
+    ///
+    /// May reach a bit beyond the visible region, so that all cells are square.
+    rect: egui::Rect,
+
+    /// Number of cells along x and y.
+    resolution: [u32; 2],
+
+    /// Row-major counts per cell.
+    counts: Vec<u32>,
+}
+
+impl PointDensityGrid {
+    /// Grid covering `visible_rect` with cells of a fixed size on screen.
+    ///
+    /// Returns `None` if the region is empty or the size of a pixel is unknown.
+    pub fn new(visible_rect: egui::Rect, scene_units_per_pixel: f32) -> Option<Self> {
+        let cell_size = scene_units_per_pixel * CELL_SIZE_IN_PIXELS;
+        if !visible_rect.is_positive() || !visible_rect.is_finite() || cell_size <= 0.0 {
+            return None;
+        }
+
+        let cells = (visible_rect.size() / cell_size).ceil();
+        let resolution = [
+            (cells.x as u32).clamp(1, MAX_RESOLUTION),
+            (cells.y as u32).clamp(1, MAX_RESOLUTION),
+        ];
+        let rect = egui::Rect::from_min_size(
+            visible_rect.min,
+            egui::vec2(
+                (resolution[0] as f32 * cell_size).max(visible_rect.width()),
+                (resolution[1] as f32 * cell_size).max(visible_rect.height()),
+            ),
+        );
+
+        Some(Self {
+            rect,
+            resolution,
+            counts: vec![0; (resolution[0] * resolution[1]) as usize],
+        })
+    }
+
+    fn cell_index(&self, pos: egui::Pos2) -> Option<usize> {
+        if !self.rect.contains(pos) {
+            return None;
+        }
+        let [width, height] = self.resolution;
+        let relative = (pos - self.rect.min) / self.rect.size();
+        let x = ((relative.x * width as f32) as u32).min(width - 1);
+        let y = ((relative.y * height as f32) as u32).min(height - 1);
+        Some((y * width + x) as usize)
+    }
+
+    /// Counts a point in world coordinates, if it lies within the grid.
+    pub fn add(&mut self, pos: egui::Pos2) {
+        if let Some(index) = self.cell_index(pos) {
+            self.counts[index] += 1;
+        }
+    }
+
+    /// Number of points in the cell at the given position in world coordinates.
+    pub fn count_at(&self, pos: egui::Pos2) -> Option<u32> {
+        self.cell_index(pos).map(|index| self.counts[index])
+    }
+
+    fn max_count(&self) -> u32 {
+        self.counts.iter().copied().max().unwrap_or(0)
+    }
+
+    /// The counts, colormapped on a logarithmic scale, as a rectangle covering the grid.
+    ///
+    /// Empty cells are transparent.
+    pub fn textured_rect(
+        &self,
+        render_ctx: &re_renderer::RenderContext,
+        depth_offset: re_renderer::DepthOffset,
+    ) -> Option<TexturedRect> {
+        re_tracing::profile_function!();
+
+        let max_count = self.max_count();
+        if max_count == 0 {
+            return None;
+        }
+
+        let [width, height] = self.resolution;
+        let texture_key = egui::util::hash(("point_density", self.resolution, &self.counts));
+        let texture = gpu_bridge::get_or_create_texture(render_ctx, texture_key, || {
+            let log_max_count = (max_count as f32).ln_1p();
+            let data = self
+                .counts
+                .iter()
+                .flat_map(|&count| {
+                    if count == 0 {
+                        [0, 0, 0, 0]
+                    } else {
+                        re_renderer::colormap_viridis_srgb((count as f32).ln_1p() / log_max_count)
+                    }
+                })
+                .collect::<Vec<u8>>();
+            re_renderer::resource_managers::Texture2DCreationDesc {
+                label: "point density".into(),
+                data: data.into(),
+                format: re_renderer::external::wgpu::TextureFormat::Rgba8UnormSrgb,
+                width,
+                height,
+            }
+        });
+        let texture = match texture {
+            Ok(texture) => texture,
+            Err(err) => {
+                re_log::error_once!("Failed to create point density texture: {err}");
+                return None;
+            }
+        };
+
+        Some(TexturedRect {
+            top_left_corner_position: glam::vec3(self.rect.min.x, self.rect.min.y, 0.0),
+            extent_u: glam::Vec3::X * self.rect.width(),
+            extent_v: glam::Vec3::Y * self.rect.height(),
+            colormapped_texture: ColormappedTexture::from_unorm_rgba(texture),
+            options: RectangleOptions {
+                texture_filter_magnification: TextureFilterMag::Nearest,
+                texture_filter_minification: TextureFilterMin::Linear,
+                multiplicative_tint: egui::Rgba::WHITE,
+                depth_offset,
+                outline_mask: re_renderer::OutlineMaskPreference::NONE,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PointDensityGrid;
+
+    #[test]
+    fn counts_points_per_cell() {
+        let visible_rect = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(10.0, 5.0));
+        // Cells are two pixels, i.e. one scene unit wide.
+        let mut grid = PointDensityGrid::new(visible_rect, 0.5).unwrap();
+        assert_eq!(grid.resolution, [10, 5]);
+
+        grid.add(egui::pos2(0.5, 0.5));
+        grid.add(egui::pos2(0.9, 0.1));
+        grid.add(egui::pos2(9.5, 4.5));
+        grid.add(egui::pos2(20.0, 0.0));
+
+        assert_eq!(grid.count_at(egui::pos2(0.2, 0.8)), Some(2));
+        assert_eq!(grid.count_at(egui::pos2(9.9, 4.9)), Some(1));
+        assert_eq!(grid.count_at(egui::pos2(5.0, 2.0)), Some(0));
+        assert_eq!(grid.count_at(egui::pos2(20.0, 0.0)), None);
+        assert_eq!(grid.max_count(), 2);
+
+        assert!(PointDensityGrid::new(visible_rect, 0.0).is_none());
+    }
+}
@@ -1,6 +1,9 @@
+use std::collections::VecDeque;
+
 use nohash_hasher::IntMap;
+use re_entity_db::InstancePathHash;
 use re_log_types::EntityPathHash;
-use re_viewer_context::VisualizerCollection;
+use re_viewer_context::{SpaceViewId, VisualizerCollection};
 
 use crate::visualizers::SpatialViewVisualizerData;
 
@@ -14,6 +17,15 @@ pub struct SceneBoundingBoxes {
 
     /// Per-entity bounding boxes for the current query.
     pub per_entity: IntMap<EntityPathHash, macaw::BoundingBox>,
+
+    /// Per-instance bounding boxes for the current query.
+    ///
+    /// Only visualizers that know about the extent of individual instances contribute to this.
+    /// 2D points only contribute while [`point_instances_requested`] is set for the view.
+    pub per_instance: Vec<(InstancePathHash, macaw::BoundingBox)>,
+
+    /// Bounding boxes of the most recent frames, used by [`BoundingBoxAccumulation::RollingWindow`].
+    recent: VecDeque<macaw::BoundingBox>,
 }
 
 impl Default for SceneBoundingBoxes {
@@ -22,16 +34,64 @@ impl Default for SceneBoundingBoxes {
             accumulated: macaw::BoundingBox::nothing(),
             current: macaw::BoundingBox::nothing(),
             per_entity: IntMap::default(),
+            per_instance: Vec::new(),
+            recent: VecDeque::new(),
         }
     }
 }
 
+fn point_instances_requested_id(space_view_id: SpaceViewId) -> egui::Id {
+    egui::Id::new(space_view_id).with("point_instances_requested")
+}
+
+/// Remember whether the 2D points of the given view should add per-instance bounding boxes in the next frame.
+pub fn remember_point_instances_requested(
+    egui_ctx: &egui::Context,
+    space_view_id: SpaceViewId,
+    requested: bool,
+) {
+    egui_ctx
+        .data_mut(|data| data.insert_temp(point_instances_requested_id(space_view_id), requested));
+}
+
+/// Whether the 2D points of the given view should add per-instance bounding boxes.
+///
+/// Views can hold a lot of points, so their bounding boxes are only collected while a feature
+/// working on individual instances may be used.
+pub fn point_instances_requested(egui_ctx: &egui::Context, space_view_id: SpaceViewId) -> bool {
+    egui_ctx
+        .data(|data| data.get_temp::<bool>(point_instances_requested_id(space_view_id)))
+        .unwrap_or(false)
+}
+
+/// How [`SceneBoundingBoxes::accumulated`] follows the bounding box of the current query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum BoundingBoxAccumulation {
+    /// Grow to include everything that was ever shown, keeping the camera stable.
+    Union,
+
+    /// Fit whatever is shown right now, so removed or shrinking objects no longer take up space.
+    ///
+    /// If nothing is shown, the previous bounding box is kept.
+    Current,
+
+    /// Fit everything that was shown during the last `frames` frames.
+    ///
+    /// Unlike [`Self::Union`], outliers in live data stop affecting the fit once they are old enough.
+    RollingWindow { frames: usize },
+}
+
 impl SceneBoundingBoxes {
-    pub fn update(&mut self, visualizers: &VisualizerCollection) {
+    pub fn update(
+        &mut self,
+        visualizers: &VisualizerCollection,
+        accumulation: BoundingBoxAccumulation,
+    ) {
         re_tracing::profile_function!();
 
         self.current = macaw::BoundingBox::nothing();
         self.per_entity.clear();
+        self.per_instance.clear();
 
         for visualizer in visualizers.iter() {
             if let Some(data) = visualizer
@@ -44,6 +104,8 @@ impl SceneBoundingBoxes {
                         .and_modify(|bbox_entry| *bbox_entry = bbox_entry.union(*bbox))
                         .or_insert(*bbox);
                 }
+                self.per_instance
+                    .extend(data.instance_bounding_boxes.iter().copied());
             }
         }
 
@@ -51,10 +113,101 @@ impl SceneBoundingBoxes {
             self.current = self.current.union(*bbox);
         }
 
+        self.accumulate(accumulation);
+    }
+
+    fn accumulate(&mut self, accumulation: BoundingBoxAccumulation) {
+        let current_is_valid = !self.current.is_nothing() && self.current.size().is_finite();
+
+        if let BoundingBoxAccumulation::RollingWindow { frames } = accumulation {
+            if current_is_valid {
+                self.recent.push_back(self.current);
+            }
+            while self.recent.len() > frames.max(1) {
+                self.recent.pop_front();
+            }
+        } else {
+            self.recent.clear();
+        }
+
         if self.accumulated.is_nothing() || !self.accumulated.size().is_finite() {
             self.accumulated = self.current;
-        } else {
-            self.accumulated = self.accumulated.union(self.current);
+            return;
+        }
+
+        match accumulation {
+            BoundingBoxAccumulation::Union => {
+                self.accumulated = self.accumulated.union(self.current);
+            }
+            BoundingBoxAccumulation::Current => {
+                if current_is_valid {
+                    self.accumulated = self.current;
+                }
+            }
+            BoundingBoxAccumulation::RollingWindow { .. } => {
+                if !self.recent.is_empty() {
+                    self.accumulated = self
+                        .recent
+                        .iter()
+                        .fold(macaw::BoundingBox::nothing(), |acc, bbox| acc.union(*bbox));
+                }
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use super::*;
+
+    fn bbox(max: f32) -> macaw::BoundingBox {
+        macaw::BoundingBox::from_min_max(Vec3::ZERO, Vec3::splat(max))
+    }
+
+    fn accumulate_all(
+        currents: &[macaw::BoundingBox],
+        accumulation: BoundingBoxAccumulation,
+    ) -> macaw::BoundingBox {
+        let mut bounding_boxes = SceneBoundingBoxes::default();
+        for current in currents {
+            bounding_boxes.current = *current;
+            bounding_boxes.accumulate(accumulation);
+        }
+        bounding_boxes.accumulated
+    }
+
+    #[test]
+    fn shrinking_scene_refits() {
+        let accumulated =
+            accumulate_all(&[bbox(100.0), bbox(10.0)], BoundingBoxAccumulation::Current);
+        assert_eq!(accumulated, bbox(10.0));
+    }
+
+    #[test]
+    fn empty_scene_keeps_previous_fit() {
+        let accumulated = accumulate_all(
+            &[bbox(10.0), macaw::BoundingBox::nothing()],
+            BoundingBoxAccumulation::Current,
+        );
+        assert_eq!(accumulated, bbox(10.0));
+    }
+
+    #[test]
+    fn rolling_window_forgets_old_frames() {
+        let rolling_window = BoundingBoxAccumulation::RollingWindow { frames: 2 };
+        let accumulated = accumulate_all(&[bbox(100.0), bbox(10.0), bbox(20.0)], rolling_window);
+        assert_eq!(accumulated, bbox(20.0));
+
+        let accumulated = accumulate_all(&[bbox(100.0), bbox(10.0)], rolling_window);
+        assert_eq!(accumulated, bbox(100.0));
+    }
+
+    #[test]
+    fn union_never_shrinks() {
+        let accumulated =
+            accumulate_all(&[bbox(100.0), bbox(10.0)], BoundingBoxAccumulation::Union);
+        assert_eq!(accumulated, bbox(100.0));
+    }
+}
@@ -0,0 +1,97 @@
+use re_viewer_context::{ViewContextCollection, VisualizerCollection};
+
+use crate::{
+    contexts::SharedRenderBuilders,
+    visualizers::{
+        Arrows2DVisualizer, Boxes2DVisualizer, ImageVisualizer, Lines2DVisualizer,
+        Points2DVisualizer,
+    },
+};
+
+/// Number of objects of each type in the scene of the current frame.
+#[derive(Clone, Default)]
+pub struct SceneStats {
+    pub num_images: usize,
+    pub num_boxes: usize,
+    pub num_line_strips: usize,
+    pub num_arrows: usize,
+    pub num_points: usize,
+
+    /// Number of boxes, line strips & points that weren't drawn because they were outside of the visible region.
+    pub num_culled: usize,
+
+    /// Number of point vertices handed to the renderer.
+    pub num_point_vertices: usize,
+
+    /// Number of line vertices handed to the renderer.
+    pub num_line_vertices: usize,
+}
+
+impl SceneStats {
+    /// All counts along with their labels.
+    pub fn counts(&self) -> [(&'static str, usize); 8] {
+        [
+            ("Images", self.num_images),
+            ("Boxes", self.num_boxes),
+            ("Line strips", self.num_line_strips),
+            ("Arrows", self.num_arrows),
+            ("Points", self.num_points),
+            ("Culled", self.num_culled),
+            ("Point vertices", self.num_point_vertices),
+            ("Line vertices", self.num_line_vertices),
+        ]
+    }
+
+    /// Must be called before the shared render builders are turned into draw data.
+    pub fn update(&mut self, visualizers: &VisualizerCollection, view_ctx: &ViewContextCollection) {
+        re_tracing::profile_function!();
+
+        self.num_images = visualizers
+            .get::<ImageVisualizer>()
+            .map_or(0, |images| images.images.len());
+        self.num_boxes = visualizers
+            .get::<Boxes2DVisualizer>()
+            .map_or(0, |boxes| boxes.data.instance_bounding_boxes.len());
+        self.num_line_strips = visualizers
+            .get::<Lines2DVisualizer>()
+            .map_or(0, |lines| lines.data.instance_bounding_boxes.len());
+        self.num_arrows = visualizers
+            .get::<Arrows2DVisualizer>()
+            .map_or(0, |arrows| arrows.data.instance_bounding_boxes.len());
+        self.num_points = visualizers
+            .get::<Points2DVisualizer>()
+            .map_or(0, |points| points.num_points);
+
+        self.num_culled = [
+            visualizers
+                .get::<Boxes2DVisualizer>()
+                .map(|boxes| &boxes.data),
+            visualizers
+                .get::<Lines2DVisualizer>()
+                .map(|lines| &lines.data),
+            visualizers
+                .get::<Points2DVisualizer>()
+                .map(|points| &points.data),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|data| data.num_culled)
+        .sum();
+
+        let builders = view_ctx.get::<SharedRenderBuilders>().ok();
+        self.num_point_vertices = builders.map_or(0, |builders| {
+            builders
+                .points
+                .lock()
+                .as_ref()
+                .map_or(0, |points| points.vertices.len())
+        });
+        self.num_line_vertices = builders.map_or(0, |builders| {
+            builders
+                .lines
+                .lock()
+                .as_ref()
+                .map_or(0, |lines| lines.vertices.len())
+        });
+    }
+}
@@ -4,6 +4,7 @@ use macaw::IsoTransform;
 use re_log_types::EntityPath;
 use re_types::archetypes::Pinhole;
 use re_types::components::ViewCoordinates;
+use re_viewer_context::ProjectedRay;
 
 use crate::visualizers::image_view_coordinates;
 
@@ -76,4 +77,90 @@ impl SpaceCamera3D {
         let point_in_image = pinhole.project(point_in_image_unprojected);
         Some(point_in_image)
     }
+
+    /// Projects a ray in world space onto the image plane, i.e. returns its epipolar line.
+    ///
+    /// The line is returned as two distinct points on it in image/pixel coordinates.
+    /// Unlike the ray, the line extends infinitely in both directions.
+    /// Returns `None` if the camera has no pinhole or the ray passes through the camera origin.
+    pub fn project_ray_onto_2d(&self, ray_in_world: macaw::Ray3) -> Option<[glam::Vec2; 2]> {
+        let pinhole = self.pinhole.as_ref()?;
+        let image_from_world = glam::Mat3::from(pinhole.image_from_camera.0)
+            * image_view_coordinates().from_other(&self.pinhole_view_coordinates);
+        let cam_from_world = self.cam_from_world();
+
+        // Homogeneous image coordinates of two points on the ray, not divided by depth
+        // so that points behind the camera still lie on the line.
+        let [a, b] = [ray_in_world.origin, ray_in_world.origin + ray_in_world.dir]
+            .map(|point| image_from_world * cam_from_world.transform_point3(point));
+
+        // The line through two points in homogeneous coordinates: `line.dot(point) == 0`.
+        let line = a.cross(b);
+        let normal = line.truncate();
+        let normal_length_sq = normal.length_squared();
+        if normal_length_sq <= f32::EPSILON * line.length_squared() {
+            return None;
+        }
+        let point_on_line = -line.z * normal / normal_length_sq;
+        Some([point_on_line, point_on_line + normal.perp()])
+    }
+
+    /// Projects a ray in world space onto the image plane, keeping only the part in front of the camera.
+    ///
+    /// Returns `None` if the camera has no pinhole or the ray is entirely behind the camera.
+    pub fn project_ray_segment_onto_2d(&self, ray_in_world: macaw::Ray3) -> Option<ProjectedRay> {
+        let pinhole = self.pinhole.as_ref()?;
+        let image_from_cam = glam::Mat3::from(pinhole.image_from_camera.0);
+        let image_view_from_cam =
+            image_view_coordinates().from_other(&self.pinhole_view_coordinates);
+        let cam_from_world = self.cam_from_world();
+
+        // Ray in image view coordinates, i.e. z is the depth in front of the camera.
+        let origin = image_view_from_cam * cam_from_world.transform_point3(ray_in_world.origin);
+        let dir = image_view_from_cam * cam_from_world.transform_vector3(ray_in_world.dir);
+
+        // Points too close to (or behind) the camera plane don't have a meaningful projection.
+        let near = self.picture_plane_distance * 1e-3;
+        let project = |point: Vec3| {
+            let point = image_from_cam * point;
+            point.truncate() / point.z
+        };
+
+        // Range of `t >= 0` for which `origin + t * dir` is in front of the near plane.
+        let (t_start, t_end) = if dir.z.abs() <= f32::EPSILON {
+            if origin.z <= near {
+                return None;
+            }
+            (0.0, f32::INFINITY)
+        } else {
+            let t_near = (near - origin.z) / dir.z;
+            if dir.z > 0.0 {
+                (t_near.max(0.0), f32::INFINITY)
+            } else if t_near <= 0.0 {
+                return None;
+            } else {
+                (0.0, t_near)
+            }
+        };
+
+        let start = origin + t_start * dir;
+        let (end, end_inv_depth) = if t_end.is_finite() {
+            (project(origin + t_end * dir), near.recip())
+        } else if dir.z > f32::EPSILON {
+            // All parallel lines meet at the vanishing point of their direction.
+            (project(dir), 0.0)
+        } else {
+            // Parallel to the image plane: the ray goes on forever in its image space direction.
+            let dir_in_image = (image_from_cam * dir).truncate().normalize_or_zero();
+            (project(start) + dir_in_image * 1e6, start.z.recip())
+        };
+        let (start, start_inv_depth) = (project(start), start.z.recip());
+
+        (start.is_finite() && end.is_finite() && start != end).then_some(ProjectedRay {
+            start,
+            end,
+            start_inv_depth,
+            end_inv_depth,
+        })
+    }
 }
@@ -246,7 +246,13 @@ impl SpaceViewClass for SpatialSpaceView2D {
     ) -> Result<(), SpaceViewSystemExecutionError> {
         re_tracing::profile_function!();
 
-        state.bounding_boxes.update(&system_output.view_systems);
+        state.bounding_boxes.update(
+            &system_output.view_systems,
+            state.state_2d.bbox_accumulation,
+        );
+        state
+            .scene_stats
+            .update(&system_output.view_systems, &system_output.context_systems);
         state.scene_num_primitives = system_output
             .context_systems
             .get::<PrimitiveCounter>()?
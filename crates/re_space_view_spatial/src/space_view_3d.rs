@@ -14,6 +14,7 @@ use crate::{
         default_visualized_entities_for_visualizer_kind, root_space_split_heuristic,
         update_object_property_heuristics,
     },
+    scene_bounding_boxes::BoundingBoxAccumulation,
     spatial_topology::{SpatialTopology, SubSpaceDimensionality},
     ui::SpatialSpaceViewState,
     view_kind::SpatialSpaceViewKind,
@@ -238,7 +239,9 @@ impl SpaceViewClass for SpatialSpaceView3D {
     ) -> Result<(), SpaceViewSystemExecutionError> {
         re_tracing::profile_function!();
 
-        state.bounding_boxes.update(&system_output.view_systems);
+        state
+            .bounding_boxes
+            .update(&system_output.view_systems, BoundingBoxAccumulation::Union);
         state.scene_num_primitives = system_output
             .context_systems
             .get::<PrimitiveCounter>()?
@@ -0,0 +1,107 @@
+use re_log_types::RowId;
+use re_viewer_context::Cache;
+use resvg::usvg::{self, TreeParsing as _};
+
+// ----------------------------------------------------------------------------
+
+/// Largest width or height SVG documents are rasterized at, in pixels.
+const MAX_RASTER_SIZE: f32 = 4096.0;
+
+/// Caches the size of SVG documents, keyed on the [`RowId`] of their blob.
+///
+/// Parsed documents can't be shared between threads, so only their size is kept.
+/// The rasterized documents are cached by the texture manager, keyed on the blob and resolution.
+#[derive(Default)]
+pub struct SvgCache(ahash::HashMap<RowId, Option<glam::Vec2>>);
+
+impl SvgCache {
+    /// Returns the size of the document in SVG user units, or `None` if it can't be parsed.
+    ///
+    /// The key should be the `RowId` of the `Blob`.
+    pub fn size(&mut self, key: RowId, svg: &[u8]) -> Option<glam::Vec2> {
+        *self.0.entry(key).or_insert_with(|| match parse_svg(svg) {
+            Ok(tree) => Some(glam::vec2(tree.size.width(), tree.size.height())),
+            Err(err) => {
+                re_log::warn_once!("Failed to parse SVG document: {err}");
+                None
+            }
+        })
+    }
+}
+
+impl Cache for SvgCache {
+    fn begin_frame(&mut self) {}
+
+    fn purge_memory(&mut self) {
+        self.0.clear();
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+fn parse_svg(svg: &[u8]) -> Result<usvg::Tree, usvg::Error> {
+    re_tracing::profile_function!();
+    usvg::Tree::from_data(svg, &usvg::Options::default())
+}
+
+/// Resolution to rasterize an SVG document of the given size at, if it is shown with
+/// `pixels_per_unit` device pixels per SVG user unit.
+///
+/// The scale is rounded up to the next power of two, so that zooming doesn't rasterize the
+/// document again every frame.
+pub fn raster_resolution(size: glam::Vec2, pixels_per_unit: f32) -> Option<[u32; 2]> {
+    if !(size.x > 0.0 && size.y > 0.0) {
+        return None;
+    }
+    let pixels_per_unit = if pixels_per_unit.is_finite() && pixels_per_unit > 0.0 {
+        pixels_per_unit
+    } else {
+        1.0
+    };
+    let scale = 2.0_f32
+        .powf(pixels_per_unit.log2().ceil())
+        .min(MAX_RASTER_SIZE / size.max_element());
+    let resolution = (size * scale).ceil().max(glam::Vec2::ONE);
+    Some([resolution.x as u32, resolution.y as u32])
+}
+
+/// Rasterizes an SVG document to tightly packed, unmultiplied RGBA pixels.
+pub fn rasterize_svg(svg: &[u8], [width, height]: [u32; 2]) -> anyhow::Result<Vec<u8>> {
+    re_tracing::profile_function!();
+
+    let tree = parse_svg(svg)?;
+    let mut pixmap = resvg::tiny_skia::Pixmap::new(width, height)
+        .ok_or_else(|| anyhow::anyhow!("Invalid SVG raster resolution {width}x{height}"))?;
+    let transform = resvg::tiny_skia::Transform::from_scale(
+        width as f32 / tree.size.width(),
+        height as f32 / tree.size.height(),
+    );
+    resvg::Tree::from_usvg(&tree).render(transform, &mut pixmap.as_mut());
+
+    // tiny-skia works with premultiplied alpha.
+    Ok(pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::raster_resolution;
+
+    #[test]
+    fn raster_resolution_is_quantized() {
+        let size = glam::vec2(128.0, 64.0);
+        assert_eq!(raster_resolution(size, 1.0), Some([128, 64]));
+        assert_eq!(raster_resolution(size, 1.5), Some([256, 128]));
+        assert_eq!(raster_resolution(size, 0.3), Some([64, 32]));
+        assert_eq!(raster_resolution(size, 1000.0), Some([4096, 2048]));
+        assert_eq!(raster_resolution(glam::Vec2::ZERO, 1.0), None);
+    }
+}
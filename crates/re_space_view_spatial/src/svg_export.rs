@@ -0,0 +1,312 @@
+//! Export of the 2D scene as an SVG document.
+//!
+//! Only what can be represented as vectors ends up in the document:
+//! lines, points and labels. Images, meshes and filled rectangles are skipped,
+//! and the background is left transparent.
+
+use std::fmt::Write as _;
+
+use egui::Rect;
+use re_renderer::{AutoSizeConfig, Color32, LineStripSeriesBuilder, PointCloudBuilder, Size};
+
+use crate::visualizers::{UiLabel, UiLabelTarget};
+
+/// Factor [`Size::AUTO_LARGE`] is larger than [`Size::AUTO`], see `size.wgsl`.
+const AUTO_LARGE_FACTOR: f32 = 1.33;
+
+/// Font size of labels in ui points, matching the body text style.
+const LABEL_FONT_SIZE_IN_POINTS: f32 = 14.0;
+
+/// Everything needed to place the scene on the SVG canvas.
+pub struct SvgExportSettings {
+    /// Region of the scene that becomes the canvas of the document, in scene coordinates.
+    pub scene_rect: Rect,
+
+    /// How many scene units one ui point covers in the view the export is taken from.
+    ///
+    /// Used to resolve sizes given in ui points.
+    pub scene_units_per_point: f32,
+
+    /// Sizes used for [`Size::AUTO`].
+    pub auto_size_config: AutoSizeConfig,
+}
+
+/// A single element of the document along with the depth offset it is sorted by.
+struct Element {
+    depth_offset: i16,
+    svg: String,
+}
+
+/// Writes lines, points and labels of a 2D scene into an SVG document.
+///
+/// The document uses scene coordinates, i.e. the `viewBox` is the exported region of the scene.
+pub fn scene_to_svg(
+    lines: Option<&LineStripSeriesBuilder>,
+    points: Option<&PointCloudBuilder>,
+    labels: &[UiLabel],
+    settings: &SvgExportSettings,
+) -> String {
+    re_tracing::profile_function!();
+
+    let mut elements = Vec::new();
+    if let Some(lines) = lines {
+        line_elements(lines, settings, &mut elements);
+    }
+    if let Some(points) = points {
+        point_elements(points, settings, &mut elements);
+    }
+
+    // Stable sort, so lines stay below points with the same depth offset just like in the view.
+    elements.sort_by_key(|element| element.depth_offset);
+
+    let rect = settings.scene_rect;
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="{}" height="{}">"#,
+        rect.min.x,
+        rect.min.y,
+        rect.width(),
+        rect.height(),
+        rect.width() / settings.scene_units_per_point,
+        rect.height() / settings.scene_units_per_point,
+    )
+    .ok();
+    for element in elements {
+        svg.push_str(&element.svg);
+        svg.push('\n');
+    }
+    for label in labels {
+        if let Some(text) = label_element(label, settings) {
+            svg.push_str(&text);
+            svg.push('\n');
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn line_elements(
+    lines: &LineStripSeriesBuilder,
+    settings: &SvgExportSettings,
+    elements: &mut Vec<Element>,
+) {
+    for (batch, vertices) in lines.iter_vertices_by_batch() {
+        let mut vertices = vertices.peekable();
+        while let Some(first) = vertices.next() {
+            let strip_index = first.strip_index;
+            let mut positions = vec![batch.world_from_obj.transform_point3(first.position)];
+            while let Some(vertex) = vertices.next_if(|v| v.strip_index == strip_index) {
+                positions.push(batch.world_from_obj.transform_point3(vertex.position));
+            }
+
+            let Some(strip) = lines.strips.get(strip_index as usize) else {
+                continue;
+            };
+            let radius = resolve_size(
+                strip.radius,
+                settings.auto_size_config.line_radius,
+                settings,
+            );
+
+            let mut svg = String::from(r#"<polyline points=""#);
+            for (i, pos) in positions.iter().enumerate() {
+                if i > 0 {
+                    svg.push(' ');
+                }
+                write!(svg, "{},{}", pos.x, pos.y).ok();
+            }
+            write!(
+                svg,
+                r#"" fill="none" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round" {}/>"#,
+                2.0 * radius,
+                paint_attributes("stroke", strip.color),
+            )
+            .ok();
+
+            elements.push(Element {
+                depth_offset: batch.depth_offset,
+                svg,
+            });
+        }
+    }
+}
+
+fn point_elements(
+    points: &PointCloudBuilder,
+    settings: &SvgExportSettings,
+    elements: &mut Vec<Element>,
+) {
+    for (batch, vertices) in points.iter_vertices_and_colors_by_batch() {
+        let mut svg = String::new();
+        for (vertex, color) in vertices {
+            let pos = batch.world_from_obj.transform_point3(vertex.pos);
+            let radius = resolve_size(
+                vertex.radius,
+                settings.auto_size_config.point_radius,
+                settings,
+            );
+            if !svg.is_empty() {
+                svg.push('\n');
+            }
+            write!(
+                svg,
+                r#"<circle cx="{}" cy="{}" r="{}" {}/>"#,
+                pos.x,
+                pos.y,
+                radius,
+                paint_attributes("fill", *color),
+            )
+            .ok();
+        }
+        if !svg.is_empty() {
+            elements.push(Element {
+                depth_offset: batch.depth_offset,
+                svg,
+            });
+        }
+    }
+}
+
+fn label_element(label: &UiLabel, settings: &SvgExportSettings) -> Option<String> {
+    // Same placement as the labels drawn by egui: centered below the labeled shape.
+    let offset = egui::vec2(0.0, 3.0 * settings.scene_units_per_point);
+    let (anchor, align, font_size) = match label.target {
+        UiLabelTarget::Rect(rect) => (
+            rect.center_bottom() + offset,
+            egui::Align2::CENTER_TOP,
+            None,
+        ),
+        UiLabelTarget::Point2D(pos) => (pos + offset, egui::Align2::CENTER_TOP, None),
+        UiLabelTarget::Text2D {
+            pos,
+            anchor,
+            font_size,
+            // SVG text is never wrapped.
+            wrap_width: _,
+        } => (pos, anchor, font_size),
+        UiLabelTarget::Position3D(_) => return None,
+    };
+    let text_anchor = match align.x() {
+        egui::Align::Min => "start",
+        egui::Align::Center => "middle",
+        egui::Align::Max => "end",
+    };
+    let dominant_baseline = match align.y() {
+        egui::Align::Min => "hanging",
+        egui::Align::Center => "middle",
+        egui::Align::Max => "text-after-edge",
+    };
+
+    Some(format!(
+        r#"<text x="{}" y="{}" font-family="sans-serif" font-size="{}" text-anchor="{text_anchor}" dominant-baseline="{dominant_baseline}" {}>{}</text>"#,
+        anchor.x,
+        anchor.y,
+        font_size.unwrap_or(LABEL_FONT_SIZE_IN_POINTS) * settings.scene_units_per_point,
+        paint_attributes("fill", label.color),
+        escape_xml(&label.text),
+    ))
+}
+
+/// Resolves a [`Size`] to a radius in scene units.
+fn resolve_size(size: Size, auto_size: Size, settings: &SvgExportSettings) -> f32 {
+    let (size, factor) = if size == Size::AUTO_LARGE {
+        (auto_size, AUTO_LARGE_FACTOR)
+    } else if size.is_auto() {
+        (auto_size, 1.0)
+    } else {
+        (size, 1.0)
+    };
+
+    let radius = if let Some(scene) = size.scene() {
+        scene
+    } else if let Some(points) = size.points() {
+        points * settings.scene_units_per_point
+    } else {
+        // Auto size without a configured auto size, re_renderer picks something small as well.
+        settings.scene_units_per_point
+    };
+    radius * factor
+}
+
+/// `fill`/`stroke` attribute plus the matching opacity attribute for translucent colors.
+fn paint_attributes(attribute: &str, color: Color32) -> String {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    if a == 255 {
+        format!(r##"{attribute}="#{r:02x}{g:02x}{b:02x}""##)
+    } else {
+        format!(
+            r##"{attribute}="#{r:02x}{g:02x}{b:02x}" {attribute}-opacity="{}""##,
+            a as f32 / 255.0
+        )
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> SvgExportSettings {
+        SvgExportSettings {
+            scene_rect: Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(100.0, 50.0)),
+            scene_units_per_point: 0.5,
+            auto_size_config: AutoSizeConfig {
+                point_radius: Size::new_points(2.0),
+                line_radius: Size::new_scene(3.0),
+            },
+        }
+    }
+
+    #[test]
+    fn resolves_sizes_to_scene_units() {
+        let settings = settings();
+        let auto_points = settings.auto_size_config.point_radius;
+        let auto_lines = settings.auto_size_config.line_radius;
+
+        assert_eq!(
+            resolve_size(Size::new_scene(4.0), auto_points, &settings),
+            4.0
+        );
+        assert_eq!(
+            resolve_size(Size::new_points(4.0), auto_points, &settings),
+            2.0
+        );
+        assert_eq!(resolve_size(Size::AUTO, auto_points, &settings), 1.0);
+        assert_eq!(resolve_size(Size::AUTO, auto_lines, &settings), 3.0);
+        assert_eq!(
+            resolve_size(Size::AUTO_LARGE, auto_lines, &settings),
+            3.0 * AUTO_LARGE_FACTOR
+        );
+    }
+
+    #[test]
+    fn labels_are_escaped() {
+        let label = UiLabel {
+            text: "a < b & \"c\"".to_owned(),
+            color: egui::Color32::WHITE,
+            target: UiLabelTarget::Point2D(egui::pos2(10.0, 20.0)),
+            labeled_instance: Default::default(),
+        };
+        let svg = scene_to_svg(None, None, &[label], &settings());
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains(r#"viewBox="0 0 100 50""#));
+        assert!(svg.contains("a &lt; b &amp; &quot;c&quot;"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+}
@@ -0,0 +1,240 @@
+//! Trails following the points and boxes of a 2D view through the previous frames they were logged at.
+//!
+//! Instances are matched across frames by their [`InstancePathHash`]. A trail ends at the first
+//! previous frame the instance doesn't appear in.
+
+use std::collections::{BTreeSet, VecDeque};
+
+use ahash::HashMap;
+
+use re_data_store::{DataStore, LatestAtQuery};
+use re_entity_db::{EntityPath, EntityTree, InstancePathHash};
+use re_log_types::{TimeInt, Timeline};
+use re_renderer::{Color32, LineStripSeriesBuilder, Size};
+use re_types::{
+    archetypes::{Boxes2D, Points2D},
+    components::{HalfSizes2D, Position2D},
+    Loggable as _,
+};
+use re_viewer_context::{IdentifiedViewSystem as _, ViewQuery};
+
+use crate::{
+    contexts::TransformContext,
+    visualizers::{Boxes2DVisualizer, Points2DVisualizer},
+};
+
+/// Color of the trails, faded out towards their old end.
+pub const TRAIL_COLOR: Color32 = Color32::from_rgb(255, 200, 60);
+
+/// Largest number of previous frames a trail can reach back.
+///
+/// Every frame of a trail means another query of all points and boxes of the view.
+pub const MAX_TRAIL_LENGTH: usize = 100;
+
+/// Centers of a point or box in entity space, from the current frame back to the oldest one.
+pub struct Trail {
+    pub entity_path: EntityPath,
+    pub centers: Vec<glam::Vec2>,
+}
+
+/// Collects the trails of all points and boxes of the view over at most `length` previous frames.
+pub fn trails(
+    store: &DataStore,
+    tree: &EntityTree,
+    query: &ViewQuery<'_>,
+    length: usize,
+) -> Vec<Trail> {
+    re_tracing::profile_function!();
+
+    let length = length.min(MAX_TRAIL_LENGTH);
+    if length == 0 {
+        return Vec::new();
+    }
+
+    let entity_paths = query
+        .iter_visible_data_results(Points2DVisualizer::identifier())
+        .chain(query.iter_visible_data_results(Boxes2DVisualizer::identifier()))
+        .map(|data_result| &data_result.entity_path)
+        .collect::<BTreeSet<_>>();
+
+    let mut trails = Vec::new();
+    for entity_path in entity_paths {
+        let frames = previous_frames(tree, entity_path, query.timeline, query.latest_at, length)
+            .into_iter()
+            .map(|time| {
+                centers_at(
+                    store,
+                    &LatestAtQuery::new(query.timeline, time),
+                    entity_path,
+                )
+            })
+            .collect::<Vec<_>>();
+        trails.extend(
+            trails_through_frames(&frames)
+                .into_iter()
+                .map(|centers| Trail {
+                    entity_path: entity_path.clone(),
+                    centers,
+                }),
+        );
+    }
+    trails
+}
+
+/// The times of the current and up to `length` previous frames the entity's points or boxes were logged at, newest first.
+fn previous_frames(
+    tree: &EntityTree,
+    entity_path: &EntityPath,
+    timeline: Timeline,
+    latest_at: TimeInt,
+    length: usize,
+) -> Vec<TimeInt> {
+    let Some(subtree) = tree.subtree(entity_path) else {
+        return Vec::new();
+    };
+
+    // Only the newest times are kept while going through all times up to the current one.
+    let mut times = BTreeSet::new();
+    for component_name in [Position2D::name(), HalfSizes2D::name()] {
+        let Some(histogram) = subtree.time_histogram_for_component(&timeline, component_name)
+        else {
+            continue;
+        };
+        let mut newest = VecDeque::with_capacity(length + 1);
+        for (range, _) in histogram.range(..=latest_at.as_i64(), 1) {
+            if newest.len() > length {
+                newest.pop_front();
+            }
+            newest.push_back(range.min);
+        }
+        times.extend(newest);
+    }
+
+    times
+        .into_iter()
+        .rev()
+        .take(length + 1)
+        .map(TimeInt::from)
+        .collect()
+}
+
+/// Centers of all points and boxes logged to `entity_path` as of `query`, by instance.
+fn centers_at(
+    store: &DataStore,
+    query: &LatestAtQuery,
+    entity_path: &EntityPath,
+) -> HashMap<InstancePathHash, glam::Vec2> {
+    let mut centers = HashMap::default();
+
+    if let Ok(points) = re_query::query_archetype::<Points2D>(store, query, entity_path) {
+        if let Ok(positions) = points.iter_required_component::<Position2D>() {
+            for (instance_key, position) in points.iter_instance_keys().zip(positions) {
+                centers.insert(
+                    InstancePathHash::instance(entity_path, instance_key),
+                    position.into(),
+                );
+            }
+        }
+    }
+
+    if let Ok(boxes) = re_query::query_archetype::<Boxes2D>(store, query, entity_path) {
+        if let Ok(box_centers) = boxes.iter_optional_component::<Position2D>() {
+            for (instance_key, center) in boxes.iter_instance_keys().zip(box_centers) {
+                centers.insert(
+                    InstancePathHash::instance(entity_path, instance_key),
+                    center.unwrap_or(Position2D::ZERO).into(),
+                );
+            }
+        }
+    }
+
+    centers
+}
+
+/// Follows every instance of the first frame back through the following frames, for as long as it appears in them.
+///
+/// Instances that only appear in the first frame have no trail.
+fn trails_through_frames<T: Copy>(frames: &[HashMap<InstancePathHash, T>]) -> Vec<Vec<T>> {
+    let Some((current, previous)) = frames.split_first() else {
+        return Vec::new();
+    };
+
+    current
+        .iter()
+        .filter_map(|(instance, center)| {
+            let trail = std::iter::once(*center)
+                .chain(
+                    previous
+                        .iter()
+                        .map_while(|frame| frame.get(instance).copied()),
+                )
+                .collect::<Vec<_>>();
+            (trail.len() > 1).then_some(trail)
+        })
+        .collect()
+}
+
+/// Draws all trails on top of the scene, fading out towards their old end.
+pub fn add_trails_to_builders(
+    trails: &[Trail],
+    transforms: &TransformContext,
+    line_builder: &mut LineStripSeriesBuilder,
+) {
+    re_tracing::profile_function!();
+
+    for trail in trails {
+        // Entities that are no longer part of the view's transform tree can't be placed.
+        let Some(world_from_entity) = transforms.reference_from_entity(&trail.entity_path) else {
+            continue;
+        };
+
+        let mut line_batch = line_builder
+            .batch("trails")
+            .depth_offset(re_renderer::DepthOffset::MAX)
+            .world_from_obj(world_from_entity);
+
+        // Each segment is drawn on its own, since colors can't change within a strip.
+        let num_segments = trail.centers.len() - 1;
+        for (age, (newer, older)) in trail
+            .centers
+            .iter()
+            .zip(trail.centers.iter().skip(1))
+            .enumerate()
+        {
+            let opacity = 1.0 - age as f32 / num_segments as f32;
+            line_batch
+                .add_segment_2d(*newer, *older)
+                .color(TRAIL_COLOR.gamma_multiply(opacity))
+                .radius(Size::AUTO);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::components::InstanceKey;
+
+    use super::*;
+
+    #[test]
+    fn trails_end_at_first_missing_frame() {
+        let entity_path = EntityPath::from("points");
+        let instance = |key| InstancePathHash::instance(&entity_path, InstanceKey(key));
+
+        let frames: Vec<HashMap<_, _>> = vec![
+            [(instance(0), 0), (instance(1), 1), (instance(2), 2)]
+                .into_iter()
+                .collect(),
+            [(instance(0), 10), (instance(2), 12)].into_iter().collect(),
+            [(instance(0), 20), (instance(1), 21), (instance(2), 22)]
+                .into_iter()
+                .collect(),
+        ];
+
+        let mut trails = trails_through_frames(&frames);
+        trails.sort();
+        assert_eq!(trails, vec![vec![0, 10, 20], vec![2, 12, 22]]);
+
+        assert!(trails_through_frames::<i32>(&[]).is_empty());
+    }
+}
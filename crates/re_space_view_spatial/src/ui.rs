@@ -1,28 +1,51 @@
-use egui::{epaint::util::OrderedFloat, text::TextWrapping, NumExt, WidgetText};
+use egui::{epaint::util::OrderedFloat, NumExt, WidgetText};
+use itertools::Itertools as _;
 use macaw::BoundingBox;
 
 use re_data_ui::{image_meaning_for_entity, item_ui, DataUi};
-use re_data_ui::{show_zoomed_image_region, show_zoomed_image_region_area_outline};
-use re_entity_db::EntityPath;
+use re_data_ui::{
+    show_zoomed_image_region, show_zoomed_image_region_area_outline, ZoomedImageRegionOptions,
+};
+use re_entity_db::{EntityPath, InstancePath};
 use re_format::format_f32;
 use re_renderer::OutlineConfig;
-use re_space_view::ScreenshotMode;
-use re_types::components::{DepthMeter, InstanceKey, TensorData};
+use re_space_view::{
+    controls::{
+        HIGHLIGHT_WHOLE_ENTITY_2D_MODIFIER, PIN_TOOLTIP_KEY, PIXEL_QUERY_2D_MODIFIER,
+        RADIUS_HOVER_2D_MODIFIER, TOGGLE_SELECTION_2D_MODIFIER,
+    },
+    ScreenshotMode,
+};
+use re_types::components::{DepthMeter, InstanceKey, LineStrip2D, TensorData, Tooltip};
 use re_types::tensor_data::TensorDataMeaning;
 use re_viewer_context::{
     HoverHighlight, Item, SelectedSpaceContext, SelectionHighlight, SpaceViewHighlights,
-    SpaceViewState, SpaceViewSystemExecutionError, TensorDecodeCache, TensorStatsCache,
-    UiVerbosity, ViewContextCollection, ViewQuery, ViewerContext, VisualizerCollection,
+    SpaceViewId, SpaceViewState, SpaceViewSystemExecutionError, TensorDecodeCache,
+    TensorStatsCache, UiVerbosity, ViewContextCollection, ViewQuery, ViewerContext,
+    VisualizerCollection,
 };
 
-use super::{eye::Eye, ui_2d::View2DState, ui_3d::View3DState};
+use super::{
+    eye::Eye,
+    ui_2d::{
+        LabelAppearance, LabelBackground, LabelStyle, PointerStatus, ProjectedPointMarker,
+        ProjectedPointShape, View2DState, ViewTransform2D,
+    },
+    ui_3d::View3DState,
+};
+use crate::class_region_cache::ClassRegionCache;
 use crate::heuristics::auto_size_world_heuristic;
-use crate::scene_bounding_boxes::SceneBoundingBoxes;
+use crate::label_galley_cache::LabelGalleyCache;
+use crate::scene_bounding_boxes::{BoundingBoxAccumulation, SceneBoundingBoxes};
+use crate::scene_stats::SceneStats;
 use crate::{
-    contexts::{AnnotationSceneContext, NonInteractiveEntities},
+    contexts::{AnnotationSceneContext, NonInteractiveEntities, TransformContext},
     picking::{PickableUiRect, PickingContext, PickingHitType, PickingResult},
     view_kind::SpatialSpaceViewKind,
-    visualizers::{CamerasVisualizer, ImageVisualizer, UiLabel, UiLabelTarget},
+    visualizers::{
+        CamerasVisualizer, ImageVisualizer, Polygons2DVisualizer, UiLabel, UiLabelTarget,
+        ViewerImage,
+    },
 };
 
 /// Default auto point radius in UI points.
@@ -31,6 +54,9 @@ const AUTO_POINT_RADIUS: f32 = 1.5;
 /// Default auto line radius in UI points.
 const AUTO_LINE_RADIUS: f32 = 1.5;
 
+/// Standalone texts with a wrap width are cut short after this many rows.
+const TEXT_BOX_MAX_ROWS: usize = 3;
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum AutoSizeUnit {
     Auto,
@@ -56,6 +82,9 @@ pub struct SpatialSpaceViewState {
     /// Estimated number of primitives last frame. Used to inform some heuristics.
     pub scene_num_primitives: usize,
 
+    /// Object counts of the last frame, only gathered for 2D views.
+    pub scene_stats: SceneStats,
+
     /// Last frame's picking result.
     pub previous_picking_result: Option<PickingResult>,
 
@@ -88,6 +117,23 @@ impl SpatialSpaceViewState {
         config
     }
 
+    /// Like [`Self::auto_size_config`], with line radii in ui points scaled by the line width scale of the 2D view.
+    pub fn auto_size_config_2d(&self) -> re_renderer::AutoSizeConfig {
+        let mut config = self.auto_size_config();
+        if let Some(points) = config.line_radius.points() {
+            config.line_radius =
+                re_renderer::Size::new_points(points * self.state_2d.line_width_scale);
+        }
+        config
+    }
+
+    /// How the 2D view mapped scene coordinates to the ui in its last frame.
+    ///
+    /// `None` for 3D views and views that weren't shown yet.
+    pub fn view_transform_2d(&self) -> Option<ViewTransform2D> {
+        self.state_2d.view_transform()
+    }
+
     pub fn selection_ui(
         &mut self,
         ctx: &ViewerContext<'_>,
@@ -152,6 +198,29 @@ impl SpatialSpaceViewState {
                         .on_hover_text("Spin camera around the orbit center").changed() {
                         self.state_3d.set_spin(spin);
                     }
+                } else {
+                    re_ui.checkbox(ui, &mut self.state_2d.animate_zoom_transitions, "Animate transitions")
+                        .on_hover_text("Smoothly move the camera when resetting or zooming to an object, instead of jumping");
+                    re_ui.checkbox(ui, &mut self.state_2d.follow_selection, "Follow selection")
+                        .on_hover_text("While zoomed in, keep the selected object in the center of the view");
+                    ui.horizontal(|ui| {
+                        re_ui.checkbox(ui, &mut self.state_2d.constrain_pan, "Stay near the scene")
+                            .on_hover_text("Prevent panning and zooming so far that only empty space is visible");
+                        ui.add_enabled(
+                            self.state_2d.constrain_pan,
+                            egui::DragValue::new(&mut self.state_2d.min_visible_scene_fraction)
+                                .clamp_range(0.0..=1.0)
+                                .speed(0.01)
+                                .custom_formatter(|fraction, _| format!("{:.0}%", fraction * 100.0))
+                                .custom_parser(|text| text.trim_end_matches('%').trim().parse::<f64>().ok().map(|percent| percent / 100.0)),
+                        )
+                        .on_hover_text("How much of the scene's width and height always stays in view");
+                    });
+                    re_ui.checkbox(ui, &mut self.state_2d.pixel_snap, "Snap zoom to pixels")
+                        .on_hover_text("When zoomed in, only show each scene unit as a whole number of screen pixels, keeping pixel boundaries crisp");
+                    bbox_accumulation_ui(ui, &mut self.state_2d.bbox_accumulation);
+                    fit_target_ui(ui, &mut self.state_2d);
+                    focus_rect_ui(ui, &mut self.state_2d);
                 }
             });
             ui.end_row();
@@ -180,6 +249,128 @@ impl SpatialSpaceViewState {
                 ui.end_row();
             }
 
+            if spatial_kind == SpatialSpaceViewKind::TwoD {
+                ctx.re_ui.grid_left_hand_label(ui, "Background");
+                ui.vertical(|ui| {
+                    re_ui.checkbox(ui, &mut self.state_2d.checkerboard_background, "Checkerboard")
+                        .on_hover_text("Draw a checkerboard behind the scene, making transparent parts of images visible");
+                    background_color_ui(ui, &mut self.state_2d.background_color);
+                });
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Line width");
+                ui.add(
+                    egui::DragValue::new(&mut self.state_2d.line_width_scale)
+                        .clamp_range(0.25..=8.0)
+                        .speed(0.01)
+                        .prefix("× "),
+                )
+                .on_hover_text("Scales the width of lines and box outlines without an explicit radius, and of selection outlines. Useful on high resolution displays");
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Cursor");
+                ui.vertical(|ui| {
+                    re_ui.checkbox(ui, &mut self.state_2d.show_snap_crosshair, "Show snap crosshair")
+                        .on_hover_text("Show a crosshair at the center of the hovered point or box");
+                    projection_rays_ui(re_ui, ui, &mut self.state_2d);
+                    re_ui.checkbox(ui, &mut self.state_2d.highlight_whole_entity, "Highlight whole entity")
+                        .on_hover_text("Hovering an instance highlights all instances of its entity");
+                    selection_outline_color_ui(ui, &mut self.state_2d.selection_outline_color);
+                });
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Labels");
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut self.state_2d.label_style, LabelStyle::Plain, "Plain")
+                        .on_hover_text("Show labels right below the points and boxes they label");
+                    ui.selectable_value(&mut self.state_2d.label_style, LabelStyle::Callout, "Callout")
+                        .on_hover_text("Move labels a bit away and point a line from each label to the center of its point or box");
+                });
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Label background");
+                label_background_ui(ui, &mut self.state_2d.label_background);
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Label text");
+                label_text_color_ui(ui, &mut self.state_2d.label_text_color);
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Projected point")
+                    .on_hover_text("How the point hovered in a 3D view is marked in this view");
+                projected_point_marker_ui(ui, &mut self.state_2d.projected_point_marker);
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Scale bar");
+                ui.vertical(|ui| {
+                    re_ui.checkbox(ui, &mut self.state_2d.show_scale_bar, "Show scale bar")
+                        .on_hover_text("Show a bar of a round length at the bottom of the view");
+                    scene_units_per_meter_ui(ui, &mut self.state_2d.scene_units_per_meter);
+                });
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Histogram");
+                re_ui.checkbox(ui, &mut self.state_2d.show_histogram, "Show histogram")
+                    .on_hover_text("Show a histogram of the values of each channel below the view, if it shows a single image");
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Magnifier")
+                    .on_hover_text("The zoomed-in texels around the pointer in image tooltips");
+                magnifier_ui(re_ui, ui, &mut self.state_2d);
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Point size legend");
+                re_ui.checkbox(ui, &mut self.state_2d.show_point_size_legend, "Show point size legend")
+                    .on_hover_text("Show circles of the smallest, median and largest radius of the points in the view, if their radii differ");
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Point density");
+                re_ui.checkbox(ui, &mut self.state_2d.show_point_density, "Show as heatmap")
+                    .on_hover_text("Instead of drawing a marker for each point, color each region of the view by how many points fall into it. Useful for very large point clouds");
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Visible region")
+                    .on_hover_text("Region of the space shown in the view, in the space's own coordinates");
+                ui.vertical(|ui| {
+                    re_ui.checkbox(ui, &mut self.state_2d.show_visible_region, "Show in view")
+                        .on_hover_text("Show the visible region in the top right corner of the view");
+                    visible_region_ui(ui, &mut self.state_2d);
+                });
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Timeline");
+                ui.vertical(|ui| {
+                    re_ui.checkbox(ui, &mut self.state_2d.show_timeline_strip, "Show timeline strip")
+                        .on_hover_text("Show a strip at the bottom of the view marking the times at which its entities have data");
+                    re_ui.checkbox(ui, &mut self.state_2d.pause_on_hover, "Pause on hover")
+                        .on_hover_text("Pause playback while the pointer is over the view, and resume it once it leaves");
+                });
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Trails");
+                trail_length_ui(re_ui, ui, &mut self.state_2d.trail_length);
+                ui.end_row();
+
+                ctx.re_ui.grid_left_hand_label(ui, "Compare frames");
+                ui.horizontal(|ui| {
+                    if let Some(reference) = self.state_2d.frame_diff_reference {
+                        ui.label(reference.timeline.typ().format(reference.time, ctx.app_options.time_zone));
+                        if ui.button("Use current time").on_hover_text("Compare against the current time instead").clicked() {
+                            self.state_2d.pin_frame_diff_reference(ctx);
+                        }
+                        if ui.button("Stop").clicked() {
+                            self.state_2d.frame_diff_reference = None;
+                        }
+                    } else if ui.button("Compare with current time")
+                        .on_hover_text("Highlight points and boxes that are added (green) or removed (red) after the current time")
+                        .clicked()
+                    {
+                        self.state_2d.pin_frame_diff_reference(ctx);
+                    }
+                });
+                ui.end_row();
+            }
+
             ctx.re_ui.grid_left_hand_label(ui, "Bounding box")
                 .on_hover_text("The bounding box encompassing all Entities in the view right now");
             ui.vertical(|ui| {
@@ -205,9 +396,394 @@ impl SpatialSpaceViewState {
             });
             ui.end_row();
         });
+
+        if spatial_kind == SpatialSpaceViewKind::TwoD {
+            egui::CollapsingHeader::new("Scene statistics")
+                .default_open(false)
+                .show(ui, |ui| {
+                    scene_stats_ui(ui, &self.scene_stats, &self.bounding_boxes.accumulated);
+                });
+        }
+    }
+}
+
+fn fit_target_ui(ui: &mut egui::Ui, state_2d: &mut View2DState) {
+    let Some(fit_target) = &state_2d.fit_target else {
+        return;
+    };
+    let fit_target = fit_target.to_string();
+    ui.horizontal(|ui| {
+        ui.label(format!("Fit to {fit_target}"))
+            .on_hover_text("Zooming to fit frames just this entity, if it is part of the scene");
+        if ui
+            .small_button("Clear")
+            .on_hover_text("Fit the whole scene again")
+            .clicked()
+        {
+            state_2d.set_fit_target(None, ui.input(|i| i.time));
+        }
+    });
+}
+
+fn focus_rect_ui(ui: &mut egui::Ui, state_2d: &mut View2DState) {
+    if state_2d.focus_rect.is_none() {
+        return;
+    }
+    ui.horizontal(|ui| {
+        ui.label("Focus region").on_hover_text(
+            "Everything outside of the focus region is dimmed. Drag its corners to adjust it",
+        );
+        if ui
+            .small_button("Clear")
+            .on_hover_text("Stop dimming the rest of the view")
+            .clicked()
+        {
+            state_2d.focus_rect = None;
+        }
+    });
+}
+
+fn bbox_accumulation_ui(ui: &mut egui::Ui, accumulation: &mut BoundingBoxAccumulation) {
+    /// Default window size when switching to [`BoundingBoxAccumulation::RollingWindow`].
+    const DEFAULT_WINDOW_FRAMES: usize = 60;
+
+    ui.horizontal(|ui| {
+        ui.label("Fit to")
+            .on_hover_text("Which data resetting the view fits to");
+        egui::ComboBox::from_id_source("bbox_accumulation")
+            .selected_text(match accumulation {
+                BoundingBoxAccumulation::Union => "All data",
+                BoundingBoxAccumulation::Current => "Current data",
+                BoundingBoxAccumulation::RollingWindow { .. } => "Recent data",
+            })
+            .show_ui(ui, |ui| {
+                ui.selectable_value(accumulation, BoundingBoxAccumulation::Union, "All data")
+                    .on_hover_text("Everything that was shown since the view was opened");
+                ui.selectable_value(
+                    accumulation,
+                    BoundingBoxAccumulation::Current,
+                    "Current data",
+                )
+                .on_hover_text("Everything that is shown right now");
+                let is_rolling_window =
+                    matches!(accumulation, BoundingBoxAccumulation::RollingWindow { .. });
+                if ui
+                    .selectable_label(is_rolling_window, "Recent data")
+                    .on_hover_text(
+                        "Everything that was shown during the last frames.\n\
+                        Outliers in live data stop affecting the view once they are old enough.",
+                    )
+                    .clicked()
+                    && !is_rolling_window
+                {
+                    *accumulation = BoundingBoxAccumulation::RollingWindow {
+                        frames: DEFAULT_WINDOW_FRAMES,
+                    };
+                }
+            });
+        if let BoundingBoxAccumulation::RollingWindow { frames } = accumulation {
+            ui.add(
+                egui::DragValue::new(frames)
+                    .clamp_range(1..=10_000)
+                    .suffix(" frames"),
+            );
+        }
+    });
+}
+
+fn projected_point_marker_ui(ui: &mut egui::Ui, marker: &mut ProjectedPointMarker) {
+    ui.vertical(|ui| {
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_id_source("projected_point_shape")
+                .selected_text(match marker.shape {
+                    ProjectedPointShape::Dot => "Dot",
+                    ProjectedPointShape::Crosshair => "Crosshair",
+                })
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut marker.shape, ProjectedPointShape::Dot, "Dot");
+                    ui.selectable_value(
+                        &mut marker.shape,
+                        ProjectedPointShape::Crosshair,
+                        "Crosshair",
+                    );
+                });
+            ui.add(
+                egui::DragValue::new(&mut marker.radius)
+                    .clamp_range(1.0..=32.0)
+                    .speed(0.1)
+                    .suffix(" pt"),
+            )
+            .on_hover_text("Radius of the marker");
+        });
+
+        ui.horizontal(|ui| {
+            let mut custom_color = marker.color.is_some();
+            if ui
+                .checkbox(&mut custom_color, "Color")
+                .on_hover_text("Use a fixed color instead of the hover and selection colors")
+                .changed()
+            {
+                marker.color = custom_color.then_some(egui::Color32::WHITE);
+            }
+            if let Some(color) = &mut marker.color {
+                ui.color_edit_button_srgba(color);
+            }
+
+            ui.label("Outline");
+            ui.color_edit_button_srgba(&mut marker.outline_color);
+        });
+    });
+}
+
+fn selection_outline_color_ui(ui: &mut egui::Ui, color: &mut Option<egui::Color32>) {
+    ui.horizontal(|ui| {
+        let mut custom_color = color.is_some();
+        if ui
+            .checkbox(&mut custom_color, "Custom selection outline")
+            .on_hover_text("Outline selected instances with a color of your choice instead of the selection color of the ui")
+            .changed()
+        {
+            *color = custom_color.then_some(egui::Color32::from_rgb(255, 0, 255));
+        }
+        if let Some(color) = color {
+            egui::color_picker::color_edit_button_srgba(
+                ui,
+                color,
+                egui::color_picker::Alpha::Opaque,
+            );
+        }
+    });
+}
+
+fn background_color_ui(ui: &mut egui::Ui, color: &mut Option<egui::Color32>) {
+    ui.horizontal(|ui| {
+        let mut custom_color = color.is_some();
+        if ui
+            .checkbox(&mut custom_color, "Custom color")
+            .on_hover_text("Clear the view with a color of your choice instead of the background color of the ui, e.g. white for printing")
+            .changed()
+        {
+            *color = custom_color.then_some(egui::Color32::WHITE);
+        }
+        if let Some(color) = color {
+            egui::color_picker::color_edit_button_srgba(
+                ui,
+                color,
+                egui::color_picker::Alpha::Opaque,
+            );
+        }
+    });
+}
+
+fn label_background_ui(ui: &mut egui::Ui, background: &mut LabelBackground) {
+    ui.horizontal(|ui| {
+        ui.selectable_value(background, LabelBackground::Theme, "Theme")
+            .on_hover_text("Back labels with the background color of the ui");
+        let is_color = matches!(background, LabelBackground::Color(_));
+        if ui
+            .selectable_label(is_color, "Color")
+            .on_hover_text("Back labels with a color of your choice, e.g. opaque black to keep them legible over bright images")
+            .clicked()
+            && !is_color
+        {
+            *background = LabelBackground::Color(egui::Color32::BLACK);
+        }
+        ui.selectable_value(background, LabelBackground::None, "None")
+            .on_hover_text("Don't back labels at all, hovered and selected labels are still highlighted");
+        if let LabelBackground::Color(color) = background {
+            egui::color_picker::color_edit_button_srgba(
+                ui,
+                color,
+                egui::color_picker::Alpha::OnlyBlend,
+            );
+        }
+    });
+}
+
+fn label_text_color_ui(ui: &mut egui::Ui, color: &mut Option<egui::Color32>) {
+    ui.horizontal(|ui| {
+        let mut custom_color = color.is_some();
+        if ui
+            .checkbox(&mut custom_color, "Custom color")
+            .on_hover_text("Color the text of all labels the same instead of like what they label")
+            .changed()
+        {
+            *color = custom_color.then_some(egui::Color32::WHITE);
+        }
+        if let Some(color) = color {
+            egui::color_picker::color_edit_button_srgba(
+                ui,
+                color,
+                egui::color_picker::Alpha::Opaque,
+            );
+        }
+    });
+}
+
+fn scene_units_per_meter_ui(ui: &mut egui::Ui, scene_units_per_meter: &mut Option<f32>) {
+    ui.horizontal(|ui| {
+        let mut is_metric = scene_units_per_meter.is_some();
+        if ui
+            .checkbox(&mut is_metric, "Metric")
+            .on_hover_text("Label the scale bar in meters instead of scene units")
+            .changed()
+        {
+            *scene_units_per_meter = is_metric.then_some(1.0);
+        }
+        if let Some(scene_units_per_meter) = scene_units_per_meter {
+            ui.add(
+                egui::DragValue::new(scene_units_per_meter)
+                    .clamp_range(1e-6..=1e6)
+                    .speed(0.01)
+                    .suffix(" units per meter"),
+            );
+        }
+    });
+}
+
+fn magnifier_ui(re_ui: &re_ui::ReUi, ui: &mut egui::Ui, state: &mut View2DState) {
+    ui.vertical(|ui| {
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::DragValue::new(&mut state.magnifier_texel_radius)
+                    .clamp_range(1..=50)
+                    .speed(0.2)
+                    .prefix("± ")
+                    .suffix(" texels"),
+            )
+            .on_hover_text("How many texels are shown on each side of the hovered one");
+            ui.add(
+                egui::DragValue::new(&mut state.magnifier_points_per_texel)
+                    .clamp_range(1.0..=64.0)
+                    .speed(0.2)
+                    .suffix(" pt per texel"),
+            )
+            .on_hover_text("How large each texel is shown");
+        });
+        re_ui
+            .checkbox(
+                ui,
+                &mut state.magnifier_show_texel_values,
+                "Show texel values",
+            )
+            .on_hover_text(
+                "Print the value of each texel into it. Needs few, large texels to fit the text",
+            );
+    });
+}
+
+fn projection_rays_ui(re_ui: &re_ui::ReUi, ui: &mut egui::Ui, state: &mut View2DState) {
+    ui.horizontal(|ui| {
+        re_ui
+            .checkbox(
+                ui,
+                &mut state.show_3d_projection_rays,
+                "Show projected rays",
+            )
+            .on_hover_text("Show the ray hovered in a 3D view, projected into this view's camera");
+        if !state.show_3d_projection_rays {
+            return;
+        }
+        let mut limit_depth = state.projection_ray_depth.is_some();
+        if ui
+            .checkbox(&mut limit_depth, "Up to depth")
+            .on_hover_text(
+                "End the ray at a depth in front of the camera instead of at its vanishing point",
+            )
+            .changed()
+        {
+            state.projection_ray_depth = limit_depth.then_some(DEFAULT_PROJECTION_RAY_DEPTH);
+        }
+        if let Some(depth) = &mut state.projection_ray_depth {
+            ui.add(
+                egui::DragValue::new(depth)
+                    .clamp_range(1e-3..=1e6)
+                    .speed(0.05),
+            );
+        }
+    });
+}
+
+/// Depth at which projected rays end when their depth is limited.
+const DEFAULT_PROJECTION_RAY_DEPTH: f32 = 5.0;
+
+fn trail_length_ui(re_ui: &re_ui::ReUi, ui: &mut egui::Ui, trail_length: &mut Option<usize>) {
+    ui.horizontal(|ui| {
+        let mut show_trails = trail_length.is_some();
+        if re_ui
+            .checkbox(ui, &mut show_trails, "Show trails")
+            .on_hover_text("Connect the centers of points and boxes over the previous frames they were logged at, fading out with age")
+            .changed()
+        {
+            *trail_length = show_trails.then_some(DEFAULT_TRAIL_LENGTH);
+        }
+        if let Some(trail_length) = trail_length {
+            ui.add(
+                egui::DragValue::new(trail_length)
+                    .clamp_range(1..=crate::trails::MAX_TRAIL_LENGTH)
+                    .speed(0.2)
+                    .suffix(" frames"),
+            )
+            .on_hover_text("How many previous frames the trails reach back");
+        }
+    });
+}
+
+/// Number of previous frames trails reach back when they are turned on.
+const DEFAULT_TRAIL_LENGTH: usize = 10;
+
+/// Edits the visible region of a 2D view, which zooms the view to exactly the entered region.
+fn visible_region_ui(ui: &mut egui::Ui, state: &mut View2DState) {
+    let Some(mut region) = state.requested_visible_region.or(state.visible_region) else {
+        return;
+    };
+
+    let mut changed = false;
+    egui::Grid::new("visible_region")
+        .num_columns(3)
+        .show(ui, |ui| {
+            for (axis, min, max) in [
+                ("x", &mut region.min.x, &mut region.max.x),
+                ("y", &mut region.min.y, &mut region.max.y),
+            ] {
+                ui.label(axis);
+                let speed = ((*max - *min).abs() * 0.005).at_least(0.01);
+                changed |= ui
+                    .add(egui::DragValue::new(min).speed(speed).max_decimals(2))
+                    .changed();
+                changed |= ui
+                    .add(egui::DragValue::new(max).speed(speed).max_decimals(2))
+                    .changed();
+                ui.end_row();
+            }
+        });
+
+    if changed && region.is_positive() {
+        state.requested_visible_region = Some(region);
     }
 }
 
+fn scene_stats_ui(ui: &mut egui::Ui, stats: &SceneStats, accumulated_bbox: &BoundingBox) {
+    egui::Grid::new("scene_stats")
+        .num_columns(2)
+        .show(ui, |ui| {
+            for (label, count) in stats.counts() {
+                ui.label(label);
+                ui.monospace(re_format::format_number(count));
+                ui.end_row();
+            }
+
+            ui.label("Accumulated size");
+            if accumulated_bbox.is_nothing() {
+                ui.monospace("–");
+            } else {
+                let size = accumulated_bbox.size();
+                ui.monospace(format!("{} × {}", format_f32(size.x), format_f32(size.y)));
+            }
+            ui.end_row();
+        });
+}
+
 fn size_ui(
     ui: &mut egui::Ui,
     default_size_points: f32,
@@ -271,16 +847,29 @@ fn size_ui(
     }
 }
 
+/// Distance between a point and its label, in ui points.
+const POINT_LABEL_OFFSET: f32 = 3.0;
+
+/// Distance between a point and its label if labels are drawn as [`LabelStyle::Callout`], in ui points.
+const CALLOUT_LABEL_OFFSET: f32 = 16.0;
+
+#[allow(clippy::too_many_arguments)]
 pub fn create_labels(
+    ctx: &ViewerContext<'_>,
     mut labels: Vec<UiLabel>,
     ui_from_canvas: egui::emath::RectTransform,
     eye3d: &Eye,
     parent_ui: &egui::Ui,
     highlights: &SpaceViewHighlights,
     spatial_kind: SpatialSpaceViewKind,
+    appearance: LabelAppearance,
 ) -> (Vec<egui::Shape>, Vec<PickableUiRect>) {
     re_tracing::profile_function!();
 
+    let point_label_offset = match appearance.style {
+        LabelStyle::Plain => POINT_LABEL_OFFSET,
+        LabelStyle::Callout => CALLOUT_LABEL_OFFSET,
+    };
     let ui_from_world_3d = eye3d.ui_from_world(*ui_from_canvas.to());
 
     // Closest last (painters algorithm)
@@ -294,19 +883,28 @@ pub fn create_labels(
 
     let mut label_shapes = Vec::with_capacity(labels.len() * 2);
     let mut ui_rects = Vec::with_capacity(labels.len());
+    let mut placed_rects: Vec<egui::Rect> = Vec::with_capacity(labels.len());
+    let font_id = egui::TextStyle::Body.resolve(parent_ui.style());
 
     for label in labels {
-        let (wrap_width, text_anchor_pos) = match label.target {
+        let is_standalone_text = matches!(label.target, UiLabelTarget::Text2D { .. });
+        let mut max_rows = usize::MAX;
+        // Center of the labeled geometry, which callouts point to.
+        let mut callout_target = None;
+        let (wrap_width, text_anchor_pos, text_align, font_size) = match label.target {
             UiLabelTarget::Rect(rect) => {
                 // TODO(#1640): 2D labels are not visible in 3D for now.
                 if spatial_kind == SpatialSpaceViewKind::ThreeD {
                     continue;
                 }
                 let rect_in_ui = ui_from_canvas.transform_rect(rect);
+                callout_target = Some(rect_in_ui.center());
                 (
                     // Place the text centered below the rect
                     (rect_in_ui.width() - 4.0).at_least(60.0),
                     rect_in_ui.center_bottom() + egui::vec2(0.0, 3.0),
+                    egui::Align2::CENTER_TOP,
+                    None,
                 )
             }
             UiLabelTarget::Point2D(pos) => {
@@ -315,7 +913,40 @@ pub fn create_labels(
                     continue;
                 }
                 let pos_in_ui = ui_from_canvas.transform_pos(pos);
-                (f32::INFINITY, pos_in_ui + egui::vec2(0.0, 3.0))
+                callout_target = Some(pos_in_ui);
+                (
+                    f32::INFINITY,
+                    pos_in_ui + egui::vec2(0.0, point_label_offset),
+                    egui::Align2::CENTER_TOP,
+                    None,
+                )
+            }
+            UiLabelTarget::Text2D {
+                pos,
+                anchor,
+                font_size,
+                wrap_width,
+            } => {
+                // TODO(#1640): 2D labels are not visible in 3D for now.
+                if spatial_kind == SpatialSpaceViewKind::ThreeD {
+                    continue;
+                }
+                let pos_in_ui = ui_from_canvas.transform_pos(pos);
+                let wrap_width = wrap_width.and_then(|wrap_width| {
+                    wrap_width
+                        .scene()
+                        .map(|width| width * ui_from_canvas.scale().x)
+                        .or_else(|| wrap_width.points())
+                });
+                if wrap_width.is_some() {
+                    max_rows = TEXT_BOX_MAX_ROWS;
+                }
+                (
+                    wrap_width.unwrap_or(f32::INFINITY),
+                    pos_in_ui,
+                    anchor,
+                    font_size,
+                )
             }
             UiLabelTarget::Position3D(pos) => {
                 // TODO(#1640): 3D labels are not visible in 2D for now.
@@ -327,104 +958,148 @@ pub fn create_labels(
                     continue; // behind camera
                 }
                 let pos_in_ui = pos_in_ui / pos_in_ui.w;
-                (f32::INFINITY, egui::pos2(pos_in_ui.x, pos_in_ui.y))
+                (
+                    f32::INFINITY,
+                    egui::pos2(pos_in_ui.x, pos_in_ui.y),
+                    egui::Align2::CENTER_TOP,
+                    None,
+                )
             }
         };
 
-        let font_id = egui::TextStyle::Body.resolve(parent_ui.style());
-        let galley = parent_ui.fonts(|fonts| {
-            fonts.layout_job({
-                egui::text::LayoutJob {
-                    sections: vec![egui::text::LayoutSection {
-                        leading_space: 0.0,
-                        byte_range: 0..label.text.len(),
-                        format: egui::TextFormat::simple(font_id, label.color),
-                    }],
-                    text: label.text.clone(),
-                    wrap: TextWrapping {
-                        max_width: wrap_width,
-                        ..Default::default()
-                    },
-                    break_on_newline: true,
-                    halign: egui::Align::Center,
-                    ..Default::default()
-                }
-            })
+        let galley = ctx.cache.entry(|c: &mut LabelGalleyCache| {
+            c.entry(
+                parent_ui,
+                &font_id,
+                font_size,
+                &label.text,
+                wrap_width,
+                max_rows,
+                label.color,
+            )
         });
 
-        let text_rect = egui::Align2::CENTER_TOP
-            .anchor_rect(egui::Rect::from_min_size(text_anchor_pos, galley.size()));
+        let text_rect =
+            text_align.anchor_rect(egui::Rect::from_min_size(text_anchor_pos, galley.size()));
         let bg_rect = text_rect.expand2(egui::vec2(4.0, 2.0));
 
         let highlight = highlights
             .entity_highlight(label.labeled_instance.entity_path_hash)
             .index_highlight(label.labeled_instance.instance_key);
-        let fill_color = match highlight.hover {
-            HoverHighlight::None => match highlight.selection {
-                SelectionHighlight::None => parent_ui.style().visuals.widgets.inactive.bg_fill,
-                SelectionHighlight::SiblingSelection => {
-                    parent_ui.style().visuals.widgets.active.bg_fill
-                }
-                SelectionHighlight::Selection => parent_ui.style().visuals.widgets.active.bg_fill,
+
+        // Declutter: standalone texts give way to the labels placed before them, unless they are hovered or selected.
+        if is_standalone_text
+            && highlight.hover == HoverHighlight::None
+            && highlight.selection == SelectionHighlight::None
+            && placed_rects.iter().any(|rect| rect.intersects(bg_rect))
+        {
+            continue;
+        }
+        placed_rects.push(bg_rect);
+        let widget_visuals = &parent_ui.style().visuals.widgets;
+        let fill_color = match (highlight.hover, highlight.selection) {
+            (HoverHighlight::Hovered, _) => Some(widget_visuals.hovered.bg_fill),
+            (
+                HoverHighlight::None,
+                SelectionHighlight::Selection | SelectionHighlight::SiblingSelection,
+            ) => Some(widget_visuals.active.bg_fill),
+            (HoverHighlight::None, SelectionHighlight::None) => match appearance.background {
+                LabelBackground::Theme => Some(widget_visuals.inactive.bg_fill),
+                LabelBackground::Color(color) => Some(color),
+                LabelBackground::None => None,
             },
-            HoverHighlight::Hovered => parent_ui.style().visuals.widgets.hovered.bg_fill,
         };
 
-        label_shapes.push(egui::Shape::rect_filled(bg_rect, 3.0, fill_color));
+        if let Some(target) = callout_target.filter(|_| appearance.style == LabelStyle::Callout) {
+            // Drawn first, so that the text box covers the line's end at its edge.
+            let edge_point = bg_rect.clamp(target);
+            if edge_point != target {
+                label_shapes.push(egui::Shape::line_segment(
+                    [edge_point, target],
+                    egui::Stroke::new(1.0, label.color),
+                ));
+            }
+        }
+        if let Some(fill_color) = fill_color {
+            label_shapes.push(egui::Shape::rect_filled(bg_rect, 3.0, fill_color));
+        }
+        let full_text = galley.elided.then(|| label.text.clone());
         label_shapes.push(egui::Shape::galley(
             text_rect.center_top(),
             galley,
-            label.color,
+            appearance.text_color.unwrap_or(label.color),
         ));
 
         ui_rects.push(PickableUiRect {
             rect: ui_from_canvas.inverse().transform_rect(bg_rect),
             instance_hash: label.labeled_instance,
+            full_text,
         });
     }
 
     (label_shapes, ui_rects)
 }
 
-pub fn outline_config(gui_ctx: &egui::Context) -> OutlineConfig {
+/// Outlines of hovered and selected instances.
+///
+/// Unless overridden, selected instances are outlined with the ui's selection color.
+/// `width_scale` scales the default outline width.
+pub fn outline_config(
+    gui_ctx: &egui::Context,
+    selection_outline_color: Option<egui::Color32>,
+    width_scale: f32,
+) -> OutlineConfig {
     // Take the exact same colors we have in the ui!
-    let selection_outline_color =
-        re_renderer::Rgba::from(gui_ctx.style().visuals.selection.bg_fill);
+    let selection_outline_color = re_renderer::Rgba::from(
+        selection_outline_color.unwrap_or(gui_ctx.style().visuals.selection.bg_fill),
+    );
     let hover_outline_color =
         re_renderer::Rgba::from(gui_ctx.style().visuals.widgets.hovered.bg_fill);
 
     OutlineConfig {
-        outline_radius_pixel: (gui_ctx.pixels_per_point() * 1.5).at_least(0.5),
+        outline_radius_pixel: (gui_ctx.pixels_per_point() * 1.5 * width_scale).at_least(0.5),
         color_layer_a: hover_outline_color,
         color_layer_b: selection_outline_color,
     }
 }
 
 pub fn screenshot_context_menu(
-    _ctx: &ViewerContext<'_>,
-    _response: &egui::Response,
+    ctx: &ViewerContext<'_>,
+    response: &egui::Response,
 ) -> Option<ScreenshotMode> {
+    if screenshots_enabled(ctx) {
+        let mut take_screenshot = None;
+        response.context_menu(|ui| {
+            ui.style_mut().wrap = Some(false);
+            take_screenshot = screenshot_context_menu_ui(ui);
+        });
+        take_screenshot
+    } else {
+        None
+    }
+}
+
+/// Whether screenshot entries should be offered in the context menu of a space view.
+pub fn screenshots_enabled(_ctx: &ViewerContext<'_>) -> bool {
     #[cfg(not(target_arch = "wasm32"))]
     {
-        if _ctx.app_options.experimental_space_view_screenshots {
-            let mut take_screenshot = None;
-            _response.context_menu(|ui| {
-                ui.style_mut().wrap = Some(false);
-                if ui.button("Save screenshot to disk").clicked() {
-                    take_screenshot = Some(ScreenshotMode::SaveAndCopyToClipboard);
-                    ui.close_menu();
-                } else if ui.button("Copy screenshot to clipboard").clicked() {
-                    take_screenshot = Some(ScreenshotMode::CopyToClipboard);
-                    ui.close_menu();
-                }
-            });
-            take_screenshot
-        } else {
-            None
-        }
+        _ctx.app_options.experimental_space_view_screenshots
     }
     #[cfg(target_arch = "wasm32")]
     {
+        false
+    }
+}
+
+/// The screenshot entries of a space view context menu.
+pub fn screenshot_context_menu_ui(ui: &mut egui::Ui) -> Option<ScreenshotMode> {
+    if ui.button("Save screenshot to disk").clicked() {
+        ui.close_menu();
+        Some(ScreenshotMode::SaveAndCopyToClipboard)
+    } else if ui.button("Copy screenshot to clipboard").clicked() {
+        ui.close_menu();
+        Some(ScreenshotMode::CopyToClipboard)
+    } else {
         None
     }
 }
@@ -483,21 +1158,74 @@ pub fn picking(
     let non_interactive = view_ctx.get::<NonInteractiveEntities>()?;
     let annotations = view_ctx.get::<AnnotationSceneContext>()?;
     let images = visualizers.get::<ImageVisualizer>()?;
+    let polygons = visualizers.get::<Polygons2DVisualizer>()?;
 
-    let picking_result = picking_context.pick(
+    // Holding the modifier hovers all instances within the interaction radius, each with its own tooltip.
+    let radius_hover_in_pixels = (spatial_kind == SpatialSpaceViewKind::TwoD
+        && parent_ui.input(|i| i.modifiers.contains(RADIUS_HOVER_2D_MODIFIER)))
+    .then(|| PickingContext::UI_INTERACTION_RADIUS * parent_ui.ctx().pixels_per_point());
+
+    let mut picking_result = picking_context.pick(
         ctx.render_ctx,
         query.space_view_id.gpu_readback_id(),
         &state.previous_picking_result,
         &images.images,
+        &polygons.polygons,
         ui_rects,
+        radius_hover_in_pixels,
     );
+    if spatial_kind == SpatialSpaceViewKind::TwoD {
+        picking_result
+            .hits
+            .retain(|hit| !state.state_2d.is_instance_locked(&hit.instance_path_hash));
+    }
     state.previous_picking_result = Some(picking_result.clone());
 
     let mut hovered_items = Vec::new();
 
+    let show_tooltips = {
+        let tooltip_delay = ctx.app_options.hover_tooltip_delay as f64;
+        let dwell_time = pointer_dwell_time(parent_ui.ctx(), response.id, pointer_pos_ui);
+        if dwell_time < tooltip_delay {
+            parent_ui
+                .ctx()
+                .request_repaint_after(std::time::Duration::from_secs_f64(
+                    tooltip_delay - dwell_time,
+                ));
+        }
+        dwell_time >= tooltip_delay
+    };
+
+    // Copying in a hovered 2D view copies the topmost hovered pixel value or instance path.
+    let copy_requested = spatial_kind == SpatialSpaceViewKind::TwoD
+        && parent_ui.input(|i| {
+            i.events
+                .iter()
+                .any(|event| matches!(event, egui::Event::Copy))
+        });
+    let mut copied_text = None;
+
+    // Pinning keeps the tooltip of the topmost hovered image in a window.
+    let mut pin_requested = spatial_kind == SpatialSpaceViewKind::TwoD
+        && parent_ui.input(|i| i.key_pressed(PIN_TOOLTIP_KEY));
+
+    // Any click closes the value of a previously queried pixel, clicking with the modifier queries a new one.
+    let pixel_query_requested = spatial_kind == SpatialSpaceViewKind::TwoD
+        && response.clicked()
+        && parent_ui.input(|i| i.modifiers.contains(PIXEL_QUERY_2D_MODIFIER));
+    if spatial_kind == SpatialSpaceViewKind::TwoD && response.clicked() {
+        state.state_2d.pixel_query = None;
+    }
+
     // Depth at pointer used for projecting rays from a hovered 2D view to corresponding 3D view(s).
     // TODO(#1818): Depth at pointer only works for depth images so far.
     let mut depth_at_pointer = None;
+    // Pixel in the topmost hovered image.
+    let mut hovered_pixel = None;
+    // Raw value of that pixel, shown in the status bar of 2D views.
+    let mut hovered_pixel_value = None;
+    // Hovered images, front to back.
+    let mut stacked_images = Vec::new();
     for hit in &picking_result.hits {
         let Some(mut instance_path) = hit.instance_path_hash.resolve(ctx.entity_db) else {
             continue;
@@ -544,7 +1272,15 @@ pub fn picking(
                             None
                         } else {
                             let tensor_path_hash = hit.instance_path_hash.versioned(tensor.row_id);
-                            tensor.image_height_width_channels().map(|[_, w, _]| {
+                            let width = tensor
+                                .image_height_width_channels()
+                                .map(|[_, w, _]| w)
+                                .or_else(|| match tensor.shape_short() {
+                                    // Too many channels for an image, shown via a channel selection.
+                                    [_, width, _] => Some(width.size),
+                                    _ => None,
+                                });
+                            width.map(|w| {
                                 let coordinates = hit
                                     .instance_path_hash
                                     .instance_key
@@ -556,7 +1292,53 @@ pub fn picking(
             } else {
                 None
             };
-        if picked_image_with_coords.is_some() {
+        if let Some((tensor_path_hash, tensor, meaning, coords)) = &picked_image_with_coords {
+            if hit.hit_type == PickingHitType::TexturedRect && hovered_pixel.is_none() {
+                hovered_pixel = Some(*coords);
+                if spatial_kind == SpatialSpaceViewKind::TwoD {
+                    let [x, y] = *coords;
+                    let decoded_tensor = ctx
+                        .cache
+                        .entry(|c: &mut TensorDecodeCache| {
+                            c.entry(tensor_path_hash.row_id, tensor.value.0.clone())
+                        })
+                        .ok();
+                    hovered_pixel_value = decoded_tensor
+                        .as_ref()
+                        .and_then(|tensor| pixel_value_text(tensor, [x as _, y as _]));
+                    if pixel_query_requested {
+                        state.state_2d.pixel_query = decoded_tensor
+                            .as_ref()
+                            .and_then(|tensor| pixel_query_text(tensor, *meaning, [x as _, y as _]))
+                            .map(|text| PixelQuery {
+                                pointer_pos: pointer_pos_ui,
+                                text,
+                            });
+                    }
+                }
+            }
+
+            // Hits on textured rects are sorted front to back, so the topmost image wins.
+            if spatial_kind == SpatialSpaceViewKind::TwoD
+                && hit.hit_type == PickingHitType::TexturedRect
+                && state.state_2d.hover_highlight_region.is_none()
+            {
+                state.state_2d.hover_highlight_region = images
+                    .images
+                    .iter()
+                    .find(|image| image.ent_path == instance_path.entity_path)
+                    .and_then(|image| {
+                        image_hover_highlight_region(
+                            ctx,
+                            image,
+                            tensor_path_hash.row_id,
+                            tensor,
+                            *meaning,
+                            *coords,
+                        )
+                    });
+            }
+
             // We don't support selecting pixels yet.
             instance_path.instance_key = InstanceKey::SPLAT;
         }
@@ -565,6 +1347,24 @@ pub fn picking(
             Some(query.space_view_id),
             instance_path.clone(),
         ));
+        if hit.hit_type == PickingHitType::TexturedRect {
+            stacked_images.push(instance_path.clone());
+        }
+
+        if copy_requested && copied_text.is_none() {
+            let pixel_value = picked_image_with_coords.as_ref().and_then(
+                |(tensor_path_hash, tensor, _, [x, y])| {
+                    let tensor = ctx
+                        .cache
+                        .entry(|c: &mut TensorDecodeCache| {
+                            c.entry(tensor_path_hash.row_id, tensor.value.0.clone())
+                        })
+                        .ok()?;
+                    pixel_value_text(&tensor, [*x as _, *y as _])
+                },
+            );
+            copied_text = Some(pixel_value.unwrap_or_else(|| instance_path.to_string()));
+        }
 
         response = if let Some((tensor_path_hash, tensor, meaning, coords)) =
             picked_image_with_coords
@@ -589,10 +1389,31 @@ pub fn picking(
                 }
             }
 
+            let channel_selector = images
+                .images
+                .iter()
+                .find(|image| image.ent_path == instance_path.entity_path)
+                .and_then(|image| image.channel_selector);
+            let magnifier = state.state_2d.magnifier();
+
+            if pin_requested && hit.hit_type == PickingHitType::TexturedRect {
+                pin_requested = false;
+                state.state_2d.pin_tooltip(PinnedImageTooltip {
+                    id: 0,
+                    pointer_pos: pointer_pos_ui,
+                    instance_path: instance_path.clone(),
+                    tensor: tensor.value.clone(),
+                    tensor_data_row_id: tensor_path_hash.row_id,
+                    coords,
+                    meaning,
+                    meter,
+                    channel_selector,
+                });
+            }
+
             response
                 .on_hover_cursor(egui::CursorIcon::Crosshair)
-                .on_hover_ui_at_pointer(|ui| {
-                    ui.set_max_width(320.0);
+                .on_hover_ui_at_pointer_after(show_tooltips, ctx.app_options, |ui| {
                     ui.vertical(|ui| {
                         image_hover_ui(
                             ui,
@@ -607,13 +1428,54 @@ pub fn picking(
                             annotations,
                             meaning,
                             meter,
+                            channel_selector,
+                            magnifier,
                         );
                     });
                 })
         } else {
+            let line_segment = if spatial_kind == SpatialSpaceViewKind::TwoD {
+                hovered_line_segment_2d(
+                    store,
+                    &query.latest_at_query(),
+                    view_ctx.get::<TransformContext>()?,
+                    &instance_path,
+                    picking_context.pointer_in_space2d,
+                )
+            } else {
+                None
+            };
+            let scene_units_per_meter = state.state_2d.scene_units_per_meter;
+            let full_label_text = if hit.hit_type == PickingHitType::GuiOverlay {
+                let pointer_in_space2d = egui::pos2(
+                    picking_context.pointer_in_space2d.x,
+                    picking_context.pointer_in_space2d.y,
+                );
+                ui_rects
+                    .iter()
+                    .find(|ui_rect| {
+                        ui_rect.instance_hash == hit.instance_path_hash
+                            && ui_rect.rect.contains(pointer_in_space2d)
+                    })
+                    .and_then(|ui_rect| ui_rect.full_text.clone())
+            } else {
+                None
+            };
+            let tooltip = hovered_tooltip(store, &query.latest_at_query(), &instance_path);
+
             // Hover ui for everything else
-            response.on_hover_ui_at_pointer(|ui| {
+            response.on_hover_ui_at_pointer_after(show_tooltips, ctx.app_options, |ui| {
                 hit_ui(ui, hit);
+                if let Some(full_label_text) = &full_label_text {
+                    ui.label(full_label_text);
+                }
+                if let Some((segment_index, length)) = line_segment {
+                    line_segment_ui(ui, segment_index, length, scene_units_per_meter);
+                }
+                if let Some(tooltip) = &tooltip {
+                    ui.label(tooltip_layout_job(tooltip.as_str(), ui.style()));
+                    return;
+                }
                 item_ui::instance_path_button(
                     ctx,
                     &query.latest_at_query(),
@@ -627,6 +1489,26 @@ pub fn picking(
         };
     }
 
+    if let Some(copied_text) = copied_text {
+        parent_ui.ctx().output_mut(|o| o.copied_text = copied_text);
+    }
+
+    if spatial_kind == SpatialSpaceViewKind::TwoD {
+        if let Some(picked_image) =
+            state
+                .state_2d
+                .pick_through(pointer_pos_ui, &response, &stacked_images)
+        {
+            // Only the picked one of the stacked images gets selected by the click.
+            hovered_items.retain(|item| match item {
+                Item::InstancePath(_, instance_path) => {
+                    instance_path == picked_image || !stacked_images.contains(instance_path)
+                }
+                _ => true,
+            });
+        }
+    }
+
     if hovered_items.is_empty() {
         // If we hover nothing, we are hovering the space-view itself.
         hovered_items.push(Item::SpaceView(query.space_view_id));
@@ -642,21 +1524,65 @@ pub fn picking(
 
     if let Some((_, context)) = hovered_items.first_mut() {
         *context = Some(match spatial_kind {
-            SpatialSpaceViewKind::TwoD => SelectedSpaceContext::TwoD {
-                space_2d: query.space_origin.clone(),
-                pos: picking_context
-                    .pointer_in_space2d
-                    .extend(depth_at_pointer.unwrap_or(f32::INFINITY)),
-            },
+            SpatialSpaceViewKind::TwoD => {
+                // Other views expect the position in the space's own coordinates, not the flipped or rotated ones of this view.
+                let pointer = picking_context.pointer_in_space2d;
+                let pointer_in_space = state
+                    .state_2d
+                    .orientation
+                    .space_from_view_pos(egui::pos2(pointer.x, pointer.y));
+                state.state_2d.pointer_status = Some(PointerStatus {
+                    pos: pointer_in_space,
+                    pixel: hovered_pixel,
+                    pixel_value: hovered_pixel_value,
+                    point_density: None,
+                });
+                SelectedSpaceContext::TwoD {
+                    space_2d: query.space_origin.clone(),
+                    pos: glam::vec3(
+                        pointer_in_space.x,
+                        pointer_in_space.y,
+                        depth_at_pointer.unwrap_or(f32::INFINITY),
+                    ),
+                    pixel: hovered_pixel,
+                }
+            }
             SpatialSpaceViewKind::ThreeD => {
                 let hovered_point = picking_result.space_position();
+                let space_cameras = &visualizers.get::<CamerasVisualizer>()?.space_cameras;
+
+                // When looking through a camera, the hovered ray shows up as a line in all other cameras.
+                let tracked_camera =
+                    state.state_3d.tracked_entity.as_ref().filter(|tracked| {
+                        space_cameras.iter().any(|cam| &cam.ent_path == *tracked)
+                    });
+                let epipolar_lines_in_space_cameras = tracked_camera
+                    .map(|tracked| {
+                        space_cameras
+                            .iter()
+                            .filter(|cam| &cam.ent_path != tracked)
+                            .filter_map(|cam| {
+                                let line = cam.project_ray_onto_2d(picking_context.ray_in_world)?;
+                                Some((cam.ent_path.clone(), line))
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let ray_in_space_cameras = space_cameras
+                    .iter()
+                    .filter(|cam| Some(&cam.ent_path) != tracked_camera)
+                    .filter_map(|cam| {
+                        let segment =
+                            cam.project_ray_segment_onto_2d(picking_context.ray_in_world)?;
+                        Some((cam.ent_path.clone(), segment))
+                    })
+                    .collect();
+
                 SelectedSpaceContext::ThreeD {
                     space_3d: query.space_origin.clone(),
                     pos: hovered_point,
                     tracked_entity: state.state_3d.tracked_entity.clone(),
-                    point_in_space_cameras: visualizers
-                        .get::<CamerasVisualizer>()?
-                        .space_cameras
+                    point_in_space_cameras: space_cameras
                         .iter()
                         .map(|cam| {
                             (
@@ -665,16 +1591,379 @@ pub fn picking(
                             )
                         })
                         .collect(),
+                    epipolar_lines_in_space_cameras,
+                    ray_in_space_cameras,
                 }
             }
         });
     };
 
-    ctx.select_hovered_on_click(&response, re_viewer_context::Selection(hovered_items));
+    // Hovering highlights whole entities by swapping the hovered instances for their entities,
+    // without affecting what clicking selects.
+    let highlight_whole_entity = spatial_kind == SpatialSpaceViewKind::TwoD
+        && (state.state_2d.highlight_whole_entity
+            != parent_ui.input(|i| {
+                i.modifiers.contains(HIGHLIGHT_WHOLE_ENTITY_2D_MODIFIER)
+                    && radius_hover_in_pixels.is_none()
+            }));
+    let whole_entity_hovered_items = highlight_whole_entity.then(|| {
+        hovered_items
+            .iter()
+            .map(|(item, context)| {
+                let item = match item {
+                    Item::InstancePath(space_view_id, instance_path) => Item::InstancePath(
+                        *space_view_id,
+                        InstancePath::entity_splat(instance_path.entity_path.clone()),
+                    ),
+                    item => item.clone(),
+                };
+                (item, context.clone())
+            })
+            .collect_vec()
+    });
+
+    // A click only queries a pixel if it hit an image, otherwise it selects as usual.
+    let pixel_queried = pixel_query_requested && state.state_2d.pixel_query.is_some();
+    let view_locked = spatial_kind == SpatialSpaceViewKind::TwoD && state.state_2d.lock_view;
+    let inspect_mode = spatial_kind == SpatialSpaceViewKind::TwoD && state.state_2d.inspect_mode;
+    if inspect_mode && response.clicked() && !view_locked && !pixel_queried {
+        let clicked_instance = hovered_items.iter().find_map(|(item, _)| match item {
+            Item::InstancePath(_, instance_path) => Some(instance_path.clone()),
+            _ => None,
+        });
+        if let Some(instance_path) = clicked_instance {
+            state.state_2d.inspect(instance_path, pointer_pos_ui);
+        }
+    }
+    let click_selection = click_selection(
+        spatial_kind,
+        parent_ui.input(|i| i.modifiers),
+        pixel_queried,
+        view_locked,
+        inspect_mode,
+    );
+    match click_selection {
+        ClickSelection::Unchanged => {
+            // Keep hover highlights & tooltips, but don't let clicks change the selection.
+            let mut selection = re_viewer_context::Selection(hovered_items);
+            selection.resolve_mono_instance_path_items(ctx);
+            ctx.selection_state().set_hovered(selection);
+        }
+        ClickSelection::Toggle if response.clicked() => {
+            // Add or remove the hovered instances from the selection, leaving the rest of the selection untouched.
+            let mut selection = re_viewer_context::Selection(hovered_items);
+            selection.resolve_mono_instance_path_items(ctx);
+            ctx.selection_state().set_hovered(selection.clone());
+            ctx.selection_state().toggle_selection(selection);
+        }
+        ClickSelection::Toggle | ClickSelection::Replace => {
+            ctx.select_hovered_on_click(&response, re_viewer_context::Selection(hovered_items));
+        }
+    }
+    if let Some(whole_entity_hovered_items) = whole_entity_hovered_items {
+        ctx.selection_state()
+            .set_hovered(re_viewer_context::Selection(whole_entity_hovered_items));
+    }
 
     Ok(response)
 }
 
+/// How clicking a spatial view changes the selection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ClickSelection {
+    /// Clicks don't change the selection, only hovering is shown.
+    Unchanged,
+
+    /// Clicking adds the hovered instances to the selection or removes them from it.
+    Toggle,
+
+    /// Clicking replaces the selection with the hovered instances.
+    Replace,
+}
+
+/// Determines how a click while holding `modifiers` changes the selection.
+///
+/// `pixel_queried` is whether the click showed the value of an image pixel,
+/// which only clicks on images holding [`PIXEL_QUERY_2D_MODIFIER`] do.
+fn click_selection(
+    spatial_kind: SpatialSpaceViewKind,
+    modifiers: egui::Modifiers,
+    pixel_queried: bool,
+    view_locked: bool,
+    inspect_mode: bool,
+) -> ClickSelection {
+    if view_locked || pixel_queried || inspect_mode {
+        ClickSelection::Unchanged
+    } else if modifiers.command
+        || (spatial_kind == SpatialSpaceViewKind::TwoD
+            && modifiers.contains(TOGGLE_SELECTION_2D_MODIFIER))
+    {
+        // Clicking with the command modifier toggles the selection everywhere in the viewer.
+        ClickSelection::Toggle
+    } else {
+        ClickSelection::Replace
+    }
+}
+
+/// Determines the part of a hovered image that should be highlighted, in scene coordinates.
+///
+/// For segmentation images this is the region covered by the hovered class,
+/// for all other images just the hovered pixel.
+fn image_hover_highlight_region(
+    ctx: &ViewerContext<'_>,
+    image: &ViewerImage,
+    tensor_data_row_id: re_log_types::RowId,
+    tensor: &TensorData,
+    meaning: TensorDataMeaning,
+    [x, y]: [u32; 2],
+) -> Option<egui::Rect> {
+    let pixel_rect = if meaning == TensorDataMeaning::ClassId {
+        let class_id = tensor
+            .get_with_image_coords(x as _, y as _, 0)?
+            .try_as_u16()?;
+        ctx.cache
+            .entry(|c: &mut ClassRegionCache| c.entry(tensor_data_row_id, &tensor.0, class_id))?
+    } else {
+        egui::Rect::from_min_size(egui::pos2(x as f32, y as f32), egui::Vec2::splat(1.0))
+    };
+
+    Some(scene_rect_from_pixel_rect(image, pixel_rect))
+}
+
+/// Region covered by the given class in a segmentation image, in scene coordinates.
+pub fn image_class_region(
+    ctx: &ViewerContext<'_>,
+    image: &ViewerImage,
+    class_id: u16,
+) -> Option<egui::Rect> {
+    if image.meaning != TensorDataMeaning::ClassId {
+        return None;
+    }
+    let pixel_rect = ctx.cache.entry(|c: &mut ClassRegionCache| {
+        c.entry(image.tensor_data_row_id, &image.tensor, class_id)
+    })?;
+    Some(scene_rect_from_pixel_rect(image, pixel_rect))
+}
+
+/// Maps a rectangle in pixel coordinates of an image to scene coordinates, clipped to the image.
+fn scene_rect_from_pixel_rect(image: &ViewerImage, pixel_rect: egui::Rect) -> egui::Rect {
+    let textured_rect = &image.textured_rect;
+    let [width, height] = textured_rect.colormapped_texture.width_height();
+    let scene_from_pixel = |pos: egui::Pos2| {
+        let pos = textured_rect.top_left_corner_position
+            + textured_rect.extent_u * (pos.x / width as f32)
+            + textured_rect.extent_v * (pos.y / height as f32);
+        egui::pos2(pos.x, pos.y)
+    };
+
+    let image_rect = egui::Rect::from_two_pos(
+        scene_from_pixel(egui::Pos2::ZERO),
+        scene_from_pixel(egui::pos2(width as f32, height as f32)),
+    );
+    let region = egui::Rect::from_two_pos(
+        scene_from_pixel(pixel_rect.min),
+        scene_from_pixel(pixel_rect.max),
+    );
+    region.intersect(image_rect)
+}
+
+/// The tooltip of a hovered image, pinned into a floating window of a 2D view.
+///
+/// Keeps the image data it was pinned with, so it can be compared with whatever is hovered later.
+#[derive(Clone, PartialEq)]
+pub struct PinnedImageTooltip {
+    /// Tells the windows of several pinned tooltips apart.
+    pub id: u64,
+
+    /// Where the pointer was when the tooltip was pinned, in ui coordinates.
+    pub pointer_pos: egui::Pos2,
+
+    pub instance_path: InstancePath,
+    pub tensor: TensorData,
+    pub tensor_data_row_id: re_log_types::RowId,
+    pub coords: [u32; 2],
+    pub meaning: TensorDataMeaning,
+    pub meter: Option<f32>,
+    pub channel_selector: Option<[usize; 3]>,
+}
+
+/// Shows the pinned image tooltips of a 2D view as floating windows, until they are closed.
+pub fn pinned_image_tooltips_ui(
+    ctx: &ViewerContext<'_>,
+    egui_ctx: &egui::Context,
+    state_2d: &mut View2DState,
+    annotations: &AnnotationSceneContext,
+    space_view_id: SpaceViewId,
+    space_from_ui: egui::emath::RectTransform,
+    ui_clip_rect: egui::Rect,
+) {
+    let magnifier = state_2d.magnifier();
+    state_2d.pinned_tooltips.retain(|pinned| {
+        let mut open = true;
+        egui::Window::new(pinned.instance_path.to_string())
+            .id(egui::Id::new(space_view_id)
+                .with("pinned_tooltip")
+                .with(pinned.id))
+            .default_pos(pinned.pointer_pos)
+            .open(&mut open)
+            .resizable(false)
+            .show(egui_ctx, |ui| {
+                ui.set_max_width(ctx.app_options.hover_tooltip_max_width);
+                image_hover_ui(
+                    ui,
+                    &pinned.instance_path,
+                    ctx,
+                    pinned.tensor.clone(),
+                    SpatialSpaceViewKind::TwoD,
+                    ui_clip_rect,
+                    pinned.coords,
+                    space_from_ui,
+                    pinned.tensor_data_row_id,
+                    annotations,
+                    pinned.meaning,
+                    pinned.meter,
+                    pinned.channel_selector,
+                    magnifier,
+                );
+            });
+        open
+    });
+}
+
+/// An instance clicked in the inspector mode of a 2D view, whose details are shown in a floating window.
+#[derive(Clone, PartialEq)]
+pub struct InspectedInstance {
+    pub instance_path: InstancePath,
+
+    /// Where the instance was clicked, in ui coordinates.
+    pub pointer_pos: egui::Pos2,
+}
+
+/// Shows the details of the inspected instances of a 2D view as floating windows, until they are closed.
+///
+/// Unlike selecting, inspecting leaves the selection of the viewer untouched,
+/// so several instances can be compared side by side.
+pub fn inspected_instances_ui(
+    ctx: &ViewerContext<'_>,
+    egui_ctx: &egui::Context,
+    state_2d: &mut View2DState,
+    space_view_id: SpaceViewId,
+) {
+    let store = ctx.entity_db.store();
+    let query = ctx.current_query();
+    state_2d.inspected_instances.retain(|inspected| {
+        let mut open = true;
+        egui::Window::new(inspected.instance_path.to_string())
+            .id(egui::Id::new(space_view_id)
+                .with("inspected_instance")
+                .with(&inspected.instance_path))
+            .default_pos(inspected.pointer_pos + egui::vec2(8.0, 8.0))
+            .open(&mut open)
+            .show(egui_ctx, |ui| {
+                ui.set_max_width(ctx.app_options.hover_tooltip_max_width);
+                item_ui::instance_path_button(
+                    ctx,
+                    &query,
+                    store,
+                    ui,
+                    Some(space_view_id),
+                    &inspected.instance_path,
+                );
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    inspected
+                        .instance_path
+                        .data_ui(ctx, ui, UiVerbosity::Full, &query, store);
+                });
+            });
+        open
+    });
+}
+
+/// The value of an image pixel queried by clicking it while holding [`PIXEL_QUERY_2D_MODIFIER`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PixelQuery {
+    /// Where the pixel was clicked, in ui coordinates.
+    pub pointer_pos: egui::Pos2,
+
+    /// Position and value of the pixel, e.g. `Pixel (x=42, y=17): R=255, G=128, B=0`.
+    pub text: String,
+}
+
+/// Shows the value of the queried pixel of a 2D view in a floating window, until the next click.
+pub fn pixel_query_ui(
+    egui_ctx: &egui::Context,
+    state_2d: &mut View2DState,
+    space_view_id: SpaceViewId,
+) {
+    let Some(pixel_query) = &state_2d.pixel_query else {
+        return;
+    };
+
+    let mut open = true;
+    egui::Window::new("Pixel value")
+        .id(egui::Id::new(space_view_id).with("pixel_query"))
+        .fixed_pos(pixel_query.pointer_pos + egui::vec2(8.0, 8.0))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .show(egui_ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(&pixel_query.text);
+                if ui.small_button("Copy").clicked() {
+                    ui.output_mut(|o| o.copied_text = pixel_query.text.clone());
+                }
+            });
+        });
+    if !open {
+        state_2d.pixel_query = None;
+    }
+}
+
+/// How long the pointer has been resting at its current position over the given widget, in seconds.
+fn pointer_dwell_time(egui_ctx: &egui::Context, id: egui::Id, pointer_pos: egui::Pos2) -> f64 {
+    let now = egui_ctx.input(|i| i.time);
+    egui_ctx.data_mut(|data| {
+        let (resting_pos, resting_since) =
+            data.get_temp_mut_or_insert_with(id.with("pointer_dwell"), || (pointer_pos, now));
+        if *resting_pos != pointer_pos {
+            *resting_pos = pointer_pos;
+            *resting_since = now;
+        }
+        now - *resting_since
+    })
+}
+
+/// Hover tooltips of 2D and 3D views, delayed and limited in size as set in the viewer preferences.
+trait DelayedHoverUi {
+    /// Shows the tooltip at the pointer once `show` is set, i.e. the pointer rested long enough.
+    fn on_hover_ui_at_pointer_after(
+        self,
+        show: bool,
+        app_options: &re_viewer_context::AppOptions,
+        add_contents: impl FnOnce(&mut egui::Ui),
+    ) -> Self;
+}
+
+impl DelayedHoverUi for egui::Response {
+    fn on_hover_ui_at_pointer_after(
+        self,
+        show: bool,
+        app_options: &re_viewer_context::AppOptions,
+        add_contents: impl FnOnce(&mut egui::Ui),
+    ) -> Self {
+        if !show {
+            return self;
+        }
+        self.on_hover_ui_at_pointer(|ui| {
+            ui.set_max_width(app_options.hover_tooltip_max_width);
+            if let Some(max_height) = app_options.hover_tooltip_max_height {
+                ui.set_max_height(max_height);
+            }
+            add_contents(ui);
+        })
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn image_hover_ui(
     ui: &mut egui::Ui,
@@ -689,6 +1978,8 @@ fn image_hover_ui(
     annotations: &AnnotationSceneContext,
     meaning: TensorDataMeaning,
     meter: Option<f32>,
+    channel_selector: Option<[usize; 3]>,
+    magnifier: ZoomedImageRegionOptions,
 ) {
     ui.label(instance_path.to_string());
     if true {
@@ -716,9 +2007,13 @@ fn image_hover_ui(
         );
     }
 
+    if let Some([r, g, b]) = channel_selector {
+        ui.label(format!("Showing channels {r}, {g} and {b} as RGB"));
+    }
+
     if let Some([h, w, ..]) = tensor.image_height_width_channels() {
         ui.separator();
-        ui.horizontal(|ui| {
+        let color = ui.horizontal(|ui| {
             let (w, h) = (w as f32, h as f32);
             if spatial_kind == SpatialSpaceViewKind::TwoD {
                 let rect = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(w, h));
@@ -728,6 +2023,7 @@ fn image_hover_ui(
                     &tensor.0,
                     [coords[0] as _, coords[1] as _],
                     space_from_ui.inverse().transform_rect(rect),
+                    magnifier,
                 );
             }
 
@@ -751,21 +2047,370 @@ fn image_hover_ui(
                         &annotations,
                         meaning,
                         meter,
+                        ctx.app_options.depth_unit,
                         &tensor_name,
                         [coords[0] as _, coords[1] as _],
+                        magnifier,
+                    );
+
+                    // The pixel color doesn't match what is shown if channels are selected.
+                    (meaning == TensorDataMeaning::Unknown && channel_selector.is_none())
+                        .then(|| pixel_color(&decoded_tensor, [coords[0] as _, coords[1] as _]))
+                        .flatten()
+                }
+                Err(err) => {
+                    re_log::warn_once!(
+                        "Encountered problem decoding tensor at path {tensor_name}: {err}"
                     );
+                    None
                 }
-                Err(err) => re_log::warn_once!(
-                    "Encountered problem decoding tensor at path {tensor_name}: {err}"
-                ),
             }
         });
+
+        if let Some(color) = color.inner {
+            ui.horizontal(|ui| {
+                let swatch_size = ui.text_style_height(&egui::TextStyle::Body) * 1.5;
+                egui::color_picker::show_color(ui, color, egui::Vec2::splat(swatch_size));
+                let [r, g, b, a] = color.to_srgba_unmultiplied();
+                ui.monospace(format!("#{r:02X}{g:02X}{b:02X}{a:02X}"));
+            });
+        }
     }
 }
 
+/// The color of a pixel of an RGB or RGBA image.
+fn pixel_color(
+    tensor: &re_types::datatypes::TensorData,
+    [x, y]: [u64; 2],
+) -> Option<egui::Color32> {
+    use re_types::tensor_data::TensorElement;
+
+    fn to_u8(element: TensorElement) -> Option<u8> {
+        match element {
+            TensorElement::U8(value) => Some(value),
+            TensorElement::U16(value) => Some((value >> 8) as u8),
+            TensorElement::F16(_) | TensorElement::F32(_) | TensorElement::F64(_) => {
+                Some((element.as_f64().clamp(0.0, 1.0) * 255.0).round() as u8)
+            }
+            _ => None,
+        }
+    }
+
+    let [r, g, b, a] = match tensor.get_pixel_with_image_coords(x, y)?.as_slice() {
+        [r, g, b] => [*r, *g, *b, TensorElement::U8(255)],
+        [r, g, b, a] => [*r, *g, *b, *a],
+        _ => return None,
+    };
+
+    Some(egui::Color32::from_rgba_unmultiplied(
+        to_u8(r)?,
+        to_u8(g)?,
+        to_u8(b)?,
+        to_u8(a)?,
+    ))
+}
+
+/// The raw value(s) of an image pixel as text, e.g. `42` or `[255, 128, 0]`.
+fn pixel_value_text(tensor: &re_types::datatypes::TensorData, [x, y]: [u64; 2]) -> Option<String> {
+    Some(match tensor.get_pixel_with_image_coords(x, y)?.as_slice() {
+        [value] => value.to_string(),
+        values => format!("[{}]", values.iter().join(", ")),
+    })
+}
+
+/// Position and value(s) of an image pixel as text,
+/// e.g. `Pixel (x=42, y=17): R=255, G=128, B=0`.
+///
+/// Depth images and images with a single channel show the raw value.
+fn pixel_query_text(
+    tensor: &re_types::datatypes::TensorData,
+    meaning: TensorDataMeaning,
+    [x, y]: [u64; 2],
+) -> Option<String> {
+    let values = tensor.get_pixel_with_image_coords(x, y)?;
+    let value_text = match values.as_slice() {
+        [value] => value.to_string(),
+        values if meaning == TensorDataMeaning::Depth => values.iter().join(", "),
+        values @ ([_, _, _] | [_, _, _, _]) => ["R", "G", "B", "A"]
+            .iter()
+            .zip(values)
+            .map(|(channel, value)| format!("{channel}={value}"))
+            .join(", "),
+        values => format!("[{}]", values.iter().join(", ")),
+    };
+    Some(format!("Pixel (x={x}, y={y}): {value_text}"))
+}
+
+/// Index and length of the segment of a hovered 2D line strip that is closest to the pointer.
+///
+/// Both the pointer and the returned length are in scene units.
+fn hovered_line_segment_2d(
+    store: &re_data_store::DataStore,
+    query: &re_data_store::LatestAtQuery,
+    transforms: &TransformContext,
+    instance_path: &InstancePath,
+    pointer_in_space2d: glam::Vec2,
+) -> Option<(usize, f32)> {
+    use re_types::Loggable as _;
+
+    let (_, _, strips) = re_query::get_component_with_instances(
+        store,
+        query,
+        &instance_path.entity_path,
+        LineStrip2D::name(),
+    )?;
+    let strip = strips
+        .lookup::<LineStrip2D>(&instance_path.instance_key)
+        .ok()?;
+    let world_from_entity = transforms.reference_from_entity(&instance_path.entity_path)?;
+    let points = strip
+        .0
+        .iter()
+        .map(|point| {
+            world_from_entity
+                .transform_point3(glam::Vec2::from(*point).extend(0.0))
+                .truncate()
+        })
+        .collect_vec();
+
+    closest_segment(&points, pointer_in_space2d)
+}
+
+/// Index and length of the segment of the line strip through `points` that is closest to `pos`.
+fn closest_segment(points: &[glam::Vec2], pos: glam::Vec2) -> Option<(usize, f32)> {
+    points
+        .iter()
+        .tuple_windows()
+        .enumerate()
+        .map(|(index, (start, end))| {
+            let segment = *end - *start;
+            let t = if segment.length_squared() > 0.0 {
+                ((pos - *start).dot(segment) / segment.length_squared()).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let dist_sq = (*start + t * segment).distance_squared(pos);
+            (index, segment.length(), dist_sq)
+        })
+        .min_by(|(_, _, a), (_, _, b)| a.total_cmp(b))
+        .map(|(index, length, _)| (index, length))
+}
+
+fn line_segment_ui(
+    ui: &mut egui::Ui,
+    segment_index: usize,
+    length: f32,
+    scene_units_per_meter: Option<f32>,
+) {
+    ui.label(format!("Segment: {segment_index}"));
+    let mut length_text = format!("Length: {}", format_f32(length));
+    if let Some(scene_units_per_meter) = scene_units_per_meter {
+        length_text += &format!(" ({} m)", format_f32(length / scene_units_per_meter));
+    }
+    ui.label(length_text);
+}
+
+/// The [`Tooltip`] logged for the hovered instance, if any.
+fn hovered_tooltip(
+    store: &re_data_store::DataStore,
+    query: &re_data_store::LatestAtQuery,
+    instance_path: &InstancePath,
+) -> Option<Tooltip> {
+    use re_types::Loggable as _;
+
+    let (_, _, tooltips) = re_query::get_component_with_instances(
+        store,
+        query,
+        &instance_path.entity_path,
+        Tooltip::name(),
+    )?;
+    tooltips.lookup::<Tooltip>(&instance_path.instance_key).ok()
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct TooltipEmphasis {
+    bold: bool,
+    italic: bool,
+}
+
+/// Splits the markup of a [`Tooltip`] into runs of equally emphasized text, without the markers.
+///
+/// `**` toggles bold and `*` toggles italic text.
+fn tooltip_spans(text: &str) -> Vec<(&str, TooltipEmphasis)> {
+    let mut spans = Vec::new();
+    let mut emphasis = TooltipEmphasis::default();
+    let mut rest = text;
+    while let Some(marker_start) = rest.find('*') {
+        if marker_start > 0 {
+            spans.push((&rest[..marker_start], emphasis));
+        }
+        rest = &rest[marker_start..];
+        if let Some(after_marker) = rest.strip_prefix("**") {
+            emphasis.bold = !emphasis.bold;
+            rest = after_marker;
+        } else {
+            emphasis.italic = !emphasis.italic;
+            rest = &rest[1..];
+        }
+    }
+    if !rest.is_empty() {
+        spans.push((rest, emphasis));
+    }
+    spans
+}
+
+/// Lays out the markup of a [`Tooltip`], keeping its line breaks.
+///
+/// Bold text is shown in the strong text color, since egui has no bold font by default.
+fn tooltip_layout_job(text: &str, style: &egui::Style) -> egui::text::LayoutJob {
+    let font_id = egui::TextStyle::Body.resolve(style);
+    let mut job = egui::text::LayoutJob::default();
+    for (span, emphasis) in tooltip_spans(text) {
+        job.append(
+            span,
+            0.0,
+            egui::TextFormat {
+                font_id: font_id.clone(),
+                color: if emphasis.bold {
+                    style.visuals.strong_text_color()
+                } else {
+                    style.visuals.text_color()
+                },
+                italics: emphasis.italic,
+                ..Default::default()
+            },
+        );
+    }
+    job
+}
+
 fn hit_ui(ui: &mut egui::Ui, hit: &crate::picking::PickingRayHit) {
     if hit.hit_type == PickingHitType::GpuPickingResult {
         let glam::Vec3 { x, y, z } = hit.space_position;
         ui.label(format!("Hover position: [{x:.5}, {y:.5}, {z:.5}]"));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use re_types::datatypes::{TensorBuffer, TensorData, TensorDimension};
+    use re_types::tensor_data::TensorDataMeaning;
+
+    use crate::view_kind::SpatialSpaceViewKind;
+
+    use super::{
+        click_selection, closest_segment, pixel_query_text, tooltip_spans, ClickSelection,
+        TooltipEmphasis,
+    };
+
+    #[test]
+    fn toggle_modifier_alone_does_not_hover_within_a_radius() {
+        use re_space_view::controls::{RADIUS_HOVER_2D_MODIFIER, TOGGLE_SELECTION_2D_MODIFIER};
+
+        // Clicking with just the toggle modifier only affects the closest instance,
+        // while clicking with the radius hover modifier toggles all hovered instances.
+        assert!(!TOGGLE_SELECTION_2D_MODIFIER.contains(RADIUS_HOVER_2D_MODIFIER));
+        assert!(RADIUS_HOVER_2D_MODIFIER.contains(TOGGLE_SELECTION_2D_MODIFIER));
+    }
+
+    #[test]
+    fn click_selection_toggles_unless_a_pixel_was_queried() {
+        let click = |modifiers, pixel_queried| {
+            click_selection(
+                SpatialSpaceViewKind::TwoD,
+                modifiers,
+                pixel_queried,
+                false,
+                false,
+            )
+        };
+        // Clicking anything but an image with the pixel query modifier still toggles the selection.
+        assert_eq!(
+            click(egui::Modifiers::COMMAND, false),
+            ClickSelection::Toggle
+        );
+        assert_eq!(
+            click(egui::Modifiers::COMMAND, true),
+            ClickSelection::Unchanged
+        );
+        assert_eq!(click(egui::Modifiers::SHIFT, false), ClickSelection::Toggle);
+        assert_eq!(click(egui::Modifiers::NONE, false), ClickSelection::Replace);
+
+        // Shift-clicking only toggles in 2D views.
+        assert_eq!(
+            click_selection(
+                SpatialSpaceViewKind::ThreeD,
+                egui::Modifiers::SHIFT,
+                false,
+                false,
+                false
+            ),
+            ClickSelection::Replace
+        );
+    }
+
+    #[test]
+    fn closest_segment_of_line_strip() {
+        let points = [
+            glam::vec2(0.0, 0.0),
+            glam::vec2(3.0, 0.0),
+            glam::vec2(3.0, 4.0),
+        ];
+        assert_eq!(
+            closest_segment(&points, glam::vec2(1.0, -1.0)),
+            Some((0, 3.0))
+        );
+        assert_eq!(
+            closest_segment(&points, glam::vec2(4.0, 3.0)),
+            Some((1, 4.0))
+        );
+        assert_eq!(closest_segment(&points[..1], glam::Vec2::ZERO), None);
+    }
+
+    #[test]
+    fn pixel_query_names_color_channels() {
+        let rgb = TensorData::new(
+            vec![
+                TensorDimension::height(1),
+                TensorDimension::width(2),
+                TensorDimension::depth(3),
+            ],
+            TensorBuffer::U8(vec![0, 0, 0, 255, 128, 0].into()),
+        );
+        assert_eq!(
+            pixel_query_text(&rgb, TensorDataMeaning::Unknown, [1, 0]).as_deref(),
+            Some("Pixel (x=1, y=0): R=255, G=128, B=0")
+        );
+        assert_eq!(
+            pixel_query_text(&rgb, TensorDataMeaning::Unknown, [2, 0]),
+            None
+        );
+
+        let depth = TensorData::new(
+            vec![TensorDimension::height(1), TensorDimension::width(1)],
+            TensorBuffer::F32(vec![0.5].into()),
+        );
+        assert_eq!(
+            pixel_query_text(&depth, TensorDataMeaning::Depth, [0, 0]).as_deref(),
+            Some("Pixel (x=0, y=0): 0.5")
+        );
+    }
+
+    #[test]
+    fn tooltip_markup_spans() {
+        let plain = TooltipEmphasis::default();
+        let bold = TooltipEmphasis {
+            bold: true,
+            italic: false,
+        };
+        let italic = TooltipEmphasis {
+            bold: false,
+            italic: true,
+        };
+        assert_eq!(
+            tooltip_spans("**score:** 0.93\n*occluded*"),
+            vec![("score:", bold), (" 0.93\n", plain), ("occluded", italic)]
+        );
+        assert_eq!(tooltip_spans("track 7"), vec![("track 7", plain)]);
+    }
+}
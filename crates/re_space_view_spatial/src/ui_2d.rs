@@ -1,37 +1,568 @@
+use std::{collections::BTreeSet, ops::RangeInclusive};
+
 use egui::{
     emath::RectTransform, pos2, vec2, Align2, Color32, NumExt as _, Pos2, Rect, ScrollArea, Shape,
     Vec2,
 };
+use itertools::Itertools as _;
 use macaw::IsoTransform;
 
-use re_entity_db::EntityPath;
-use re_renderer::view_builder::{TargetConfiguration, ViewBuilder};
-use re_space_view::controls::{DRAG_PAN2D_BUTTON, RESET_VIEW_BUTTON_TEXT, ZOOM_SCROLL_MODIFIER};
-use re_types::{archetypes::Pinhole, components::ViewCoordinates};
+use re_data_ui::ZoomedImageRegionOptions;
+use re_entity_db::{
+    EntityPath, EntityProperties, EntityTree, ImageMagnification, ImageRotation, InstancePath,
+    InstancePathHash,
+};
+use re_log_types::{EntityPathHash, RowId, TimeInt};
+use re_renderer::{
+    renderer::{
+        ColormappedTexture, RectangleDrawData, RectangleOptions, TextureFilterMag,
+        TextureFilterMin, TexturedRect,
+    },
+    view_builder::{TargetConfiguration, ViewBuilder},
+    QueueableDrawData,
+};
+use re_space_view::{
+    controls::{
+        ASPECT_SCROLL_MODIFIER, DRAG_PAN2D_ALT_BUTTON, DRAG_PAN2D_BUTTON,
+        HIGHLIGHT_WHOLE_ENTITY_2D_MODIFIER, INSPECT_MODE_2D_KEY, MARQUEE_SELECT_2D_MODIFIER,
+        PIN_TOOLTIP_KEY, PIXEL_QUERY_2D_MODIFIER, RADIUS_HOVER_2D_MODIFIER, RESET_VIEW_BUTTON_TEXT,
+        SELECT_NEXT_INSTANCE_KEY, SELECT_PREVIOUS_INSTANCE_KEY, TOGGLE_SELECTION_2D_MODIFIER,
+        ZOOM_SCROLL_MODIFIER, ZOOM_TO_SELECTION_KEY,
+    },
+    ScreenshotMode,
+};
+use re_types::{archetypes::Pinhole, components::ViewCoordinates, datatypes::ClassId};
 use re_viewer_context::{
-    gpu_bridge, SelectedSpaceContext, SpaceViewSystemExecutionError, SystemExecutionOutput,
-    ViewQuery, ViewerContext,
+    gpu_bridge, AppOptions, DataResult, DefaultColor, DepthUnit, IdentifiedViewSystem as _, Item,
+    SelectedSpaceContext, Selection, SpaceViewSystemExecutionError, SystemCommand,
+    SystemCommandSender as _, SystemExecutionOutput, TensorHistogram, TensorHistogramCache,
+    ViewContextCollection, ViewQuery, ViewerContext, VisualizerCollection,
 };
 
 use super::{
     eye::Eye,
-    ui::{create_labels, picking, screenshot_context_menu},
+    ui::{
+        create_labels, image_class_region, inspected_instances_ui, picking,
+        pinned_image_tooltips_ui, pixel_query_ui, screenshot_context_menu_ui, screenshots_enabled,
+        InspectedInstance, PinnedImageTooltip, PixelQuery,
+    },
 };
 use crate::{
-    contexts::SharedRenderBuilders,
-    query_pinhole,
+    contexts::{
+        AnnotationSceneContext, HiddenClasses, NonInteractiveEntities, SharedRenderBuilders,
+        TransformContext, ViewportCulling,
+    },
+    frame_diff::{self, FrameDiffReference},
+    picking::{PickingContext, PickingHitType, PickingResult},
+    point_density, query_pinhole,
+    scene_bounding_boxes::{self, BoundingBoxAccumulation, SceneBoundingBoxes},
+    scene_stats::SceneStats,
+    trails,
     ui::{outline_config, SpatialSpaceViewState},
     view_kind::SpatialSpaceViewKind,
-    visualizers::collect_ui_labels,
+    view_orientation::ViewOrientation,
+    visualizers::{
+        collect_ui_labels, Boxes2DVisualizer, ImageVisualizer, Points2DVisualizer,
+        Polygons2DVisualizer, UiLabel, ViewerImage,
+    },
 };
 
 // ---
 
-#[derive(Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 #[serde(default)]
 pub struct View2DState {
     /// The zoom and pan state, which is either a zoom/center or `Auto` which will fill the screen
     zoom: ZoomState2D,
+
+    /// Region of the hovered image that should be highlighted, in scene coordinates.
+    ///
+    /// For segmentation images this covers all pixels of the hovered class,
+    /// for all other images it is the hovered pixel.
+    #[serde(skip)]
+    pub hover_highlight_region: Option<Rect>,
+
+    /// Scene position at which an ongoing marquee selection started.
+    #[serde(skip)]
+    marquee_start: Option<Pos2>,
+
+    /// Show a crosshair at the center of the hovered point or box.
+    pub show_snap_crosshair: bool,
+
+    /// Show the ray hovered in a 3D view, projected into the camera of this view.
+    pub show_3d_projection_rays: bool,
+
+    /// Depth in front of the camera at which projected rays end.
+    ///
+    /// `None` draws them all the way to their vanishing point.
+    pub projection_ray_depth: Option<f32>,
+
+    /// Hovering an instance highlights all instances of its entity.
+    ///
+    /// Holding [`HIGHLIGHT_WHOLE_ENTITY_2D_MODIFIER`] inverts this.
+    pub highlight_whole_entity: bool,
+
+    /// Color selected instances are outlined with.
+    ///
+    /// If `None`, the selection color of the ui is used.
+    pub selection_outline_color: Option<Color32>,
+
+    /// Color the view is cleared with.
+    ///
+    /// If `None`, the background color of the ui theme is used.
+    pub background_color: Option<Color32>,
+
+    /// Draw a checkerboard behind the scene instead of a solid color, making transparency visible.
+    ///
+    /// Exported images still use [`Self::background_color`].
+    pub checkerboard_background: bool,
+
+    /// How the point hovered in a 3D view is marked in this view.
+    pub projected_point_marker: ProjectedPointMarker,
+
+    /// How labels of points and boxes are connected to what they label.
+    pub label_style: LabelStyle,
+
+    /// What labels of points and boxes are drawn on.
+    pub label_background: LabelBackground,
+
+    /// Color of the text of labels.
+    ///
+    /// If `None`, labels have the color of what they label.
+    pub label_text_color: Option<Color32>,
+
+    /// Factor for the width of all lines whose width is given in ui points, including selection outlines.
+    ///
+    /// Widths in scene units are left as they are.
+    pub line_width_scale: f32,
+
+    /// Ignore all input that would zoom, pan or change the selection.
+    ///
+    /// Hovering still works as usual.
+    pub lock_view: bool,
+
+    /// Instance the context menu was opened on.
+    #[serde(skip)]
+    context_menu_instance: Option<InstancePath>,
+
+    /// Zoom state we're animating towards, and the time (as in `egui::InputState::time`) at which it should be reached.
+    #[serde(skip)]
+    zoom_target: Option<(ZoomState2D, f64)>,
+
+    /// Whether programmatic zoom changes (resetting the view, zooming to an object) are animated.
+    pub animate_zoom_transitions: bool,
+
+    /// Keep a single selected instance centered while zoomed in, e.g. while the timeline is playing.
+    pub follow_selection: bool,
+
+    /// When zoomed in beyond 1×, round the scale to a whole number of device pixels per scene unit
+    /// and align the view to the device pixel grid, so that image pixel boundaries stay crisp.
+    pub pixel_snap: bool,
+
+    /// Overrides the smallest allowed scale, in ui points per scene unit.
+    ///
+    /// If `None`, the view can't be zoomed out further than fitting the entire scene.
+    pub min_zoom_scale: Option<f32>,
+
+    /// Overrides the largest allowed scale, in ui points per scene unit.
+    ///
+    /// If `None`, a single scene unit can at most fill the entire width of the view.
+    pub max_zoom_scale: Option<f32>,
+
+    /// Instances that can't be hovered or selected in this view.
+    ///
+    /// A locked splat instance locks all instances of its entity.
+    pub locked_instances: ahash::HashSet<InstancePath>,
+
+    /// Show an overlay listing all entities of the view with a visibility toggle each.
+    pub show_entity_legend: bool,
+
+    /// What dragging with [`DRAG_PAN2D_BUTTON`] does.
+    pub interaction_mode: InteractionMode,
+
+    /// Clicking an instance shows its details in a floating window instead of selecting it.
+    pub inspect_mode: bool,
+
+    /// Scene position at which an ongoing box annotation drag started.
+    #[serde(skip)]
+    annotation_start: Option<Pos2>,
+
+    /// Box that was dragged out in [`InteractionMode::AnnotateBox`] and is waiting for a label.
+    #[serde(skip)]
+    pending_annotation: Option<PendingBoxAnnotation>,
+
+    /// Resolution of exported views, as a multiple of the on-screen resolution.
+    pub export_scale: f32,
+
+    /// How the bounding box the view is fit to follows the data.
+    pub bbox_accumulation: BoundingBoxAccumulation,
+
+    /// Entity the view is fit to when zoomed to fit, instead of the whole scene.
+    ///
+    /// The whole scene is fit while the entity isn't part of it.
+    pub fit_target: Option<EntityPath>,
+
+    /// Region of interest in scene coordinates; everything outside of it is dimmed.
+    ///
+    /// Its corners can be dragged to adjust it.
+    pub focus_rect: Option<Rect>,
+
+    /// Show a colorbar for colormapped depth images.
+    pub show_depth_legend: bool,
+
+    /// Keep panning and zooming from moving the center far outside the scene.
+    ///
+    /// Programmatic moves like zooming to an instance are not constrained.
+    pub constrain_pan: bool,
+
+    /// Share of the scene's extent along each axis that stays in view while [`Self::constrain_pan`] is set.
+    ///
+    /// Capped by the size of the view, so that zooming in far stays possible.
+    pub min_visible_scene_fraction: f32,
+
+    /// Show a strip at the bottom of the view marking the times this view has data at.
+    pub show_timeline_strip: bool,
+
+    /// Pause playback while the pointer is over the view.
+    pub pause_on_hover: bool,
+
+    /// Whether playback is currently paused because of [`Self::pause_on_hover`].
+    #[serde(skip)]
+    paused_on_hover: bool,
+
+    /// Show a scale bar at the bottom of the view.
+    pub show_scale_bar: bool,
+
+    /// How many scene units make up a meter, used to label the scale bar.
+    ///
+    /// If `None`, the scale bar is labeled in scene units, which usually are image pixels.
+    pub scene_units_per_meter: Option<f32>,
+
+    /// Show a histogram of the image values below the view, if the view shows a single image.
+    pub show_histogram: bool,
+
+    /// Show reference circles for the smallest, median and largest radius of the points in the view.
+    pub show_point_size_legend: bool,
+
+    /// Show 2D points as a heatmap of how many fall into each region of the view, instead of as markers.
+    pub show_point_density: bool,
+
+    /// How many texels the magnifier in image tooltips shows on each side of the hovered texel.
+    pub magnifier_texel_radius: u32,
+
+    /// Size of each texel in the magnifier of image tooltips, in ui points.
+    pub magnifier_points_per_texel: f32,
+
+    /// Print the value of each texel into the magnifier of image tooltips, if there is room for it.
+    pub magnifier_show_texel_values: bool,
+
+    /// Class hovered in the class legend, along with the row id of its annotation context.
+    #[serde(skip)]
+    hovered_legend_class: Option<(RowId, ClassId)>,
+
+    /// Class ids hidden via the class legend.
+    ///
+    /// Stored as raw ids rather than [`ClassId`]s so they can be serialized.
+    pub hidden_class_ids: BTreeSet<u16>,
+
+    /// Time the points and boxes of the view are compared against, if comparing frames.
+    #[serde(skip)]
+    pub frame_diff_reference: Option<FrameDiffReference>,
+
+    /// Number of previous frames the trails of points and boxes reach back, if trails are shown.
+    ///
+    /// At most [`trails::MAX_TRAIL_LENGTH`].
+    pub trail_length: Option<usize>,
+
+    /// Flips and rotation applied to the whole content of the view.
+    pub orientation: ViewOrientation,
+
+    /// Index into the images stacked under the pointer that the last click selected.
+    ///
+    /// Repeated clicks at the same spot cycle through all stacked images.
+    #[serde(skip)]
+    pick_through_idx: usize,
+
+    /// Ui position of the last click that selected one of several stacked images.
+    #[serde(skip)]
+    pick_through_pos: Option<Pos2>,
+
+    /// What's under the pointer, shown in the status bar below the view.
+    #[serde(skip)]
+    pub pointer_status: Option<PointerStatus>,
+
+    /// Show the region of the space that is currently visible in the top right corner of the view.
+    pub show_visible_region: bool,
+
+    /// Region of the space visible in the last frame, in the space's own coordinates.
+    #[serde(skip)]
+    pub visible_region: Option<Rect>,
+
+    /// Region of the space, in its own coordinates, that the view should show exactly in the next frame.
+    #[serde(skip)]
+    pub requested_visible_region: Option<Rect>,
+
+    /// Mapping between ui and scene coordinates in the last frame.
+    #[serde(skip)]
+    view_transform: Option<ViewTransform2D>,
+
+    /// Value of the pixel last clicked while holding [`PIXEL_QUERY_2D_MODIFIER`], shown until the next click.
+    #[serde(skip)]
+    pub pixel_query: Option<PixelQuery>,
+
+    /// Image tooltips pinned into floating windows, shown until they are closed.
+    #[serde(skip)]
+    pub pinned_tooltips: Vec<PinnedImageTooltip>,
+
+    /// Id of the next pinned tooltip, used to tell their windows apart.
+    #[serde(skip)]
+    next_pinned_tooltip_id: u64,
+
+    /// Instances clicked in [`Self::inspect_mode`], shown in floating windows until they are closed.
+    #[serde(skip)]
+    pub inspected_instances: Vec<InspectedInstance>,
+}
+
+impl Default for View2DState {
+    fn default() -> Self {
+        Self {
+            zoom: Default::default(),
+            hover_highlight_region: None,
+            marquee_start: None,
+            show_snap_crosshair: true,
+            show_3d_projection_rays: false,
+            projection_ray_depth: None,
+            highlight_whole_entity: false,
+            selection_outline_color: None,
+            background_color: None,
+            checkerboard_background: false,
+            projected_point_marker: ProjectedPointMarker::default(),
+            label_style: LabelStyle::default(),
+            label_background: LabelBackground::default(),
+            label_text_color: None,
+            line_width_scale: 1.0,
+            lock_view: false,
+            context_menu_instance: None,
+            zoom_target: None,
+            animate_zoom_transitions: true,
+            follow_selection: false,
+            pixel_snap: false,
+            min_zoom_scale: None,
+            max_zoom_scale: None,
+            locked_instances: Default::default(),
+            show_entity_legend: false,
+            interaction_mode: InteractionMode::default(),
+            inspect_mode: false,
+            annotation_start: None,
+            pending_annotation: None,
+            export_scale: 2.0,
+            bbox_accumulation: BoundingBoxAccumulation::Current,
+            fit_target: None,
+            focus_rect: None,
+            show_depth_legend: true,
+            constrain_pan: true,
+            min_visible_scene_fraction: 0.1,
+            show_timeline_strip: false,
+            pause_on_hover: false,
+            paused_on_hover: false,
+            show_scale_bar: false,
+            scene_units_per_meter: None,
+            show_histogram: false,
+            show_point_size_legend: false,
+            show_point_density: false,
+            magnifier_texel_radius: ZoomedImageRegionOptions::default().texel_radius,
+            magnifier_points_per_texel: ZoomedImageRegionOptions::default().points_per_texel,
+            magnifier_show_texel_values: false,
+            hovered_legend_class: None,
+            hidden_class_ids: Default::default(),
+            frame_diff_reference: None,
+            trail_length: None,
+            orientation: ViewOrientation::default(),
+            pick_through_idx: 0,
+            pick_through_pos: None,
+            pointer_status: None,
+            show_visible_region: false,
+            visible_region: None,
+            requested_visible_region: None,
+            view_transform: None,
+            pixel_query: None,
+            pinned_tooltips: Vec::new(),
+            next_pinned_tooltip_id: 0,
+            inspected_instances: Vec::new(),
+        }
+    }
+}
+
+/// Snapshot of how a 2D view mapped scene coordinates to the ui in its last frame.
+///
+/// Scene coordinates are those of the canvas, i.e. after flipping and rotating the view.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewTransform2D {
+    ui_from_space: RectTransform,
+}
+
+impl ViewTransform2D {
+    /// Maps scene coordinates to ui points.
+    pub fn ui_from_space(&self) -> RectTransform {
+        self.ui_from_space
+    }
+
+    /// Maps ui points to scene coordinates.
+    pub fn space_from_ui(&self) -> RectTransform {
+        self.ui_from_space.inverse()
+    }
+
+    /// Ui points per scene unit along each axis.
+    ///
+    /// The axes only differ if the view is stretched.
+    pub fn scale(&self) -> Vec2 {
+        self.ui_from_space.scale()
+    }
+}
+
+/// Position and image pixel under the pointer of a 2D view.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointerStatus {
+    /// Pointer position in the space's own coordinates, i.e. not flipped or rotated.
+    pub pos: Pos2,
+
+    /// Pixel in the topmost hovered image, if any.
+    pub pixel: Option<[u32; 2]>,
+
+    /// Raw value of [`Self::pixel`], if it could be read.
+    pub pixel_value: Option<String>,
+
+    /// Number of points in the hovered cell, if points are shown as a density heatmap.
+    pub point_density: Option<u32>,
+}
+
+impl PointerStatus {
+    fn text(&self) -> String {
+        let mut text = format!("x: {:.1}, y: {:.1}", self.pos.x, self.pos.y);
+        if let Some([x, y]) = self.pixel {
+            text += &format!("   pixel: [{x}, {y}]");
+            if let Some(value) = &self.pixel_value {
+                text += &format!("   value: {value}");
+            }
+        }
+        if let Some(point_density) = self.point_density {
+            text += &format!("   points: {point_density}");
+        }
+        text
+    }
+}
+
+/// What dragging in a 2D view does.
+#[derive(Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum InteractionMode {
+    /// Dragging pans the view.
+    #[default]
+    Navigate,
+
+    /// Dragging creates a new box annotation.
+    AnnotateBox,
+}
+
+/// How a point hovered in a 3D view is marked when projected into a 2D view.
+#[derive(Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ProjectedPointMarker {
+    pub shape: ProjectedPointShape,
+
+    /// Radius of the marker, in ui points.
+    pub radius: f32,
+
+    /// Color of the marker.
+    ///
+    /// If `None`, the selection or hover color is used.
+    pub color: Option<Color32>,
+
+    /// Color of the outline around the marker, also used for the background of its depth label.
+    pub outline_color: Color32,
+}
+
+impl Default for ProjectedPointMarker {
+    fn default() -> Self {
+        Self {
+            shape: ProjectedPointShape::default(),
+            radius: 4.0,
+            color: None,
+            outline_color: Color32::BLACK,
+        }
+    }
+}
+
+/// Shape of a [`ProjectedPointMarker`].
+#[derive(Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum ProjectedPointShape {
+    /// A filled circle.
+    #[default]
+    Dot,
+
+    /// Two crossed lines, leaving the marked spot itself uncovered.
+    Crosshair,
+}
+
+/// How labels of points and boxes are drawn in a 2D view.
+#[derive(Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum LabelStyle {
+    /// Text boxes right next to what they label.
+    #[default]
+    Plain,
+
+    /// Text boxes a bit further away, with a thin line pointing to the center of what they label.
+    ///
+    /// Keeps labels of objects close to each other apart.
+    Callout,
+}
+
+/// What labels are drawn on in a 2D view.
+///
+/// Hovered and selected labels always use the highlight colors of the ui.
+#[derive(Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum LabelBackground {
+    /// The background color of widgets in the ui theme.
+    #[default]
+    Theme,
+
+    /// A color of your choice, possibly translucent.
+    Color(Color32),
+
+    /// No background at all.
+    None,
+}
+
+/// Everything about how labels are drawn that can be configured per view.
+#[derive(Clone, Copy, Default)]
+pub struct LabelAppearance {
+    pub style: LabelStyle,
+    pub background: LabelBackground,
+
+    /// If `None`, labels have the color of what they label.
+    pub text_color: Option<Color32>,
+}
+
+/// Screenshot requested via the context menu of a 2D view.
+#[derive(Clone, Copy)]
+enum ScreenshotRequest {
+    /// Read back what is shown on screen.
+    Visible(ScreenshotMode),
+
+    /// Render the visible region again at a multiple of the on-screen resolution and save it to disk.
+    Export { scale: f32 },
+
+    /// Save the lines, points and labels of the visible region as an SVG document.
+    ExportSvg,
+}
+
+/// A box annotation that has been dragged out but not yet submitted.
+#[derive(Clone, PartialEq)]
+struct PendingBoxAnnotation {
+    /// The box in scene coordinates.
+    rect: Rect,
+
+    /// Label being typed in by the user.
+    label: String,
 }
 
 #[derive(Clone, Copy, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -44,6 +575,12 @@ pub enum ZoomState2D {
         /// Number of ui points per scene unit
         scale: f32,
 
+        /// Number of ui points per scene unit along the vertical axis, if different from `scale`.
+        ///
+        /// Set when the horizontal axis is zoomed on its own, e.g. to stretch out a time series.
+        #[serde(default)]
+        scale_y: Option<f32>,
+
         /// Which scene coordinate will be at the center of the zoomed region.
         center: Pos2,
 
@@ -52,7 +589,26 @@ pub enum ZoomState2D {
     },
 }
 
+/// How long it takes to animate to a new [`ZoomState2D`], in seconds.
+const ZOOM_ANIMATION_DURATION: f64 = 0.3;
+
 impl View2DState {
+    /// Compare the view against the current time of the active timeline from now on.
+    pub fn pin_frame_diff_reference(&mut self, ctx: &ViewerContext<'_>) {
+        let time_ctrl = ctx.rec_cfg.time_ctrl.read();
+        self.frame_diff_reference = time_ctrl.time_int().map(|time| FrameDiffReference {
+            timeline: *time_ctrl.timeline(),
+            time,
+        });
+    }
+
+    /// Reference time to compare against, unless it is on a different timeline than the view.
+    fn active_frame_diff_reference(&self, query: &ViewQuery<'_>) -> Option<TimeInt> {
+        self.frame_diff_reference
+            .filter(|reference| reference.timeline == query.timeline)
+            .map(|reference| reference.time)
+    }
+
     /// Determine the optimal sub-region and size based on the `ZoomState` and
     /// available size. This will generally be used to construct the painter and
     /// subsequent transforms
@@ -60,14 +616,29 @@ impl View2DState {
     /// Returns `(desired_size, scroll_offset)` where:
     ///   - `desired_size` is the size of the painter necessary to capture the zoomed view in ui points
     ///   - `scroll_offset` is the position of the `ScrollArea` offset in ui points
-    fn desired_size_and_offset(&self, available_size: Vec2, canvas_rect: Rect) -> (Vec2, Vec2) {
+    fn desired_size_and_offset(
+        &self,
+        available_size: Vec2,
+        canvas_rect: Rect,
+        pixels_per_point: f32,
+    ) -> (Vec2, Vec2) {
         match self.zoom {
-            ZoomState2D::Scaled { scale, center, .. } => {
+            ZoomState2D::Scaled {
+                scale,
+                scale_y,
+                center,
+                ..
+            } => {
+                let scale = self.display_scales(scale, scale_y, pixels_per_point);
                 let desired_size = canvas_rect.size() * scale;
 
                 // Try to keep the center of the scene in the middle of the available size
-                let scroll_offset = (center.to_vec2() - canvas_rect.left_top().to_vec2()) * scale
+                let mut scroll_offset = (center.to_vec2() - canvas_rect.left_top().to_vec2())
+                    * scale
                     - available_size / 2.0;
+                if self.is_pixel_snapping(scale.max_elem()) {
+                    scroll_offset = (scroll_offset * pixels_per_point).round() / pixels_per_point;
+                }
 
                 (desired_size, scroll_offset)
             }
@@ -91,6 +662,30 @@ impl View2DState {
         }
     }
 
+    /// Whether [`Self::pixel_snap`] applies at the given scale.
+    fn is_pixel_snapping(&self, scale: f32) -> bool {
+        self.pixel_snap && scale > 1.0
+    }
+
+    /// The scale at which the scene is actually shown, taking [`Self::pixel_snap`] into account.
+    ///
+    /// The zoom state keeps the unsnapped scale, so that many small zoom steps still add up.
+    fn display_scale(&self, scale: f32, pixels_per_point: f32) -> f32 {
+        if self.is_pixel_snapping(scale) {
+            (scale * pixels_per_point).round().at_least(1.0) / pixels_per_point
+        } else {
+            scale
+        }
+    }
+
+    /// The horizontal and vertical scale at which the scene is actually shown.
+    fn display_scales(&self, scale: f32, scale_y: Option<f32>, pixels_per_point: f32) -> Vec2 {
+        vec2(
+            self.display_scale(scale, pixels_per_point),
+            self.display_scale(scale_y.unwrap_or(scale), pixels_per_point),
+        )
+    }
+
     /// Update our zoom state based on response
     /// If nothing else happens this will reset `accepting_scroll` to true when appropriate
     fn update(
@@ -99,309 +694,3230 @@ impl View2DState {
         ui_to_space: egui::emath::RectTransform,
         canvas_rect: Rect,
         available_size: Vec2,
+        app_options: &AppOptions,
     ) {
+        if self.lock_view {
+            return;
+        }
+
         // Determine if we are zooming
-        let zoom_delta = response.ctx.input(|i| i.zoom_delta());
+        let zoom_delta = response.ctx.input(|i| {
+            let mut zoom_delta = i.zoom_delta();
+            if !app_options.zoom_2d_requires_modifier && zoom_delta == 1.0 {
+                // Plain scrolling zooms, using the same conversion as egui does for modifier-scroll.
+                zoom_delta = (i.smooth_scroll_delta.y / 200.0).exp();
+            }
+            if app_options.invert_zoom_2d {
+                zoom_delta = 1.0 / zoom_delta;
+            }
+            zoom_delta
+        });
         let hovered_zoom = if response.hovered() && zoom_delta != 1.0 {
             Some(zoom_delta)
         } else {
             None
         };
+        // Two-finger pinches on touch screens already show up in `zoom_delta`, two-finger drags pan.
+        let multi_touch = if response.hovered() {
+            response.ctx.input(|i| i.multi_touch())
+        } else {
+            None
+        };
+        let touch_pan = touch_pan_delta(multi_touch.as_ref());
+        let alt_panning = response.dragged_by(DRAG_PAN2D_ALT_BUTTON);
+        let zoom_horizontal_only = response
+            .ctx
+            .input(|i| i.modifiers.contains(ASPECT_SCROLL_MODIFIER));
+
+        // Any zoom or pan input cancels an ongoing animation.
+        if hovered_zoom.is_some()
+            || response.dragged_by(DRAG_PAN2D_BUTTON)
+            || alt_panning
+            || touch_pan.is_some()
+            || response.double_clicked()
+        {
+            self.zoom_target = None;
+        }
+        if let Some((target, target_time)) = self.zoom_target {
+            self.animate_zoom(response, target, target_time, canvas_rect, available_size);
+            return;
+        }
 
         match self.zoom {
             ZoomState2D::Auto => {
-                if let Some(input_zoom) = hovered_zoom {
-                    if input_zoom > 1.0 {
-                        let scale = response.rect.height() / ui_to_space.to().height();
-                        let center = canvas_rect.center();
-                        self.zoom = ZoomState2D::Scaled {
-                            scale,
-                            center,
-                            accepting_scroll: false,
-                        };
-                        // Recursively update now that we have initialized `ZoomState` to `Scaled`
-                        self.update(response, ui_to_space, canvas_rect, available_size);
-                    }
+                let zooming_in = hovered_zoom.map_or(false, |input_zoom| input_zoom > 1.0);
+                if zooming_in || alt_panning || touch_pan.is_some() {
+                    let scale = response.rect.height() / ui_to_space.to().height();
+                    let center = canvas_rect.center();
+                    self.zoom = ZoomState2D::Scaled {
+                        scale,
+                        scale_y: None,
+                        center,
+                        accepting_scroll: false,
+                    };
+                    // Recursively update now that we have initialized `ZoomState` to `Scaled`
+                    self.update(
+                        response,
+                        ui_to_space,
+                        canvas_rect,
+                        available_size,
+                        app_options,
+                    );
                 }
             }
             ZoomState2D::Scaled {
                 mut scale,
+                mut scale_y,
                 mut center,
                 ..
             } => {
                 let mut accepting_scroll = true;
+                let mut moved_by_user = false;
+                let mut zoomed_out_of_scene = false;
+
+                let pixels_per_point = response.ctx.pixels_per_point();
 
                 // If we are zooming, adjust the scale and center
                 if let Some(input_zoom) = hovered_zoom {
-                    let new_scale = scale * input_zoom;
+                    let scale_range = self.scale_range(available_size, canvas_rect);
+                    let unclamped_scale = scale * input_zoom;
+                    let new_scale = unclamped_scale.clamp(*scale_range.start(), *scale_range.end());
+                    let new_scale_y = if zoom_horizontal_only {
+                        // Keep the vertical scale, only stretching the horizontal axis.
+                        Some(scale_y.unwrap_or(scale))
+                    } else {
+                        zoomed_out_of_scene =
+                            self.min_zoom_scale.is_none() && unclamped_scale < *scale_range.start();
+                        scale_y.map(|scale_y| scale_y * new_scale / scale)
+                    };
 
                     // Adjust for mouse location while executing zoom
                     if let Some(hover_pos) = response.ctx.input(|i| i.pointer.hover_pos()) {
                         let zoom_loc = ui_to_space.transform_pos(hover_pos);
 
+                        // The cursor stays fixed relative to what's actually shown.
+                        let shown_scale = self.display_scales(scale, scale_y, pixels_per_point);
+                        let new_shown_scale =
+                            self.display_scales(new_scale, new_scale_y, pixels_per_point);
+
                         // Space-units under the cursor will shift based on distance from center
                         let dist_from_center = zoom_loc - center;
                         // In UI points this happens based on the difference in scale;
-                        let shift_in_ui = dist_from_center * (new_scale - scale);
+                        let shift_in_ui = dist_from_center * (new_shown_scale - shown_scale);
                         // But we will compensate for it by a shift in space units
-                        let shift_in_space = shift_in_ui / new_scale;
+                        let shift_in_space = shift_in_ui / new_shown_scale;
 
                         // Moving the center in the direction of the desired shift
                         center += shift_in_space;
                     }
-                    // Don't show less than one horizontal scene unit in the entire screen.
-                    scale = new_scale.at_most(available_size.x);
+                    scale = new_scale;
+                    scale_y = new_scale_y;
                     accepting_scroll = false;
+                    moved_by_user = true;
                 }
 
                 // If we are dragging, adjust the center accordingly
-                if response.dragged_by(DRAG_PAN2D_BUTTON) {
+                // The pointer follows one of the fingers of a touch gesture, which mustn't pan a second time.
+                let primary_panning = response.dragged_by(DRAG_PAN2D_BUTTON)
+                    && multi_touch.is_none()
+                    && self.marquee_start.is_none()
+                    && self.interaction_mode == InteractionMode::Navigate;
+                if primary_panning || alt_panning {
                     // Adjust center based on drag
-                    center -= response.drag_delta() / scale;
+                    center -= response.drag_delta()
+                        / self.display_scales(scale, scale_y, pixels_per_point);
+                    accepting_scroll = false;
+                    moved_by_user = true;
+                }
+                if let Some(touch_pan) = touch_pan {
+                    center -= touch_pan / self.display_scales(scale, scale_y, pixels_per_point);
                     accepting_scroll = false;
+                    moved_by_user = true;
+                }
+
+                if self.constrain_pan && moved_by_user {
+                    let visible_size =
+                        available_size / self.display_scales(scale, scale_y, pixels_per_point);
+                    center = constrain_center(
+                        center,
+                        canvas_rect,
+                        visible_size,
+                        self.min_visible_scene_fraction,
+                    );
                 }
 
                 // Save the zoom state
-                self.zoom = ZoomState2D::Scaled {
-                    scale,
-                    center,
-                    accepting_scroll,
+                self.zoom = if zoomed_out_of_scene {
+                    // Zooming out beyond the scene means fitting the scene.
+                    ZoomState2D::Auto
+                } else {
+                    ZoomState2D::Scaled {
+                        scale,
+                        scale_y,
+                        center,
+                        accepting_scroll,
+                    }
                 };
             }
         }
 
         // Process things that might reset ZoomState to Auto
-        if let ZoomState2D::Scaled { scale, .. } = self.zoom {
+        if let ZoomState2D::Scaled { scale, scale_y, .. } = self.zoom {
             // If the user double-clicks
             if response.double_clicked() {
-                self.zoom = ZoomState2D::Auto;
+                self.animate_to(ZoomState2D::Auto, response.ctx.input(|i| i.time));
             }
 
             // If our zoomed region is smaller than the available size
-            if canvas_rect.size().x * scale < available_size.x
-                && canvas_rect.size().y * scale < available_size.y
-            {
+            let zoomed_size = canvas_rect.size() * vec2(scale, scale_y.unwrap_or(scale));
+            if zoomed_size.x < available_size.x && zoomed_size.y < available_size.y {
                 self.zoom = ZoomState2D::Auto;
             }
         }
     }
 
-    /// Take the offset from the `ScrollArea` and apply it back to center so that other
-    /// scroll interfaces work as expected.
-    fn capture_scroll(&mut self, offset: Vec2, available_size: Vec2, canvas_rect: Rect) {
-        if let ZoomState2D::Scaled {
-            scale,
-            accepting_scroll,
-            ..
-        } = self.zoom
+    /// Track a marquee selection dragged out while holding [`MARQUEE_SELECT_2D_MODIFIER`].
+    ///
+    /// Returns the marquee rectangle in scene coordinates and whether the drag just finished.
+    fn update_marquee(
+        &mut self,
+        response: &egui::Response,
+        canvas_from_ui: RectTransform,
+    ) -> Option<(Rect, bool)> {
+        if self.lock_view || self.interaction_mode != InteractionMode::Navigate {
+            self.marquee_start = None;
+            return None;
+        }
+
+        let (press_origin, pointer_pos, modifiers) = response.ctx.input(|i| {
+            (
+                i.pointer.press_origin(),
+                i.pointer.interact_pos(),
+                i.modifiers,
+            )
+        });
+
+        if response.drag_started_by(DRAG_PAN2D_BUTTON)
+            && modifiers.contains(MARQUEE_SELECT_2D_MODIFIER)
         {
-            if accepting_scroll {
-                let center = canvas_rect.left_top() + (available_size / 2.0 + offset) / scale;
-                self.zoom = ZoomState2D::Scaled {
-                    scale,
-                    center,
-                    accepting_scroll,
-                };
+            self.marquee_start = press_origin.map(|pos| canvas_from_ui.transform_pos(pos));
+        }
+
+        let start = self.marquee_start?;
+        let end = pointer_pos.map_or(start, |pos| canvas_from_ui.transform_pos(pos));
+
+        let finished = !response.dragged_by(DRAG_PAN2D_BUTTON);
+        if finished {
+            self.marquee_start = None;
+        }
+
+        Some((Rect::from_two_pos(start, end), finished))
+    }
+
+    /// Track a box dragged out in [`InteractionMode::AnnotateBox`].
+    ///
+    /// Returns the box being dragged in scene coordinates.
+    /// Once the drag finishes, the box becomes the pending annotation.
+    fn update_box_annotation(
+        &mut self,
+        response: &egui::Response,
+        canvas_from_ui: RectTransform,
+    ) -> Option<Rect> {
+        if self.lock_view || self.interaction_mode != InteractionMode::AnnotateBox {
+            self.annotation_start = None;
+            self.pending_annotation = None;
+            return None;
+        }
+
+        let (press_origin, pointer_pos) = response
+            .ctx
+            .input(|i| (i.pointer.press_origin(), i.pointer.interact_pos()));
+
+        if response.drag_started_by(DRAG_PAN2D_BUTTON) {
+            self.annotation_start = press_origin.map(|pos| canvas_from_ui.transform_pos(pos));
+            self.pending_annotation = None;
+        }
+
+        let start = self.annotation_start?;
+        let end = pointer_pos.map_or(start, |pos| canvas_from_ui.transform_pos(pos));
+        let rect = Rect::from_two_pos(start, end);
+
+        if !response.dragged_by(DRAG_PAN2D_BUTTON) {
+            self.annotation_start = None;
+            if rect.is_positive() {
+                self.pending_annotation = Some(PendingBoxAnnotation {
+                    rect,
+                    label: String::new(),
+                });
             }
+            return None;
         }
+
+        Some(rect)
+    }
+
+    /// Whether the given instance can't be hovered or selected.
+    pub fn is_instance_locked(&self, instance: &InstancePathHash) -> bool {
+        self.locked_instances.iter().any(|locked| {
+            let locked_hash = locked.hash();
+            locked_hash == *instance
+                || (locked.is_splat() && locked_hash.entity_path_hash == instance.entity_path_hash)
+        })
+    }
+
+    /// The largest allowed scale, in ui points per scene unit.
+    fn max_scale(&self, available_size: Vec2) -> f32 {
+        // Don't show less than one horizontal scene unit in the entire screen.
+        self.max_zoom_scale.unwrap_or(available_size.x)
+    }
+
+    /// The range of allowed scales, in ui points per scene unit.
+    fn scale_range(&self, available_size: Vec2, canvas_rect: Rect) -> RangeInclusive<f32> {
+        let max_scale = self.max_scale(available_size);
+        let min_scale = self.min_zoom_scale.unwrap_or_else(|| {
+            // Don't zoom out further than fitting the entire scene.
+            (available_size / canvas_rect.size()).min_elem()
+        });
+        let min_scale = if min_scale.is_finite() {
+            min_scale.at_most(max_scale)
+        } else {
+            0.0
+        };
+        min_scale..=max_scale
+    }
+
+    /// Start a smooth transition to the given zoom state.
+    ///
+    /// Jumps to the target right away if animations are disabled.
+    /// `now` is the current time as in `egui::InputState::time`.
+    fn animate_to(&mut self, target: ZoomState2D, now: f64) {
+        if self.animate_zoom_transitions {
+            self.zoom_target = Some((target, now + ZOOM_ANIMATION_DURATION));
+        } else {
+            self.zoom = target;
+            self.zoom_target = None;
+        }
+    }
+
+    /// Move the zoom state towards `target`, such that it is reached at `target_time`.
+    fn animate_zoom(
+        &mut self,
+        response: &egui::Response,
+        target: ZoomState2D,
+        target_time: f64,
+        canvas_rect: Rect,
+        available_size: Vec2,
+    ) {
+        let (now, dt) = response.ctx.input(|i| (i.time, i.stable_dt as f64));
+        let remaining = target_time - now;
+        if remaining <= 0.0 {
+            self.zoom = target;
+            self.zoom_target = None;
+            return;
+        }
+
+        // Linearly interpolate such that we arrive at the target exactly at `target_time`.
+        let t = (dt / remaining).min(1.0) as f32;
+        let pixels_per_point = response.ctx.pixels_per_point();
+        let (scale, center) = self.scale_and_center(available_size, canvas_rect, pixels_per_point);
+        let (target_scale, target_center) = Self {
+            zoom: target,
+            ..Default::default()
+        }
+        .scale_and_center(available_size, canvas_rect, pixels_per_point);
+
+        let is_uniform = scale.x == scale.y && target_scale.x == target_scale.y;
+        self.zoom = ZoomState2D::Scaled {
+            scale: egui::lerp(scale.x..=target_scale.x, t),
+            scale_y: (!is_uniform).then(|| egui::lerp(scale.y..=target_scale.y, t)),
+            center: center.lerp(target_center, t),
+            accepting_scroll: false,
+        };
+        response.ctx.request_repaint();
+    }
+
+    /// The number of ui points per scene unit along each axis and the scene coordinate at the
+    /// center of the view, resolving [`ZoomState2D::Auto`] to the values it currently results in.
+    fn scale_and_center(
+        &self,
+        available_size: Vec2,
+        canvas_rect: Rect,
+        pixels_per_point: f32,
+    ) -> (Vec2, Pos2) {
+        match self.zoom {
+            ZoomState2D::Scaled {
+                scale,
+                scale_y,
+                center,
+                ..
+            } => (vec2(scale, scale_y.unwrap_or(scale)), center),
+            ZoomState2D::Auto => {
+                let (desired_size, _) =
+                    self.desired_size_and_offset(available_size, canvas_rect, pixels_per_point);
+                let scale = desired_size.x / canvas_rect.width();
+                let scale = if scale.is_finite() { scale } else { 1.0 };
+                (Vec2::splat(scale), canvas_rect.center())
+            }
+        }
+    }
+
+    /// Move the center of the view to the given scene position, keeping the scale.
+    ///
+    /// Does nothing while the whole scene is shown, or while animating to a new zoom state.
+    fn center_on(&mut self, position: Pos2) {
+        if self.zoom_target.is_some() {
+            return;
+        }
+        if let ZoomState2D::Scaled {
+            scale,
+            scale_y,
+            accepting_scroll,
+            ..
+        } = self.zoom
+        {
+            self.zoom = ZoomState2D::Scaled {
+                scale,
+                scale_y,
+                center: position,
+                accepting_scroll,
+            };
+        }
+    }
+
+    /// Zoom & pan such that the given rectangle in scene coordinates fills the view.
+    ///
+    /// Rectangles without extent (e.g. single points) get a fixed padding.
+    /// A stretched horizontal axis stays stretched by the same factor.
+    ///
+    /// `now` is the current time as in `egui::InputState::time`.
+    pub fn zoom_to_rect(&mut self, rect: Rect, available_size: Vec2, now: f64) {
+        // Leave some room around the rectangle, and make sure that single points don't end up infinitely zoomed in.
+        let padding = (rect.size().max_elem() * 0.1).at_least(10.0);
+        let rect = rect.expand(padding);
+
+        let y_from_x_scale = match self.zoom {
+            ZoomState2D::Scaled {
+                scale,
+                scale_y: Some(scale_y),
+                ..
+            } => scale_y / scale,
+            _ => 1.0,
+        };
+        let scale = (available_size.x / rect.width())
+            .min(available_size.y / (rect.height() * y_from_x_scale))
+            .at_most(self.max_scale(available_size));
+        if !scale.is_finite() || scale <= 0.0 {
+            return;
+        }
+
+        self.animate_to(
+            ZoomState2D::Scaled {
+                scale,
+                scale_y: (y_from_x_scale != 1.0).then_some(scale * y_from_x_scale),
+                center: rect.center(),
+                accepting_scroll: false,
+            },
+            now,
+        );
+    }
+
+    /// How scene coordinates were mapped to the ui in the last frame, if the view was shown.
+    pub fn view_transform(&self) -> Option<ViewTransform2D> {
+        self.view_transform
+    }
+
+    /// How the magnifier of image tooltips is shown.
+    pub fn magnifier(&self) -> ZoomedImageRegionOptions {
+        ZoomedImageRegionOptions {
+            texel_radius: self.magnifier_texel_radius,
+            points_per_texel: self.magnifier_points_per_texel,
+            show_texel_values: self.magnifier_show_texel_values,
+        }
+    }
+
+    /// Fit the view to the given entity from now on, or to the whole scene again if `None`.
+    ///
+    /// Zooms to fit right away.
+    pub fn set_fit_target(&mut self, fit_target: Option<EntityPath>, now: f64) {
+        self.fit_target = fit_target;
+        self.animate_to(ZoomState2D::Auto, now);
+    }
+
+    /// How labels are drawn in this view.
+    pub fn label_appearance(&self) -> LabelAppearance {
+        LabelAppearance {
+            style: self.label_style,
+            background: self.label_background,
+            text_color: self.label_text_color,
+        }
+    }
+
+    /// Color the view is cleared with, falling back to the background of the ui theme.
+    pub fn background_color(&self, visuals: &egui::Visuals) -> Color32 {
+        self.background_color.unwrap_or(visuals.extreme_bg_color)
+    }
+
+    /// Pins an image tooltip into a floating window.
+    pub fn pin_tooltip(&mut self, mut tooltip: PinnedImageTooltip) {
+        tooltip.id = self.next_pinned_tooltip_id;
+        self.next_pinned_tooltip_id += 1;
+        self.pinned_tooltips.push(tooltip);
+    }
+
+    /// Shows the details of an instance in a floating window, unless it is already being inspected.
+    pub fn inspect(&mut self, instance_path: InstancePath, pointer_pos: Pos2) {
+        if self
+            .inspected_instances
+            .iter()
+            .all(|inspected| inspected.instance_path != instance_path)
+        {
+            self.inspected_instances.push(InspectedInstance {
+                instance_path,
+                pointer_pos,
+            });
+        }
+    }
+
+    /// Picks one of several images stacked under the pointer if they were just clicked.
+    ///
+    /// The first click picks the topmost image, every further click at the same spot the next one below,
+    /// wrapping around at the bottom.
+    /// Moving the pointer more than [`PickingContext::UI_INTERACTION_RADIUS`] away starts over.
+    pub fn pick_through<'a>(
+        &mut self,
+        pointer_pos_ui: Pos2,
+        response: &egui::Response,
+        stacked_images: &'a [InstancePath],
+    ) -> Option<&'a InstancePath> {
+        if self.pick_through_pos.map_or(false, |pos| {
+            pos.distance(pointer_pos_ui) > PickingContext::UI_INTERACTION_RADIUS
+        }) {
+            self.pick_through_pos = None;
+        }
+
+        let toggles_selection = response
+            .ctx
+            .input(|i| i.modifiers.contains(TOGGLE_SELECTION_2D_MODIFIER));
+        if stacked_images.len() < 2 || !response.clicked() || toggles_selection || self.lock_view {
+            return None;
+        }
+
+        self.pick_through_idx = if self.pick_through_pos.is_some() {
+            (self.pick_through_idx + 1) % stacked_images.len()
+        } else {
+            0
+        };
+        self.pick_through_pos = Some(pointer_pos_ui);
+        stacked_images.get(self.pick_through_idx)
+    }
+
+    /// Zoom such that `rect` exactly fills the view, without any padding.
+    ///
+    /// If the aspect ratios of `rect` and the view differ, the axes are scaled independently.
+    fn show_exact_rect(&mut self, rect: Rect, available_size: Vec2) {
+        let scale = available_size.x / rect.width();
+        let scale_y = available_size.y / rect.height();
+        if !(scale.is_finite() && scale > 0.0 && scale_y.is_finite() && scale_y > 0.0) {
+            return;
+        }
+
+        self.zoom_target = None;
+        self.zoom = ZoomState2D::Scaled {
+            scale,
+            scale_y: (scale_y != scale).then_some(scale_y),
+            center: rect.center(),
+            accepting_scroll: false,
+        };
+    }
+
+    /// Take the offset from the `ScrollArea` and apply it back to center so that other
+    /// scroll interfaces work as expected.
+    fn capture_scroll(
+        &mut self,
+        offset: Vec2,
+        available_size: Vec2,
+        canvas_rect: Rect,
+        pixels_per_point: f32,
+    ) {
+        if let ZoomState2D::Scaled {
+            scale,
+            scale_y,
+            accepting_scroll,
+            ..
+        } = self.zoom
+        {
+            if accepting_scroll {
+                let shown_scale = self.display_scales(scale, scale_y, pixels_per_point);
+                let center = canvas_rect.left_top() + (available_size / 2.0 + offset) / shown_scale;
+                self.zoom = ZoomState2D::Scaled {
+                    scale,
+                    scale_y,
+                    center,
+                    accepting_scroll,
+                };
+            }
+        }
+    }
+}
+
+pub fn help_text(re_ui: &re_ui::ReUi) -> egui::WidgetText {
+    let mut layout = re_ui::LayoutJobBuilder::new(re_ui);
+
+    layout.add(ZOOM_SCROLL_MODIFIER);
+    layout.add(" + scroll to zoom.\n");
+
+    layout.add(ASPECT_SCROLL_MODIFIER);
+    layout.add(" + scroll to zoom only the horizontal axis.\n");
+
+    layout.add("Click and drag with ");
+    layout.add(DRAG_PAN2D_BUTTON);
+    layout.add(" or ");
+    layout.add(DRAG_PAN2D_ALT_BUTTON);
+    layout.add(" to pan.\n");
+
+    layout.add("Hold ");
+    layout.add(TOGGLE_SELECTION_2D_MODIFIER);
+    layout.add(" while clicking to add or remove from the selection.\n");
+
+    layout.add("Hold ");
+    layout.add(MARQUEE_SELECT_2D_MODIFIER);
+    layout.add(" while dragging to select everything inside a rectangle.\n");
+
+    layout.add("Hold ");
+    layout.add(HIGHLIGHT_WHOLE_ENTITY_2D_MODIFIER);
+    layout.add(" while hovering to highlight all instances of the hovered entity.\n");
+
+    layout.add("Hold ");
+    layout.add(RADIUS_HOVER_2D_MODIFIER);
+    layout.add(" while hovering to show all instances close to the pointer.\n");
+
+    layout.add("Toggle ☐ in the top right corner to create box annotations by dragging.\n");
+
+    layout.add("Toggle ☰ in the top right corner to list all entities and hide or show them.\n");
+
+    layout.add("Toggle 🔍 in the top right corner or press ");
+    layout.add(INSPECT_MODE_2D_KEY);
+    layout.add(" to show the details of clicked instances in windows instead of selecting them.\n");
+
+    layout.add("Press ");
+    layout.add(ZOOM_TO_SELECTION_KEY);
+    layout.add(" to zoom to the selection.\n");
+
+    layout.add("Press ");
+    layout.add(SELECT_PREVIOUS_INSTANCE_KEY);
+    layout.add(" or ");
+    layout.add(SELECT_NEXT_INSTANCE_KEY);
+    layout.add(" to step the selection through all instances and zoom to them.\n");
+
+    layout.add("Press ");
+    layout.add(PIN_TOOLTIP_KEY);
+    layout.add(" to pin the tooltip of the hovered image into a window.\n");
+
+    layout.add("Hold ");
+    layout.add(PIXEL_QUERY_2D_MODIFIER);
+    layout.add(" while clicking an image to show the value of the clicked pixel.\n");
+
+    layout.add("Press ");
+    layout.add(egui::Modifiers::COMMAND);
+    layout.add(" + ");
+    layout.add(egui::Key::C);
+    layout.add(" to copy the hovered pixel value or instance path.\n");
+
+    layout.add_button_text(RESET_VIEW_BUTTON_TEXT);
+    layout.add(" to reset the view.");
+
+    layout.layout_job.into()
+}
+
+/// How far a two-finger touch gesture moved this frame, in ui points.
+///
+/// `None` if there is no such gesture or it didn't move.
+fn touch_pan_delta(multi_touch: Option<&egui::MultiTouchInfo>) -> Option<Vec2> {
+    multi_touch
+        .filter(|touch| touch.num_touches >= 2)
+        .map(|touch| touch.translation_delta)
+        .filter(|delta| *delta != Vec2::ZERO)
+}
+
+/// Keeps `center` such that at least `min_visible_fraction` of the scene's extent along each axis
+/// remains visible, but no more than fits into `visible_size`.
+///
+/// That way the scene never leaves the view entirely, no matter how far the user pans.
+fn constrain_center(
+    center: Pos2,
+    scene_rect: Rect,
+    visible_size: Vec2,
+    min_visible_fraction: f32,
+) -> Pos2 {
+    if !scene_rect.is_finite() || scene_rect.is_negative() {
+        return center;
+    }
+    let min_visible = (scene_rect.size() * min_visible_fraction.clamp(0.0, 1.0)).min(visible_size);
+    let allowed = scene_rect.shrink2(min_visible).expand2(visible_size * 0.5);
+    allowed.clamp(center)
+}
+
+/// Create the outer 2D view, which consists of a scrollable region
+pub fn view_2d(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    state: &mut SpatialSpaceViewState,
+    query: &ViewQuery<'_>,
+    system_output: re_viewer_context::SystemExecutionOutput,
+) -> Result<(), SpaceViewSystemExecutionError> {
+    re_tracing::profile_function!();
+
+    let SystemExecutionOutput {
+        view_systems: parts,
+        context_systems: view_ctx,
+        mut draw_data,
+    } = system_output;
+
+    let depth_legend = depth_legend(&parts);
+
+    // Also done every frame rather than only on change, since the state may have been restored from disk.
+    HiddenClasses::remember_hidden_classes(
+        ui.ctx(),
+        query.space_view_id,
+        &state.state_2d.hidden_class_ids,
+    );
+
+    let histogram_image = if state.state_2d.show_histogram {
+        match parts.get::<ImageVisualizer>()?.images.as_slice() {
+            [image] => Some(image),
+            _ => None,
+        }
+    } else {
+        None
+    };
+    let histogram_state = egui::collapsing_header::CollapsingState::load_with_default_open(
+        ui.ctx(),
+        ui.make_persistent_id("image_histogram"),
+        true,
+    );
+
+    // Leave room for the status bar and the histogram below the view.
+    let mut status_bar_height =
+        ui.text_style_height(&egui::TextStyle::Body) + ui.spacing().item_spacing.y;
+    if histogram_image.is_some() {
+        status_bar_height += ui.spacing().interact_size.y + ui.spacing().item_spacing.y;
+        if histogram_state.is_open() {
+            status_bar_height += HISTOGRAM_HEIGHT + ui.spacing().item_spacing.y;
+        }
+    }
+
+    // Save off the available_size since this is used for some of the layout updates later
+    let available_size = (ui.available_size() - vec2(0.0, status_bar_height)).at_least(Vec2::ZERO);
+    let view_rect = Rect::from_min_size(ui.available_rect_before_wrap().min, available_size);
+    let store = ctx.entity_db.store();
+
+    let scene_rect_accum = state.bounding_boxes.accumulated;
+    let scene_rect_accum = egui::Rect::from_min_max(
+        scene_rect_accum.min.truncate().to_array().into(),
+        scene_rect_accum.max.truncate().to_array().into(),
+    );
+
+    // Determine the canvas which determines the extent of the explorable scene coordinates,
+    // and thus the size of the scroll area.
+    //
+    // TODO(andreas): We want to move away from the scroll area and instead work with open ended 2D scene coordinates!
+    // The term canvas might then refer to the area in scene coordinates visible at a given moment.
+    // Orthogonally, we'll want to visualize the resolution rectangle of the pinhole camera.
+    //
+    // For that we need to check if this is defined by a pinhole camera.
+    // Note that we can't rely on the camera being part of scene.space_cameras since that requires
+    // the camera to be added to the scene!
+    let pinhole = query_pinhole(store, &ctx.current_query(), query.space_origin);
+    let orientation = state.state_2d.orientation;
+    let fit_target_rect = state
+        .state_2d
+        .fit_target
+        .as_ref()
+        .and_then(|entity_path| state.bounding_boxes.per_entity.get(&entity_path.hash()))
+        .filter(|bbox| bbox.is_something())
+        .map(|bbox| Rect::from_min_max(pos2(bbox.min.x, bbox.min.y), pos2(bbox.max.x, bbox.max.y)));
+    // Bounding boxes are already in view coordinates, unlike the pinhole's resolution.
+    let canvas_rect = if let Some(fit_target_rect) = fit_target_rect {
+        fit_target_rect
+    } else {
+        pinhole
+            .as_ref()
+            .and_then(|p| p.resolution())
+            .map(|res| Rect::from_min_max(Pos2::ZERO, pos2(res.x, res.y)))
+            .map_or(scene_rect_accum, |rect| {
+                orientation.view_from_space_rect(rect)
+            })
+    };
+    // The pinhole doesn't describe a flipped or rotated view, nor one fit to a single entity,
+    // in that case the renderer makes up a camera looking at the entire canvas instead.
+    let pinhole = pinhole.filter(|_| orientation.is_identity() && fit_target_rect.is_none());
+
+    if state.state_2d.follow_selection {
+        if let [instance] = selected_instances(ctx, query).as_slice() {
+            if let Some(rect) = instance_rect(&state.bounding_boxes, instance) {
+                state.state_2d.center_on(rect.center());
+            }
+        }
+    }
+
+    if let Some(region) = state.state_2d.requested_visible_region.take() {
+        state
+            .state_2d
+            .show_exact_rect(orientation.view_from_space_rect(region), available_size);
+    }
+
+    let (desired_size, offset) = state.state_2d.desired_size_and_offset(
+        available_size,
+        canvas_rect,
+        ui.ctx().pixels_per_point(),
+    );
+
+    // Bound the offset based on sizes
+    // TODO(jleibs): can we derive this from the ScrollArea shape?
+    let offset = offset.at_most(desired_size - available_size);
+    let offset = offset.at_least(Vec2::ZERO);
+
+    let scroll_area = ScrollArea::both()
+        .scroll_offset(offset)
+        .auto_shrink([false, false])
+        .max_height(available_size.y)
+        // If scrolling zooms, it shouldn't also pan the view.
+        .enable_scrolling(!state.state_2d.lock_view && ctx.app_options.zoom_2d_requires_modifier);
+
+    let scroll_out = scroll_area.show(ui, |ui| -> Result<(), SpaceViewSystemExecutionError> {
+        let desired_size = desired_size.at_least(Vec2::ZERO);
+        let (mut response, painter) =
+            ui.allocate_painter(desired_size, egui::Sense::click_and_drag());
+
+        if !response.rect.is_positive() {
+            return Ok(());
+        }
+
+        let ui_from_canvas = egui::emath::RectTransform::from_to(canvas_rect, response.rect);
+        let canvas_from_ui = ui_from_canvas.inverse();
+
+        // Lets the visualizers skip whatever is off screen in the next frame.
+        let visible_rect = canvas_from_ui.transform_rect(painter.clip_rect());
+        ViewportCulling::remember_visible_rect(
+            ui.ctx(),
+            query.space_view_id,
+            visible_rect,
+            ui_from_canvas.scale().x,
+        );
+        state.state_2d.visible_region = Some(orientation.space_from_view_rect(visible_rect));
+        state.state_2d.view_transform = Some(ViewTransform2D {
+            ui_from_space: ui_from_canvas,
+        });
+
+        let marquee = state.state_2d.update_marquee(&response, canvas_from_ui);
+        let annotation_box = state
+            .state_2d
+            .update_box_annotation(&response, canvas_from_ui);
+        state.state_2d.update(
+            &response,
+            canvas_from_ui,
+            canvas_rect,
+            available_size,
+            ctx.app_options,
+        );
+
+        if state.state_2d.pause_on_hover && response.hovered() {
+            // Requested every frame, since another view may have resumed playback in the meantime.
+            ctx.request_pause();
+            state.state_2d.paused_on_hover = true;
+        } else if state.state_2d.paused_on_hover {
+            ctx.release_pause();
+            state.state_2d.paused_on_hover = false;
+        }
+
+        // TODO(andreas): Use the same eye & transformations as in `setup_target_config`.
+        let eye = Eye {
+            world_from_rub_view: IsoTransform::IDENTITY,
+            fov_y: None,
+        };
+
+        let target_config = match setup_target_config(
+            &painter,
+            canvas_from_ui,
+            &query.space_origin.to_string(),
+            state.auto_size_config_2d(),
+            query.highlights.any_outlines().then(|| {
+                outline_config(
+                    ui.ctx(),
+                    state.state_2d.selection_outline_color,
+                    state.state_2d.line_width_scale,
+                )
+            }),
+            pinhole.clone(),
+            ui.ctx().pixels_per_point(),
+        ) {
+            Ok(target_config) => target_config,
+            Err(err) => {
+                // Views without any visible area can't be drawn, which isn't worth reporting.
+                if painter.clip_rect().is_positive() {
+                    let error = format!("Failed to set up the view: {err}");
+                    re_log::error_once!("{error}");
+                    draw_errors_ui(&painter, response.rect, ui.style(), &[error]);
+                }
+                return Ok(());
+            }
+        };
+
+        let mut view_builder = ViewBuilder::new(ctx.render_ctx, target_config);
+
+        // Create labels now since their shapes participate are added to scene.ui for picking.
+        let (mut label_shapes, ui_rects) = create_labels(
+            ctx,
+            collect_ui_labels(&parts),
+            ui_from_canvas,
+            &eye,
+            ui,
+            &query.highlights,
+            SpatialSpaceViewKind::TwoD,
+            state.state_2d.label_appearance(),
+        );
+
+        state.state_2d.hover_highlight_region = None;
+        state.state_2d.pointer_status = None;
+        if !re_ui::egui_helpers::is_anything_being_dragged(ui.ctx()) {
+            response = picking(
+                ctx,
+                response,
+                canvas_from_ui,
+                painter.clip_rect(),
+                ui,
+                eye,
+                &mut view_builder,
+                state,
+                &view_ctx,
+                &parts,
+                &ui_rects,
+                query,
+                SpatialSpaceViewKind::TwoD,
+            )?;
+
+            // Let code embedding the viewer react to clicks that didn't select anything.
+            if response.clicked()
+                && !state.state_2d.lock_view
+                && state
+                    .previous_picking_result
+                    .as_ref()
+                    .map_or(true, |result| result.hits.is_empty())
+            {
+                if let Some(pointer_pos) = response.interact_pointer_pos() {
+                    let pos_in_space = state
+                        .state_2d
+                        .orientation
+                        .space_from_view_pos(canvas_from_ui.transform_pos(pointer_pos));
+                    for callback in ctx.on_2d_click_callbacks {
+                        callback(query.space_origin, pos_in_space);
+                    }
+                }
+            }
+        }
+        if let (Some(pointer_status), Ok(points)) = (
+            &mut state.state_2d.pointer_status,
+            parts.get::<Points2DVisualizer>(),
+        ) {
+            pointer_status.point_density = points
+                .density
+                .as_ref()
+                .and_then(|density| density.count_at(pointer_status.pos));
+        }
+        pinned_image_tooltips_ui(
+            ctx,
+            ui.ctx(),
+            &mut state.state_2d,
+            view_ctx.get::<AnnotationSceneContext>()?,
+            query.space_view_id,
+            canvas_from_ui,
+            painter.clip_rect(),
+        );
+        pixel_query_ui(ui.ctx(), &mut state.state_2d, query.space_view_id);
+        inspected_instances_ui(ctx, ui.ctx(), &mut state.state_2d, query.space_view_id);
+        if state.state_2d.hover_highlight_region.is_none() {
+            if let Some((annotations_row_id, class_id)) = state.state_2d.hovered_legend_class {
+                state.state_2d.hover_highlight_region =
+                    class_highlight_region(ctx, &view_ctx, &parts, annotations_row_id, class_id);
+            }
+        }
+
+        // ------------------------------------------------------------------------
+
+        if response.secondary_clicked() {
+            state.state_2d.context_menu_instance = if state.state_2d.lock_view {
+                None
+            } else {
+                hovered_instance(ctx, &view_ctx, state.previous_picking_result.as_ref())?
+            };
+        }
+
+        if response.hovered() && ui.input(|i| i.key_pressed(INSPECT_MODE_2D_KEY)) {
+            state.state_2d.inspect_mode = !state.state_2d.inspect_mode;
+        }
+
+        if response.hovered()
+            && !state.state_2d.lock_view
+            && ui.input(|i| i.key_pressed(ZOOM_TO_SELECTION_KEY))
+        {
+            if let Some(rect) = selection_rect(ctx, query, &state.bounding_boxes) {
+                state
+                    .state_2d
+                    .zoom_to_rect(rect, available_size, ui.input(|i| i.time));
+            }
+        }
+
+        let step_forward = ui.input(|i| {
+            if i.key_pressed(SELECT_NEXT_INSTANCE_KEY) {
+                Some(true)
+            } else if i.key_pressed(SELECT_PREVIOUS_INSTANCE_KEY) {
+                Some(false)
+            } else {
+                None
+            }
+        });
+        if let Some(forward) = step_forward
+            .filter(|_| response.hovered() && !response.dragged() && !state.state_2d.lock_view)
+        {
+            if let Some(instance) = next_instance(
+                ctx,
+                query,
+                &view_ctx,
+                &state.state_2d,
+                &state.bounding_boxes,
+                forward,
+            )? {
+                if let Some(rect) = instance_rect(&state.bounding_boxes, &instance) {
+                    state
+                        .state_2d
+                        .zoom_to_rect(rect, available_size, ui.input(|i| i.time));
+                }
+                ctx.selection_state().set_selection(Selection(vec![(
+                    Item::InstancePath(Some(query.space_view_id), instance),
+                    None,
+                )]));
+            }
+        }
+
+        // Instance & screenshot context menu.
+        let screenshot_request = context_menu_2d(
+            ctx,
+            query,
+            &response,
+            &mut state.state_2d,
+            &state.bounding_boxes,
+            available_size,
+        );
+
+        // The SVG export reads the shared builders, so it has to happen before they're turned into draw data.
+        let shared_render_builders = view_ctx.get::<SharedRenderBuilders>().ok();
+        if let (Some(reference_time), Some(shared_render_builders)) = (
+            state.state_2d.active_frame_diff_reference(query),
+            shared_render_builders,
+        ) {
+            let diff = frame_diff::diff_against_reference(store, query, reference_time);
+            frame_diff::add_diff_to_builders(
+                &diff,
+                view_ctx.get::<TransformContext>()?,
+                &mut shared_render_builders.lines(),
+                &mut shared_render_builders.points(),
+            );
+        }
+        if let (Some(trail_length), Some(shared_render_builders)) =
+            (state.state_2d.trail_length, shared_render_builders)
+        {
+            let trails = trails::trails(store, ctx.entity_db.tree(), query, trail_length);
+            trails::add_trails_to_builders(
+                &trails,
+                view_ctx.get::<TransformContext>()?,
+                &mut shared_render_builders.lines(),
+            );
+        }
+        if let (Some(ScreenshotRequest::ExportSvg), Some(shared_render_builders)) =
+            (screenshot_request, shared_render_builders)
+        {
+            export_svg(
+                &painter,
+                canvas_from_ui,
+                state,
+                shared_render_builders,
+                &collect_ui_labels(&parts),
+            );
+        }
+        let mut draw_errors = if let Some(shared_render_builders) = shared_render_builders {
+            let (shared_draw_data, errors) =
+                shared_render_builders.queuable_draw_data(ctx.render_ctx);
+            draw_data.extend(shared_draw_data);
+            errors
+        } else {
+            Vec::new()
+        };
+        // Images that can't be shown are reported along with the draw errors instead of failing the whole view.
+        if let Ok(images) = parts.get::<ImageVisualizer>() {
+            draw_errors.extend(
+                images
+                    .unsupported_images
+                    .iter()
+                    .map(|(ent_path, problem)| format!("{ent_path}: {problem}")),
+            );
+        }
+
+        match screenshot_request {
+            Some(ScreenshotRequest::Visible(mode)) => {
+                view_builder
+                    .schedule_screenshot(
+                        ctx.render_ctx,
+                        query.space_view_id.gpu_readback_id(),
+                        mode,
+                    )
+                    .ok();
+            }
+            Some(ScreenshotRequest::Export { scale }) => {
+                export_view(
+                    ctx,
+                    &painter,
+                    canvas_from_ui,
+                    query,
+                    state,
+                    pinhole,
+                    scale,
+                    &draw_data,
+                );
+            }
+            Some(ScreenshotRequest::ExportSvg) | None => {}
+        }
+
+        // Queued first, so that transparent images are blended on top of it.
+        if state.state_2d.checkerboard_background {
+            let visible_rect_in_ui = painter.clip_rect();
+            if let Some(checkerboard) = checkerboard_draw_data(
+                ctx.render_ctx,
+                canvas_from_ui.transform_rect(visible_rect_in_ui),
+                visible_rect_in_ui.size(),
+                ui.visuals().dark_mode,
+            ) {
+                view_builder.queue_draw(checkerboard);
+            }
+        }
+        for draw_data in draw_data {
+            view_builder.queue_draw(draw_data);
+        }
+
+        // Draw a re_renderer driven view.
+        // Camera & projection are configured to ingest space coordinates directly.
+        painter.add(gpu_bridge::new_renderer_callback(
+            view_builder,
+            painter.clip_rect(),
+            state.state_2d.background_color(ui.visuals()).into(),
+        ));
+
+        // Polygon fills are translucent, which re_renderer's meshes don't support, so egui draws them.
+        if let Ok(polygons) = parts.get::<Polygons2DVisualizer>() {
+            painter.extend(polygons.fill_shapes(&ui_from_canvas));
+        }
+
+        // Highlight the hovered part of an image (e.g. the hovered class of a segmentation image).
+        if let Some(region) = state.state_2d.hover_highlight_region {
+            painter.rect_filled(
+                ui_from_canvas.transform_rect(region),
+                0.0,
+                ui.visuals().widgets.hovered.bg_fill.gamma_multiply(0.3),
+            );
+        }
+
+        // Make sure to _first_ draw the selected, and *then* the hovered context on top!
+        for selected_context in ctx.selection_state().selected_space_context() {
+            painter.extend(show_projections_from_3d_space(
+                ui,
+                query.space_origin,
+                &ui_from_canvas,
+                response.rect,
+                selected_context,
+                ui.style().visuals.selection.bg_fill,
+                ctx.app_options.depth_unit,
+                &state.state_2d,
+            ));
+        }
+        if let Some(hovered_context) = ctx.selection_state().hovered_space_context() {
+            painter.extend(show_projections_from_3d_space(
+                ui,
+                query.space_origin,
+                &ui_from_canvas,
+                response.rect,
+                hovered_context,
+                egui::Color32::WHITE,
+                ctx.app_options.depth_unit,
+                &state.state_2d,
+            ));
+        }
+
+        if state.state_2d.show_snap_crosshair {
+            if let Some(snap_pos) =
+                snap_crosshair_position(&parts, state.previous_picking_result.as_ref())
+            {
+                label_shapes.extend(crosshair_shapes(
+                    ui_from_canvas.transform_pos(snap_pos),
+                    ui.visuals().widgets.hovered.fg_stroke,
+                ));
+            }
+        }
+
+        if state.state_2d.show_point_size_legend {
+            if let Some(radii) = point_size_legend(&parts) {
+                draw_point_size_legend(&painter, &ui_from_canvas, radii, &mut label_shapes);
+            }
+        }
+
+        if state.state_2d.focus_rect.is_some() {
+            focus_region_ui(ui, &painter, ui_from_canvas, &mut state.state_2d);
+        }
+
+        // Add egui driven labels on top of re_renderer content.
+        painter.extend(label_shapes);
+
+        if !draw_errors.is_empty() {
+            draw_errors_ui(&painter, response.rect, ui.style(), &draw_errors);
+        }
+
+        if state.state_2d.show_scale_bar {
+            let mut scale_bar_shapes = Vec::new();
+            draw_scale_bar(
+                &painter,
+                &ui_from_canvas,
+                state.state_2d.scene_units_per_meter,
+                &mut scale_bar_shapes,
+            );
+            painter.extend(scale_bar_shapes);
+        }
+
+        if let Some((marquee, finished)) = marquee {
+            let marquee_in_ui = ui_from_canvas.transform_rect(marquee);
+            let selection_visuals = ui.visuals().selection;
+            painter.rect(
+                marquee_in_ui,
+                0.0,
+                selection_visuals.bg_fill.gamma_multiply(0.2),
+                selection_visuals.stroke,
+            );
+            if finished {
+                let toggle = ui.input(|i| i.modifiers.contains(TOGGLE_SELECTION_2D_MODIFIER));
+                select_instances_in_rect(
+                    ctx,
+                    query,
+                    &view_ctx,
+                    &state.state_2d,
+                    &state.bounding_boxes,
+                    marquee,
+                    toggle,
+                )?;
+            }
+        }
+
+        if let Some(annotation_box) = annotation_box.or_else(|| {
+            state
+                .state_2d
+                .pending_annotation
+                .as_ref()
+                .map(|pending| pending.rect)
+        }) {
+            painter.rect_stroke(
+                ui_from_canvas.transform_rect(annotation_box),
+                0.0,
+                ui.visuals().selection.stroke,
+            );
+        }
+        box_annotation_popup_ui(ctx, ui, query, &mut state.state_2d, ui_from_canvas);
+
+        Ok(())
+    });
+    scroll_out.inner?;
+
+    ui.label(
+        state
+            .state_2d
+            .pointer_status
+            .as_ref()
+            .map(PointerStatus::text)
+            .unwrap_or_default(),
+    );
+    if let Some(image) = histogram_image {
+        histogram_ui(ctx, ui, histogram_state, image);
+    }
+
+    // Update the scroll area based on the computed offset
+    // This handles cases of dragging/zooming the space
+    state.state_2d.capture_scroll(
+        scroll_out.state.offset,
+        available_size,
+        canvas_rect,
+        ui.ctx().pixels_per_point(),
+    );
+
+    // Scrolling over the view is always used up by the view, whether it zooms, pans or the scroll area is at its end.
+    // Otherwise it would scroll whatever contains the view, e.g. while the whole scene is shown in auto zoom.
+    if ui.rect_contains_pointer(view_rect) {
+        ui.input_mut(|i| i.smooth_scroll_delta = Vec2::ZERO);
+    }
+
+    lock_view_button_ui(ui, view_rect, &mut state.state_2d.lock_view);
+    annotation_mode_button_ui(ui, view_rect, &mut state.state_2d.interaction_mode);
+    entity_legend_button_ui(ui, view_rect, &mut state.state_2d.show_entity_legend);
+    frame_diff_button_ui(ctx, ui, view_rect, &mut state.state_2d);
+    orientation_buttons_ui(ui, view_rect, &mut state.state_2d.orientation);
+    inspect_mode_button_ui(ui, view_rect, &mut state.state_2d.inspect_mode);
+    // Also done every frame rather than only on change, since the state may have been restored from disk.
+    state
+        .state_2d
+        .orientation
+        .remember(ui.ctx(), query.space_view_id);
+    point_density::remember_enabled(
+        ui.ctx(),
+        query.space_view_id,
+        state.state_2d.show_point_density,
+    );
+    // Marquee selection, stepping through instances and the snap crosshair all start while hovered.
+    let point_instances_requested = ui.rect_contains_pointer(view_rect)
+        || state.state_2d.marquee_start.is_some()
+        || state.state_2d.context_menu_instance.is_some()
+        || state.state_2d.follow_selection
+        || state.state_2d.show_entity_legend
+        || !selected_instances(ctx, query).is_empty();
+    scene_bounding_boxes::remember_point_instances_requested(
+        ui.ctx(),
+        query.space_view_id,
+        point_instances_requested,
+    );
+    if state.state_2d.show_entity_legend {
+        entity_legend_ui(ctx, ui, query, &state.bounding_boxes, view_rect);
+    }
+    if state.state_2d.show_timeline_strip {
+        timeline_strip_ui(ctx, ui, query, view_rect);
+    }
+    if state.state_2d.show_visible_region {
+        if let Some(region) = state.state_2d.visible_region {
+            visible_region_hud_ui(ui, view_rect, region);
+        }
+    }
+    if ctx.app_options.show_2d_data_density_overlay {
+        data_density_overlay_ui(ui, view_rect, &state.scene_stats);
+    }
+    class_legend_ui(
+        ctx,
+        ui,
+        query,
+        &view_ctx,
+        view_rect,
+        &mut state.state_2d.hovered_legend_class,
+        &mut state.state_2d.hidden_class_ids,
+    );
+    if let Some(depth_legend) = depth_legend {
+        depth_legend_button_ui(ui, view_rect, &mut state.state_2d.show_depth_legend);
+        if state.state_2d.show_depth_legend {
+            depth_legend_ui(ctx, ui, view_rect, &depth_legend);
+        }
+    }
+
+    Ok(())
+}
+
+const HISTOGRAM_HEIGHT: f32 = 100.0;
+
+/// Collapsible histogram of the values of each channel of an image, shown below the view.
+fn histogram_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    histogram_state: egui::collapsing_header::CollapsingState,
+    image: &ViewerImage,
+) {
+    histogram_state
+        .show_header(ui, |ui| ui.label("Histogram"))
+        .body(|ui| {
+            let Some(bar_charts) = ctx.cache.entry(|c: &mut TensorHistogramCache| {
+                c.entry(image.tensor_data_row_id, &image.tensor)
+                    .map(histogram_bar_charts)
+            }) else {
+                ui.label("No histogram available for this image");
+                return;
+            };
+
+            egui_plot::Plot::new("image_histogram")
+                .height(HISTOGRAM_HEIGHT)
+                .show_y(false)
+                .y_axis_width(3)
+                .allow_drag(false)
+                .allow_zoom(false)
+                .allow_scroll(false)
+                .allow_boxed_zoom(false)
+                .show(ui, |plot_ui| {
+                    // Channels are overlaid on top of each other.
+                    for bar_chart in bar_charts {
+                        plot_ui.bar_chart(bar_chart);
+                    }
+                });
+        });
+}
+
+/// One bar chart per channel of the histogram.
+fn histogram_bar_charts(histogram: &TensorHistogram) -> Vec<egui_plot::BarChart> {
+    let channels: &[(&str, Color32)] = if histogram.channels.len() == 1 {
+        &[("Value", Color32::GRAY)]
+    } else {
+        &[
+            ("Red", Color32::RED),
+            ("Green", Color32::GREEN),
+            ("Blue", Color32::BLUE),
+        ]
+    };
+
+    histogram
+        .channels
+        .iter()
+        .zip(channels)
+        .map(|(counts, (name, color))| {
+            let bars = counts
+                .iter()
+                .enumerate()
+                .map(|(bucket, &count)| {
+                    let (start, end) = histogram.bucket_range(bucket);
+                    egui_plot::Bar::new((start + end) * 0.5, count as f64).width(end - start)
+                })
+                .collect();
+            egui_plot::BarChart::new(bars)
+                .name(*name)
+                .color(color.gamma_multiply(0.6))
+        })
+        .collect()
+}
+
+const TOOLBAR_BUTTON_SIZE: Vec2 = Vec2::splat(24.0);
+const TOOLBAR_BUTTON_MARGIN: f32 = 4.0;
+
+/// Rectangle of the `index`-th button from the right in the top right corner of the view.
+fn toolbar_button_rect(view_rect: Rect, index: usize) -> Rect {
+    let offset_x = (index + 1) as f32 * (TOOLBAR_BUTTON_SIZE.x + TOOLBAR_BUTTON_MARGIN);
+    Rect::from_min_size(
+        view_rect.right_top() + vec2(-offset_x, TOOLBAR_BUTTON_MARGIN),
+        TOOLBAR_BUTTON_SIZE,
+    )
+}
+
+/// Small toggle in the top right corner of the view indicating whether the view is locked.
+fn lock_view_button_ui(ui: &mut egui::Ui, view_rect: Rect, lock_view: &mut bool) {
+    let button_rect = toolbar_button_rect(view_rect, 0);
+
+    let icon = if *lock_view { "🔒" } else { "🔓" };
+    let response = ui
+        .put(button_rect, egui::SelectableLabel::new(*lock_view, icon))
+        .on_hover_text(if *lock_view {
+            "The view is locked: zooming, panning and selecting are disabled. Click to unlock."
+        } else {
+            "Lock the view to prevent zooming, panning and selecting."
+        });
+    if response.clicked() {
+        *lock_view = !*lock_view;
+    }
+}
+
+/// Small toggle next to the lock button switching between navigating and annotating boxes.
+fn annotation_mode_button_ui(
+    ui: &mut egui::Ui,
+    view_rect: Rect,
+    interaction_mode: &mut InteractionMode,
+) {
+    let button_rect = toolbar_button_rect(view_rect, 1);
+
+    let annotating = *interaction_mode == InteractionMode::AnnotateBox;
+    let response = ui
+        .put(button_rect, egui::SelectableLabel::new(annotating, "☐"))
+        .on_hover_text(if annotating {
+            "Dragging creates a new box annotation. Click to go back to navigating."
+        } else {
+            "Create box annotations by dragging."
+        });
+    if response.clicked() {
+        *interaction_mode = if annotating {
+            InteractionMode::Navigate
+        } else {
+            InteractionMode::AnnotateBox
+        };
+    }
+}
+
+/// Small toggle next to the annotation button showing or hiding the entity legend.
+fn entity_legend_button_ui(ui: &mut egui::Ui, view_rect: Rect, show_entity_legend: &mut bool) {
+    let button_rect = toolbar_button_rect(view_rect, 2);
+
+    let response = ui
+        .put(
+            button_rect,
+            egui::SelectableLabel::new(*show_entity_legend, "☰"),
+        )
+        .on_hover_text("Show a list of all entities in this view to quickly hide or show them.");
+    if response.clicked() {
+        *show_entity_legend = !*show_entity_legend;
+    }
+}
+
+/// Small toggle comparing the points and boxes of the view against the current time.
+fn frame_diff_button_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    view_rect: Rect,
+    state: &mut View2DState,
+) {
+    let button_rect = toolbar_button_rect(view_rect, 4);
+
+    let comparing = state.frame_diff_reference.is_some();
+    let response = ui
+        .put(button_rect, egui::SelectableLabel::new(comparing, "🔀"))
+        .on_hover_ui(|ui| {
+            if let Some(reference) = state.frame_diff_reference {
+                ui.label(format!(
+                    "Comparing against {} on {}. Click to stop comparing.",
+                    reference
+                        .timeline
+                        .typ()
+                        .format(reference.time, ctx.app_options.time_zone),
+                    reference.timeline.name(),
+                ));
+            } else {
+                ui.label("Compare frames: keep the current time as a reference to compare later times against.");
+            }
+            ui.horizontal(|ui| {
+                ui.colored_label(frame_diff::ADDED_COLOR, "⏺ added");
+                ui.colored_label(frame_diff::REMOVED_COLOR, "⏺ removed");
+            });
+        });
+    if response.clicked() {
+        if comparing {
+            state.frame_diff_reference = None;
+        } else {
+            state.pin_frame_diff_reference(ctx);
+        }
+    }
+}
+
+/// Small buttons next to the frame comparison toggle flipping and rotating the whole view.
+fn orientation_buttons_ui(ui: &mut egui::Ui, view_rect: Rect, orientation: &mut ViewOrientation) {
+    let previous_orientation = *orientation;
+
+    let rotated = orientation.quarter_turns != 0;
+    let response = ui
+        .put(
+            toolbar_button_rect(view_rect, 5),
+            egui::SelectableLabel::new(rotated, "⟳"),
+        )
+        .on_hover_text(format!(
+            "Rotate the view by 90° clockwise. Currently rotated by {}°.",
+            orientation.quarter_turns as u32 * 90
+        ));
+    if response.clicked() {
+        orientation.rotate_clockwise();
+    }
+
+    // Whether the view appears mirrored along the respective screen axis.
+    let (flipped_horizontally, flipped_vertically) = if orientation.quarter_turns % 2 == 0 {
+        (orientation.flip_x, orientation.flip_y)
+    } else {
+        (orientation.flip_y, orientation.flip_x)
+    };
+    let response = ui
+        .put(
+            toolbar_button_rect(view_rect, 6),
+            egui::SelectableLabel::new(flipped_horizontally, "↔"),
+        )
+        .on_hover_text("Mirror the view horizontally.");
+    if response.clicked() {
+        orientation.flip_horizontally();
+    }
+    let response = ui
+        .put(
+            toolbar_button_rect(view_rect, 7),
+            egui::SelectableLabel::new(flipped_vertically, "↕"),
+        )
+        .on_hover_text("Mirror the view vertically.");
+    if response.clicked() {
+        orientation.flip_vertically();
+    }
+
+    if *orientation != previous_orientation {
+        // The transforms pick up the new orientation in the next frame.
+        ui.ctx().request_repaint();
+    }
+}
+
+/// Small toggle next to the orientation buttons switching clicks between selecting and inspecting instances.
+fn inspect_mode_button_ui(ui: &mut egui::Ui, view_rect: Rect, inspect_mode: &mut bool) {
+    let button_rect = toolbar_button_rect(view_rect, 8);
+
+    let response = ui
+        .put(button_rect, egui::SelectableLabel::new(*inspect_mode, "🔍"))
+        .on_hover_text(if *inspect_mode {
+            format!(
+                "Clicking an instance shows its details in a window without selecting it. Click or press {} to go back to selecting.",
+                INSPECT_MODE_2D_KEY.name()
+            )
+        } else {
+            format!(
+                "Inspect instances: clicking shows their details in a window instead of selecting them. Shortcut: {}.",
+                INSPECT_MODE_2D_KEY.name()
+            )
+        });
+    if response.clicked() {
+        *inspect_mode = !*inspect_mode;
+    }
+}
+
+/// Overlay in the top left corner of the view showing the hierarchy of all entities of the view.
+///
+/// Each entity has a visibility toggle and lists its visualizers and instance count.
+fn entity_legend_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &egui::Ui,
+    query: &ViewQuery<'_>,
+    bounding_boxes: &SceneBoundingBoxes,
+    view_rect: Rect,
+) {
+    // The same entity shows up once for every visualizer it is part of.
+    let data_results: std::collections::BTreeMap<_, _> = query
+        .iter_all_data_results()
+        .map(|data_result| (&data_result.entity_path, data_result))
+        .collect();
+    let instance_counts = bounding_boxes
+        .per_instance
+        .iter()
+        .map(|(instance, _)| instance.entity_path_hash)
+        .counts();
+
+    egui::Area::new(egui::Id::new(query.space_view_id).with("entity_legend"))
+        .order(egui::Order::Foreground)
+        .fixed_pos(view_rect.left_top() + Vec2::splat(TOOLBAR_BUTTON_MARGIN))
+        .show(ui.ctx(), |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ScrollArea::vertical()
+                    .max_height(view_rect.height() * 0.5)
+                    .show(ui, |ui| {
+                        let legend = EntityLegend {
+                            ctx,
+                            query,
+                            data_results: &data_results,
+                            instance_counts: &instance_counts,
+                        };
+                        legend.tree_ui(ui, ctx.entity_db.tree());
+                    });
+            });
+        });
+}
+
+/// Everything needed to show the entity hierarchy of a view in the entity legend.
+struct EntityLegend<'a> {
+    ctx: &'a ViewerContext<'a>,
+    query: &'a ViewQuery<'a>,
+    data_results: &'a std::collections::BTreeMap<&'a EntityPath, &'a DataResult>,
+
+    /// Number of instances with a bounding box, per entity.
+    instance_counts: &'a std::collections::HashMap<EntityPathHash, usize>,
+}
+
+impl EntityLegend<'_> {
+    /// Whether the view shows the entity itself or any of its descendants.
+    fn is_shown(&self, tree: &EntityTree) -> bool {
+        self.data_results
+            .keys()
+            .any(|entity_path| entity_path.starts_with(&tree.path))
+    }
+
+    /// Shows the tree's entity and its shown descendants, collapsible if there are any.
+    fn tree_ui(&self, ui: &mut egui::Ui, tree: &EntityTree) {
+        let children = tree
+            .children
+            .values()
+            .filter(|child| self.is_shown(child))
+            .collect_vec();
+
+        if tree.path.is_root() {
+            // The root itself is only listed if it has data of its own.
+            if self.data_results.contains_key(&tree.path) {
+                ui.horizontal(|ui| self.line_ui(ui, tree, false));
+            }
+            for child in children {
+                self.tree_ui(ui, child);
+            }
+        } else if children.is_empty() {
+            ui.horizontal(|ui| self.line_ui(ui, tree, false));
+        } else {
+            egui::collapsing_header::CollapsingState::load_with_default_open(
+                ui.ctx(),
+                ui.id().with(&tree.path),
+                true,
+            )
+            .show_header(ui, |ui| self.line_ui(ui, tree, true))
+            .body(|ui| {
+                for child in children {
+                    self.tree_ui(ui, child);
+                }
+            });
+        }
+    }
+
+    /// Visibility toggle, name, visualizers and instance count of an entity.
+    ///
+    /// The toggle of a collapsible entity applies to all of its descendants as well.
+    fn line_ui(&self, ui: &mut egui::Ui, tree: &EntityTree, include_subtree: bool) {
+        let data_result = self.data_results.get(&tree.path);
+
+        let mut visible = if let Some(data_result) = data_result {
+            data_result.accumulated_properties().visible
+        } else {
+            self.data_results
+                .iter()
+                .filter(|(entity_path, _)| entity_path.is_descendant_of(&tree.path))
+                .any(|(_, data_result)| data_result.accumulated_properties().visible)
+        };
+        if self
+            .ctx
+            .re_ui
+            .visibility_toggle_button(ui, &mut visible)
+            .on_hover_text(if include_subtree {
+                "Toggle visibility of this entity and all its descendants"
+            } else {
+                "Toggle visibility"
+            })
+            .changed()
+        {
+            set_entities_visible(self.ctx, self.query, &tree.path, include_subtree, visible);
+        }
+
+        let name = tree
+            .path
+            .last()
+            .map_or_else(|| "/".to_owned(), |part| part.ui_string());
+        ui.label(name).on_hover_text(tree.path.to_string());
+
+        if let Some(data_result) = data_result {
+            let mut details = data_result.visualizers.iter().join(", ");
+            if let Some(count) = self.instance_counts.get(&tree.path.hash()) {
+                details += &format!(" ×{count}");
+            }
+            ui.weak(details);
+        }
+    }
+}
+
+/// Collapsible overlay in the bottom left corner of the view listing all annotated classes.
+///
+/// Hovering a class highlights the region it covers in segmentation images,
+/// each class has a toggle hiding its pixels, points and boxes.
+fn class_legend_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &egui::Ui,
+    query: &ViewQuery<'_>,
+    view_ctx: &ViewContextCollection,
+    view_rect: Rect,
+    hovered_legend_class: &mut Option<(RowId, ClassId)>,
+    hidden_class_ids: &mut BTreeSet<u16>,
+) {
+    *hovered_legend_class = None;
+
+    let Ok(annotations) = view_ctx.get::<AnnotationSceneContext>() else {
+        return;
+    };
+    let AnnotationSceneContext(annotation_map) = annotations;
+    let annotation_contexts = annotation_map
+        .0
+        .iter()
+        .filter(|(_, annotations)| annotations.class_ids().next().is_some())
+        .collect::<Vec<_>>();
+    if annotation_contexts.is_empty() {
+        return;
+    }
+
+    egui::Area::new(egui::Id::new(query.space_view_id).with("class_legend"))
+        .order(egui::Order::Foreground)
+        .pivot(Align2::LEFT_BOTTOM)
+        .fixed_pos(
+            view_rect.left_bottom()
+                + vec2(
+                    TOOLBAR_BUTTON_MARGIN,
+                    -TOOLBAR_BUTTON_MARGIN - TIMELINE_STRIP_HEIGHT,
+                ),
+        )
+        .show(ui.ctx(), |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                egui::CollapsingHeader::new("Classes")
+                    .id_source(egui::Id::new(query.space_view_id).with("class_legend_header"))
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        ScrollArea::vertical()
+                            .max_height(view_rect.height() * 0.5)
+                            .show(ui, |ui| {
+                                for (context_path, annotations) in &annotation_contexts {
+                                    if annotation_contexts.len() > 1 {
+                                        ui.label(
+                                            egui::RichText::new(context_path.to_string()).weak(),
+                                        );
+                                    }
+                                    for class_id in annotations.class_ids().sorted() {
+                                        let annotation_info = annotations
+                                            .resolved_class_description(Some(
+                                                re_types::components::ClassId(class_id),
+                                            ))
+                                            .annotation_info();
+                                        let color = annotation_info
+                                            .color(None, DefaultColor::TransparentBlack);
+                                        let label = annotation_info.label(None);
+
+                                        let response = ui
+                                            .horizontal(|ui| {
+                                                let mut visible =
+                                                    !hidden_class_ids.contains(&class_id.0);
+                                                if ctx
+                                                    .re_ui
+                                                    .visibility_toggle_button(ui, &mut visible)
+                                                    .on_hover_text("Toggle visibility")
+                                                    .changed()
+                                                {
+                                                    if visible {
+                                                        hidden_class_ids.remove(&class_id.0);
+                                                    } else {
+                                                        hidden_class_ids.insert(class_id.0);
+                                                    }
+                                                }
+                                                let (swatch_rect, _) = ui.allocate_exact_size(
+                                                    Vec2::splat(12.0),
+                                                    egui::Sense::hover(),
+                                                );
+                                                ui.painter().rect_filled(swatch_rect, 2.0, color);
+                                                ui.label(class_id.0.to_string());
+                                                if let Some(label) = label {
+                                                    ui.label(label);
+                                                }
+                                            })
+                                            .response;
+                                        if response.hovered() {
+                                            *hovered_legend_class =
+                                                Some((annotations.row_id(), class_id));
+                                        }
+                                    }
+                                }
+                            });
+                    });
+            });
+        });
+}
+
+/// Union of the regions a class covers in all segmentation images using the given annotation context.
+fn class_highlight_region(
+    ctx: &ViewerContext<'_>,
+    view_ctx: &ViewContextCollection,
+    visualizers: &VisualizerCollection,
+    annotations_row_id: RowId,
+    class_id: ClassId,
+) -> Option<Rect> {
+    let annotations = view_ctx.get::<AnnotationSceneContext>().ok()?;
+    let images = visualizers.get::<ImageVisualizer>().ok()?;
+    images
+        .images
+        .iter()
+        .filter(|image| annotations.0.find(&image.ent_path).row_id() == annotations_row_id)
+        .filter_map(|image| image_class_region(ctx, image, class_id.0))
+        .reduce(Rect::union)
+}
+
+/// Height of the timeline strip at the bottom of the view.
+const TIMELINE_STRIP_HEIGHT: f32 = 16.0;
+
+/// Compact scrubber at the bottom of the view, shading the times any visible entity of this view has data at.
+///
+/// Spans all times of the recording on the current timeline, clicking or dragging moves the time cursor.
+fn timeline_strip_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &egui::Ui,
+    query: &ViewQuery<'_>,
+    view_rect: Rect,
+) {
+    re_tracing::profile_function!();
+
+    let timeline = *ctx.rec_cfg.time_ctrl.read().timeline();
+    let Some(all_times) = ctx.entity_db.time_histogram(&timeline) else {
+        return;
+    };
+    let (Some(min_time), Some(max_time)) = (all_times.min_key(), all_times.max_key()) else {
+        return;
+    };
+
+    let strip_rect = Rect::from_min_max(
+        pos2(view_rect.left(), view_rect.bottom() - TIMELINE_STRIP_HEIGHT),
+        view_rect.right_bottom(),
+    );
+    let response = ui.interact(
+        strip_rect,
+        egui::Id::new(query.space_view_id).with("timeline_strip"),
+        egui::Sense::click_and_drag(),
+    );
+
+    // The same entity shows up once for every visualizer it is part of.
+    let entity_paths: std::collections::BTreeSet<_> = query
+        .iter_all_data_results()
+        .filter(|data_result| data_result.accumulated_properties().visible)
+        .map(|data_result| &data_result.entity_path)
+        .collect();
+    let tree = ctx.entity_db.tree();
+    let histograms = entity_paths
+        .into_iter()
+        .filter_map(|entity_path| tree.subtree(entity_path))
+        .flat_map(|subtree| subtree.entity.components.values())
+        .filter_map(|histograms| histograms.get(&timeline))
+        .collect::<Vec<_>>();
+
+    let time_span = (max_time - min_time).max(1) as f64;
+    let x_from_time = |time: i64| {
+        strip_rect.left() + ((time - min_time) as f64 / time_span) as f32 * strip_rect.width()
+    };
+    let time_from_x = |x: f32| {
+        let t = ((x - strip_rect.left()) / strip_rect.width()).clamp(0.0, 1.0);
+        min_time + (t as f64 * time_span).round() as i64
+    };
+
+    let painter = ui.painter().with_clip_rect(view_rect);
+    let visuals = ui.visuals();
+    painter.rect_filled(
+        strip_rect,
+        0.0,
+        visuals.extreme_bg_color.gamma_multiply(0.8),
+    );
+
+    // One column per ui point, shaded if any entity has data within the times it covers.
+    let data_color = visuals.selection.bg_fill;
+    let num_columns = strip_rect.width().round().at_least(1.0) as usize;
+    let column_width = strip_rect.width() / num_columns as f32;
+    for column in 0..num_columns {
+        let x = strip_rect.left() + column as f32 * column_width;
+        let start = time_from_x(x);
+        let end = time_from_x(x + column_width);
+        let times = if column + 1 == num_columns {
+            start..=max_time
+        } else {
+            start..=(end - 1).max(start)
+        };
+        if histograms
+            .iter()
+            .any(|histogram| histogram.range_count(times.clone()) > 0)
+        {
+            painter.rect_filled(
+                Rect::from_x_y_ranges(x..=x + column_width, strip_rect.y_range()),
+                0.0,
+                data_color,
+            );
+        }
+    }
+
+    if let Some(pointer_pos) = response.interact_pointer_pos() {
+        ctx.rec_cfg
+            .time_ctrl
+            .write()
+            .set_time(re_log_types::TimeInt::from(time_from_x(pointer_pos.x)));
+    }
+    let current_time = ctx.rec_cfg.time_ctrl.read().time_int();
+    if let Some(time) = current_time {
+        let x = x_from_time(time.as_i64());
+        painter.line_segment(
+            [pos2(x, strip_rect.top()), pos2(x, strip_rect.bottom())],
+            visuals.widgets.active.fg_stroke,
+        );
+    }
+    let hovered_time = response.hover_pos().map(|pos| time_from_x(pos.x));
+
+    if let Some(hovered_time) = hovered_time {
+        response.on_hover_text(
+            timeline
+                .typ()
+                .format(hovered_time.into(), ctx.app_options.time_zone),
+        );
+    }
+}
+
+/// Colormap of a depth image shown as a colorbar on top of the view.
+struct DepthLegend {
+    /// Texture of the depth image, carrying its value range and color mapping.
+    colormapped_texture: ColormappedTexture,
+
+    /// How many depth units make up a meter.
+    meter: f32,
+}
+
+/// Picks the topmost colormapped depth image that has a meter set.
+fn depth_legend(visualizers: &VisualizerCollection) -> Option<DepthLegend> {
+    let images = visualizers.get::<ImageVisualizer>().ok()?;
+    images
+        .images
+        .iter()
+        .filter(|image| image.textured_rect.colormapped_texture.color_mapper.is_on())
+        .filter_map(|image| Some((image, image.depth_meter?)))
+        .max_by_key(|(image, _)| image.draw_order)
+        .map(|(image, meter)| DepthLegend {
+            colormapped_texture: image.textured_rect.colormapped_texture.clone(),
+            meter,
+        })
+}
+
+/// Small toggle next to the entity legend button showing or hiding the depth legend.
+fn depth_legend_button_ui(ui: &mut egui::Ui, view_rect: Rect, show_depth_legend: &mut bool) {
+    let button_rect = toolbar_button_rect(view_rect, 3);
+
+    let response = ui
+        .put(
+            button_rect,
+            egui::SelectableLabel::new(*show_depth_legend, "🌈"),
+        )
+        .on_hover_text("Show a colorbar for the depth image shown in this view.");
+    if response.clicked() {
+        *show_depth_legend = !*show_depth_legend;
+    }
+}
+
+/// Vertical colorbar in the bottom right corner of the view, with depth ticks in the preferred depth unit.
+fn depth_legend_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &egui::Ui,
+    view_rect: Rect,
+    depth_legend: &DepthLegend,
+) {
+    const NUM_TICKS: usize = 5;
+    const BAR_WIDTH: f32 = 16.0;
+    const TICK_LENGTH: f32 = 4.0;
+
+    let painter = ui.painter().with_clip_rect(view_rect);
+    let visuals = ui.visuals();
+    let font_id = egui::TextStyle::Small.resolve(ui.style());
+
+    let [min, max] = depth_legend.colormapped_texture.range;
+    let meter = if depth_legend.meter > 0.0 {
+        depth_legend.meter
+    } else {
+        1.0
+    };
+    let tick_labels = (0..NUM_TICKS)
+        .map(|i| {
+            let t = i as f32 / (NUM_TICKS - 1) as f32;
+            let depth = egui::lerp(min..=max, t) / meter;
+            let depth = ctx.app_options.depth_unit.convert(depth as f64);
+            (
+                t,
+                format!(
+                    "{} {}",
+                    re_format::format_f64(depth),
+                    ctx.app_options.depth_unit.suffix()
+                ),
+            )
+        })
+        .collect::<Vec<_>>();
+    let label_width = tick_labels
+        .iter()
+        .map(|(_, text)| {
+            painter
+                .layout_no_wrap(text.clone(), font_id.clone(), visuals.text_color())
+                .size()
+                .x
+        })
+        .fold(0.0, f32::max);
+
+    let bar_height = (view_rect.height() * 0.4).at_most(160.0);
+    // Leave room for the timeline strip.
+    let margin = TOOLBAR_BUTTON_MARGIN + font_id.size + TIMELINE_STRIP_HEIGHT;
+    let bar_rect = Rect::from_min_size(
+        view_rect.right_bottom() - vec2(margin + BAR_WIDTH, margin + bar_height),
+        vec2(BAR_WIDTH, bar_height),
+    );
+    let background_rect = Rect::from_min_max(
+        bar_rect.left_top() - vec2(label_width + TICK_LENGTH + 8.0, font_id.size),
+        bar_rect.right_bottom() + Vec2::splat(font_id.size * 0.5),
+    );
+    painter.rect_filled(
+        background_rect,
+        visuals.window_rounding,
+        visuals.extreme_bg_color.gamma_multiply(0.8),
+    );
+
+    if let Err(err) = gpu_bridge::paint_colormap_bar(
+        ctx.render_ctx,
+        &painter,
+        bar_rect,
+        &depth_legend.colormapped_texture,
+    ) {
+        re_log::error_once!("Failed to paint depth legend: {err}");
+        return;
+    }
+
+    // Ticks are spaced evenly in depth, matching the linear mapping from depth to colormap.
+    let stroke = visuals.widgets.noninteractive.fg_stroke;
+    for (t, text) in tick_labels {
+        let y = egui::lerp(bar_rect.bottom()..=bar_rect.top(), t);
+        painter.line_segment(
+            [
+                pos2(bar_rect.left() - TICK_LENGTH, y),
+                pos2(bar_rect.left(), y),
+            ],
+            stroke,
+        );
+        painter.text(
+            pos2(bar_rect.left() - TICK_LENGTH - 2.0, y),
+            Align2::RIGHT_CENTER,
+            text,
+            font_id.clone(),
+            visuals.text_color(),
+        );
+    }
+}
+
+/// Shows or hides the given entity, and if `include_subtree` is set also all its descendants.
+///
+/// This is stored as an override in the blueprint, just like toggling visibility in the blueprint panel.
+fn set_entities_visible(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    entity_path: &EntityPath,
+    include_subtree: bool,
+    visible: bool,
+) {
+    let data_results: std::collections::BTreeMap<_, _> = query
+        .iter_all_data_results()
+        .filter(|data_result| {
+            &data_result.entity_path == entity_path
+                || (include_subtree && data_result.entity_path.is_descendant_of(entity_path))
+        })
+        .map(|data_result| (&data_result.entity_path, data_result))
+        .collect();
+
+    for data_result in data_results.into_values() {
+        let mut props = data_result
+            .individual_properties()
+            .cloned()
+            .unwrap_or_default();
+        props.visible = visible;
+        data_result.save_override(Some(props), ctx);
+    }
+}
+
+/// Switches the magnification and rotation of the entity, if it is shown as an image.
+fn image_display_menu_ui(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    ui: &mut egui::Ui,
+    entity_path: &EntityPath,
+) {
+    let Some(data_result) = query.iter_all_data_results().find(|data_result| {
+        &data_result.entity_path == entity_path
+            && data_result
+                .visualizers
+                .contains(&ImageVisualizer::identifier())
+    }) else {
+        return;
+    };
+    let save_properties = |edit: &dyn Fn(&mut EntityProperties)| {
+        let mut props = data_result
+            .individual_properties()
+            .cloned()
+            .unwrap_or_default();
+        edit(&mut props);
+        data_result.save_override(Some(props), ctx);
+    };
+
+    let accumulated_properties = data_result.accumulated_properties();
+    let current = accumulated_properties
+        .image_magnification
+        .unwrap_or_default();
+    ui.horizontal(|ui| {
+        ui.label("Magnification:");
+        for (magnification, hover_text) in [
+            (
+                ImageMagnification::Sharp,
+                "Show crisp individual pixels when zoomed in",
+            ),
+            (
+                ImageMagnification::Smooth,
+                "Blend between neighboring pixels when zoomed in",
+            ),
+        ] {
+            if ui
+                .selectable_label(current == magnification, magnification.to_string())
+                .on_hover_text(hover_text)
+                .clicked()
+            {
+                save_properties(&|props| props.image_magnification = Some(magnification));
+                ui.close_menu();
+            }
+        }
+    });
+
+    let current = accumulated_properties.image_rotation.unwrap_or_default();
+    ui.menu_button(format!("Rotation: {current}"), |ui| {
+        for rotation in ImageRotation::ALL {
+            if ui
+                .selectable_label(current == rotation, rotation.to_string())
+                .clicked()
+            {
+                save_properties(&|props| props.image_rotation = Some(rotation));
+                ui.close_menu();
+            }
+        }
+    })
+    .response
+    .on_hover_text("Rotate or mirror the image, e.g. for a camera mounted sideways");
+}
+
+/// Popup asking for the label of a freshly dragged out box annotation.
+fn box_annotation_popup_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &egui::Ui,
+    query: &ViewQuery<'_>,
+    state_2d: &mut View2DState,
+    ui_from_canvas: RectTransform,
+) {
+    let Some(pending) = &mut state_2d.pending_annotation else {
+        return;
+    };
+
+    let mut submit = false;
+    let mut cancel = ui.input(|i| i.key_pressed(egui::Key::Escape));
+
+    let popup_pos = ui_from_canvas.transform_rect(pending.rect).left_bottom() + vec2(0.0, 4.0);
+    egui::Area::new(egui::Id::new(query.space_view_id).with("box_annotation_popup"))
+        .order(egui::Order::Foreground)
+        .fixed_pos(popup_pos)
+        .show(ui.ctx(), |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut pending.label)
+                            .hint_text("Label (optional)")
+                            .desired_width(150.0),
+                    );
+                    if !response.has_focus() && pending.label.is_empty() {
+                        response.request_focus();
+                    }
+                    submit |=
+                        response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    submit |= ui.button("Create").clicked();
+                    cancel |= ui.button("Cancel").clicked();
+                });
+            });
+        });
+
+    if submit {
+        // The box is logged in the space's own coordinates, which may be flipped or rotated in the view.
+        let rect = state_2d.orientation.space_from_view_rect(pending.rect);
+        submit_box_annotation(ctx, query, rect, &pending.label);
+        state_2d.pending_annotation = None;
+    } else if cancel {
+        state_2d.pending_annotation = None;
+    }
+}
+
+/// Sends a new [`re_types::archetypes::Boxes2D`] for the given scene rectangle to the application,
+/// which adds it to the recording as a child of `annotations` under the space origin.
+fn submit_box_annotation(ctx: &ViewerContext<'_>, query: &ViewQuery<'_>, rect: Rect, label: &str) {
+    let annotations_path = query.space_origin.join(&EntityPath::from("annotations"));
+    let index = ctx
+        .entity_db
+        .tree()
+        .subtree(&annotations_path)
+        .map_or(0, |tree| tree.children.len());
+    let entity_path = annotations_path.join(&EntityPath::from(format!("box_{index}")));
+
+    let mut boxes = re_types::archetypes::Boxes2D::from_mins_and_sizes(
+        [(rect.min.x, rect.min.y)],
+        [(rect.width(), rect.height())],
+    );
+    if !label.is_empty() {
+        boxes = boxes.with_labels([label.to_owned()]);
+    }
+
+    let time_query = ctx.current_query();
+    let timepoint = re_log_types::TimePoint::from([(time_query.timeline, time_query.at)]);
+
+    match re_log_types::DataRow::from_archetype(
+        re_log_types::RowId::new(),
+        timepoint,
+        entity_path,
+        &boxes,
+    ) {
+        Ok(row) => ctx
+            .command_sender
+            .send_system(SystemCommand::UpdateRecording(
+                ctx.entity_db.store_id().clone(),
+                vec![row],
+            )),
+        Err(err) => re_log::error_once!("Failed to create DataRow for box annotation: {err}"),
+    }
+}
+
+/// The first interactive instance hit by the given picking result.
+fn hovered_instance(
+    ctx: &ViewerContext<'_>,
+    view_ctx: &ViewContextCollection,
+    picking_result: Option<&PickingResult>,
+) -> Result<Option<InstancePath>, SpaceViewSystemExecutionError> {
+    let non_interactive = view_ctx.get::<NonInteractiveEntities>()?;
+
+    Ok(picking_result.and_then(|picking_result| {
+        picking_result
+            .hits
+            .iter()
+            .filter(|hit| {
+                !non_interactive
+                    .0
+                    .contains(&hit.instance_path_hash.entity_path_hash)
+            })
+            .find_map(|hit| hit.instance_path_hash.resolve(ctx.entity_db))
+    }))
+}
+
+/// Scene rectangle covered by an instance, or by the entire entity if the instance is a splat.
+fn instance_rect(bounding_boxes: &SceneBoundingBoxes, instance: &InstancePath) -> Option<Rect> {
+    let instance_hash = InstancePathHash::instance(&instance.entity_path, instance.instance_key);
+
+    bounding_boxes
+        .per_instance
+        .iter()
+        .filter(|(hash, _)| {
+            if instance.instance_key.is_splat() {
+                hash.entity_path_hash == instance_hash.entity_path_hash
+            } else {
+                *hash == instance_hash
+            }
+        })
+        .map(|(_, bbox)| {
+            Rect::from_min_max(pos2(bbox.min.x, bbox.min.y), pos2(bbox.max.x, bbox.max.y))
+        })
+        .reduce(Rect::union)
+}
+
+/// Scene rectangle covering all selected instances that are shown in this view.
+fn selection_rect(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    bounding_boxes: &SceneBoundingBoxes,
+) -> Option<Rect> {
+    selected_instances(ctx, query)
+        .iter()
+        .filter_map(|instance| instance_rect(bounding_boxes, instance))
+        .reduce(Rect::union)
+}
+
+/// All selected instances that are either selected in this view or not tied to any view.
+fn selected_instances(ctx: &ViewerContext<'_>, query: &ViewQuery<'_>) -> Vec<InstancePath> {
+    ctx.selection_state()
+        .current()
+        .iter_items()
+        .filter_map(|item| match item {
+            Item::InstancePath(space_view_id, instance)
+                if space_view_id.map_or(true, |id| id == query.space_view_id) =>
+            {
+                Some(instance.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Context menu of the 2D view.
+///
+/// Offers actions on the instance it was opened on, as well as taking screenshots if enabled.
+fn context_menu_2d(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    response: &egui::Response,
+    state: &mut View2DState,
+    bounding_boxes: &SceneBoundingBoxes,
+    available_size: Vec2,
+) -> Option<ScreenshotRequest> {
+    let instance = state.context_menu_instance.clone();
+    let selected_instances = if state.lock_view {
+        Vec::new()
+    } else {
+        selected_instances(ctx, query)
+    };
+    let screenshots_enabled = screenshots_enabled(ctx);
+    let export_enabled = cfg!(not(target_arch = "wasm32"));
+    if instance.is_none()
+        && selected_instances.is_empty()
+        && !screenshots_enabled
+        && !export_enabled
+    {
+        return None;
+    }
+
+    let mut take_screenshot = None;
+    response.context_menu(|ui| {
+        ui.style_mut().wrap = Some(false);
+        if let Some(instance) = &instance {
+            instance_context_menu_ui(
+                ctx,
+                query,
+                ui,
+                instance,
+                state,
+                bounding_boxes,
+                available_size,
+            );
+        }
+        if !selected_instances.is_empty() {
+            if instance.is_some() {
+                ui.separator();
+            }
+            selection_context_menu_ui(
+                ui,
+                &selected_instances,
+                state,
+                bounding_boxes,
+                available_size,
+            );
+        }
+        if (screenshots_enabled || export_enabled)
+            && (instance.is_some() || !selected_instances.is_empty())
+        {
+            ui.separator();
+        }
+        if screenshots_enabled {
+            take_screenshot = screenshot_context_menu_ui(ui).map(ScreenshotRequest::Visible);
+        }
+        if export_enabled {
+            ui.horizontal(|ui| {
+                if ui
+                    .button("Save view as PNG")
+                    .on_hover_text(
+                        "Renders the visible region at a multiple of the on-screen resolution.\n\
+                        Labels and other overlays are not included.",
+                    )
+                    .clicked()
+                {
+                    take_screenshot = Some(ScreenshotRequest::Export {
+                        scale: state.export_scale,
+                    });
+                    ui.close_menu();
+                }
+                ui.add(
+                    egui::DragValue::new(&mut state.export_scale)
+                        .clamp_range(0.25..=8.0)
+                        .speed(0.05)
+                        .suffix("×"),
+                )
+                .on_hover_text("Resolution multiplier");
+            });
+            if ui
+                .button("Save view as SVG")
+                .on_hover_text(
+                    "Saves lines, points and labels of the visible region as vector graphics.\n\
+                    Images and filled boxes are not included.",
+                )
+                .clicked()
+            {
+                take_screenshot = Some(ScreenshotRequest::ExportSvg);
+                ui.close_menu();
+            }
+        }
+    });
+    take_screenshot
+}
+
+/// Renders the visible region of the view again, `scale` times the on-screen resolution,
+/// and saves it to disk once it has been read back.
+///
+/// Only what `re_renderer` draws ends up in the image, egui drawn labels and overlays don't.
+#[allow(clippy::too_many_arguments)]
+fn export_view(
+    ctx: &ViewerContext<'_>,
+    painter: &egui::Painter,
+    canvas_from_ui: RectTransform,
+    query: &ViewQuery<'_>,
+    state: &SpatialSpaceViewState,
+    pinhole: Option<Pinhole>,
+    scale: f32,
+    draw_data: &[QueueableDrawData],
+) {
+    re_tracing::profile_function!();
+
+    // Stay within what the gpu can render to and read back.
+    let clip_rect = painter.clip_rect();
+    let limits = ctx.render_ctx.device.limits();
+    let max_pixels_per_point = (limits.max_texture_dimension_2d as f32
+        / clip_rect.width().max(clip_rect.height()))
+    .min((limits.max_buffer_size as f32 / 4.0 / clip_rect.area()).sqrt());
+    let pixels_per_point = (painter.ctx().pixels_per_point() * scale).min(max_pixels_per_point);
+
+    let target_config = match setup_target_config(
+        painter,
+        canvas_from_ui,
+        &format!("{} - export", query.space_origin),
+        state.auto_size_config_2d(),
+        None,
+        pinhole,
+        pixels_per_point,
+    ) {
+        Ok(target_config) => target_config,
+        Err(err) => {
+            re_log::error!("Failed to export view: {err}");
+            return;
+        }
+    };
+
+    let mut view_builder = ViewBuilder::new(ctx.render_ctx, target_config);
+    for draw_data in draw_data {
+        view_builder.queue_draw(draw_data.clone());
+    }
+    if let Err(err) = view_builder.schedule_screenshot(
+        ctx.render_ctx,
+        query.space_view_id.gpu_readback_id(),
+        ScreenshotMode::SaveToDisk,
+    ) {
+        re_log::error!("Failed to export view: {err}");
+        return;
+    }
+
+    painter.add(gpu_bridge::new_offscreen_renderer_callback(
+        view_builder,
+        clip_rect.min,
+        state
+            .state_2d
+            .background_color(&painter.ctx().style().visuals)
+            .into(),
+    ));
+}
+
+/// Writes the lines, points and labels of the visible region to an SVG file picked by the user.
+#[cfg(not(target_arch = "wasm32"))]
+fn export_svg(
+    painter: &egui::Painter,
+    canvas_from_ui: RectTransform,
+    state: &SpatialSpaceViewState,
+    shared_render_builders: &SharedRenderBuilders,
+    labels: &[UiLabel],
+) {
+    re_tracing::profile_function!();
+
+    use crate::svg_export::{scene_to_svg, SvgExportSettings};
+
+    let settings = SvgExportSettings {
+        scene_rect: canvas_from_ui.transform_rect(painter.clip_rect()),
+        scene_units_per_point: canvas_from_ui.scale().y,
+        auto_size_config: state.auto_size_config_2d(),
+    };
+    let svg = scene_to_svg(
+        shared_render_builders.lines.lock().as_ref(),
+        shared_render_builders.points.lock().as_ref(),
+        labels,
+        &settings,
+    );
+
+    if let Some(path) = rfd::FileDialog::new().set_file_name("view.svg").save_file() {
+        match std::fs::write(&path, svg) {
+            Ok(()) => {
+                re_log::info!("View saved to {path:?}");
+            }
+            Err(err) => {
+                re_log::error!("Failed saving view to {path:?}: {err}");
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn export_svg(
+    _painter: &egui::Painter,
+    _canvas_from_ui: RectTransform,
+    _state: &SpatialSpaceViewState,
+    _shared_render_builders: &SharedRenderBuilders,
+    _labels: &[UiLabel],
+) {
+}
+
+fn selection_context_menu_ui(
+    ui: &mut egui::Ui,
+    selected_instances: &[InstancePath],
+    state: &mut View2DState,
+    bounding_boxes: &SceneBoundingBoxes,
+    available_size: Vec2,
+) {
+    let selection_rect = selected_instances
+        .iter()
+        .filter_map(|instance| instance_rect(bounding_boxes, instance))
+        .reduce(Rect::union);
+    if ui
+        .add_enabled(
+            selection_rect.is_some(),
+            egui::Button::new("Zoom to selection"),
+        )
+        .clicked()
+    {
+        if let Some(selection_rect) = selection_rect {
+            state.zoom_to_rect(selection_rect, available_size, ui.input(|i| i.time));
+        }
+        ui.close_menu();
+    }
+
+    let all_locked = selected_instances
+        .iter()
+        .all(|instance| state.locked_instances.contains(instance));
+    let label = if all_locked {
+        "Unlock instance"
+    } else {
+        "Lock instance"
+    };
+    if ui
+        .button(label)
+        .on_hover_text("Locked instances can't be hovered or selected in this view")
+        .clicked()
+    {
+        if all_locked {
+            for instance in selected_instances {
+                state.locked_instances.remove(instance);
+            }
+        } else {
+            state
+                .locked_instances
+                .extend(selected_instances.iter().cloned());
+        }
+        ui.close_menu();
+    }
+}
+
+fn instance_context_menu_ui(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    ui: &mut egui::Ui,
+    instance: &InstancePath,
+    state: &mut View2DState,
+    bounding_boxes: &SceneBoundingBoxes,
+    available_size: Vec2,
+) {
+    ui.label(egui::RichText::new(instance.to_string()).weak());
+
+    if ui.button("Select").clicked() {
+        ctx.selection_state().set_selection(Item::InstancePath(
+            Some(query.space_view_id),
+            instance.clone(),
+        ));
+        ui.close_menu();
+    }
+
+    if ui.button("Hide this object").clicked() {
+        set_entities_visible(ctx, query, &instance.entity_path, false, false);
+        ui.close_menu();
+    }
+
+    // Offer to hide entire subtrees for all ancestors below the space origin.
+    let mut ancestor = instance.entity_path.parent();
+    while let Some(path) = ancestor {
+        if !path.is_descendant_of(query.space_origin) {
+            break;
+        }
+        if ui.button(format!("Hide all from {path}")).clicked() {
+            set_entities_visible(ctx, query, &path, true, false);
+            ui.close_menu();
+        }
+        ancestor = path.parent();
+    }
+
+    let rect = instance_rect(bounding_boxes, instance);
+    if ui
+        .add_enabled(rect.is_some(), egui::Button::new("Zoom to"))
+        .clicked()
+    {
+        if let Some(rect) = rect {
+            state.zoom_to_rect(rect, available_size, ui.input(|i| i.time));
+        }
+        ui.close_menu();
+    }
+
+    if state.fit_target.as_ref() == Some(&instance.entity_path) {
+        if ui
+            .button("Fit view to whole scene")
+            .on_hover_text("Stop fitting the view to this entity")
+            .clicked()
+        {
+            state.set_fit_target(None, ui.input(|i| i.time));
+            ui.close_menu();
+        }
+    } else if ui
+        .button("Fit view to this")
+        .on_hover_text(
+            "Zooming to fit frames just this entity from now on, ignoring the rest of the scene",
+        )
+        .clicked()
+    {
+        state.set_fit_target(Some(instance.entity_path.clone()), ui.input(|i| i.time));
+        ui.close_menu();
+    }
+
+    if ui
+        .add_enabled(rect.is_some(), egui::Button::new("Focus on this"))
+        .on_hover_text("Dim everything outside of this instance")
+        .clicked()
+    {
+        state.focus_rect = rect;
+        ui.close_menu();
+    }
+    if state.focus_rect.is_some() && ui.button("Clear focus region").clicked() {
+        state.focus_rect = None;
+        ui.close_menu();
+    }
+
+    image_display_menu_ui(ctx, query, ui, &instance.entity_path);
+
+    if ui.button("Copy path").clicked() {
+        ui.output_mut(|o| o.copied_text = instance.to_string());
+        ui.close_menu();
+    }
+}
+
+/// Side length of the square handles at the corners of [`View2DState::focus_rect`], in ui points.
+const FOCUS_HANDLE_SIZE: f32 = 8.0;
+
+/// Dims everything outside of [`View2DState::focus_rect`] and lets its corners be dragged.
+fn focus_region_ui(
+    ui: &egui::Ui,
+    painter: &egui::Painter,
+    ui_from_canvas: RectTransform,
+    state: &mut View2DState,
+) {
+    let Some(focus_rect) = state.focus_rect else {
+        return;
+    };
+
+    let corners = [
+        focus_rect.left_top(),
+        focus_rect.right_top(),
+        focus_rect.right_bottom(),
+        focus_rect.left_bottom(),
+    ];
+    for (index, corner) in corners.iter().enumerate() {
+        let handle_rect = Rect::from_center_size(
+            ui_from_canvas.transform_pos(*corner),
+            Vec2::splat(FOCUS_HANDLE_SIZE),
+        );
+        let response = ui
+            .interact(
+                handle_rect,
+                ui.id().with("focus_rect_handle").with(index),
+                egui::Sense::drag(),
+            )
+            .on_hover_cursor(egui::CursorIcon::Crosshair);
+        if state.lock_view || !response.dragged() {
+            continue;
+        }
+        if let Some(pointer_pos) = response.interact_pointer_pos() {
+            // The opposite corner stays put.
+            let opposite = corners[(index + 2) % corners.len()];
+            state.focus_rect = Some(Rect::from_two_pos(
+                opposite,
+                ui_from_canvas.inverse().transform_pos(pointer_pos),
+            ));
+        }
+    }
+
+    let Some(focus_rect) = state.focus_rect else {
+        return;
+    };
+    let cutout = ui_from_canvas.transform_rect(focus_rect);
+    painter.add(dimmed_outside_shape(
+        painter.clip_rect(),
+        cutout,
+        Color32::from_black_alpha(160),
+    ));
+
+    let stroke = ui.visuals().selection.stroke;
+    for corner in [
+        cutout.left_top(),
+        cutout.right_top(),
+        cutout.right_bottom(),
+        cutout.left_bottom(),
+    ] {
+        let handle_rect = Rect::from_center_size(corner, Vec2::splat(FOCUS_HANDLE_SIZE));
+        painter.add(Shape::rect_filled(handle_rect, 0.0, stroke.color));
+        painter.add(Shape::rect_stroke(handle_rect, 0.0, (1.0, Color32::BLACK)));
+    }
+}
+
+/// A filled `outer` rectangle with a rectangular hole at `cutout`.
+fn dimmed_outside_shape(outer: Rect, cutout: Rect, color: Color32) -> Shape {
+    let cutout = cutout.intersect(outer);
+    if !cutout.is_positive() {
+        return Shape::rect_filled(outer, 0.0, color);
     }
-}
 
-pub fn help_text(re_ui: &re_ui::ReUi) -> egui::WidgetText {
-    let mut layout = re_ui::LayoutJobBuilder::new(re_ui);
+    // Ring of quads between the outer and the inner corners, both listed clockwise.
+    let mut mesh = egui::Mesh::default();
+    for corner in [
+        outer.left_top(),
+        outer.right_top(),
+        outer.right_bottom(),
+        outer.left_bottom(),
+        cutout.left_top(),
+        cutout.right_top(),
+        cutout.right_bottom(),
+        cutout.left_bottom(),
+    ] {
+        mesh.colored_vertex(corner, color);
+    }
+    for side in 0..4 {
+        let next = (side + 1) % 4;
+        mesh.add_triangle(side, next, 4 + side);
+        mesh.add_triangle(next, 4 + next, 4 + side);
+    }
+    mesh.into()
+}
 
-    layout.add(ZOOM_SCROLL_MODIFIER);
-    layout.add(" + scroll to zoom.\n");
+/// Position in scene coordinates the crosshair should snap to.
+///
+/// This is the center of the hovered point or box, if any.
+fn snap_crosshair_position(
+    parts: &VisualizerCollection,
+    picking_result: Option<&PickingResult>,
+) -> Option<Pos2> {
+    let picking_result = picking_result?;
 
-    layout.add("Click and drag with ");
-    layout.add(DRAG_PAN2D_BUTTON);
-    layout.add(" to pan.\n");
+    let mut instance_bounding_boxes = Vec::new();
+    if let Ok(points) = parts.get::<Points2DVisualizer>() {
+        instance_bounding_boxes.extend(points.data.instance_bounding_boxes.iter());
+    }
+    if let Ok(boxes) = parts.get::<Boxes2DVisualizer>() {
+        instance_bounding_boxes.extend(boxes.data.instance_bounding_boxes.iter());
+    }
 
-    layout.add_button_text(RESET_VIEW_BUTTON_TEXT);
-    layout.add(" to reset the view.");
+    picking_result
+        .hits
+        .iter()
+        .filter(|hit| hit.hit_type == PickingHitType::GpuPickingResult)
+        .find_map(|hit| {
+            instance_bounding_boxes
+                .iter()
+                .find(|(instance, _)| *instance == hit.instance_path_hash)
+                .map(|(_, bbox)| {
+                    let center = bbox.center();
+                    pos2(center.x, center.y)
+                })
+        })
+}
 
-    layout.layout_job.into()
+/// Two short line segments forming a `+` centered on the given position in ui coordinates.
+fn crosshair_shapes(center: Pos2, stroke: egui::Stroke) -> [Shape; 2] {
+    const HALF_SIZE: f32 = 6.0;
+    [
+        Shape::line_segment(
+            [center - vec2(HALF_SIZE, 0.0), center + vec2(HALF_SIZE, 0.0)],
+            stroke,
+        ),
+        Shape::line_segment(
+            [center - vec2(0.0, HALF_SIZE), center + vec2(0.0, HALF_SIZE)],
+            stroke,
+        ),
+    ]
 }
 
-/// Create the outer 2D view, which consists of a scrollable region
-pub fn view_2d(
-    ctx: &ViewerContext<'_>,
-    ui: &mut egui::Ui,
-    state: &mut SpatialSpaceViewState,
-    query: &ViewQuery<'_>,
-    system_output: re_viewer_context::SystemExecutionOutput,
-) -> Result<(), SpaceViewSystemExecutionError> {
-    re_tracing::profile_function!();
+/// Adds a scale bar centered at the bottom of the painter's clip rect to `shapes`.
+///
+/// The bar has a "nice" length (1, 2 or 5 times a power of ten) of about 100 ui points
+/// and is labeled in meters if `scene_units_per_meter` is known, in scene units otherwise.
+fn draw_scale_bar(
+    painter: &egui::Painter,
+    ui_from_space: &RectTransform,
+    scene_units_per_meter: Option<f32>,
+    shapes: &mut Vec<Shape>,
+) {
+    const TARGET_LENGTH_IN_UI: f32 = 100.0;
+    const CAP_HEIGHT: f32 = 8.0;
 
-    let SystemExecutionOutput {
-        view_systems: parts,
-        context_systems: view_ctx,
-        draw_data,
-    } = system_output;
+    let ui_per_scene_unit = ui_from_space.scale().x;
+    let scene_units_per_label_unit = scene_units_per_meter
+        .filter(|units| *units > 0.0)
+        .unwrap_or(1.0);
+    let ui_per_label_unit = ui_per_scene_unit * scene_units_per_label_unit;
+    if !ui_per_label_unit.is_finite() || ui_per_label_unit <= 0.0 {
+        return;
+    }
 
-    // Save off the available_size since this is used for some of the layout updates later
-    let available_size = ui.available_size();
-    let store = ctx.entity_db.store();
+    let (length, decimals) = nice_length(TARGET_LENGTH_IN_UI / ui_per_label_unit);
+    let length_in_ui = length * ui_per_label_unit;
+    let suffix = if scene_units_per_meter.is_some() {
+        "m"
+    } else {
+        "px"
+    };
 
-    let scene_rect_accum = state.bounding_boxes.accumulated;
-    let scene_rect_accum = egui::Rect::from_min_max(
-        scene_rect_accum.min.truncate().to_array().into(),
-        scene_rect_accum.max.truncate().to_array().into(),
-    );
+    let clip_rect = painter.clip_rect();
+    // Leave room for the timeline strip.
+    let bottom = clip_rect.bottom() - TOOLBAR_BUTTON_MARGIN - TIMELINE_STRIP_HEIGHT - CAP_HEIGHT;
+    let left = pos2(clip_rect.center().x - 0.5 * length_in_ui, bottom);
+    let right = left + vec2(length_in_ui, 0.0);
+    let cap = vec2(0.0, 0.5 * CAP_HEIGHT);
+    let segments = [
+        [left, right],
+        [left - cap, left + cap],
+        [right - cap, right + cap],
+    ];
 
-    // Determine the canvas which determines the extent of the explorable scene coordinates,
-    // and thus the size of the scroll area.
-    //
-    // TODO(andreas): We want to move away from the scroll area and instead work with open ended 2D scene coordinates!
-    // The term canvas might then refer to the area in scene coordinates visible at a given moment.
-    // Orthogonally, we'll want to visualize the resolution rectangle of the pinhole camera.
-    //
-    // For that we need to check if this is defined by a pinhole camera.
-    // Note that we can't rely on the camera being part of scene.space_cameras since that requires
-    // the camera to be added to the scene!
-    let pinhole = query_pinhole(store, &ctx.current_query(), query.space_origin);
-    let canvas_rect = pinhole
-        .as_ref()
-        .and_then(|p| p.resolution())
-        .map_or(scene_rect_accum, |res| {
-            Rect::from_min_max(egui::Pos2::ZERO, egui::pos2(res.x, res.y))
-        });
+    // Draw an outline first so the bar is visible on both bright and dark backgrounds.
+    for (width, color) in [(4.0, Color32::BLACK), (2.0, Color32::WHITE)] {
+        shapes.extend(
+            segments
+                .iter()
+                .map(|segment| Shape::line_segment(*segment, (width, color))),
+        );
+    }
 
-    let (desired_size, offset) = state
-        .state_2d
-        .desired_size_and_offset(available_size, canvas_rect);
+    let galley = painter.layout_no_wrap(
+        format!("{length:.decimals$} {suffix}"),
+        egui::TextStyle::Small.resolve(&painter.ctx().style()),
+        Color32::WHITE,
+    );
+    let text_rect = Align2::CENTER_BOTTOM.anchor_rect(Rect::from_min_size(
+        pos2(clip_rect.center().x, bottom - CAP_HEIGHT),
+        galley.size(),
+    ));
+    shapes.push(Shape::rect_filled(
+        text_rect.expand(2.0),
+        2.0,
+        Color32::from_black_alpha(196),
+    ));
+    shapes.push(Shape::galley(text_rect.min, galley, Color32::WHITE));
+}
 
-    // Bound the offset based on sizes
-    // TODO(jleibs): can we derive this from the ScrollArea shape?
-    let offset = offset.at_most(desired_size - available_size);
-    let offset = offset.at_least(Vec2::ZERO);
+/// Size of the squares of the checkerboard background, in ui points.
+const CHECKERBOARD_TILE_SIZE: f32 = 8.0;
 
-    let scroll_area = ScrollArea::both()
-        .scroll_offset(offset)
-        .auto_shrink([false, false]);
+/// Checkerboard with squares of a fixed size on screen, covering the visible region of the scene.
+///
+/// Drawn as a texture with a single texel per square, magnified without filtering, behind everything else.
+fn checkerboard_draw_data(
+    render_ctx: &re_renderer::RenderContext,
+    visible_rect_in_scene: Rect,
+    visible_size_in_ui: Vec2,
+    dark_mode: bool,
+) -> Option<QueueableDrawData> {
+    let num_tiles = (visible_size_in_ui / CHECKERBOARD_TILE_SIZE).ceil();
+    let [width, height] = [num_tiles.x as u32, num_tiles.y as u32];
+    if width == 0 || height == 0 || !visible_rect_in_scene.is_positive() {
+        return None;
+    }
+    let (light, dark) = if dark_mode { (64, 40) } else { (255, 204) };
 
-    let scroll_out = scroll_area.show(ui, |ui| -> Result<(), SpaceViewSystemExecutionError> {
-        let desired_size = desired_size.at_least(Vec2::ZERO);
-        let (mut response, painter) =
-            ui.allocate_painter(desired_size, egui::Sense::click_and_drag());
+    let texture_key = egui::util::hash(("checkerboard", width, height, dark_mode));
+    let texture = gpu_bridge::get_or_create_texture(render_ctx, texture_key, || {
+        let data = (0..height)
+            .flat_map(|y| {
+                (0..width).flat_map(move |x| {
+                    let gray = if (x + y) % 2 == 0 { light } else { dark };
+                    [gray, gray, gray, 255]
+                })
+            })
+            .collect::<Vec<u8>>();
+        re_renderer::resource_managers::Texture2DCreationDesc {
+            label: "checkerboard".into(),
+            data: data.into(),
+            format: re_renderer::external::wgpu::TextureFormat::Rgba8UnormSrgb,
+            width,
+            height,
+        }
+    });
+    let texture = match texture {
+        Ok(texture) => texture,
+        Err(err) => {
+            re_log::error_once!("Failed to create checkerboard texture: {err}");
+            return None;
+        }
+    };
 
-        if !response.rect.is_positive() {
-            return Ok(());
+    // Whole squares may reach a bit beyond the visible region.
+    let scene_units_per_tile =
+        visible_rect_in_scene.size() / visible_size_in_ui * CHECKERBOARD_TILE_SIZE;
+    let rect = TexturedRect {
+        top_left_corner_position: glam::vec3(
+            visible_rect_in_scene.min.x,
+            visible_rect_in_scene.min.y,
+            0.0,
+        ),
+        extent_u: glam::Vec3::X * width as f32 * scene_units_per_tile.x,
+        extent_v: glam::Vec3::Y * height as f32 * scene_units_per_tile.y,
+        colormapped_texture: ColormappedTexture::from_unorm_rgba(texture),
+        options: RectangleOptions {
+            texture_filter_magnification: TextureFilterMag::Nearest,
+            texture_filter_minification: TextureFilterMin::Linear,
+            multiplicative_tint: egui::Rgba::WHITE,
+            depth_offset: re_renderer::DepthOffset::MIN,
+            outline_mask: re_renderer::OutlineMaskPreference::NONE,
+        },
+    };
+    match RectangleDrawData::new(render_ctx, &[rect]) {
+        Ok(draw_data) => Some(draw_data.into()),
+        Err(err) => {
+            re_log::error_once!("Failed to create checkerboard draw data: {err}");
+            None
         }
+    }
+}
 
-        let ui_from_canvas = egui::emath::RectTransform::from_to(canvas_rect, response.rect);
-        let canvas_from_ui = ui_from_canvas.inverse();
+/// Smallest, median and largest radius in scene units of the 2D points in the view.
+///
+/// `None` unless there are points of different sizes, since the legend wouldn't tell anything then.
+fn point_size_legend(visualizers: &VisualizerCollection) -> Option<[f32; 3]> {
+    let points = visualizers.get::<Points2DVisualizer>().ok()?;
+    let radii = points
+        .scene_radii
+        .iter()
+        .copied()
+        .filter(|radius| radius.is_finite())
+        .sorted_by(f32::total_cmp)
+        .collect_vec();
+    let (&min, &max) = (radii.first()?, radii.last()?);
+    (min < max).then(|| [min, radii[radii.len() / 2], max])
+}
 
-        state
-            .state_2d
-            .update(&response, canvas_from_ui, canvas_rect, available_size);
+/// Reference circles for the given radii in the bottom left corner of the view, with their values below.
+///
+/// Circles are shown at the current zoom, but kept within a sensible size on screen.
+fn draw_point_size_legend(
+    painter: &egui::Painter,
+    ui_from_space: &RectTransform,
+    radii: [f32; 3],
+    shapes: &mut Vec<Shape>,
+) {
+    const MAX_RADIUS_IN_UI: f32 = 32.0;
+    const SPACING: f32 = 8.0;
 
-        // TODO(andreas): Use the same eye & transformations as in `setup_target_config`.
-        let eye = Eye {
-            world_from_rub_view: IsoTransform::IDENTITY,
-            fov_y: None,
-        };
+    let ui_per_scene_unit = ui_from_space.scale().x;
+    let font_id = egui::TextStyle::Small.resolve(&painter.ctx().style());
+    let entries = radii.map(|radius| {
+        let radius_in_ui = (radius * ui_per_scene_unit).clamp(1.0, MAX_RADIUS_IN_UI);
+        let galley = painter.layout_no_wrap(
+            re_format::format_f32(radius),
+            font_id.clone(),
+            Color32::WHITE,
+        );
+        (radius_in_ui, galley)
+    });
+    let column_widths = entries
+        .iter()
+        .map(|(radius_in_ui, galley)| (2.0 * radius_in_ui).max(galley.size().x))
+        .collect_vec();
+    let circles_height = entries
+        .iter()
+        .map(|(radius_in_ui, _)| 2.0 * radius_in_ui)
+        .fold(0.0, f32::max);
 
-        let Ok(target_config) = setup_target_config(
-            &painter,
-            canvas_from_ui,
-            &query.space_origin.to_string(),
-            state.auto_size_config(),
-            query.highlights.any_outlines(),
-            pinhole,
-        ) else {
-            return Ok(());
-        };
+    let clip_rect = painter.clip_rect();
+    // Leave room for the timeline strip.
+    let bottom = clip_rect.bottom() - TOOLBAR_BUTTON_MARGIN - TIMELINE_STRIP_HEIGHT;
+    let width = column_widths.iter().sum::<f32>() + SPACING * (column_widths.len() + 1) as f32;
+    let height = circles_height + font_id.size + 3.0 * SPACING;
+    let background_rect = Rect::from_min_size(
+        pos2(clip_rect.left() + TOOLBAR_BUTTON_MARGIN, bottom - height),
+        vec2(width, height),
+    );
+    shapes.push(Shape::rect_filled(
+        background_rect,
+        4.0,
+        Color32::from_black_alpha(196),
+    ));
 
-        let mut view_builder = ViewBuilder::new(ctx.render_ctx, target_config);
+    let circles_bottom = background_rect.top() + SPACING + circles_height;
+    let mut left = background_rect.left() + SPACING;
+    for ((radius_in_ui, galley), column_width) in entries.into_iter().zip(column_widths) {
+        let center_x = left + 0.5 * column_width;
+        shapes.push(Shape::circle_stroke(
+            pos2(center_x, circles_bottom - radius_in_ui),
+            radius_in_ui,
+            (1.5, Color32::WHITE),
+        ));
+        let text_rect = Align2::CENTER_TOP.anchor_rect(Rect::from_min_size(
+            pos2(center_x, circles_bottom + SPACING),
+            galley.size(),
+        ));
+        shapes.push(Shape::galley(text_rect.min, galley, Color32::WHITE));
+        left += column_width + SPACING;
+    }
+}
 
-        // Create labels now since their shapes participate are added to scene.ui for picking.
-        let (label_shapes, ui_rects) = create_labels(
-            collect_ui_labels(&parts),
-            ui_from_canvas,
-            &eye,
-            ui,
-            &query.highlights,
-            SpatialSpaceViewKind::TwoD,
+/// Shows the visible region of the space, in its own coordinates, below the toolbar.
+fn visible_region_hud_ui(ui: &egui::Ui, view_rect: Rect, region: Rect) {
+    let painter = ui.painter_at(view_rect);
+    let galley = painter.layout_no_wrap(
+        format!(
+            "x: {:.1} – {:.1}\ny: {:.1} – {:.1}",
+            region.min.x, region.max.x, region.min.y, region.max.y
+        ),
+        egui::TextStyle::Small.resolve(ui.style()),
+        Color32::WHITE,
+    );
+    let text_rect = Align2::RIGHT_TOP.anchor_rect(Rect::from_min_size(
+        view_rect.right_top()
+            + vec2(
+                -TOOLBAR_BUTTON_MARGIN - 2.0,
+                TOOLBAR_BUTTON_SIZE.y + 2.0 * TOOLBAR_BUTTON_MARGIN + 2.0,
+            ),
+        galley.size(),
+    ));
+    painter.rect_filled(text_rect.expand(2.0), 2.0, Color32::from_black_alpha(196));
+    painter.galley(text_rect.min, galley, Color32::WHITE);
+}
+
+/// Shows how much the view draws in its top left corner, for debugging slow views.
+fn data_density_overlay_ui(ui: &egui::Ui, view_rect: Rect, stats: &SceneStats) {
+    let painter = ui.painter_at(view_rect);
+    let text = stats
+        .counts()
+        .into_iter()
+        .map(|(label, count)| format!("{label}: {}", re_format::format_number(count)))
+        .join("\n");
+    let galley = painter.layout_no_wrap(
+        text,
+        egui::TextStyle::Monospace.resolve(ui.style()),
+        Color32::WHITE,
+    );
+    let text_pos = view_rect.left_top()
+        + vec2(
+            TOOLBAR_BUTTON_MARGIN + 2.0,
+            TOOLBAR_BUTTON_SIZE.y + 2.0 * TOOLBAR_BUTTON_MARGIN + 2.0,
         );
+    let text_rect = Rect::from_min_size(text_pos, galley.size());
+    painter.rect_filled(text_rect.expand(2.0), 2.0, Color32::from_black_alpha(196));
+    painter.galley(text_rect.min, galley, Color32::WHITE);
+}
 
-        if !re_ui::egui_helpers::is_anything_being_dragged(ui.ctx()) {
-            response = picking(
-                ctx,
-                response,
-                canvas_from_ui,
-                painter.clip_rect(),
-                ui,
-                eye,
-                &mut view_builder,
-                state,
-                &view_ctx,
-                &parts,
-                &ui_rects,
-                query,
-                SpatialSpaceViewKind::TwoD,
-            )?;
-        }
+/// Reports what failed to draw at the top of the view, so that missing content isn't mistaken for missing data.
+fn draw_errors_ui(
+    painter: &egui::Painter,
+    view_rect: Rect,
+    style: &egui::Style,
+    errors: &[String],
+) {
+    let galley = painter.layout(
+        errors.join("\n"),
+        egui::TextStyle::Small.resolve(style),
+        style.visuals.error_fg_color,
+        view_rect.width() - 4.0 * TOOLBAR_BUTTON_MARGIN,
+    );
+    let text_rect = Align2::CENTER_TOP.anchor_rect(Rect::from_min_size(
+        view_rect.center_top() + vec2(0.0, TOOLBAR_BUTTON_SIZE.y + 2.0 * TOOLBAR_BUTTON_MARGIN),
+        galley.size(),
+    ));
+    painter.rect_filled(text_rect.expand(2.0), 2.0, Color32::from_black_alpha(196));
+    painter.galley(text_rect.min, galley, Color32::WHITE);
+}
 
-        for draw_data in draw_data {
-            view_builder.queue_draw(draw_data);
-        }
-        if let Ok(shared_render_builders) = view_ctx.get::<SharedRenderBuilders>() {
-            for draw_data in shared_render_builders.queuable_draw_data(ctx.render_ctx) {
-                view_builder.queue_draw(draw_data);
-            }
-        }
+/// The "nice" length (1, 2 or 5 times a power of ten) closest to `target`
+/// along with the number of decimals needed to print it.
+fn nice_length(target: f32) -> (f32, usize) {
+    let exponent = target.log10().floor() as i32;
+    let power = 10f32.powi(exponent);
+    let mantissa = [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .min_by(|a: &f32, b: &f32| {
+            let error = |m: f32| (m * power / target).ln().abs();
+            error(*a).total_cmp(&error(*b))
+        })
+        .unwrap_or(1.0);
+    let exponent = if mantissa == 10.0 {
+        exponent + 1
+    } else {
+        exponent
+    };
+    let decimals = (-exponent).max(0) as usize;
+    (mantissa * power, decimals)
+}
 
-        // ------------------------------------------------------------------------
+/// Selects all interactive, unlocked instances whose bounding box intersects the given rectangle in scene coordinates.
+///
+/// If `toggle` is set, the instances are toggled in and out of the current selection instead.
+fn select_instances_in_rect(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    view_ctx: &ViewContextCollection,
+    state: &View2DState,
+    bounding_boxes: &SceneBoundingBoxes,
+    rect: Rect,
+    toggle: bool,
+) -> Result<(), SpaceViewSystemExecutionError> {
+    re_tracing::profile_function!();
 
-        // Screenshot context menu.
-        if let Some(mode) = screenshot_context_menu(ctx, &response) {
-            view_builder
-                .schedule_screenshot(ctx.render_ctx, query.space_view_id.gpu_readback_id(), mode)
-                .ok();
-        }
+    let non_interactive = view_ctx.get::<NonInteractiveEntities>()?;
 
-        // Draw a re_renderer driven view.
-        // Camera & projection are configured to ingest space coordinates directly.
-        painter.add(gpu_bridge::new_renderer_callback(
-            view_builder,
-            painter.clip_rect(),
-            ui.visuals().extreme_bg_color.into(),
-        ));
+    let mut seen = ahash::HashSet::default();
+    let items = bounding_boxes
+        .per_instance
+        .iter()
+        .filter(|(instance, bbox)| {
+            let instance_rect =
+                Rect::from_min_max(pos2(bbox.min.x, bbox.min.y), pos2(bbox.max.x, bbox.max.y));
+            instance_rect.intersects(rect)
+                && !non_interactive.0.contains(&instance.entity_path_hash)
+                && !state.is_instance_locked(instance)
+                && seen.insert(*instance)
+        })
+        .filter_map(|(instance, _)| instance.resolve(ctx.entity_db))
+        .map(|instance_path| {
+            (
+                Item::InstancePath(Some(query.space_view_id), instance_path),
+                None,
+            )
+        })
+        .collect();
 
-        // Make sure to _first_ draw the selected, and *then* the hovered context on top!
-        for selected_context in ctx.selection_state().selected_space_context() {
-            painter.extend(show_projections_from_3d_space(
-                ui,
-                query.space_origin,
-                &ui_from_canvas,
-                selected_context,
-                ui.style().visuals.selection.bg_fill,
-            ));
-        }
-        if let Some(hovered_context) = ctx.selection_state().hovered_space_context() {
-            painter.extend(show_projections_from_3d_space(
-                ui,
-                query.space_origin,
-                &ui_from_canvas,
-                hovered_context,
-                egui::Color32::WHITE,
-            ));
-        }
+    let selection = Selection(items);
+    if toggle {
+        ctx.selection_state().toggle_selection(selection);
+    } else {
+        ctx.selection_state().set_selection(selection);
+    }
 
-        // Add egui driven labels on top of re_renderer content.
-        painter.extend(label_shapes);
+    Ok(())
+}
 
-        Ok(())
-    });
-    scroll_out.inner?;
+/// The instance after (or before, if not `forward`) the single selected instance, wrapping around at the ends.
+///
+/// Only interactive, unlocked instances are stepped through, ordered by entity path and instance key
+/// so that the order doesn't change from frame to frame.
+/// If not exactly one of them is selected, this starts at the first (or last) one.
+fn next_instance(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    view_ctx: &ViewContextCollection,
+    state: &View2DState,
+    bounding_boxes: &SceneBoundingBoxes,
+    forward: bool,
+) -> Result<Option<InstancePath>, SpaceViewSystemExecutionError> {
+    let non_interactive = view_ctx.get::<NonInteractiveEntities>()?;
 
-    // Update the scroll area based on the computed offset
-    // This handles cases of dragging/zooming the space
-    state
-        .state_2d
-        .capture_scroll(scroll_out.state.offset, available_size, scene_rect_accum);
-    Ok(())
+    // Images and other instances that aren't individually selectable are added as splats.
+    let instances = bounding_boxes
+        .per_instance
+        .iter()
+        .filter(|(instance, _)| {
+            !instance.instance_key.is_splat()
+                && !non_interactive.0.contains(&instance.entity_path_hash)
+                && !state.is_instance_locked(instance)
+        })
+        .filter_map(|(instance, _)| instance.resolve(ctx.entity_db))
+        .sorted()
+        .dedup()
+        .collect_vec();
+    if instances.is_empty() {
+        return Ok(None);
+    }
+
+    let current = match selected_instances(ctx, query).as_slice() {
+        [selected] => instances.iter().position(|instance| instance == selected),
+        _ => None,
+    };
+    let num_instances = instances.len();
+    let index = match (current, forward) {
+        (Some(current), true) => (current + 1) % num_instances,
+        (Some(current), false) => (current + num_instances - 1) % num_instances,
+        (None, true) => 0,
+        (None, false) => num_instances - 1,
+    };
+
+    Ok(instances.into_iter().nth(index))
 }
 
 fn setup_target_config(
@@ -409,10 +3925,10 @@ fn setup_target_config(
     canvas_from_ui: RectTransform,
     space_name: &str,
     auto_size_config: re_renderer::AutoSizeConfig,
-    any_outlines: bool,
+    outline_config: Option<re_renderer::OutlineConfig>,
     pinhole: Option<Pinhole>,
+    pixels_from_points: f32,
 ) -> anyhow::Result<TargetConfiguration> {
-    let pixels_from_points = egui_painter.ctx().pixels_per_point();
     let resolution_in_pixel =
         gpu_bridge::viewport_resolution_in_pixels(egui_painter.clip_rect(), pixels_from_points);
     anyhow::ensure!(resolution_in_pixel[0] > 0 && resolution_in_pixel[1] > 0);
@@ -495,7 +4011,7 @@ fn setup_target_config(
             viewport_transformation,
             pixels_from_point: pixels_from_points,
             auto_size_config,
-            outline_config: any_outlines.then(|| outline_config(egui_painter.ctx())),
+            outline_config,
         }
     })
 }
@@ -509,44 +4025,116 @@ fn re_render_rect_from_egui_rect(rect: egui::Rect) -> re_renderer::RectF32 {
 
 // ------------------------------------------------------------------------
 
+#[allow(clippy::too_many_arguments)]
 fn show_projections_from_3d_space(
     ui: &egui::Ui,
     space: &EntityPath,
     ui_from_canvas: &RectTransform,
+    ui_rect: Rect,
     space_context: &SelectedSpaceContext,
     color: egui::Color32,
+    depth_unit: DepthUnit,
+    state: &View2DState,
 ) -> Vec<Shape> {
+    let marker = &state.projected_point_marker;
+
+    // Projections are in the space's own pixel coordinates, which may be flipped or rotated in the view.
+    let ui_from_space = |pos: glam::Vec2| {
+        ui_from_canvas.transform_pos(state.orientation.view_from_space_pos(pos2(pos.x, pos.y)))
+    };
+
     let mut shapes = Vec::new();
     if let SelectedSpaceContext::ThreeD {
         point_in_space_cameras: target_spaces,
+        epipolar_lines_in_space_cameras,
+        ray_in_space_cameras,
         ..
     } = space_context
     {
+        if state.show_3d_projection_rays {
+            for (space_2d, ray) in ray_in_space_cameras {
+                if space_2d != space {
+                    continue;
+                }
+                // User is hovering a ray inside a 3D view.
+                let ray = match state.projection_ray_depth {
+                    Some(max_depth) => ray.up_to_depth(max_depth),
+                    None => Some(*ray),
+                };
+                if let Some(ray) = ray {
+                    let (start, end) = (ui_from_space(ray.start), ui_from_space(ray.end));
+                    shapes.extend(dashed_ray_shapes(
+                        ui,
+                        start,
+                        end,
+                        ui_rect,
+                        color,
+                        &space_2d.to_string(),
+                    ));
+                }
+            }
+        }
+
+        for (space_2d, [a, b]) in epipolar_lines_in_space_cameras {
+            if space_2d == space {
+                // User is hovering a ray through another camera inside a 3D view.
+                let (a, b) = (ui_from_space(*a), ui_from_space(*b));
+                if let Some(segment) = clip_line_to_rect(a, b, ui_rect) {
+                    shapes.push(Shape::line_segment(segment, (3.0, Color32::BLACK)));
+                    shapes.push(Shape::line_segment(segment, (1.5, color)));
+                }
+            }
+        }
+
         for (space_2d, pos_2d) in target_spaces {
             if space_2d == space {
                 if let Some(pos_2d) = pos_2d {
                     // User is hovering a 2D point inside a 3D view.
-                    let pos_in_ui = ui_from_canvas.transform_pos(pos2(pos_2d.x, pos_2d.y));
-                    let radius = 4.0;
-                    shapes.push(Shape::circle_filled(
-                        pos_in_ui,
-                        radius + 2.0,
-                        Color32::BLACK,
-                    ));
-                    shapes.push(Shape::circle_filled(pos_in_ui, radius, color));
+                    let pos_in_ui = ui_from_space(pos_2d.truncate());
+                    let radius = marker.radius;
+                    let marker_color = marker.color.unwrap_or(color);
+                    match marker.shape {
+                        ProjectedPointShape::Dot => {
+                            shapes.push(Shape::circle_filled(
+                                pos_in_ui,
+                                radius + 2.0,
+                                marker.outline_color,
+                            ));
+                            shapes.push(Shape::circle_filled(pos_in_ui, radius, marker_color));
+                        }
+                        ProjectedPointShape::Crosshair => {
+                            for dir in [vec2(1.0, 0.0), vec2(0.0, 1.0)] {
+                                for sign in [-1.0, 1.0] {
+                                    let arm = [
+                                        pos_in_ui + sign * 0.5 * radius * dir,
+                                        pos_in_ui + sign * 2.0 * radius * dir,
+                                    ];
+                                    shapes.push(Shape::line_segment(
+                                        arm,
+                                        (4.0, marker.outline_color),
+                                    ));
+                                    shapes.push(Shape::line_segment(arm, (2.0, marker_color)));
+                                }
+                            }
+                        }
+                    }
 
                     let text_color = Color32::WHITE;
-                    let text = format!("Depth: {:.3} m", pos_2d.z);
+                    let text = format!("Depth: {}", depth_unit.format(pos_2d.z as f64));
                     let font_id = egui::TextStyle::Body.resolve(ui.style());
                     let galley = ui.fonts(|fonts| fonts.layout_no_wrap(text, font_id, text_color));
+                    let label_offset = match marker.shape {
+                        ProjectedPointShape::Dot => radius + 1.0,
+                        ProjectedPointShape::Crosshair => 2.0 * radius + 1.0,
+                    };
                     let rect = Align2::CENTER_TOP.anchor_rect(Rect::from_min_size(
-                        pos_in_ui + vec2(0.0, 5.0),
+                        pos_in_ui + vec2(0.0, label_offset),
                         galley.size(),
                     ));
                     shapes.push(Shape::rect_filled(
                         rect,
                         2.0,
-                        Color32::from_black_alpha(196),
+                        marker.outline_color.gamma_multiply(196.0 / 255.0),
                     ));
                     shapes.push(Shape::galley(rect.min, galley, text_color));
                 }
@@ -555,3 +4143,194 @@ fn show_projections_from_3d_space(
     }
     shapes
 }
+
+/// Dashes for the ray from `start` towards `end`, clipped to `rect`, labeled with the name of the camera.
+///
+/// The ray fades out towards `end` so that its direction is apparent.
+fn dashed_ray_shapes(
+    ui: &egui::Ui,
+    start: Pos2,
+    end: Pos2,
+    rect: Rect,
+    color: Color32,
+    camera_name: &str,
+) -> Vec<Shape> {
+    const DASH_LENGTH: f32 = 6.0;
+    const GAP_LENGTH: f32 = 4.0;
+
+    let Some((t_min, t_max)) = clip_parameter_range(start, end, rect, 0.0, 1.0) else {
+        return Vec::new();
+    };
+    let (from, to) = (start + t_min * (end - start), start + t_max * (end - start));
+    let length = from.distance(to);
+    if length <= 0.0 {
+        return Vec::new();
+    }
+    let dir = (to - from) / length;
+
+    // The fade is relative to the visible part of the ray, otherwise a ray heading towards
+    // a far away vanishing point would look uniformly opaque.
+    let mut shapes = Vec::new();
+    let mut dash_start = 0.0;
+    while dash_start < length {
+        let dash = [
+            from + dash_start * dir,
+            from + (dash_start + DASH_LENGTH).min(length) * dir,
+        ];
+        let opacity = 1.0 - dash_start / length;
+        shapes.push(Shape::line_segment(
+            dash,
+            (3.0, Color32::BLACK.gamma_multiply(0.5 * opacity)),
+        ));
+        shapes.push(Shape::line_segment(
+            dash,
+            (1.5, color.gamma_multiply(opacity)),
+        ));
+        dash_start += DASH_LENGTH + GAP_LENGTH;
+    }
+
+    let text_color = Color32::WHITE;
+    let font_id = egui::TextStyle::Small.resolve(ui.style());
+    let galley =
+        ui.fonts(|fonts| fonts.layout_no_wrap(camera_name.to_owned(), font_id, text_color));
+    let label_rect = Align2::LEFT_BOTTOM
+        .anchor_rect(Rect::from_min_size(from + vec2(4.0, -4.0), galley.size()))
+        .expand(1.0);
+    shapes.push(Shape::rect_filled(
+        label_rect,
+        2.0,
+        Color32::BLACK.gamma_multiply(0.75),
+    ));
+    shapes.push(Shape::galley(
+        label_rect.min + vec2(1.0, 1.0),
+        galley,
+        text_color,
+    ));
+
+    shapes
+}
+
+/// Clips the infinite line through `a` and `b` to `rect`.
+///
+/// Returns `None` if the line misses the rectangle or `a` and `b` coincide.
+fn clip_line_to_rect(a: Pos2, b: Pos2, rect: Rect) -> Option<[Pos2; 2]> {
+    let (t_min, t_max) = clip_parameter_range(a, b, rect, f32::NEG_INFINITY, f32::INFINITY)?;
+    Some([a + t_min * (b - a), a + t_max * (b - a)])
+}
+
+/// Narrows down the parameter range `t_min..=t_max` of `a + t * (b - a)` to the part within `rect`.
+///
+/// Returns `None` if no part of the range lies within the rectangle or `a` and `b` coincide.
+fn clip_parameter_range(
+    a: Pos2,
+    b: Pos2,
+    rect: Rect,
+    mut t_min: f32,
+    mut t_max: f32,
+) -> Option<(f32, f32)> {
+    let dir = b - a;
+    if dir == Vec2::ZERO {
+        return None;
+    }
+
+    // Liang-Barsky: narrow down the parameter range of `a + t * dir` that lies within the rect.
+    for (start, dir, min, max) in [
+        (a.x, dir.x, rect.min.x, rect.max.x),
+        (a.y, dir.y, rect.min.y, rect.max.y),
+    ] {
+        if dir == 0.0 {
+            if start < min || max < start {
+                return None;
+            }
+        } else {
+            let (t0, t1) = ((min - start) / dir, (max - start) / dir);
+            t_min = t_min.max(t0.min(t1));
+            t_max = t_max.min(t0.max(t1));
+        }
+    }
+
+    (t_min <= t_max).then_some((t_min, t_max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggressive_drag_keeps_scene_in_view() {
+        let scene_rect = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 50.0));
+        let visible_size = vec2(40.0, 40.0);
+
+        // Dragged far to the bottom right, the top left corner of the scene remains in view.
+        let center = constrain_center(pos2(1.0e6, 1.0e6), scene_rect, visible_size, 0.1);
+        let visible = Rect::from_center_size(center, visible_size);
+        let overlap = visible.intersect(scene_rect);
+        assert!(overlap.is_positive());
+        assert_eq!(overlap.size(), vec2(10.0, 5.0));
+
+        // The view can show no more than its own size.
+        let center = constrain_center(pos2(-1.0e6, 25.0), scene_rect, visible_size, 1.0);
+        assert_eq!(center, pos2(20.0, 25.0));
+
+        // Without a fraction, the scene may just touch the edge of the view.
+        let center = constrain_center(pos2(-1.0e6, 25.0), scene_rect, visible_size, 0.0);
+        assert_eq!(center, pos2(-20.0, 25.0));
+    }
+
+    #[test]
+    fn idle_scaled_frame_keeps_center() {
+        // A fit target away from the origin of the scene.
+        let canvas_rect = Rect::from_min_max(pos2(100.0, 100.0), pos2(200.0, 150.0));
+        let available_size = vec2(40.0, 40.0);
+        let center = pos2(150.0, 125.0);
+
+        let mut state = View2DState {
+            zoom: ZoomState2D::Scaled {
+                scale: 2.0,
+                scale_y: None,
+                center,
+                accepting_scroll: true,
+            },
+            ..Default::default()
+        };
+
+        // Without any interaction, the scroll area reports back the offset it was given.
+        let (_, offset) = state.desired_size_and_offset(available_size, canvas_rect, 1.0);
+        state.capture_scroll(offset, available_size, canvas_rect, 1.0);
+
+        let ZoomState2D::Scaled {
+            center: new_center, ..
+        } = state.zoom
+        else {
+            panic!("the view should remain scaled");
+        };
+        assert_eq!(new_center, center);
+    }
+
+    fn multi_touch(num_touches: usize, translation_delta: Vec2) -> egui::MultiTouchInfo {
+        egui::MultiTouchInfo {
+            start_time: 0.0,
+            start_pos: egui::Pos2::ZERO,
+            num_touches,
+            zoom_delta: 1.0,
+            zoom_delta_2d: Vec2::splat(1.0),
+            rotation_delta: 0.0,
+            translation_delta,
+            force: 0.0,
+        }
+    }
+
+    #[test]
+    fn two_finger_drag_pans() {
+        assert_eq!(
+            touch_pan_delta(Some(&multi_touch(2, vec2(3.0, -4.0)))),
+            Some(vec2(3.0, -4.0))
+        );
+        assert_eq!(touch_pan_delta(Some(&multi_touch(2, Vec2::ZERO))), None);
+        assert_eq!(
+            touch_pan_delta(Some(&multi_touch(1, vec2(3.0, -4.0)))),
+            None
+        );
+        assert_eq!(touch_pan_delta(None), None);
+    }
+}
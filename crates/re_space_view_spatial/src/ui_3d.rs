@@ -23,6 +23,7 @@ use crate::{
     scene_bounding_boxes::SceneBoundingBoxes,
     space_camera_3d::SpaceCamera3D,
     ui::{create_labels, outline_config, picking, screenshot_context_menu, SpatialSpaceViewState},
+    ui_2d::LabelAppearance,
     view_kind::SpatialSpaceViewKind,
     visualizers::{
         collect_ui_labels, image_view_coordinates, CamerasVisualizer,
@@ -490,19 +491,21 @@ pub fn view_3d(
         outline_config: query
             .highlights
             .any_outlines()
-            .then(|| outline_config(ui.ctx())),
+            .then(|| outline_config(ui.ctx(), None, 1.0)),
     };
 
     let mut view_builder = ViewBuilder::new(ctx.render_ctx, target_config);
 
     // Create labels now since their shapes participate are added to scene.ui for picking.
     let (label_shapes, ui_rects) = create_labels(
+        ctx,
         collect_ui_labels(&parts),
         RectTransform::from_to(rect, rect),
         &eye,
         ui,
         highlights,
         SpatialSpaceViewKind::ThreeD,
+        LabelAppearance::default(),
     );
 
     if !re_ui::egui_helpers::is_anything_being_dragged(ui.ctx()) {
@@ -697,7 +700,9 @@ pub fn view_3d(
         view_builder.queue_draw(draw_data);
     }
     if let Ok(shared_render_builders) = view_ctx.get::<SharedRenderBuilders>() {
-        for draw_data in shared_render_builders.queuable_draw_data(ctx.render_ctx) {
+        // Failures are logged while building the draw data.
+        let (shared_draw_data, _errors) = shared_render_builders.queuable_draw_data(ctx.render_ctx);
+        for draw_data in shared_draw_data {
             view_builder.queue_draw(draw_data);
         }
     }
@@ -737,7 +742,7 @@ fn show_projections_from_2d_space(
     color: egui::Color32,
 ) {
     match space_context {
-        SelectedSpaceContext::TwoD { space_2d, pos } => {
+        SelectedSpaceContext::TwoD { space_2d, pos, .. } => {
             if let Some(cam) = space_cameras.iter().find(|cam| &cam.ent_path == space_2d) {
                 if let Some(pinhole) = cam.pinhole.as_ref() {
                     // Render a thick line to the actual z value if any and a weaker one as an extension
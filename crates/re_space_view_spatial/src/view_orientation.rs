@@ -0,0 +1,153 @@
+//! Flipping and rotating the entire content of a 2D view.
+//!
+//! The orientation is applied on top of the transforms of all entities (see
+//! [`crate::contexts::TransformContext`]), so everything drawn, picked or hovered in the view
+//! lives in the oriented "view" coordinates. Positions that are shared with other views,
+//! e.g. the hovered position, need to be mapped back to the space's own coordinates.
+
+use re_viewer_context::SpaceViewId;
+
+/// Flips and quarter turns applied to the whole content of a 2D view, e.g. to show camera
+/// frames that were logged upside-down or mirrored.
+///
+/// The flips are applied first, then the rotation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ViewOrientation {
+    /// Mirror the space's x axis.
+    pub flip_x: bool,
+
+    /// Mirror the space's y axis.
+    pub flip_y: bool,
+
+    /// Number of clockwise quarter turns, in `0..4`.
+    pub quarter_turns: u8,
+}
+
+impl ViewOrientation {
+    fn view_orientation_id(space_view_id: SpaceViewId) -> egui::Id {
+        egui::Id::new(space_view_id).with("view_orientation")
+    }
+
+    /// Remember the orientation of the given 2D view for the transforms of the next frame.
+    pub fn remember(self, egui_ctx: &egui::Context, space_view_id: SpaceViewId) {
+        egui_ctx.data_mut(|data| {
+            data.insert_temp(Self::view_orientation_id(space_view_id), self);
+        });
+    }
+
+    /// The orientation last remembered for the given view, identity if there is none.
+    pub fn recall(egui_ctx: &egui::Context, space_view_id: SpaceViewId) -> Self {
+        egui_ctx
+            .data(|data| data.get_temp::<Self>(Self::view_orientation_id(space_view_id)))
+            .unwrap_or_default()
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Rotate the view content by 90° clockwise, as seen on screen.
+    pub fn rotate_clockwise(&mut self) {
+        self.quarter_turns = (self.quarter_turns + 1) % 4;
+    }
+
+    /// Mirror the view content horizontally, as seen on screen.
+    pub fn flip_horizontally(&mut self) {
+        // After an odd number of quarter turns, the space's y axis is horizontal on screen.
+        if self.quarter_turns % 2 == 0 {
+            self.flip_x = !self.flip_x;
+        } else {
+            self.flip_y = !self.flip_y;
+        }
+    }
+
+    /// Mirror the view content vertically, as seen on screen.
+    pub fn flip_vertically(&mut self) {
+        if self.quarter_turns % 2 == 0 {
+            self.flip_y = !self.flip_y;
+        } else {
+            self.flip_x = !self.flip_x;
+        }
+    }
+
+    /// Transform from the space's own coordinates to the oriented coordinates shown in the view.
+    pub fn view_from_space(&self) -> glam::Affine3A {
+        let flip = glam::vec3(
+            if self.flip_x { -1.0 } else { 1.0 },
+            if self.flip_y { -1.0 } else { 1.0 },
+            1.0,
+        );
+        // The y axis of 2D views points down, so a positive angle turns clockwise on screen.
+        let rotation = glam::Quat::from_rotation_z(
+            (self.quarter_turns % 4) as f32 * std::f32::consts::FRAC_PI_2,
+        );
+        glam::Affine3A::from_quat(rotation) * glam::Affine3A::from_scale(flip)
+    }
+
+    /// Maps a position in the space's own coordinates to view coordinates.
+    pub fn view_from_space_pos(&self, pos: egui::Pos2) -> egui::Pos2 {
+        let pos = self
+            .view_from_space()
+            .transform_point3(glam::vec3(pos.x, pos.y, 0.0));
+        egui::pos2(pos.x, pos.y)
+    }
+
+    /// Maps a position in view coordinates back to the space's own coordinates.
+    pub fn space_from_view_pos(&self, pos: egui::Pos2) -> egui::Pos2 {
+        let pos = self
+            .view_from_space()
+            .inverse()
+            .transform_point3(glam::vec3(pos.x, pos.y, 0.0));
+        egui::pos2(pos.x, pos.y)
+    }
+
+    /// Maps a rectangle in the space's own coordinates to view coordinates.
+    ///
+    /// Flips and quarter turns keep axis aligned rectangles axis aligned.
+    pub fn view_from_space_rect(&self, rect: egui::Rect) -> egui::Rect {
+        egui::Rect::from_two_pos(
+            self.view_from_space_pos(rect.min),
+            self.view_from_space_pos(rect.max),
+        )
+    }
+
+    /// Maps a rectangle in view coordinates back to the space's own coordinates.
+    pub fn space_from_view_rect(&self, rect: egui::Rect) -> egui::Rect {
+        egui::Rect::from_two_pos(
+            self.space_from_view_pos(rect.min),
+            self.space_from_view_pos(rect.max),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ViewOrientation;
+
+    #[test]
+    fn quarter_turn_is_clockwise_on_screen() {
+        let mut orientation = ViewOrientation::default();
+        orientation.rotate_clockwise();
+
+        // With y pointing down, right turns into down.
+        let pos = orientation.view_from_space_pos(egui::pos2(1.0, 0.0));
+        assert!((pos - egui::pos2(0.0, 1.0)).length() < 1e-6);
+        let pos = orientation.space_from_view_pos(pos);
+        assert!((pos - egui::pos2(1.0, 0.0)).length() < 1e-6);
+    }
+
+    #[test]
+    fn flips_follow_the_screen_axes() {
+        let mut orientation = ViewOrientation::default();
+        orientation.rotate_clockwise();
+        orientation.flip_horizontally();
+
+        // What is shown right of the origin would be shown left of it without the flip.
+        let space_pos = orientation.space_from_view_pos(egui::pos2(1.0, 0.0));
+        let mut unflipped = orientation;
+        unflipped.flip_horizontally();
+        let pos = unflipped.view_from_space_pos(space_pos);
+        assert!((pos - egui::pos2(-1.0, 0.0)).length() < 1e-6);
+    }
+}
@@ -1,4 +1,4 @@
-use re_entity_db::{EntityPath, InstancePathHash};
+use re_entity_db::{EntityPath, EntityProperties, InstancePathHash};
 use re_renderer::{renderer::LineStripFlags, PickingLayerInstanceId};
 use re_types::{
     archetypes::Arrows2D,
@@ -11,8 +11,8 @@ use re_viewer_context::{
 };
 
 use super::{
-    process_annotation_and_keypoint_slices, process_color_slice, process_radius_slice,
-    SpatialViewVisualizerData,
+    apply_entity_alpha, process_annotation_and_keypoint_slices, process_color_slice,
+    process_radius_slice, SpatialViewVisualizerData,
 };
 use crate::{
     contexts::{EntityDepthOffsets, SpatialSceneEntityContext},
@@ -20,6 +20,20 @@ use crate::{
     visualizers::{filter_visualizable_2d_entities, UiLabel, UiLabelTarget},
 };
 
+/// Angle between the shaft of an arrow and each of the two lines of a fixed size arrowhead.
+const ARROW_HEAD_HALF_ANGLE: f32 = 25.0 * std::f32::consts::PI / 180.0;
+
+/// The two lines of an arrowhead of the given length at the end of an arrow.
+fn arrow_head_segments(
+    end: glam::Vec2,
+    vector: glam::Vec2,
+    head_length: f32,
+) -> [(glam::Vec2, glam::Vec2); 2] {
+    let back = -vector.normalize_or_zero() * head_length;
+    [ARROW_HEAD_HALF_ANGLE, -ARROW_HEAD_HALF_ANGLE]
+        .map(|angle| (end, end + glam::Vec2::from_angle(angle).rotate(back)))
+}
+
 pub struct Arrows2DVisualizer {
     /// If the number of arrows in the batch is > max_labels, don't render point labels.
     pub max_labels: usize,
@@ -82,6 +96,7 @@ impl Arrows2DVisualizer {
         query: &ViewQuery<'_>,
         data: &Arrows2DComponentData<'_>,
         ent_path: &EntityPath,
+        ent_props: &EntityProperties,
         ent_context: &SpatialSceneEntityContext<'_>,
     ) {
         let (annotation_infos, _) = process_annotation_and_keypoint_slices(
@@ -94,7 +109,8 @@ impl Arrows2DVisualizer {
         );
 
         let radii = process_radius_slice(data.radii, data.vectors.len(), ent_path);
-        let colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        let mut colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        apply_entity_alpha(&mut colors, ent_context.alpha);
         let origins = || {
             data.origins.map_or_else(
                 || itertools::Either::Left(std::iter::repeat(Some(Position2D::ZERO))),
@@ -133,10 +149,17 @@ impl Arrows2DVisualizer {
         let mut line_builder = ent_context.shared_render_builders.lines();
         let mut line_batch = line_builder
             .batch("arrows2d")
+            .depth_offset(ent_context.depth_offset)
             .world_from_obj(ent_context.world_from_entity)
             .outline_mask_ids(ent_context.highlight.overall)
             .picking_object_id(re_renderer::PickingLayerObjectId(ent_path.hash64()));
 
+        // Fixed size heads need to know the zoom level, which isn't known in 3D views.
+        let world_head_length = ent_props
+            .arrow_head_size
+            .zip(ent_context.culling.scene_units_per_point())
+            .map(|(head_size, scene_units_per_point)| head_size * scene_units_per_point);
+
         let mut bounding_box = macaw::BoundingBox::nothing();
 
         for (instance_key, vector, origin, radius, color) in
@@ -146,16 +169,33 @@ impl Arrows2DVisualizer {
             let origin: glam::Vec2 = origin.unwrap_or(Position2D::ZERO).0.into();
             let end = origin + vector;
 
-            let segment = line_batch
-                .add_segment_2d(origin, end)
-                .radius(radius)
-                .color(color)
-                .flags(
+            let segment = if let Some(world_head_length) = world_head_length {
+                // The head length is given in world units, undo the scaling of the entity's transform.
+                let world_units_per_entity_unit = ent_context
+                    .world_from_entity
+                    .transform_vector3(vector.normalize_or_zero().extend(0.0))
+                    .length();
+                let head_length = if world_units_per_entity_unit > 0.0 {
+                    world_head_length / world_units_per_entity_unit
+                } else {
+                    0.0
+                };
+                let [left, right] = arrow_head_segments(end, vector, head_length);
+                line_batch
+                    .add_segments_2d([(origin, end), left, right].into_iter())
+                    .flags(
+                        LineStripFlags::FLAG_CAP_END_ROUND | LineStripFlags::FLAG_CAP_START_ROUND,
+                    )
+            } else {
+                line_batch.add_segment_2d(origin, end).flags(
                     LineStripFlags::FLAG_CAP_END_TRIANGLE
                         | LineStripFlags::FLAG_CAP_START_ROUND
                         | LineStripFlags::FLAG_CAP_START_EXTEND_OUTWARDS,
                 )
-                .picking_instance_id(PickingLayerInstanceId(instance_key.0));
+            }
+            .radius(radius)
+            .color(color)
+            .picking_instance_id(PickingLayerInstanceId(instance_key.0));
 
             if let Some(outline_mask_ids) = ent_context.highlight.instances.get(instance_key) {
                 segment.outline_mask_ids(*outline_mask_ids);
@@ -163,6 +203,11 @@ impl Arrows2DVisualizer {
 
             bounding_box.extend(origin.extend(0.0));
             bounding_box.extend(end.extend(0.0));
+            self.data.add_instance_bounding_box(
+                InstancePathHash::instance(ent_path, *instance_key),
+                macaw::BoundingBox::from_points([origin.extend(0.0), end.extend(0.0)].into_iter()),
+                ent_context.world_from_entity,
+            );
         }
 
         self.data
@@ -227,7 +272,7 @@ impl VisualizerSystem for Arrows2DVisualizer {
             view_ctx.get::<EntityDepthOffsets>()?.points,
             |_ctx,
              ent_path,
-             _ent_props,
+             ent_props,
              ent_context,
              (_time, _row_id),
              instance_keys,
@@ -248,7 +293,7 @@ impl VisualizerSystem for Arrows2DVisualizer {
                     keypoint_ids,
                     class_ids,
                 };
-                self.process_data(query, &data, ent_path, ent_context);
+                self.process_data(query, &data, ent_path, ent_props, ent_context);
                 Ok(())
             },
         )?;
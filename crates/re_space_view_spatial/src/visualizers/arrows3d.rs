@@ -11,8 +11,8 @@ use re_viewer_context::{
 };
 
 use super::{
-    process_annotation_and_keypoint_slices, process_color_slice, process_radius_slice,
-    SpatialViewVisualizerData,
+    apply_entity_alpha, process_annotation_and_keypoint_slices, process_color_slice,
+    process_radius_slice, SpatialViewVisualizerData,
 };
 use crate::{
     contexts::{EntityDepthOffsets, SpatialSceneEntityContext},
@@ -94,7 +94,8 @@ impl Arrows3DVisualizer {
         );
 
         let radii = process_radius_slice(data.radii, data.vectors.len(), ent_path);
-        let colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        let mut colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        apply_entity_alpha(&mut colors, ent_context.alpha);
         let origins = || {
             data.origins.map_or_else(
                 || itertools::Either::Left(std::iter::repeat(Some(Position3D::ZERO))),
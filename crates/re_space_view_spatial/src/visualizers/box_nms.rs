@@ -0,0 +1,110 @@
+//! Previews non-maximum suppression (NMS) of overlapping 2D boxes, as done after object detection.
+//!
+//! Boxes carry no scores, so they are assumed to be logged in order of descending score,
+//! which is how most detectors output them.
+
+/// What non-maximum suppression would do with a single box.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NmsOutcome {
+    /// The box doesn't overlap any other box enough to be part of a cluster.
+    Alone,
+
+    /// The box is kept and suppresses at least one other box.
+    Kept,
+
+    /// The box is suppressed by an earlier box of its cluster.
+    Suppressed,
+}
+
+/// Intersection over union of two axis aligned boxes.
+pub fn intersection_over_union(a: &macaw::BoundingBox, b: &macaw::BoundingBox) -> f32 {
+    let intersection = a.intersection(*b);
+    if intersection.is_nothing() {
+        return 0.0;
+    }
+    let area = |bbox: &macaw::BoundingBox| {
+        let size = bbox.size();
+        size.x * size.y
+    };
+    let intersection_area = area(&intersection);
+    let union_area = area(a) + area(b) - intersection_area;
+    if union_area > 0.0 {
+        intersection_area / union_area
+    } else {
+        0.0
+    }
+}
+
+/// Greedy non-maximum suppression of `boxes`, which are expected to be ordered by descending score.
+///
+/// Every box that isn't suppressed yet suppresses all later boxes whose IoU with it is above `iou_threshold`.
+pub fn non_maximum_suppression(
+    boxes: &[macaw::BoundingBox],
+    iou_threshold: f32,
+) -> Vec<NmsOutcome> {
+    re_tracing::profile_function!();
+
+    let mut outcomes = vec![NmsOutcome::Alone; boxes.len()];
+    for (index, bbox) in boxes.iter().enumerate() {
+        if outcomes[index] == NmsOutcome::Suppressed {
+            continue;
+        }
+        for other_index in index + 1..boxes.len() {
+            if outcomes[other_index] != NmsOutcome::Suppressed
+                && intersection_over_union(bbox, &boxes[other_index]) > iou_threshold
+            {
+                outcomes[other_index] = NmsOutcome::Suppressed;
+                outcomes[index] = NmsOutcome::Kept;
+            }
+        }
+    }
+    outcomes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(min: f32, size: f32) -> macaw::BoundingBox {
+        macaw::BoundingBox::from_min_max(
+            glam::vec3(min, min, 0.0),
+            glam::vec3(min + size, min + size, 0.0),
+        )
+    }
+
+    #[test]
+    fn iou() {
+        assert_eq!(
+            intersection_over_union(&square(0.0, 2.0), &square(0.0, 2.0)),
+            1.0
+        );
+        assert_eq!(
+            intersection_over_union(&square(0.0, 1.0), &square(2.0, 1.0)),
+            0.0
+        );
+        // Overlap of 1x1, union of 4 + 4 - 1.
+        assert_eq!(
+            intersection_over_union(&square(0.0, 2.0), &square(1.0, 2.0)),
+            1.0 / 7.0
+        );
+    }
+
+    #[test]
+    fn nms() {
+        let boxes = [
+            square(0.0, 2.0),
+            square(0.1, 2.0),
+            square(10.0, 1.0),
+            square(0.2, 2.0),
+        ];
+        assert_eq!(
+            non_maximum_suppression(&boxes, 0.5),
+            vec![
+                NmsOutcome::Kept,
+                NmsOutcome::Suppressed,
+                NmsOutcome::Alone,
+                NmsOutcome::Suppressed,
+            ]
+        );
+    }
+}
@@ -1,7 +1,11 @@
-use re_entity_db::{EntityPath, InstancePathHash};
+use itertools::Itertools as _;
+
+use re_entity_db::{AutoLabelMode, BoxAutoLabel, EntityPath, InstancePathHash};
 use re_types::{
     archetypes::Boxes2D,
-    components::{ClassId, Color, HalfSizes2D, InstanceKey, KeypointId, Position2D, Radius, Text},
+    components::{
+        ClassId, Color, HalfSizes2D, InstanceKey, KeypointId, Position2D, Radius, Rotation2D, Text,
+    },
 };
 use re_viewer_context::{
     ApplicableEntities, IdentifiedViewSystem, ResolvedAnnotationInfos,
@@ -16,15 +20,84 @@ use crate::{
 };
 
 use super::{
+    apply_entity_alpha,
+    box_nms::{non_maximum_suppression, NmsOutcome},
     filter_visualizable_2d_entities, picking_id_from_instance_key,
     process_annotation_and_keypoint_slices, process_color_slice, process_radius_slice,
     SpatialViewVisualizerData,
 };
 
+/// Color of boxes that are kept by the NMS preview and suppress others.
+const NMS_KEPT_COLOR: egui::Color32 = egui::Color32::from_rgb(0, 220, 0);
+
+/// Color of boxes that are suppressed in the NMS preview.
+const NMS_SUPPRESSED_COLOR: egui::Color32 = egui::Color32::from_rgba_premultiplied(110, 0, 0, 110);
+
 pub struct Boxes2DVisualizer {
     /// If the number of points in the batch is > max_labels, don't render box labels.
     pub max_labels: usize,
     pub data: SpatialViewVisualizerData,
+
+    /// Filled interiors of boxes whose entity has a fill color.
+    fills: Vec<BoxFill>,
+}
+
+/// Filled interior of a single box, in world space.
+struct BoxFill {
+    top_left: glam::Vec3,
+    extent_u: glam::Vec3,
+    extent_v: glam::Vec3,
+    color: egui::Color32,
+    depth_offset: re_renderer::DepthOffset,
+}
+
+/// Corner and edges of a single, possibly rotated, box in entity space.
+///
+/// Outlines, fills, labels and bounding boxes are all derived from this,
+/// so rotated and axis aligned boxes go through the same code paths.
+#[derive(Clone, Copy)]
+pub struct BoxGeometry {
+    /// The corner both edges start from.
+    ///
+    /// For unrotated boxes this is the minimum, i.e. the top-left corner in image space.
+    pub corner: glam::Vec2,
+    pub extent_u: glam::Vec2,
+    pub extent_v: glam::Vec2,
+}
+
+impl BoxGeometry {
+    pub fn new(center: Position2D, half_size: HalfSizes2D, rotation: Option<Rotation2D>) -> Self {
+        let mut extent_u = glam::vec2(half_size.width(), 0.0);
+        let mut extent_v = glam::vec2(0.0, half_size.height());
+        if let Some(Rotation2D(radians)) = rotation {
+            let rotation = glam::Vec2::from_angle(radians);
+            extent_u = rotation.rotate(extent_u);
+            extent_v = rotation.rotate(extent_v);
+        }
+
+        Self {
+            corner: glam::Vec2::from(center) - 0.5 * (extent_u + extent_v),
+            extent_u,
+            extent_v,
+        }
+    }
+
+    fn corners(&self) -> [glam::Vec2; 4] {
+        [
+            self.corner,
+            self.corner + self.extent_u,
+            self.corner + self.extent_u + self.extent_v,
+            self.corner + self.extent_v,
+        ]
+    }
+
+    fn bounding_box(&self) -> macaw::BoundingBox {
+        let mut bounding_box = macaw::BoundingBox::nothing();
+        for corner in self.corners() {
+            bounding_box.extend(corner.extend(0.0));
+        }
+        bounding_box
+    }
 }
 
 impl Default for Boxes2DVisualizer {
@@ -32,32 +105,44 @@ impl Default for Boxes2DVisualizer {
         Self {
             max_labels: 20,
             data: SpatialViewVisualizerData::new(Some(SpatialSpaceViewKind::TwoD)),
+            fills: Vec::new(),
         }
     }
 }
 
 impl Boxes2DVisualizer {
     fn process_labels<'a>(
-        labels: &'a [Option<Text>],
-        half_sizes: &'a [HalfSizes2D],
-        centers: impl Iterator<Item = Position2D> + 'a,
+        labels: Option<&'a [Option<Text>]>,
+        auto_label: &'a BoxAutoLabel,
+        geometries: &'a [BoxGeometry],
         instance_path_hashes: &'a [InstancePathHash],
         colors: &'a [egui::Color32],
         annotation_infos: &'a ResolvedAnnotationInfos,
+        is_hidden: impl Fn(usize) -> bool + 'a,
     ) -> impl Iterator<Item = UiLabel> + 'a {
+        let labels = labels.map_or(
+            itertools::Either::Left(std::iter::repeat(&None).take(geometries.len())),
+            |labels| itertools::Either::Right(labels.iter()),
+        );
         itertools::izip!(
             annotation_infos.iter(),
-            half_sizes,
-            centers,
+            geometries,
             labels,
             colors,
             instance_path_hashes,
         )
+        .enumerate()
+        .filter(move |(index, _)| !is_hidden(*index))
         .filter_map(
-            move |(annotation_info, half_size, center, label, color, labeled_instance)| {
-                let label = annotation_info.label(label.as_ref().map(|l| l.as_str()));
-                let min = half_size.box_min(center);
-                let max = half_size.box_max(center);
+            move |(_, (annotation_info, geometry, label, color, labeled_instance))| {
+                // Boxes without a logged or annotated label get a generated one, if enabled.
+                let label = annotation_info
+                    .label(label.as_ref().map(|l| l.as_str()))
+                    .or_else(|| {
+                        auto_label.label(geometry.extent_u.length(), geometry.extent_v.length())
+                    });
+                let bounding_box = geometry.bounding_box();
+                let (min, max) = (bounding_box.min, bounding_box.max);
                 label.map(|label| UiLabel {
                     text: label,
                     color: *color,
@@ -71,12 +156,16 @@ impl Boxes2DVisualizer {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn process_data(
         &mut self,
         query: &ViewQuery<'_>,
         data: &Boxes2DComponentData<'_>,
         ent_path: &EntityPath,
         ent_context: &SpatialSceneEntityContext<'_>,
+        fill_color: Option<egui::Color32>,
+        auto_label: &BoxAutoLabel,
+        nms_iou_threshold: Option<f32>,
     ) {
         let (annotation_infos, _) = process_annotation_and_keypoint_slices(
             query.latest_at,
@@ -87,18 +176,42 @@ impl Boxes2DVisualizer {
             &ent_context.annotations,
         );
 
-        let centers = || {
-            data.centers
-                .as_ref()
-                .map_or(
-                    itertools::Either::Left(std::iter::repeat(&None).take(data.half_sizes.len())),
-                    |data| itertools::Either::Right(data.iter()),
-                )
-                .map(|center| center.unwrap_or(Position2D::ZERO))
+        let geometries = {
+            let num_instances = data.half_sizes.len();
+            let centers = data.centers.map_or(
+                itertools::Either::Left(std::iter::repeat(&None).take(num_instances)),
+                |data| itertools::Either::Right(data.iter()),
+            );
+            let rotations = data.rotations.map_or(
+                itertools::Either::Left(std::iter::repeat(&None).take(num_instances)),
+                |data| itertools::Either::Right(data.iter()),
+            );
+            itertools::izip!(data.half_sizes, centers, rotations)
+                .map(|(half_size, center, rotation)| {
+                    BoxGeometry::new(center.unwrap_or(Position2D::ZERO), *half_size, *rotation)
+                })
+                .collect_vec()
         };
 
         let radii = process_radius_slice(data.radii, data.half_sizes.len(), ent_path);
-        let colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        let mut colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        apply_entity_alpha(&mut colors, ent_context.alpha);
+
+        if let Some(iou_threshold) = nms_iou_threshold {
+            re_tracing::profile_scope!("nms_preview");
+            let bounding_boxes = geometries
+                .iter()
+                .map(BoxGeometry::bounding_box)
+                .collect_vec();
+            let outcomes = non_maximum_suppression(&bounding_boxes, iou_threshold);
+            for (color, outcome) in colors.iter_mut().zip(outcomes) {
+                match outcome {
+                    NmsOutcome::Alone => {}
+                    NmsOutcome::Kept => *color = NMS_KEPT_COLOR,
+                    NmsOutcome::Suppressed => *color = NMS_SUPPRESSED_COLOR,
+                }
+            }
+        }
 
         if data.instance_keys.len() <= self.max_labels {
             re_tracing::profile_scope!("labels");
@@ -115,14 +228,19 @@ impl Boxes2DVisualizer {
                     .collect::<Vec<_>>()
             };
 
-            if let Some(labels) = data.labels {
+            if data.labels.is_some() || auto_label.mode != AutoLabelMode::None {
                 self.data.ui_labels.extend(Self::process_labels(
-                    labels,
-                    data.half_sizes,
-                    centers(),
+                    data.labels,
+                    auto_label,
+                    &geometries,
                     &instance_path_hashes_for_picking,
                     &colors,
                     &annotation_infos,
+                    |index| {
+                        ent_context
+                            .hidden_classes
+                            .is_instance_hidden(data.class_ids, index)
+                    },
                 ));
             }
         }
@@ -137,26 +255,47 @@ impl Boxes2DVisualizer {
 
         let mut bounding_box = macaw::BoundingBox::nothing();
 
-        for (instance_key, half_size, center, radius, color) in itertools::izip!(
-            data.instance_keys,
-            data.half_sizes,
-            centers(),
-            radii,
-            colors
-        ) {
+        for (index, (instance_key, geometry, radius, color)) in
+            itertools::izip!(data.instance_keys, &geometries, radii, colors).enumerate()
+        {
             let instance_hash = re_entity_db::InstancePathHash::instance(ent_path, *instance_key);
 
-            let min = half_size.box_min(center);
-            let max = half_size.box_max(center);
-            bounding_box.extend(min.extend(0.0));
-            bounding_box.extend(max.extend(0.0));
+            let instance_bounding_box = geometry.bounding_box();
+            bounding_box = bounding_box.union(instance_bounding_box);
+
+            // Hidden boxes still count towards the entity's bounding box, so that hiding classes doesn't move the view.
+            if ent_context
+                .hidden_classes
+                .is_instance_hidden(data.class_ids, index)
+            {
+                continue;
+            }
+            self.data.add_instance_bounding_box(
+                instance_hash,
+                instance_bounding_box,
+                ent_context.world_from_entity,
+            );
+
+            if !ent_context.culling.is_bbox_visible(
+                &instance_bounding_box.transform_affine3(&ent_context.world_from_entity),
+            ) {
+                self.data.num_culled += 1;
+                continue;
+            }
+
+            if let Some(fill_color) = fill_color {
+                let world_from_entity = ent_context.world_from_entity;
+                self.fills.push(BoxFill {
+                    top_left: world_from_entity.transform_point3(geometry.corner.extend(0.0)),
+                    extent_u: world_from_entity.transform_vector3(geometry.extent_u.extend(0.0)),
+                    extent_v: world_from_entity.transform_vector3(geometry.extent_v.extend(0.0)),
+                    color: fill_color,
+                    depth_offset: ent_context.depth_offset,
+                });
+            }
 
             let rectangle = line_batch
-                .add_rectangle_outline_2d(
-                    min,
-                    glam::vec2(half_size.width(), 0.0),
-                    glam::vec2(0.0, half_size.height()),
-                )
+                .add_rectangle_outline_2d(geometry.corner, geometry.extent_u, geometry.extent_v)
                 .color(color)
                 .radius(radius)
                 .picking_instance_id(picking_id_from_instance_key(*instance_key));
@@ -180,6 +319,7 @@ struct Boxes2DComponentData<'a> {
     pub instance_keys: &'a [InstanceKey],
     pub half_sizes: &'a [HalfSizes2D],
     pub centers: Option<&'a [Option<Position2D>]>,
+    pub rotations: Option<&'a [Option<Rotation2D>]>,
     pub colors: Option<&'a [Option<Color>]>,
     pub radii: Option<&'a [Option<Radius>]>,
     pub labels: Option<&'a [Option<Text>]>,
@@ -187,6 +327,45 @@ struct Boxes2DComponentData<'a> {
     pub class_ids: Option<&'a [Option<ClassId>]>,
 }
 
+impl Boxes2DVisualizer {
+    fn fill_draw_data(&self, ctx: &ViewerContext<'_>) -> Option<re_renderer::QueueableDrawData> {
+        if self.fills.is_empty() {
+            return None;
+        }
+        re_tracing::profile_function!();
+
+        let white_texture = ctx
+            .render_ctx
+            .texture_manager_2d
+            .white_texture_unorm_handle();
+        let rectangles = self
+            .fills
+            .iter()
+            .map(|fill| re_renderer::renderer::TexturedRect {
+                top_left_corner_position: fill.top_left,
+                extent_u: fill.extent_u,
+                extent_v: fill.extent_v,
+                colormapped_texture: re_renderer::renderer::ColormappedTexture::from_unorm_rgba(
+                    white_texture.clone(),
+                ),
+                options: re_renderer::renderer::RectangleOptions {
+                    multiplicative_tint: fill.color.into(),
+                    depth_offset: fill.depth_offset,
+                    ..Default::default()
+                },
+            })
+            .collect_vec();
+
+        match re_renderer::renderer::RectangleDrawData::new(ctx.render_ctx, &rectangles) {
+            Ok(draw_data) => Some(draw_data.into()),
+            Err(err) => {
+                re_log::error_once!("Failed to create rectangle draw data for box fills: {err}");
+                None
+            }
+        }
+    }
+}
+
 impl IdentifiedViewSystem for Boxes2DVisualizer {
     fn identifier() -> re_viewer_context::ViewSystemIdentifier {
         "Boxes2D".into()
@@ -213,11 +392,12 @@ impl VisualizerSystem for Boxes2DVisualizer {
         query: &ViewQuery<'_>,
         view_ctx: &ViewContextCollection,
     ) -> Result<Vec<re_renderer::QueueableDrawData>, SpaceViewSystemExecutionError> {
-        super::entity_iterator::process_archetype_pov1_comp6::<
+        super::entity_iterator::process_archetype_pov1_comp7::<
             Boxes2DVisualizer,
             Boxes2D,
             HalfSizes2D,
             Position2D,
+            Rotation2D,
             Color,
             Radius,
             Text,
@@ -231,12 +411,13 @@ impl VisualizerSystem for Boxes2DVisualizer {
             view_ctx.get::<EntityDepthOffsets>()?.points,
             |_ctx,
              ent_path,
-             _ent_props,
+             ent_props,
              ent_context,
              (_time, _row_id),
              instance_keys,
              half_sizes,
              centers,
+             rotations,
              colors,
              radii,
              labels,
@@ -246,18 +427,35 @@ impl VisualizerSystem for Boxes2DVisualizer {
                     instance_keys,
                     half_sizes,
                     centers,
+                    rotations,
                     colors,
                     radii,
                     labels,
                     keypoint_ids,
                     class_ids,
                 };
-                self.process_data(query, &data, ent_path, ent_context);
+                let fill_color = ent_props.box_fill_color.map(|[r, g, b, a]| {
+                    let mut color = egui::Color32::from_rgba_unmultiplied(r, g, b, a);
+                    apply_entity_alpha(std::slice::from_mut(&mut color), ent_context.alpha);
+                    color
+                });
+                self.process_data(
+                    query,
+                    &data,
+                    ent_path,
+                    ent_context,
+                    fill_color,
+                    &ent_props.box_auto_label,
+                    ent_props.box_nms_iou_threshold,
+                );
                 Ok(())
             },
         )?;
 
-        Ok(Vec::new()) // TODO(andreas): Optionally return point & line draw data once SharedRenderBuilders is gone.
+        // Fills are queued before the shared line builder, so outlines end up on top of them.
+        // TODO(andreas): Optionally return point & line draw data once SharedRenderBuilders is gone.
+        self.fill_draw_data(ctx)
+            .map_or_else(|| Ok(Vec::new()), |draw_data| Ok(vec![draw_data]))
     }
 
     fn data(&self) -> Option<&dyn std::any::Any> {
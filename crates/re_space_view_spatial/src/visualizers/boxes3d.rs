@@ -18,7 +18,7 @@ use crate::{
 };
 
 use super::{
-    filter_visualizable_3d_entities, picking_id_from_instance_key,
+    apply_entity_alpha, filter_visualizable_3d_entities, picking_id_from_instance_key,
     process_annotation_and_keypoint_slices, process_color_slice, process_label_slice,
     process_radius_slice, SpatialViewVisualizerData,
 };
@@ -70,7 +70,8 @@ impl Boxes3DVisualizer {
         };
 
         let radii = process_radius_slice(data.radii, data.half_sizes.len(), ent_path);
-        let colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        let mut colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        apply_entity_alpha(&mut colors, ent_context.alpha);
         let labels = process_label_slice(data.labels, data.half_sizes.len(), &annotation_infos);
 
         let mut line_builder = ent_context.shared_render_builders.lines();
@@ -10,8 +10,8 @@ use re_viewer_context::{
 
 use crate::{
     contexts::{
-        AnnotationSceneContext, EntityDepthOffsets, PrimitiveCounter, SharedRenderBuilders,
-        SpatialSceneEntityContext, TransformContext,
+        AnnotationSceneContext, EntityDepthOffsets, HiddenClasses, PrimitiveCounter,
+        SharedRenderBuilders, SpatialSceneEntityContext, TransformContext, ViewportCulling,
     },
     SpatialSpaceView3D,
 };
@@ -41,6 +41,8 @@ where
     let depth_offsets = view_ctx.get::<EntityDepthOffsets>()?;
     let annotations = view_ctx.get::<AnnotationSceneContext>()?;
     let shared_render_builders = view_ctx.get::<SharedRenderBuilders>()?;
+    let culling = view_ctx.get::<ViewportCulling>()?;
+    let hidden_classes = view_ctx.get::<HiddenClasses>()?;
     let counter = view_ctx.get::<PrimitiveCounter>()?;
 
     for data_result in query.iter_visible_data_results(System::identifier()) {
@@ -67,6 +69,9 @@ where
                 .unwrap_or(&default_depth_offset),
             annotations: annotations.0.find(&data_result.entity_path),
             shared_render_builders,
+            alpha: data_result.accumulated_properties().alpha,
+            culling: *culling,
+            hidden_classes,
             highlight: query
                 .highlights
                 .entity_outline_mask(data_result.entity_path.hash()),
@@ -152,6 +157,8 @@ macro_rules! impl_process_archetype {
             let depth_offsets = view_ctx.get::<EntityDepthOffsets>()?;
             let annotations = view_ctx.get::<AnnotationSceneContext>()?;
             let shared_render_builders = view_ctx.get::<SharedRenderBuilders>()?;
+            let culling = view_ctx.get::<ViewportCulling>()?;
+            let hidden_classes = view_ctx.get::<HiddenClasses>()?;
             let counter = view_ctx.get::<PrimitiveCounter>()?;
 
             for data_result in query.iter_visible_data_results(S::identifier()) {
@@ -177,6 +184,9 @@ macro_rules! impl_process_archetype {
                         .unwrap_or(&default_depth_offset),
                     annotations: annotations.0.find(&data_result.entity_path),
                     shared_render_builders,
+                    alpha: data_result.accumulated_properties().alpha,
+                    culling: *culling,
+                    hidden_classes,
                     highlight: query
                         .highlights
                         .entity_outline_mask(data_result.entity_path.hash()),
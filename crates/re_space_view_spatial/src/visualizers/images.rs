@@ -4,7 +4,10 @@ use egui::NumExt;
 use itertools::Itertools as _;
 use nohash_hasher::IntSet;
 
-use re_entity_db::{EntityPath, EntityProperties};
+use re_entity_db::{
+    EntityPath, EntityProperties, ImageMagnification, ImageRotation, InstancePathHash,
+    NormalizationMode,
+};
 use re_log_types::{EntityPathHash, RowId};
 use re_query::{ArchetypeView, QueryError};
 use re_renderer::{
@@ -14,15 +17,16 @@ use re_renderer::{
 use re_space_view::diff_component_filter;
 use re_types::{
     archetypes::{DepthImage, Image, SegmentationImage},
-    components::{Color, DrawOrder, TensorData, ViewCoordinates},
+    components::{Color, DepthMeter, DrawOrder, TensorData, ViewCoordinates},
     tensor_data::{DecodedTensor, TensorDataMeaning},
     Archetype as _, ComponentNameSet,
 };
 use re_viewer_context::{
     gpu_bridge, ApplicableEntities, DefaultColor, IdentifiedViewSystem, SpaceViewClass,
-    SpaceViewSystemExecutionError, TensorDecodeCache, TensorStatsCache, ViewContextCollection,
-    ViewQuery, ViewerContext, VisualizableEntities, VisualizableFilterContext,
-    VisualizerAdditionalApplicabilityFilter, VisualizerQueryInfo, VisualizerSystem,
+    SpaceViewSystemExecutionError, TensorDecodeCache, TensorPercentileCache, TensorStatsCache,
+    ViewContextCollection, ViewQuery, ViewerContext, VisualizableEntities,
+    VisualizableFilterContext, VisualizerAdditionalApplicabilityFilter, VisualizerQueryInfo,
+    VisualizerSystem,
 };
 
 use crate::{
@@ -44,6 +48,9 @@ pub struct ViewerImage {
 
     pub tensor: DecodedTensor,
 
+    /// Row id of the tensor data, used as key for caches.
+    pub tensor_data_row_id: RowId,
+
     /// Textured rectangle for the renderer.
     pub textured_rect: TexturedRect,
 
@@ -52,6 +59,75 @@ pub struct ViewerImage {
 
     /// Draw order value used.
     pub draw_order: DrawOrder,
+
+    /// Tensor channels shown as red, green and blue, if not shown as is.
+    pub channel_selector: Option<[usize; 3]>,
+
+    /// How many depth units make up a meter, for depth images that have a `DepthMeter`.
+    pub depth_meter: Option<f32>,
+
+    /// Opacity the image is drawn with on top of other images in the same plane.
+    ///
+    /// If `None`, all images in the plane share the opacity evenly.
+    pub opacity: Option<f32>,
+}
+
+/// Describes why the tensor can't be shown as an image, or `None` if it can.
+///
+/// With a channel selector, any `[height, width, channels]` tensor can be shown.
+fn unsupported_image_shape(
+    tensor: &re_types::datatypes::TensorData,
+    channel_selector: Option<[usize; 3]>,
+) -> Option<String> {
+    let shape = tensor.shape();
+    let shape_text = format!("[{}]", shape.iter().join(", "));
+    if shape.is_empty() || shape.iter().any(|dim| dim.size == 0) {
+        return Some(format!("Empty tensor of shape {shape_text}"));
+    }
+
+    let supported = tensor.is_shaped_like_an_image()
+        || (channel_selector.is_some() && tensor.shape_short().len() == 3);
+    (!supported).then(|| {
+        format!(
+            "Tensor of shape {shape_text} can't be shown as an image, expected [height, width] or [height, width, channels]"
+        )
+    })
+}
+
+/// The colormap depth images of the entity are shown with, both as images and as point clouds.
+fn colormap(ent_props: &EntityProperties) -> Colormap {
+    match *ent_props.color_mapper {
+        re_entity_db::ColorMapper::Colormap(colormap) => match colormap {
+            re_entity_db::Colormap::Grayscale => Colormap::Grayscale,
+            re_entity_db::Colormap::Turbo => Colormap::Turbo,
+            re_entity_db::Colormap::Viridis => Colormap::Viridis,
+            re_entity_db::Colormap::Plasma => Colormap::Plasma,
+            re_entity_db::Colormap::Magma => Colormap::Magma,
+            re_entity_db::Colormap::Inferno => Colormap::Inferno,
+        },
+    }
+}
+
+/// Where the top left texel of a rotated image ends up in the image's own space,
+/// along with the vectors along its rows (`u`) and columns (`v`).
+///
+/// The rotated image covers the region from the origin to its rotated width and height,
+/// so that picking and pixel lookups only need to follow `u` and `v`.
+fn rotated_image_corner_and_extents(
+    rotation: ImageRotation,
+    width: f32,
+    height: f32,
+) -> [glam::Vec2; 3] {
+    use glam::{vec2, Vec2};
+
+    match rotation {
+        ImageRotation::None => [Vec2::ZERO, vec2(width, 0.0), vec2(0.0, height)],
+        ImageRotation::Rot90 => [vec2(height, 0.0), vec2(0.0, width), vec2(-height, 0.0)],
+        ImageRotation::Rot180 => [vec2(width, height), vec2(-width, 0.0), vec2(0.0, -height)],
+        ImageRotation::Rot270 => [vec2(0.0, width), vec2(0.0, -width), vec2(height, 0.0)],
+        ImageRotation::FlipH => [vec2(width, 0.0), vec2(-width, 0.0), vec2(0.0, height)],
+        ImageRotation::FlipV => [vec2(0.0, height), vec2(width, 0.0), vec2(0.0, -height)],
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -63,11 +139,17 @@ fn to_textured_rect(
     tensor: &DecodedTensor,
     meaning: TensorDataMeaning,
     multiplicative_tint: egui::Rgba,
+    channel_selector: Option<[usize; 3]>,
+    ent_props: &EntityProperties,
 ) -> Option<re_renderer::renderer::TexturedRect> {
     re_tracing::profile_function!();
 
-    let Some([height, width, _]) = tensor.image_height_width_channels() else {
-        return None;
+    let [height, width] = match (channel_selector, tensor.shape_short()) {
+        (Some(_), [height, width, _]) => [height.size, width.size],
+        _ => {
+            let [height, width, _] = tensor.image_height_width_channels()?;
+            [height, width]
+        }
     };
 
     let debug_name = ent_path.to_string();
@@ -75,19 +157,55 @@ fn to_textured_rect(
         .cache
         .entry(|c: &mut TensorStatsCache| c.entry(tensor_data_row_id, tensor));
 
-    match gpu_bridge::tensor_to_gpu(
-        ctx.render_ctx,
-        &debug_name,
-        tensor_data_row_id,
-        tensor,
-        meaning,
-        &tensor_stats,
-        &ent_context.annotations,
-    ) {
-        Ok(colormapped_texture) => {
-            // TODO(emilk): let users pick texture filtering.
-            // Always use nearest for magnification: let users see crisp individual pixels when they zoom
-            let texture_filter_magnification = re_renderer::renderer::TextureFilterMag::Nearest;
+    let colormapped_texture = if let Some(channels) = channel_selector {
+        gpu_bridge::color_tensor_channels_to_gpu(
+            ctx.render_ctx,
+            &debug_name,
+            tensor_data_row_id,
+            tensor,
+            channels.map(|channel| channel as u64),
+            &tensor_stats,
+        )
+    } else {
+        gpu_bridge::tensor_to_gpu(
+            ctx.render_ctx,
+            &debug_name,
+            tensor_data_row_id,
+            tensor,
+            meaning,
+            &tensor_stats,
+            &ent_context.annotations,
+            &ent_context.hidden_classes.0,
+        )
+    };
+
+    match colormapped_texture {
+        Ok(mut colormapped_texture) => {
+            // Depth images are shown with the same colormap as their backprojected point clouds.
+            if meaning == TensorDataMeaning::Depth && channel_selector.is_none() {
+                colormapped_texture.color_mapper =
+                    re_renderer::renderer::ColorMapper::Function(colormap(ent_props));
+            }
+
+            let data_range = match ent_props.image_normalization {
+                NormalizationMode::None => None,
+                NormalizationMode::MinMax => tensor_stats.finite_range,
+                NormalizationMode::Percentile(low, high) => {
+                    ctx.cache.entry(|c: &mut TensorPercentileCache| {
+                        c.entry(tensor_data_row_id, tensor, low, high)
+                    })
+                }
+            };
+            if let Some(data_range) = data_range {
+                gpu_bridge::apply_data_range(&mut colormapped_texture, data_range);
+            }
+
+            // Default to nearest for magnification: let users see crisp individual pixels when they zoom
+            let texture_filter_magnification =
+                match ent_props.image_magnification.unwrap_or_default() {
+                    ImageMagnification::Sharp => re_renderer::renderer::TextureFilterMag::Nearest,
+                    ImageMagnification::Smooth => re_renderer::renderer::TextureFilterMag::Linear,
+                };
 
             // For minimization: we want a smooth linear (ideally mipmapped) filter for color images.
             // Note that this filtering is done BEFORE applying the color map!
@@ -102,16 +220,21 @@ fn to_textured_rect(
                 re_renderer::renderer::TextureFilterMin::Linear
             };
 
+            let [corner, extent_u, extent_v] = rotated_image_corner_and_extents(
+                ent_props.image_rotation.unwrap_or_default(),
+                width as f32,
+                height as f32,
+            );
             Some(re_renderer::renderer::TexturedRect {
                 top_left_corner_position: ent_context
                     .world_from_entity
-                    .transform_point3(glam::Vec3::ZERO),
+                    .transform_point3(corner.extend(0.0)),
                 extent_u: ent_context
                     .world_from_entity
-                    .transform_vector3(glam::Vec3::X * width as f32),
+                    .transform_vector3(extent_u.extend(0.0)),
                 extent_v: ent_context
                     .world_from_entity
-                    .transform_vector3(glam::Vec3::Y * height as f32),
+                    .transform_vector3(extent_v.extend(0.0)),
                 colormapped_texture,
                 options: RectangleOptions {
                     texture_filter_magnification,
@@ -139,6 +262,9 @@ pub struct ImageVisualizer {
     pub data: SpatialViewVisualizerData,
     pub images: Vec<ViewerImage>,
     pub depth_cloud_entities: IntSet<EntityPathHash>,
+
+    /// Images that were skipped because their tensor isn't shaped like an image, with the reason.
+    pub unsupported_images: Vec<(EntityPath, String)>,
 }
 
 impl Default for ImageVisualizer {
@@ -147,11 +273,29 @@ impl Default for ImageVisualizer {
             data: SpatialViewVisualizerData::new(Some(SpatialSpaceViewKind::TwoD)),
             images: Vec::new(),
             depth_cloud_entities: IntSet::default(),
+            unsupported_images: Vec::new(),
         }
     }
 }
 
 impl ImageVisualizer {
+    /// Reports and skips tensors that can't be shown as an image.
+    ///
+    /// Returns whether the tensor can be shown.
+    fn check_image_shape(
+        &mut self,
+        ent_path: &EntityPath,
+        tensor: &re_types::datatypes::TensorData,
+        channel_selector: Option<[usize; 3]>,
+    ) -> bool {
+        let Some(problem) = unsupported_image_shape(tensor, channel_selector) else {
+            return true;
+        };
+        re_log::warn_once!("Can't show image at path {ent_path}: {problem}");
+        self.unsupported_images.push((ent_path.clone(), problem));
+        false
+    }
+
     fn handle_image_layering(&mut self) {
         re_tracing::profile_function!();
 
@@ -185,7 +329,9 @@ impl ImageVisualizer {
             let total_num_images = images.len();
             for (idx, image) in images.iter_mut().enumerate() {
                 // make top images transparent
-                let opacity = if idx == 0 {
+                let opacity = if let Some(opacity) = image.opacity {
+                    opacity.clamp(0.0, 1.0)
+                } else if idx == 0 {
                     1.0
                 } else {
                     // avoid precision problems in framebuffer
@@ -243,8 +389,9 @@ impl ImageVisualizer {
         ) {
             re_tracing::profile_scope!("loop_iter");
 
-            if !tensor.is_shaped_like_an_image() {
-                return Ok(());
+            let channel_selector = ent_props.channel_selector;
+            if !self.check_image_shape(ent_path, &tensor, channel_selector) {
+                continue;
             }
 
             let tensor_data_row_id = arch_view.primary_row_id();
@@ -276,6 +423,8 @@ impl ImageVisualizer {
                 &tensor,
                 meaning,
                 color.into(),
+                channel_selector,
+                ent_props,
             ) {
                 // Only update the bounding box if this is a 2D space view or
                 // the image_plane_distance is not auto. This is avoids a cyclic
@@ -290,15 +439,26 @@ impl ImageVisualizer {
                         Self::compute_bounding_box(&textured_rect),
                         ent_context.world_from_entity,
                     );
+                    // Image instances refer to pixels, so the image as a whole is added as a splat.
+                    // The textured rect is already in world space.
+                    self.data.add_instance_bounding_box(
+                        InstancePathHash::entity_splat(ent_path),
+                        Self::compute_bounding_box(&textured_rect),
+                        glam::Affine3A::IDENTITY,
+                    );
                 }
 
                 self.images.push(ViewerImage {
                     ent_path: ent_path.clone(),
                     tensor,
+                    tensor_data_row_id,
                     meaning,
                     textured_rect,
                     parent_pinhole: parent_pinhole_path.map(|p| p.hash()),
                     draw_order: draw_order.unwrap_or(DrawOrder::DEFAULT_IMAGE),
+                    channel_selector,
+                    depth_meter: None,
+                    opacity: None,
                 });
             }
         }
@@ -339,18 +499,19 @@ impl ImageVisualizer {
             };
 
         // Instance ids of tensors refer to entries inside the tensor.
-        for (tensor, color, draw_order) in itertools::izip!(
+        for (tensor, color, draw_order, meter) in itertools::izip!(
             arch_view.iter_required_component::<TensorData>()?,
             arch_view.iter_optional_component::<Color>()?,
-            arch_view.iter_optional_component::<DrawOrder>()?
+            arch_view.iter_optional_component::<DrawOrder>()?,
+            arch_view.iter_optional_component::<DepthMeter>()?
         ) {
-            // NOTE: we ignore the `DepthMeter` component here because we get it from
-            // `EntityProperties::depth_from_world_scale` instead, which is initialized to the
+            // NOTE: we only check whether the `DepthMeter` component is present and take its value
+            // from `EntityProperties::depth_from_world_scale` instead, which is initialized to the
             // same value, but the user may have edited it.
             re_tracing::profile_scope!("loop_iter");
 
-            if !tensor.is_shaped_like_an_image() {
-                return Ok(());
+            if !self.check_image_shape(ent_path, &tensor, None) {
+                continue;
             }
 
             let tensor_data_row_id = arch_view.primary_row_id();
@@ -414,6 +575,8 @@ impl ImageVisualizer {
                 &tensor,
                 meaning,
                 color.into(),
+                None,
+                ent_props,
             ) {
                 // Only update the bounding box if this is a 2D space view or
                 // the image_plane_distance is not auto. This is avoids a cyclic
@@ -428,15 +591,26 @@ impl ImageVisualizer {
                         Self::compute_bounding_box(&textured_rect),
                         ent_context.world_from_entity,
                     );
+                    // Image instances refer to pixels, so the image as a whole is added as a splat.
+                    // The textured rect is already in world space.
+                    self.data.add_instance_bounding_box(
+                        InstancePathHash::entity_splat(ent_path),
+                        Self::compute_bounding_box(&textured_rect),
+                        glam::Affine3A::IDENTITY,
+                    );
                 }
 
                 self.images.push(ViewerImage {
                     ent_path: ent_path.clone(),
                     tensor,
+                    tensor_data_row_id,
                     meaning,
                     textured_rect,
                     parent_pinhole: parent_pinhole_path.map(|p| p.hash()),
                     draw_order: draw_order.unwrap_or(DrawOrder::DEFAULT_IMAGE),
+                    channel_selector: None,
+                    depth_meter: meter.map(|_| *ent_props.depth_from_world_scale),
+                    opacity: None,
                 });
             }
         }
@@ -484,8 +658,8 @@ impl ImageVisualizer {
         ) {
             re_tracing::profile_scope!("loop_iter");
 
-            if !tensor.is_shaped_like_an_image() {
-                return Ok(());
+            if !self.check_image_shape(ent_path, &tensor, None) {
+                continue;
             }
 
             let tensor_data_row_id = arch_view.primary_row_id();
@@ -517,6 +691,8 @@ impl ImageVisualizer {
                 &tensor,
                 meaning,
                 color.into(),
+                None,
+                ent_props,
             ) {
                 // Only update the bounding box if this is a 2D space view or
                 // the image_plane_distance is not auto. This is avoids a cyclic
@@ -531,15 +707,26 @@ impl ImageVisualizer {
                         Self::compute_bounding_box(&textured_rect),
                         ent_context.world_from_entity,
                     );
+                    // Image instances refer to pixels, so the image as a whole is added as a splat.
+                    // The textured rect is already in world space.
+                    self.data.add_instance_bounding_box(
+                        InstancePathHash::entity_splat(ent_path),
+                        Self::compute_bounding_box(&textured_rect),
+                        glam::Affine3A::IDENTITY,
+                    );
                 }
 
                 self.images.push(ViewerImage {
                     ent_path: ent_path.clone(),
                     tensor,
+                    tensor_data_row_id,
                     meaning,
                     textured_rect,
                     parent_pinhole: parent_pinhole_path.map(|p| p.hash()),
                     draw_order: draw_order.unwrap_or(DrawOrder::DEFAULT_IMAGE),
+                    channel_selector: None,
+                    depth_meter: None,
+                    opacity: ent_props.segmentation_opacity,
                 });
             }
         }
@@ -606,16 +793,7 @@ impl ImageVisualizer {
 
         let world_depth_from_texture_depth = 1.0 / depth_from_world_scale;
 
-        let colormap = match *properties.color_mapper {
-            re_entity_db::ColorMapper::Colormap(colormap) => match colormap {
-                re_entity_db::Colormap::Grayscale => Colormap::Grayscale,
-                re_entity_db::Colormap::Turbo => Colormap::Turbo,
-                re_entity_db::Colormap::Viridis => Colormap::Viridis,
-                re_entity_db::Colormap::Plasma => Colormap::Plasma,
-                re_entity_db::Colormap::Magma => Colormap::Magma,
-                re_entity_db::Colormap::Inferno => Colormap::Inferno,
-            },
-        };
+        let colormap = colormap(properties);
 
         // We want point radius to be defined in a scale where the radius of a point
         // is a factor (`backproject_radius_scale`) of the diameter of a pixel projected
@@ -667,7 +845,8 @@ struct ImageVisualizerEntityFilter;
 impl VisualizerAdditionalApplicabilityFilter for ImageVisualizerEntityFilter {
     fn update_applicability(&mut self, event: &re_data_store::StoreEvent) -> bool {
         diff_component_filter(event, |tensor: &re_types::components::TensorData| {
-            tensor.is_shaped_like_an_image()
+            // Tensors with too many channels for an image can still be shown via a channel selection.
+            tensor.is_shaped_like_an_image() || tensor.shape_short().len() == 3
         })
     }
 }
@@ -843,3 +1022,79 @@ impl VisualizerSystem for ImageVisualizer {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use re_entity_db::ImageRotation;
+    use re_types::datatypes::{TensorBuffer, TensorData, TensorDimension};
+
+    use super::{rotated_image_corner_and_extents, unsupported_image_shape};
+
+    fn tensor_of_shape(shape: &[u64]) -> TensorData {
+        let num_elements = shape.iter().product::<u64>() as usize;
+        TensorData::new(
+            shape
+                .iter()
+                .copied()
+                .map(TensorDimension::unnamed)
+                .collect(),
+            TensorBuffer::U8(vec![0; num_elements].into()),
+        )
+    }
+
+    #[test]
+    fn unsupported_image_shapes() {
+        assert_eq!(
+            unsupported_image_shape(&tensor_of_shape(&[4, 3]), None),
+            None
+        );
+        assert_eq!(
+            unsupported_image_shape(&tensor_of_shape(&[4, 3, 3]), None),
+            None
+        );
+
+        // Rank 1.
+        let problem = unsupported_image_shape(&tensor_of_shape(&[12]), None).unwrap();
+        assert!(problem.contains("[12]"), "{problem}");
+
+        // Rank 4.
+        let tensor = tensor_of_shape(&[2, 4, 3, 5]);
+        let problem = unsupported_image_shape(&tensor, None).unwrap();
+        assert!(problem.contains("[2, 4, 3, 5]"), "{problem}");
+        assert!(unsupported_image_shape(&tensor, Some([0, 1, 2])).is_some());
+
+        // More channels than an image can have are only fine when selecting channels.
+        let tensor = tensor_of_shape(&[4, 3, 5]);
+        assert!(unsupported_image_shape(&tensor, None).is_some());
+        assert_eq!(unsupported_image_shape(&tensor, Some([0, 1, 2])), None);
+
+        // Zero-sized.
+        let problem = unsupported_image_shape(&tensor_of_shape(&[0, 3]), None).unwrap();
+        assert!(
+            problem.starts_with("Empty tensor of shape [0, 3]"),
+            "{problem}"
+        );
+        assert!(unsupported_image_shape(&tensor_of_shape(&[]), None).is_some());
+    }
+
+    #[test]
+    fn rotated_images_stay_in_place() {
+        let (width, height) = (4.0, 2.0);
+        for rotation in ImageRotation::ALL {
+            let [corner, u, v] = rotated_image_corner_and_extents(rotation, width, height);
+            let corners = [corner, corner + u, corner + v, corner + u + v];
+            let min = corners.into_iter().reduce(glam::Vec2::min).unwrap();
+            let max = corners.into_iter().reduce(glam::Vec2::max).unwrap();
+            let size = match rotation {
+                ImageRotation::Rot90 | ImageRotation::Rot270 => glam::vec2(height, width),
+                _ => glam::vec2(width, height),
+            };
+            assert_eq!((min, max), (glam::Vec2::ZERO, size), "{rotation}");
+        }
+
+        // A quarter turn clockwise moves the top left texel to the top right.
+        let [corner, u, _] = rotated_image_corner_and_extents(ImageRotation::Rot90, width, height);
+        assert_eq!(corner, glam::vec2(height, 0.0));
+        assert_eq!(u, glam::vec2(0.0, width));
+    }
+}
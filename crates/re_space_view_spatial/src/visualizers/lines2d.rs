@@ -17,8 +17,8 @@ use crate::{
 };
 
 use super::{
-    filter_visualizable_2d_entities, process_annotation_and_keypoint_slices, process_color_slice,
-    process_radius_slice, SpatialViewVisualizerData,
+    apply_entity_alpha, filter_visualizable_2d_entities, process_annotation_and_keypoint_slices,
+    process_color_slice, process_radius_slice, SpatialViewVisualizerData,
 };
 
 pub struct Lines2DVisualizer {
@@ -94,7 +94,8 @@ impl Lines2DVisualizer {
         );
 
         let radii = process_radius_slice(data.radii, data.strips.len(), ent_path);
-        let colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        let mut colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        apply_entity_alpha(&mut colors, ent_context.alpha);
 
         if data.instance_keys.len() <= self.max_labels {
             re_tracing::profile_scope!("labels");
@@ -135,6 +136,23 @@ impl Lines2DVisualizer {
         for (instance_key, strip, radius, color) in
             itertools::izip!(data.instance_keys, data.strips, radii, colors)
         {
+            let strip_bounding_box = macaw::BoundingBox::from_points(
+                strip.0.iter().map(|p| glam::vec3(p.x(), p.y(), 0.0)),
+            );
+            bounding_box = bounding_box.union(strip_bounding_box);
+            self.data.add_instance_bounding_box(
+                InstancePathHash::instance(ent_path, *instance_key),
+                strip_bounding_box,
+                ent_context.world_from_entity,
+            );
+
+            if !ent_context.culling.is_bbox_visible(
+                &strip_bounding_box.transform_affine3(&ent_context.world_from_entity),
+            ) {
+                self.data.num_culled += 1;
+                continue;
+            }
+
             let lines = line_batch
                 .add_strip_2d(strip.0.iter().copied().map(Into::into))
                 .color(color)
@@ -144,10 +162,6 @@ impl Lines2DVisualizer {
             if let Some(outline_mask_ids) = ent_context.highlight.instances.get(instance_key) {
                 lines.outline_mask_ids(*outline_mask_ids);
             }
-
-            for p in &strip.0 {
-                bounding_box.extend(glam::vec3(p.x(), p.y(), 0.0));
-            }
         }
 
         self.data
@@ -17,8 +17,8 @@ use crate::{
 };
 
 use super::{
-    filter_visualizable_3d_entities, process_annotation_and_keypoint_slices, process_color_slice,
-    process_radius_slice, SpatialViewVisualizerData,
+    apply_entity_alpha, filter_visualizable_3d_entities, process_annotation_and_keypoint_slices,
+    process_color_slice, process_radius_slice, SpatialViewVisualizerData,
 };
 
 pub struct Lines3DVisualizer {
@@ -97,7 +97,8 @@ impl Lines3DVisualizer {
         );
 
         let radii = process_radius_slice(data.radii, data.strips.len(), ent_path);
-        let colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        let mut colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        apply_entity_alpha(&mut colors, ent_context.alpha);
 
         if data.instance_keys.len() <= self.max_labels {
             re_tracing::profile_scope!("labels");
@@ -3,6 +3,7 @@
 mod arrows2d;
 mod arrows3d;
 mod assets3d;
+mod box_nms;
 mod boxes2d;
 mod boxes3d;
 mod cameras;
@@ -13,13 +14,23 @@ mod lines3d;
 mod meshes;
 mod points2d;
 mod points3d;
+mod polygon_geometry;
+mod polygons2d;
 mod spatial_view_visualizer;
+mod svg_overlays;
+mod texts2d;
 mod transform3d_arrows;
 
+pub use arrows2d::Arrows2DVisualizer;
+pub use boxes2d::{BoxGeometry, Boxes2DVisualizer};
 pub use cameras::CamerasVisualizer;
 pub use images::ImageVisualizer;
 pub use images::ViewerImage;
+pub use lines2d::Lines2DVisualizer;
+pub use points2d::Points2DVisualizer;
+pub use polygons2d::{Polygons2DVisualizer, ViewerPolygon};
 pub use spatial_view_visualizer::SpatialViewVisualizerData;
+pub use texts2d::Texts2DVisualizer;
 pub use transform3d_arrows::{add_axis_arrows, Transform3DArrowsVisualizer};
 
 #[doc(hidden)] // Public for benchmarks
@@ -28,7 +39,7 @@ pub use points3d::{LoadedPoints, Points3DComponentData};
 use ahash::HashMap;
 
 use re_entity_db::{EntityPath, InstancePathHash};
-use re_types::components::{Color, InstanceKey};
+use re_types::components::{Color, InstanceKey, TextWrapWidth};
 use re_types::datatypes::{KeypointId, KeypointPair};
 use re_viewer_context::{
     auto_color, Annotations, ApplicableEntities, DefaultColor, ResolvedAnnotationInfos,
@@ -63,6 +74,9 @@ pub fn register_2d_spatial_visualizers(
     system_registry.register_visualizer::<meshes::Mesh3DVisualizer>()?;
     system_registry.register_visualizer::<points2d::Points2DVisualizer>()?;
     system_registry.register_visualizer::<points3d::Points3DVisualizer>()?;
+    system_registry.register_visualizer::<polygons2d::Polygons2DVisualizer>()?;
+    system_registry.register_visualizer::<svg_overlays::SvgOverlaysVisualizer>()?;
+    system_registry.register_visualizer::<texts2d::Texts2DVisualizer>()?;
     system_registry.register_visualizer::<transform3d_arrows::Transform3DArrowsVisualizer>()?;
     Ok(())
 }
@@ -82,6 +96,9 @@ pub fn register_3d_spatial_visualizers(
     system_registry.register_visualizer::<meshes::Mesh3DVisualizer>()?;
     system_registry.register_visualizer::<points2d::Points2DVisualizer>()?;
     system_registry.register_visualizer::<points3d::Points3DVisualizer>()?;
+    system_registry.register_visualizer::<polygons2d::Polygons2DVisualizer>()?;
+    system_registry.register_visualizer::<svg_overlays::SvgOverlaysVisualizer>()?;
+    system_registry.register_visualizer::<texts2d::Texts2DVisualizer>()?;
     system_registry.register_visualizer::<transform3d_arrows::Transform3DArrowsVisualizer>()?;
     Ok(())
 }
@@ -153,6 +170,24 @@ pub fn process_color_slice<'a>(
     }
 }
 
+/// Multiplies colors with the opacity of their entity, see [`re_entity_db::EntityProperties::alpha`].
+pub fn apply_entity_alpha(colors: &mut [egui::Color32], alpha: u8) {
+    if alpha == u8::MAX {
+        return;
+    }
+
+    re_tracing::profile_function!();
+    let factor = alpha as f32 / u8::MAX as f32;
+    for color in colors {
+        *color = color.linear_multiply(factor);
+    }
+}
+
+/// Picks the elements at the given indices, e.g. those that survived viewport culling.
+pub fn select_indices<T: Clone>(values: &[T], indices: &[usize]) -> Vec<T> {
+    indices.iter().map(|&index| values[index].clone()).collect()
+}
+
 /// Process `Text` components using annotations.
 pub fn process_label_slice(
     labels: Option<&[Option<re_types::components::Text>]>,
@@ -283,6 +318,22 @@ pub enum UiLabelTarget {
 
     /// A point in space.
     Position3D(glam::Vec3),
+
+    /// A standalone text placed at a point (in scene coordinates), see [`Texts2DVisualizer`].
+    Text2D {
+        pos: egui::Pos2,
+
+        /// Which point of the text is placed at `pos`.
+        anchor: egui::Align2,
+
+        /// Font size in ui points, the body text size if `None`.
+        font_size: Option<f32>,
+
+        /// Width at which the text is wrapped and eventually cut short, not wrapped if `None`.
+        ///
+        /// Widths in scene units are converted with the current zoom of the view.
+        wrap_width: Option<TextWrapWidth>,
+    },
 }
 
 #[derive(Clone)]
@@ -309,10 +360,13 @@ pub fn load_keypoint_connections(
     re_tracing::profile_function!();
 
     // Generate keypoint connections if any.
+    // The connections belong to the entity as a whole, so they share its draw order and highlight.
     let mut line_builder = ent_context.shared_render_builders.lines();
     let mut line_batch = line_builder
         .batch("keypoint connections")
+        .depth_offset(ent_context.depth_offset)
         .world_from_obj(ent_context.world_from_entity)
+        .outline_mask_ids(ent_context.highlight.overall)
         .picking_object_id(re_renderer::PickingLayerObjectId(ent_path.hash64()));
 
     for ((class_id, _time), keypoints_in_class) in keypoints {
@@ -324,10 +378,11 @@ pub fn load_keypoint_connections(
             continue;
         };
 
-        let color = class_description.info.color.map_or_else(
+        let mut color = class_description.info.color.map_or_else(
             || auto_color(class_description.info.id),
             |color| color.into(),
         );
+        apply_entity_alpha(std::slice::from_mut(&mut color), ent_context.alpha);
 
         for KeypointPair {
             keypoint0: a,
@@ -1,3 +1,4 @@
+use itertools::Itertools as _;
 use re_entity_db::{EntityPath, InstancePathHash};
 use re_renderer::PickingLayerInstanceId;
 use re_types::{
@@ -11,11 +12,13 @@ use re_viewer_context::{
 };
 
 use crate::{
-    contexts::{EntityDepthOffsets, SpatialSceneEntityContext},
+    contexts::{EntityDepthOffsets, SpatialSceneEntityContext, ViewportCulling},
+    point_density::{self, PointDensityGrid},
+    scene_bounding_boxes,
     view_kind::SpatialSpaceViewKind,
     visualizers::{
-        load_keypoint_connections, process_annotation_and_keypoint_slices, process_color_slice,
-        UiLabel, UiLabelTarget,
+        apply_entity_alpha, load_keypoint_connections, process_annotation_and_keypoint_slices,
+        process_color_slice, select_indices, UiLabel, UiLabelTarget,
     },
 };
 
@@ -27,6 +30,18 @@ pub struct Points2DVisualizer {
     /// If the number of points in the batch is > max_labels, don't render point labels.
     pub max_labels: usize,
     pub data: SpatialViewVisualizerData,
+
+    /// Radii of the drawn points that are given in scene units, in the space of the view.
+    pub scene_radii: Vec<f32>,
+
+    /// Points counted per cell of the visible region, if points are shown as a density heatmap.
+    pub density: Option<PointDensityGrid>,
+
+    /// Number of points that aren't hidden by their class.
+    pub num_points: usize,
+
+    /// Whether every point is added to [`SpatialViewVisualizerData::instance_bounding_boxes`].
+    collect_instance_bounding_boxes: bool,
 }
 
 impl Default for Points2DVisualizer {
@@ -34,6 +49,10 @@ impl Default for Points2DVisualizer {
         Self {
             max_labels: 10,
             data: SpatialViewVisualizerData::new(Some(SpatialSpaceViewKind::TwoD)),
+            scene_radii: Vec::new(),
+            density: None,
+            num_points: 0,
+            collect_instance_bounding_boxes: false,
         }
     }
 }
@@ -45,6 +64,7 @@ impl Points2DVisualizer {
         instance_path_hashes: &'a [InstancePathHash],
         colors: &'a [egui::Color32],
         annotation_infos: &'a ResolvedAnnotationInfos,
+        is_hidden: impl Fn(usize) -> bool + 'a,
     ) -> impl Iterator<Item = UiLabel> + 'a {
         itertools::izip!(
             annotation_infos.iter(),
@@ -53,8 +73,10 @@ impl Points2DVisualizer {
             colors,
             instance_path_hashes,
         )
+        .enumerate()
+        .filter(move |(index, _)| !is_hidden(*index))
         .filter_map(
-            move |(annotation_info, point, label, color, labeled_instance)| {
+            move |(_, (annotation_info, point, label, color, labeled_instance))| {
                 let label = annotation_info.label(label.as_ref().map(|l| l.as_str()));
                 match (point, label) {
                     (point, Some(label)) => Some(UiLabel {
@@ -75,6 +97,8 @@ impl Points2DVisualizer {
         data: &Points2DComponentData<'_>,
         ent_path: &EntityPath,
         ent_context: &SpatialSceneEntityContext<'_>,
+        lod_threshold: Option<usize>,
+        connect_points: bool,
     ) {
         re_tracing::profile_function!();
 
@@ -88,13 +112,69 @@ impl Points2DVisualizer {
         );
 
         let positions = Self::load_positions(data);
-        let colors = Self::load_colors(data, ent_path, &annotation_infos);
+        let mut colors = Self::load_colors(data, ent_path, &annotation_infos);
+        apply_entity_alpha(&mut colors, ent_context.alpha);
         let radii = Self::load_radii(data, ent_path);
         let picking_instance_ids = Self::load_picking_ids(data);
 
-        {
+        if let Some(density) = &mut self.density {
+            re_tracing::profile_scope!("density");
+            for (index, position) in positions.iter().enumerate() {
+                if !ent_context
+                    .hidden_classes
+                    .is_instance_hidden(data.class_ids, index)
+                {
+                    let world_pos = ent_context.world_from_entity.transform_point3(*position);
+                    density.add(egui::pos2(world_pos.x, world_pos.y));
+                }
+            }
+        }
+
+        // Markers aren't drawn while the points are shown as a density heatmap.
+        if self.density.is_none() {
             re_tracing::profile_scope!("to_gpu");
 
+            let drawn_indices = Self::points_to_draw(
+                &positions,
+                &radii,
+                data.instance_keys,
+                data.class_ids,
+                ent_context,
+                lod_threshold,
+                &mut self.data.num_culled,
+            );
+
+            let (gpu_positions, gpu_radii, gpu_colors, gpu_picking_instance_ids) =
+                if let Some(drawn_indices) = &drawn_indices {
+                    (
+                        std::borrow::Cow::Owned(select_indices(&positions, drawn_indices)),
+                        std::borrow::Cow::Owned(select_indices(&radii, drawn_indices)),
+                        std::borrow::Cow::Owned(select_indices(&colors, drawn_indices)),
+                        std::borrow::Cow::Owned(select_indices(
+                            &picking_instance_ids,
+                            drawn_indices,
+                        )),
+                    )
+                } else {
+                    (
+                        std::borrow::Cow::Borrowed(positions.as_slice()),
+                        std::borrow::Cow::Borrowed(radii.as_slice()),
+                        std::borrow::Cow::Borrowed(colors.as_slice()),
+                        std::borrow::Cow::Borrowed(picking_instance_ids.as_slice()),
+                    )
+                };
+
+            let world_from_entity_scale = ent_context
+                .world_from_entity
+                .transform_vector3(glam::Vec3::X)
+                .length();
+            self.scene_radii.extend(
+                gpu_radii
+                    .iter()
+                    .filter_map(|radius| radius.scene())
+                    .map(|radius| radius * world_from_entity_scale),
+            );
+
             let mut point_builder = ent_context.shared_render_builders.points();
             let point_batch = point_builder
                 .batch("2d points")
@@ -107,8 +187,12 @@ impl Points2DVisualizer {
                 .outline_mask_ids(ent_context.highlight.overall)
                 .picking_object_id(re_renderer::PickingLayerObjectId(ent_path.hash64()));
 
-            let mut point_range_builder =
-                point_batch.add_points_2d(&positions, &radii, &colors, &picking_instance_ids);
+            let mut point_range_builder = point_batch.add_points_2d(
+                &gpu_positions,
+                &gpu_radii,
+                &gpu_colors,
+                &gpu_picking_instance_ids,
+            );
 
             // Determine if there's any sub-ranges that need extra highlighting.
             {
@@ -118,7 +202,11 @@ impl Points2DVisualizer {
                     let highlighted_point_index = data
                         .instance_keys
                         .iter()
-                        .position(|key| highlighted_key == key);
+                        .position(|key| highlighted_key == key)
+                        .and_then(|index| match &drawn_indices {
+                            Some(drawn_indices) => drawn_indices.binary_search(&index).ok(),
+                            None => Some(index),
+                        });
                     if let Some(highlighted_point_index) = highlighted_point_index {
                         point_range_builder = point_range_builder
                             .push_additional_outline_mask_ids_for_range(
@@ -136,8 +224,39 @@ impl Points2DVisualizer {
             ent_context.world_from_entity,
         );
 
+        self.num_points += (0..positions.len())
+            .filter(|index| {
+                !ent_context
+                    .hidden_classes
+                    .is_instance_hidden(data.class_ids, *index)
+            })
+            .count();
+
+        if self.collect_instance_bounding_boxes {
+            re_tracing::profile_scope!("instance bounding boxes");
+            for (index, (instance_key, position)) in
+                itertools::izip!(data.instance_keys, &positions).enumerate()
+            {
+                if ent_context
+                    .hidden_classes
+                    .is_instance_hidden(data.class_ids, index)
+                {
+                    continue;
+                }
+                self.data.add_instance_bounding_box(
+                    InstancePathHash::instance(ent_path, *instance_key),
+                    macaw::BoundingBox::from_min_max(*position, *position),
+                    ent_context.world_from_entity,
+                );
+            }
+        }
+
         load_keypoint_connections(ent_context, ent_path, &keypoints);
 
+        if connect_points {
+            Self::connect_points(&positions, &colors, data.class_ids, ent_path, ent_context);
+        }
+
         if data.instance_keys.len() <= self.max_labels {
             re_tracing::profile_scope!("labels");
 
@@ -160,11 +279,136 @@ impl Points2DVisualizer {
                     &instance_path_hashes_for_picking,
                     &colors,
                     &annotation_infos,
+                    |index| {
+                        ent_context
+                            .hidden_classes
+                            .is_instance_hidden(data.class_ids, index)
+                    },
                 ));
             }
         }
     }
 
+    /// Connects consecutive points with lines in the order they were logged, skipping points of hidden classes.
+    ///
+    /// Each line has the color of the point it starts at.
+    fn connect_points(
+        positions: &[glam::Vec3],
+        colors: &[egui::Color32],
+        class_ids: Option<&[Option<ClassId>]>,
+        ent_path: &EntityPath,
+        ent_context: &SpatialSceneEntityContext<'_>,
+    ) {
+        re_tracing::profile_function!();
+
+        let mut line_builder = ent_context.shared_render_builders.lines();
+        let mut line_batch = line_builder
+            .batch("connected 2d points")
+            .depth_offset(ent_context.depth_offset)
+            .world_from_obj(ent_context.world_from_entity)
+            .outline_mask_ids(ent_context.highlight.overall)
+            .picking_object_id(re_renderer::PickingLayerObjectId(ent_path.hash64()));
+
+        let shown_points = itertools::izip!(positions, colors)
+            .enumerate()
+            .filter(|(index, _)| {
+                !ent_context
+                    .hidden_classes
+                    .is_instance_hidden(class_ids, *index)
+            })
+            .map(|(_, point)| point);
+        for ((start, color), (end, _)) in shown_points.tuple_windows() {
+            line_batch
+                .add_segment_2d(start.truncate(), end.truncate())
+                .radius(re_renderer::Size::AUTO)
+                .color(*color)
+                // Select the entire entity when clicking any of the lines.
+                .picking_instance_id(PickingLayerInstanceId(InstanceKey::SPLAT.0));
+        }
+    }
+
+    /// Indices of the points that need to be uploaded, or `None` if all of them do.
+    ///
+    /// Skips points outside of the visible region, which are counted in `num_culled`, and points of hidden classes.
+    /// If there are still more than `lod_threshold` points, only one point per device pixel is kept.
+    /// Highlighted points are always kept.
+    fn points_to_draw(
+        positions: &[glam::Vec3],
+        radii: &[re_renderer::Size],
+        instance_keys: &[InstanceKey],
+        class_ids: Option<&[Option<ClassId>]>,
+        ent_context: &SpatialSceneEntityContext<'_>,
+        lod_threshold: Option<usize>,
+        num_culled: &mut usize,
+    ) -> Option<Vec<usize>> {
+        re_tracing::profile_function!();
+
+        let world_position = |index: usize| {
+            ent_context
+                .world_from_entity
+                .transform_point3(positions[index])
+        };
+
+        let mut indices = (0..positions.len())
+            .filter(|&index| {
+                !ent_context
+                    .hidden_classes
+                    .is_instance_hidden(class_ids, index)
+            })
+            .collect::<Vec<_>>();
+        let num_shown = indices.len();
+        Self::cull_points(
+            positions,
+            radii,
+            ent_context.world_from_entity,
+            &ent_context.culling,
+            &mut indices,
+        );
+        *num_culled += num_shown - indices.len();
+
+        if let (Some(lod_threshold), Some(pixel_size)) =
+            (lod_threshold, ent_context.culling.scene_units_per_pixel())
+        {
+            if indices.len() > lod_threshold {
+                re_tracing::profile_scope!("subsample");
+                let mut occupied_pixels = ahash::HashSet::default();
+                indices.retain(|&index| {
+                    let position = world_position(index);
+                    let pixel = (
+                        (position.x / pixel_size).floor() as i64,
+                        (position.y / pixel_size).floor() as i64,
+                    );
+                    occupied_pixels.insert(pixel)
+                        || ent_context
+                            .highlight
+                            .instances
+                            .contains_key(&instance_keys[index])
+                });
+            }
+        }
+
+        (indices.len() < positions.len()).then_some(indices)
+    }
+
+    /// Keeps only the indices of points any part of which may be visible in the viewport.
+    #[inline]
+    pub fn cull_points(
+        positions: &[glam::Vec3],
+        radii: &[re_renderer::Size],
+        world_from_entity: glam::Affine3A,
+        culling: &ViewportCulling,
+        indices: &mut Vec<usize>,
+    ) {
+        re_tracing::profile_function!();
+        let world_from_entity_scale = world_from_entity.transform_vector3(glam::Vec3::X).length();
+        indices.retain(|&index| {
+            culling.is_point_visible(
+                world_from_entity.transform_point3(positions[index]),
+                culling.world_radius(radii[index], world_from_entity_scale),
+            )
+        });
+    }
+
     #[inline]
     pub fn load_positions(
         Points2DComponentData { positions, .. }: &Points2DComponentData<'_>,
@@ -243,6 +487,25 @@ impl VisualizerSystem for Points2DVisualizer {
         query: &ViewQuery<'_>,
         view_ctx: &ViewContextCollection,
     ) -> Result<Vec<re_renderer::QueueableDrawData>, SpaceViewSystemExecutionError> {
+        let lod_threshold = ctx.app_options.points_2d_lod_threshold;
+
+        let culling = view_ctx.get::<ViewportCulling>()?;
+        self.density = if point_density::is_enabled(&ctx.re_ui.egui_ctx, query.space_view_id) {
+            culling
+                .visible_rect()
+                .zip(culling.scene_units_per_pixel())
+                .and_then(|(visible_rect, scene_units_per_pixel)| {
+                    PointDensityGrid::new(visible_rect, scene_units_per_pixel)
+                })
+        } else {
+            None
+        };
+        self.collect_instance_bounding_boxes = scene_bounding_boxes::point_instances_requested(
+            &ctx.re_ui.egui_ctx,
+            query.space_view_id,
+        );
+        let depth_offset = view_ctx.get::<EntityDepthOffsets>()?.points;
+
         super::entity_iterator::process_archetype_pov1_comp5::<
             Points2DVisualizer,
             Points2D,
@@ -257,10 +520,10 @@ impl VisualizerSystem for Points2DVisualizer {
             ctx,
             query,
             view_ctx,
-            view_ctx.get::<EntityDepthOffsets>()?.points,
+            depth_offset,
             |_ctx,
              ent_path,
-             _ent_props,
+             ent_props,
              ent_context,
              (_time, _row_id),
              instance_keys,
@@ -279,12 +542,33 @@ impl VisualizerSystem for Points2DVisualizer {
                     keypoint_ids,
                     class_ids,
                 };
-                self.process_data(query, &data, ent_path, ent_context);
+                self.process_data(
+                    query,
+                    &data,
+                    ent_path,
+                    ent_context,
+                    lod_threshold,
+                    *ent_props.connect_points.get(),
+                );
                 Ok(())
             },
         )?;
 
-        Ok(Vec::new()) // TODO(andreas): Optionally return point & line draw data once SharedRenderBuilders is gone.
+        // TODO(andreas): Optionally return point & line draw data once SharedRenderBuilders is gone.
+        let Some(density_rect) = self
+            .density
+            .as_ref()
+            .and_then(|density| density.textured_rect(ctx.render_ctx, depth_offset))
+        else {
+            return Ok(Vec::new());
+        };
+        match re_renderer::renderer::RectangleDrawData::new(ctx.render_ctx, &[density_rect]) {
+            Ok(draw_data) => Ok(vec![draw_data.into()]),
+            Err(err) => {
+                re_log::error_once!("Failed to create point density draw data: {err}");
+                Ok(Vec::new())
+            }
+        }
     }
 
     fn data(&self) -> Option<&dyn std::any::Any> {
@@ -15,8 +15,8 @@ use crate::{
     contexts::{EntityDepthOffsets, SpatialSceneEntityContext},
     view_kind::SpatialSpaceViewKind,
     visualizers::{
-        load_keypoint_connections, process_annotation_and_keypoint_slices, process_color_slice,
-        UiLabel, UiLabelTarget,
+        apply_entity_alpha, load_keypoint_connections, process_annotation_and_keypoint_slices,
+        process_color_slice, UiLabel, UiLabelTarget,
     },
 };
 
@@ -84,9 +84,10 @@ impl Points3DVisualizer {
             keypoints,
             positions,
             radii,
-            colors,
+            mut colors,
             picking_instance_ids,
         } = LoadedPoints::load(data, ent_path, query.latest_at, &ent_context.annotations);
+        apply_entity_alpha(&mut colors, ent_context.alpha);
 
         {
             re_tracing::profile_scope!("to_gpu");
@@ -0,0 +1,202 @@
+//! Geometry helpers for closed 2D polygons, whose last vertex connects back to the first.
+
+/// Twice the signed area of the polygon, positive for counter-clockwise winding.
+fn doubled_signed_area(vertices: &[glam::Vec2]) -> f32 {
+    closed_edges(vertices).map(|(a, b)| a.perp_dot(b)).sum()
+}
+
+fn closed_edges(vertices: &[glam::Vec2]) -> impl Iterator<Item = (glam::Vec2, glam::Vec2)> + '_ {
+    vertices
+        .iter()
+        .copied()
+        .zip(vertices.iter().copied().cycle().skip(1))
+}
+
+/// Center of mass of the polygon's area.
+///
+/// Falls back to the mean of the vertices for polygons without area.
+pub fn polygon_centroid(vertices: &[glam::Vec2]) -> Option<glam::Vec2> {
+    if vertices.is_empty() {
+        return None;
+    }
+
+    let doubled_area = doubled_signed_area(vertices);
+    if doubled_area.abs() <= f32::EPSILON {
+        return Some(vertices.iter().copied().sum::<glam::Vec2>() / vertices.len() as f32);
+    }
+
+    let weighted_sum = closed_edges(vertices)
+        .map(|(a, b)| (a + b) * a.perp_dot(b))
+        .sum::<glam::Vec2>();
+    Some(weighted_sum / (3.0 * doubled_area))
+}
+
+/// Whether the point lies inside the polygon, using the even-odd rule.
+pub fn polygon_contains(vertices: &[glam::Vec2], point: glam::Vec2) -> bool {
+    let mut inside = false;
+    for (a, b) in closed_edges(vertices) {
+        if (a.y > point.y) != (b.y > point.y) {
+            let crossing_x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+            if point.x < crossing_x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Distance from the point to the closest edge of the polygon, including the closing edge.
+pub fn distance_to_polygon_outline(vertices: &[glam::Vec2], point: glam::Vec2) -> f32 {
+    closed_edges(vertices)
+        .map(|(a, b)| {
+            let edge = b - a;
+            let t = if edge.length_squared() > 0.0 {
+                ((point - a).dot(edge) / edge.length_squared()).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            point.distance(a + edge * t)
+        })
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// Splits a simple polygon into triangles by ear clipping.
+///
+/// Works for both windings and for concave polygons.
+/// Self-intersecting polygons are only partially triangulated.
+pub fn triangulate_polygon(vertices: &[glam::Vec2]) -> Vec<[u32; 3]> {
+    re_tracing::profile_function!();
+
+    if vertices.len() < 3 {
+        return Vec::new();
+    }
+
+    // Ears are convex corners, which is easiest to test for with a known winding.
+    let mut remaining = (0..vertices.len() as u32).collect::<Vec<_>>();
+    if doubled_signed_area(vertices) < 0.0 {
+        remaining.reverse();
+    }
+
+    let mut triangles = Vec::with_capacity(vertices.len() - 2);
+    let mut index = 0;
+    let mut corners_without_ear = 0;
+    while remaining.len() > 3 {
+        let len = remaining.len();
+        index %= len;
+        let corner = [
+            remaining[(index + len - 1) % len],
+            remaining[index],
+            remaining[(index + 1) % len],
+        ];
+
+        if is_ear(vertices, &remaining, corner) {
+            triangles.push(corner);
+            remaining.remove(index);
+            corners_without_ear = 0;
+        } else {
+            index += 1;
+            corners_without_ear += 1;
+            if corners_without_ear > len {
+                // Not a simple polygon, there are no ears left to clip.
+                return triangles;
+            }
+        }
+    }
+    triangles.push([remaining[0], remaining[1], remaining[2]]);
+
+    triangles
+}
+
+fn is_ear(vertices: &[glam::Vec2], remaining: &[u32], corner: [u32; 3]) -> bool {
+    let [a, b, c] = corner.map(|i| vertices[i as usize]);
+    if (b - a).perp_dot(c - b) <= 0.0 {
+        return false; // Reflex or degenerate corner.
+    }
+
+    !remaining.iter().any(|&i| {
+        !corner.contains(&i) && {
+            let p = vertices[i as usize];
+            (b - a).perp_dot(p - a) >= 0.0
+                && (c - b).perp_dot(p - b) >= 0.0
+                && (a - c).perp_dot(p - c) >= 0.0
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangulated_area(vertices: &[glam::Vec2], triangles: &[[u32; 3]]) -> f32 {
+        triangles
+            .iter()
+            .map(|triangle| doubled_signed_area(&triangle.map(|i| vertices[i as usize])).abs())
+            .sum::<f32>()
+            * 0.5
+    }
+
+    /// An L-shape with a reflex corner at (1, 1).
+    fn l_shape() -> Vec<glam::Vec2> {
+        vec![
+            glam::vec2(0.0, 0.0),
+            glam::vec2(2.0, 0.0),
+            glam::vec2(2.0, 1.0),
+            glam::vec2(1.0, 1.0),
+            glam::vec2(1.0, 2.0),
+            glam::vec2(0.0, 2.0),
+        ]
+    }
+
+    #[test]
+    fn triangulates_concave_polygons() {
+        let vertices = l_shape();
+        let triangles = triangulate_polygon(&vertices);
+        assert_eq!(triangles.len(), 4);
+        assert_eq!(triangulated_area(&vertices, &triangles), 3.0);
+
+        let reversed = vertices.iter().rev().copied().collect::<Vec<_>>();
+        let triangles = triangulate_polygon(&reversed);
+        assert_eq!(triangles.len(), 4);
+        assert_eq!(triangulated_area(&reversed, &triangles), 3.0);
+
+        assert!(triangulate_polygon(&vertices[..2]).is_empty());
+    }
+
+    #[test]
+    fn point_in_polygon() {
+        let vertices = l_shape();
+        assert!(polygon_contains(&vertices, glam::vec2(0.5, 0.5)));
+        assert!(polygon_contains(&vertices, glam::vec2(0.5, 1.5)));
+        assert!(!polygon_contains(&vertices, glam::vec2(1.5, 1.5)));
+        assert!(!polygon_contains(&vertices, glam::vec2(3.0, 0.5)));
+    }
+
+    #[test]
+    fn outline_distance_includes_closing_edge() {
+        let vertices = l_shape();
+        assert_eq!(
+            distance_to_polygon_outline(&vertices, glam::vec2(1.5, 1.5)),
+            0.5
+        );
+        // Closest to the edge from (0, 2) back to (0, 0).
+        assert_eq!(
+            distance_to_polygon_outline(&vertices, glam::vec2(-0.25, 1.0)),
+            0.25
+        );
+    }
+
+    #[test]
+    fn centroid() {
+        let square = [
+            glam::vec2(0.0, 0.0),
+            glam::vec2(0.0, 2.0),
+            glam::vec2(2.0, 2.0),
+            glam::vec2(2.0, 0.0),
+        ];
+        assert_eq!(polygon_centroid(&square), Some(glam::vec2(1.0, 1.0)));
+
+        let line = [glam::vec2(0.0, 0.0), glam::vec2(2.0, 0.0)];
+        assert_eq!(polygon_centroid(&line), Some(glam::vec2(1.0, 0.0)));
+        assert_eq!(polygon_centroid(&[]), None);
+    }
+}
@@ -0,0 +1,314 @@
+use re_entity_db::{EntityPath, EntityProperties, InstancePathHash};
+use re_renderer::PickingLayerInstanceId;
+use re_types::{
+    archetypes::Polygons2D,
+    components::{ClassId, Color, InstanceKey, LineStrip2D, Radius, Text},
+};
+use re_viewer_context::{
+    ApplicableEntities, IdentifiedViewSystem, SpaceViewSystemExecutionError, ViewContextCollection,
+    ViewQuery, ViewerContext, VisualizableEntities, VisualizableFilterContext, VisualizerQueryInfo,
+    VisualizerSystem,
+};
+
+use crate::{
+    contexts::{EntityDepthOffsets, SpatialSceneEntityContext},
+    picking::PickingContext,
+    view_kind::SpatialSpaceViewKind,
+    visualizers::{UiLabel, UiLabelTarget},
+};
+
+use super::{
+    apply_entity_alpha, filter_visualizable_2d_entities,
+    polygon_geometry::{
+        distance_to_polygon_outline, polygon_centroid, polygon_contains, triangulate_polygon,
+    },
+    process_annotation_and_keypoint_slices, process_color_slice, process_radius_slice,
+    SpatialViewVisualizerData,
+};
+
+/// A closed polygon as shown in a view, kept around for picking and for drawing its fill.
+pub struct ViewerPolygon {
+    pub instance_path_hash: InstancePathHash,
+
+    /// Vertices in the entity's space.
+    pub vertices: Vec<glam::Vec2>,
+
+    pub world_from_entity: glam::Affine3A,
+
+    /// How far from the outline the polygon is still hovered, in the entity's space.
+    pub outline_hover_distance: f32,
+
+    /// Translucent fill and its triangulation, if the polygon is filled.
+    pub fill: Option<(egui::Color32, Vec<[u32; 3]>)>,
+}
+
+impl ViewerPolygon {
+    /// Whether a point in the entity's space hovers the fill or the outline of the polygon.
+    pub fn is_hovered_at(&self, point: glam::Vec2) -> bool {
+        (self.fill.is_some() && polygon_contains(&self.vertices, point))
+            || distance_to_polygon_outline(&self.vertices, point) <= self.outline_hover_distance
+    }
+
+    fn fill_shape(&self, ui_from_world: &egui::emath::RectTransform) -> Option<egui::Shape> {
+        let (color, triangles) = self.fill.as_ref()?;
+
+        let mut mesh = egui::Mesh::default();
+        for vertex in &self.vertices {
+            let world_pos = self.world_from_entity.transform_point3(vertex.extend(0.0));
+            mesh.colored_vertex(
+                ui_from_world.transform_pos(egui::pos2(world_pos.x, world_pos.y)),
+                *color,
+            );
+        }
+        for [a, b, c] in triangles {
+            mesh.add_triangle(*a, *b, *c);
+        }
+        Some(mesh.into())
+    }
+}
+
+pub struct Polygons2DVisualizer {
+    /// If the number of polygons in the batch is > max_labels, don't render polygon labels.
+    pub max_labels: usize,
+    pub data: SpatialViewVisualizerData,
+    pub polygons: Vec<ViewerPolygon>,
+}
+
+impl Default for Polygons2DVisualizer {
+    fn default() -> Self {
+        Self {
+            max_labels: 10,
+            data: SpatialViewVisualizerData::new(Some(SpatialSpaceViewKind::TwoD)),
+            polygons: Vec::new(),
+        }
+    }
+}
+
+impl Polygons2DVisualizer {
+    /// Shapes for the translucent fills of all filled polygons, drawn by egui on top of the view.
+    ///
+    /// Fills aren't shown in 3D views.
+    pub fn fill_shapes(&self, ui_from_world: &egui::emath::RectTransform) -> Vec<egui::Shape> {
+        re_tracing::profile_function!();
+        self.polygons
+            .iter()
+            .filter_map(|polygon| polygon.fill_shape(ui_from_world))
+            .collect()
+    }
+
+    fn process_data(
+        &mut self,
+        query: &ViewQuery<'_>,
+        data: &Polygons2DComponentData<'_>,
+        ent_path: &EntityPath,
+        ent_props: &EntityProperties,
+        ent_context: &SpatialSceneEntityContext<'_>,
+    ) {
+        let (annotation_infos, _) = process_annotation_and_keypoint_slices(
+            query.latest_at,
+            data.instance_keys,
+            None,
+            data.class_ids,
+            data.polygons.iter().map(|_| glam::Vec3::ZERO),
+            &ent_context.annotations,
+        );
+
+        let radii = process_radius_slice(data.radii, data.polygons.len(), ent_path);
+        let mut colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        apply_entity_alpha(&mut colors, ent_context.alpha);
+
+        let show_labels = data.instance_keys.len() <= self.max_labels;
+        let scene_units_per_point = ent_context.culling.scene_units_per_point().unwrap_or(0.0);
+
+        let mut line_builder = ent_context.shared_render_builders.lines();
+        let mut line_batch = line_builder
+            .batch("polygons 2d")
+            .depth_offset(ent_context.depth_offset)
+            .world_from_obj(ent_context.world_from_entity)
+            .outline_mask_ids(ent_context.highlight.overall)
+            .picking_object_id(re_renderer::PickingLayerObjectId(ent_path.hash64()));
+
+        let mut bounding_box = macaw::BoundingBox::nothing();
+
+        for (index, (instance_key, polygon, radius, color, annotation_info)) in itertools::izip!(
+            data.instance_keys,
+            data.polygons,
+            radii,
+            colors,
+            annotation_infos.iter()
+        )
+        .enumerate()
+        {
+            let instance_path_hash = InstancePathHash::instance(ent_path, *instance_key);
+            let vertices = polygon
+                .0
+                .iter()
+                .copied()
+                .map(glam::Vec2::from)
+                .collect::<Vec<_>>();
+
+            let polygon_bounding_box =
+                macaw::BoundingBox::from_points(vertices.iter().map(|v| v.extend(0.0)));
+            bounding_box = bounding_box.union(polygon_bounding_box);
+
+            // Hidden polygons still count towards the entity's bounding box, so that hiding classes doesn't move the view.
+            if ent_context
+                .hidden_classes
+                .is_instance_hidden(data.class_ids, index)
+            {
+                continue;
+            }
+            self.data.add_instance_bounding_box(
+                instance_path_hash,
+                polygon_bounding_box,
+                ent_context.world_from_entity,
+            );
+
+            if !ent_context.culling.is_bbox_visible(
+                &polygon_bounding_box.transform_affine3(&ent_context.world_from_entity),
+            ) {
+                self.data.num_culled += 1;
+                continue;
+            }
+
+            // Close the loop by returning to the first vertex.
+            let lines = line_batch
+                .add_strip_2d(vertices.iter().chain(vertices.first()).copied())
+                .color(color)
+                .radius(radius)
+                .picking_instance_id(PickingLayerInstanceId(instance_key.0));
+            if let Some(outline_mask_ids) = ent_context.highlight.instances.get(instance_key) {
+                lines.outline_mask_ids(*outline_mask_ids);
+            }
+
+            if show_labels {
+                let label = data
+                    .labels
+                    .and_then(|labels| labels.get(index).cloned().flatten());
+                if let (Some(label), Some(centroid)) = (
+                    annotation_info.label(label.as_ref().map(|l| l.as_str())),
+                    polygon_centroid(&vertices),
+                ) {
+                    let centroid = ent_context
+                        .world_from_entity
+                        .transform_point3(centroid.extend(0.0));
+                    self.data.ui_labels.push(UiLabel {
+                        text: label,
+                        color,
+                        target: UiLabelTarget::Point2D(egui::pos2(centroid.x, centroid.y)),
+                        labeled_instance: instance_path_hash,
+                    });
+                }
+            }
+
+            // Sizes in ui points are converted with the current zoom and the display's pixels per point, if known.
+            let outline_radius = radius
+                .scene()
+                .unwrap_or_else(|| radius.points().unwrap_or(0.0) * scene_units_per_point);
+            let fill = ent_props.polygon_fill_opacity.map(|opacity| {
+                (
+                    color.gamma_multiply(opacity),
+                    triangulate_polygon(&vertices),
+                )
+            });
+            self.polygons.push(ViewerPolygon {
+                instance_path_hash,
+                vertices,
+                world_from_entity: ent_context.world_from_entity,
+                outline_hover_distance: outline_radius
+                    + PickingContext::UI_INTERACTION_RADIUS * scene_units_per_point,
+                fill,
+            });
+        }
+
+        self.data
+            .add_bounding_box(ent_path.hash(), bounding_box, ent_context.world_from_entity);
+    }
+}
+
+// ---
+
+struct Polygons2DComponentData<'a> {
+    pub instance_keys: &'a [InstanceKey],
+    pub polygons: &'a [LineStrip2D],
+    pub colors: Option<&'a [Option<Color>]>,
+    pub radii: Option<&'a [Option<Radius>]>,
+    pub labels: Option<&'a [Option<Text>]>,
+    pub class_ids: Option<&'a [Option<ClassId>]>,
+}
+
+impl IdentifiedViewSystem for Polygons2DVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "Polygons2D".into()
+    }
+}
+
+impl VisualizerSystem for Polygons2DVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Polygons2D>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: ApplicableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_2d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        query: &ViewQuery<'_>,
+        view_ctx: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, SpaceViewSystemExecutionError> {
+        super::entity_iterator::process_archetype_pov1_comp4::<
+            Polygons2DVisualizer,
+            Polygons2D,
+            LineStrip2D,
+            Color,
+            Radius,
+            Text,
+            ClassId,
+            _,
+        >(
+            ctx,
+            query,
+            view_ctx,
+            view_ctx.get::<EntityDepthOffsets>()?.lines2d,
+            |_ctx,
+             ent_path,
+             ent_props,
+             ent_context,
+             (_time, _row_id),
+             instance_keys,
+             polygons,
+             colors,
+             radii,
+             labels,
+             class_ids| {
+                let data = Polygons2DComponentData {
+                    instance_keys,
+                    polygons,
+                    colors,
+                    radii,
+                    labels,
+                    class_ids,
+                };
+                self.process_data(query, &data, ent_path, ent_props, ent_context);
+                Ok(())
+            },
+        )?;
+
+        Ok(Vec::new()) // TODO(andreas): Optionally return point & line draw data once SharedRenderBuilders is gone.
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.data.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -1,3 +1,4 @@
+use re_entity_db::InstancePathHash;
 use re_log_types::EntityPathHash;
 
 use crate::{view_kind::SpatialSpaceViewKind, visualizers::UiLabel};
@@ -8,6 +9,15 @@ use crate::{view_kind::SpatialSpaceViewKind, visualizers::UiLabel};
 pub struct SpatialViewVisualizerData {
     pub ui_labels: Vec<UiLabel>,
     pub bounding_boxes: Vec<(EntityPathHash, macaw::BoundingBox)>,
+
+    /// Bounding boxes of individual instances, used for interactions like marquee selection.
+    ///
+    /// Instances that are not individually selectable (like image pixels) are added as splats.
+    pub instance_bounding_boxes: Vec<(InstancePathHash, macaw::BoundingBox)>,
+
+    /// Number of instances that weren't drawn because they were outside of the visible region.
+    pub num_culled: usize,
+
     pub preferred_view_kind: Option<SpatialSpaceViewKind>,
 }
 
@@ -16,6 +26,8 @@ impl SpatialViewVisualizerData {
         Self {
             ui_labels: Vec::new(),
             bounding_boxes: Vec::new(),
+            instance_bounding_boxes: Vec::new(),
+            num_culled: 0,
             preferred_view_kind,
         }
     }
@@ -30,6 +42,16 @@ impl SpatialViewVisualizerData {
             .push((entity, bbox.transform_affine3(&world_from_obj)));
     }
 
+    pub fn add_instance_bounding_box(
+        &mut self,
+        instance: InstancePathHash,
+        bbox: macaw::BoundingBox,
+        world_from_obj: glam::Affine3A,
+    ) {
+        self.instance_bounding_boxes
+            .push((instance, bbox.transform_affine3(&world_from_obj)));
+    }
+
     pub fn add_bounding_box_from_points(
         &mut self,
         entity: EntityPathHash,
@@ -0,0 +1,185 @@
+use re_entity_db::EntityPath;
+use re_query::{ArchetypeView, QueryError};
+use re_renderer::renderer::{ColormappedTexture, RectangleOptions, TexturedRect};
+use re_types::{
+    archetypes::SvgOverlay,
+    components::{Blob, OutOfTreeTransform3D},
+};
+use re_viewer_context::{
+    gpu_bridge, ApplicableEntities, IdentifiedViewSystem, SpaceViewSystemExecutionError,
+    ViewContextCollection, ViewQuery, ViewerContext, VisualizableEntities,
+    VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem,
+};
+
+use super::{
+    entity_iterator::process_archetype_views, filter_visualizable_2d_entities,
+    SpatialViewVisualizerData,
+};
+use crate::{
+    contexts::{EntityDepthOffsets, SpatialSceneEntityContext},
+    svg_cache::{raster_resolution, rasterize_svg, SvgCache},
+    view_kind::SpatialSpaceViewKind,
+};
+
+/// Shows SVG documents, rasterized at the resolution they are shown at.
+pub struct SvgOverlaysVisualizer(SpatialViewVisualizerData);
+
+impl Default for SvgOverlaysVisualizer {
+    fn default() -> Self {
+        Self(SpatialViewVisualizerData::new(Some(
+            SpatialSpaceViewKind::TwoD,
+        )))
+    }
+}
+
+impl SvgOverlaysVisualizer {
+    fn process_arch_view(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        rectangles: &mut Vec<TexturedRect>,
+        arch_view: &ArchetypeView<SvgOverlay>,
+        ent_path: &EntityPath,
+        ent_context: &SpatialSceneEntityContext<'_>,
+    ) -> Result<(), QueryError> {
+        let blob = arch_view.required_mono_component::<Blob>()?;
+        let entity_from_svg = arch_view.raw_optional_mono_component::<OutOfTreeTransform3D>()?;
+
+        let blob_row_id = arch_view.primary_row_id();
+        let Some(size) = ctx
+            .cache
+            .entry(|c: &mut SvgCache| c.size(blob_row_id, blob.0.as_slice()))
+        else {
+            return Ok(());
+        };
+
+        let world_from_svg = ent_context.world_from_entity
+            * entity_from_svg.map_or(glam::Affine3A::IDENTITY, |t| t.0.into());
+        let bounding_box = macaw::BoundingBox::from_min_max(glam::Vec3::ZERO, size.extend(0.0));
+        self.0
+            .add_bounding_box(ent_path.hash(), bounding_box, world_from_svg);
+
+        if !ent_context
+            .culling
+            .is_bbox_visible(&bounding_box.transform_affine3(&world_from_svg))
+        {
+            return Ok(());
+        }
+
+        let extent_u = world_from_svg.transform_vector3(glam::Vec3::X * size.x);
+        let extent_v = world_from_svg.transform_vector3(glam::Vec3::Y * size.y);
+        let pixels_per_unit =
+            ent_context
+                .culling
+                .scene_units_per_pixel()
+                .map_or(1.0, |scene_units_per_pixel| {
+                    (extent_u.length() / size.x).max(extent_v.length() / size.y)
+                        / scene_units_per_pixel
+                });
+        let Some(resolution) = raster_resolution(size, pixels_per_unit) else {
+            return Ok(());
+        };
+
+        let texture_key = egui::util::hash((blob_row_id, resolution));
+        let texture = gpu_bridge::try_get_or_create_texture(ctx.render_ctx, texture_key, || {
+            let [width, height] = resolution;
+            rasterize_svg(blob.0.as_slice(), resolution).map(|data| {
+                re_renderer::resource_managers::Texture2DCreationDesc {
+                    label: ent_path.to_string().into(),
+                    data: data.into(),
+                    format: re_renderer::external::wgpu::TextureFormat::Rgba8UnormSrgb,
+                    width,
+                    height,
+                }
+            })
+        });
+
+        match texture {
+            Ok(texture) => rectangles.push(TexturedRect {
+                top_left_corner_position: world_from_svg.transform_point3(glam::Vec3::ZERO),
+                extent_u,
+                extent_v,
+                colormapped_texture: ColormappedTexture::from_unorm_rgba(texture),
+                options: RectangleOptions {
+                    texture_filter_magnification: re_renderer::renderer::TextureFilterMag::Linear,
+                    texture_filter_minification: re_renderer::renderer::TextureFilterMin::Linear,
+                    multiplicative_tint: egui::Rgba::from_white_alpha(
+                        ent_context.alpha as f32 / 255.0,
+                    ),
+                    depth_offset: ent_context.depth_offset,
+                    outline_mask: ent_context.highlight.overall,
+                },
+            }),
+            Err(err) => {
+                re_log::error_once!("Failed to rasterize SVG document {ent_path:?}: {err}");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl IdentifiedViewSystem for SvgOverlaysVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "SvgOverlays".into()
+    }
+}
+
+impl VisualizerSystem for SvgOverlaysVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<SvgOverlay>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: ApplicableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_2d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        query: &ViewQuery<'_>,
+        view_ctx: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, SpaceViewSystemExecutionError> {
+        let mut rectangles = Vec::new();
+
+        process_archetype_views::<
+            SvgOverlaysVisualizer,
+            SvgOverlay,
+            { SvgOverlay::NUM_COMPONENTS },
+            _,
+        >(
+            ctx,
+            query,
+            view_ctx,
+            view_ctx.get::<EntityDepthOffsets>()?.box2d,
+            |ctx, ent_path, _ent_props, arch_view, ent_context| {
+                self.process_arch_view(ctx, &mut rectangles, &arch_view, ent_path, ent_context)
+            },
+        )?;
+
+        if rectangles.is_empty() {
+            return Ok(Vec::new());
+        }
+        match re_renderer::renderer::RectangleDrawData::new(ctx.render_ctx, &rectangles) {
+            Ok(draw_data) => Ok(vec![draw_data.into()]),
+            Err(err) => {
+                re_log::error_once!(
+                    "Failed to create rectangle draw data from SVG documents: {err}"
+                );
+                Ok(Vec::new())
+            }
+        }
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.0.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,246 @@
+use re_entity_db::{EntityPath, InstancePathHash};
+use re_types::{
+    archetypes::Text2D,
+    components::{Color, FontSize, InstanceKey, Position2D, Text, TextAnchor, TextWrapWidth},
+};
+use re_viewer_context::{
+    ApplicableEntities, IdentifiedViewSystem, ResolvedAnnotationInfos,
+    SpaceViewSystemExecutionError, ViewContextCollection, ViewQuery, ViewerContext,
+    VisualizableEntities, VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem,
+};
+
+use super::{apply_entity_alpha, process_color_slice, SpatialViewVisualizerData};
+use crate::{
+    contexts::{EntityDepthOffsets, SpatialSceneEntityContext},
+    view_kind::SpatialSpaceViewKind,
+    visualizers::{filter_visualizable_2d_entities, UiLabel, UiLabelTarget},
+};
+
+/// Shows standalone texts, drawn like the labels of other primitives.
+pub struct Texts2DVisualizer {
+    pub data: SpatialViewVisualizerData,
+}
+
+impl Default for Texts2DVisualizer {
+    fn default() -> Self {
+        Self {
+            data: SpatialViewVisualizerData::new(Some(SpatialSpaceViewKind::TwoD)),
+        }
+    }
+}
+
+impl Texts2DVisualizer {
+    fn process_data(
+        &mut self,
+        data: &Texts2DComponentData<'_>,
+        ent_path: &EntityPath,
+        ent_context: &SpatialSceneEntityContext<'_>,
+    ) {
+        let Some(texts) = data.texts else {
+            return;
+        };
+
+        let annotation_infos =
+            ResolvedAnnotationInfos::Same(data.positions.len(), Default::default());
+        let mut colors = process_color_slice(data.colors, ent_path, &annotation_infos);
+        apply_entity_alpha(&mut colors, ent_context.alpha);
+
+        let font_sizes = || {
+            data.font_sizes.map_or_else(
+                || itertools::Either::Left(std::iter::repeat(None)),
+                |font_sizes| itertools::Either::Right(font_sizes.iter().copied()),
+            )
+        };
+        let anchors = || {
+            data.anchors.map_or_else(
+                || itertools::Either::Left(std::iter::repeat(None)),
+                |anchors| itertools::Either::Right(anchors.iter().copied()),
+            )
+        };
+
+        let wrap_widths = || {
+            data.wrap_widths.map_or_else(
+                || itertools::Either::Left(std::iter::repeat(None)),
+                |wrap_widths| itertools::Either::Right(wrap_widths.iter().copied()),
+            )
+        };
+
+        let mut bounding_box = macaw::BoundingBox::nothing();
+
+        for (instance_key, position, text, color, font_size, anchor, wrap_width) in itertools::izip!(
+            data.instance_keys,
+            data.positions,
+            texts,
+            colors,
+            font_sizes(),
+            anchors(),
+            wrap_widths()
+        ) {
+            let position = glam::Vec2::from(*position).extend(0.0);
+            bounding_box.extend(position);
+
+            let Some(text) = text else {
+                continue;
+            };
+            let instance = InstancePathHash::instance(ent_path, *instance_key);
+            self.data.add_instance_bounding_box(
+                instance,
+                macaw::BoundingBox::from_points(std::iter::once(position)),
+                ent_context.world_from_entity,
+            );
+
+            let position_in_world = ent_context.world_from_entity.transform_point3(position);
+            // Widths in scene units are given in the entity's space, but labels are placed in world space.
+            let wrap_width = wrap_width.map(|wrap_width| match wrap_width.scene() {
+                Some(width) => TextWrapWidth::new_scene(
+                    ent_context
+                        .world_from_entity
+                        .transform_vector3(glam::Vec3::X * width)
+                        .length(),
+                ),
+                None => wrap_width,
+            });
+            self.data.ui_labels.push(UiLabel {
+                text: text.as_str().to_owned(),
+                color,
+                target: UiLabelTarget::Text2D {
+                    pos: egui::pos2(position_in_world.x, position_in_world.y),
+                    anchor: text_anchor_align(anchor.unwrap_or_default()),
+                    font_size: font_size.map(|font_size| font_size.0),
+                    wrap_width,
+                },
+                labeled_instance: instance,
+            });
+        }
+
+        self.data
+            .add_bounding_box(ent_path.hash(), bounding_box, ent_context.world_from_entity);
+    }
+}
+
+/// The egui alignment closest to the given [`TextAnchor`].
+fn text_anchor_align(anchor: TextAnchor) -> egui::Align2 {
+    let align = |value: f32| {
+        if value < -0.5 {
+            egui::Align::Min
+        } else if value > 0.5 {
+            egui::Align::Max
+        } else {
+            egui::Align::Center
+        }
+    };
+    egui::Align2([align(anchor.x()), align(anchor.y())])
+}
+
+// ---
+
+struct Texts2DComponentData<'a> {
+    pub instance_keys: &'a [InstanceKey],
+    pub positions: &'a [Position2D],
+    pub texts: Option<&'a [Option<Text>]>,
+    pub colors: Option<&'a [Option<Color>]>,
+    pub font_sizes: Option<&'a [Option<FontSize>]>,
+    pub anchors: Option<&'a [Option<TextAnchor>]>,
+    pub wrap_widths: Option<&'a [Option<TextWrapWidth>]>,
+}
+
+impl IdentifiedViewSystem for Texts2DVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "Texts2D".into()
+    }
+}
+
+impl VisualizerSystem for Texts2DVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Text2D>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: ApplicableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_2d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        query: &ViewQuery<'_>,
+        view_ctx: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, SpaceViewSystemExecutionError> {
+        super::entity_iterator::process_archetype_pov1_comp5::<
+            Texts2DVisualizer,
+            Text2D,
+            Position2D,
+            Text,
+            Color,
+            FontSize,
+            TextAnchor,
+            TextWrapWidth,
+            _,
+        >(
+            ctx,
+            query,
+            view_ctx,
+            view_ctx.get::<EntityDepthOffsets>()?.points,
+            |_ctx,
+             ent_path,
+             _ent_props,
+             ent_context,
+             (_time, _row_id),
+             instance_keys,
+             positions,
+             texts,
+             colors,
+             font_sizes,
+             anchors,
+             wrap_widths| {
+                let data = Texts2DComponentData {
+                    instance_keys,
+                    positions,
+                    texts,
+                    colors,
+                    font_sizes,
+                    anchors,
+                    wrap_widths,
+                };
+                self.process_data(&data, ent_path, ent_context);
+                Ok(())
+            },
+        )?;
+
+        // Texts are drawn by egui on top of the scene, there's nothing for the renderer to do.
+        Ok(Vec::new())
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.data.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_anchor_to_align() {
+        assert_eq!(
+            text_anchor_align(TextAnchor::TOP_LEFT),
+            egui::Align2::LEFT_TOP
+        );
+        assert_eq!(
+            text_anchor_align(TextAnchor::CENTER),
+            egui::Align2::CENTER_CENTER
+        );
+        assert_eq!(
+            text_anchor_align(TextAnchor::new(0.9, -0.2)),
+            egui::Align2::RIGHT_CENTER
+        );
+    }
+}
@@ -58,6 +58,9 @@ pub struct Boxes2D {
     /// Optional center positions of the boxes.
     pub centers: Option<Vec<crate::components::Position2D>>,
 
+    /// Optional rotations of the boxes around their centers.
+    pub rotations: Option<Vec<crate::components::Rotation2D>>,
+
     /// Optional colors for the boxes.
     pub colors: Option<Vec<crate::components::Color>>,
 
@@ -67,6 +70,9 @@ pub struct Boxes2D {
     /// Optional text labels for the boxes.
     pub labels: Option<Vec<crate::components::Text>>,
 
+    /// Optional tooltips for the boxes, shown when hovering them instead of their instance paths.
+    pub tooltips: Option<Vec<crate::components::Tooltip>>,
+
     /// An optional floating point value that specifies the 2D drawing order.
     ///
     /// Objects with higher values are drawn on top of those with lower values.
@@ -88,9 +94,11 @@ impl ::re_types_core::SizeBytes for Boxes2D {
     fn heap_size_bytes(&self) -> u64 {
         self.half_sizes.heap_size_bytes()
             + self.centers.heap_size_bytes()
+            + self.rotations.heap_size_bytes()
             + self.colors.heap_size_bytes()
             + self.radii.heap_size_bytes()
             + self.labels.heap_size_bytes()
+            + self.tooltips.heap_size_bytes()
             + self.draw_order.heap_size_bytes()
             + self.class_ids.heap_size_bytes()
             + self.instance_keys.heap_size_bytes()
@@ -100,9 +108,11 @@ impl ::re_types_core::SizeBytes for Boxes2D {
     fn is_pod() -> bool {
         <Vec<crate::components::HalfSizes2D>>::is_pod()
             && <Option<Vec<crate::components::Position2D>>>::is_pod()
+            && <Option<Vec<crate::components::Rotation2D>>>::is_pod()
             && <Option<Vec<crate::components::Color>>>::is_pod()
             && <Option<Vec<crate::components::Radius>>>::is_pod()
             && <Option<Vec<crate::components::Text>>>::is_pod()
+            && <Option<Vec<crate::components::Tooltip>>>::is_pod()
             && <Option<crate::components::DrawOrder>>::is_pod()
             && <Option<Vec<crate::components::ClassId>>>::is_pod()
             && <Option<Vec<crate::components::InstanceKey>>>::is_pod()
@@ -112,16 +122,17 @@ impl ::re_types_core::SizeBytes for Boxes2D {
 static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 1usize]> =
     once_cell::sync::Lazy::new(|| ["rerun.components.HalfSizes2D".into()]);
 
-static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 3usize]> =
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 4usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             "rerun.components.Boxes2DIndicator".into(),
             "rerun.components.Color".into(),
             "rerun.components.Position2D".into(),
+            "rerun.components.Rotation2D".into(),
         ]
     });
 
-static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 5usize]> =
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 6usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             "rerun.components.ClassId".into(),
@@ -129,26 +140,29 @@ static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 5usize]> =
             "rerun.components.InstanceKey".into(),
             "rerun.components.Radius".into(),
             "rerun.components.Text".into(),
+            "rerun.components.Tooltip".into(),
         ]
     });
 
-static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 9usize]> =
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 11usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             "rerun.components.HalfSizes2D".into(),
             "rerun.components.Boxes2DIndicator".into(),
             "rerun.components.Color".into(),
             "rerun.components.Position2D".into(),
+            "rerun.components.Rotation2D".into(),
             "rerun.components.ClassId".into(),
             "rerun.components.DrawOrder".into(),
             "rerun.components.InstanceKey".into(),
             "rerun.components.Radius".into(),
             "rerun.components.Text".into(),
+            "rerun.components.Tooltip".into(),
         ]
     });
 
 impl Boxes2D {
-    pub const NUM_COMPONENTS: usize = 9usize;
+    pub const NUM_COMPONENTS: usize = 11usize;
 }
 
 /// Indicator component for the [`Boxes2D`] [`::re_types_core::Archetype`]
@@ -222,6 +236,18 @@ impl ::re_types_core::Archetype for Boxes2D {
         } else {
             None
         };
+        let rotations = if let Some(array) = arrays_by_name.get("rerun.components.Rotation2D") {
+            Some({
+                <crate::components::Rotation2D>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.Boxes2D#rotations")?
+                    .into_iter()
+                    .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                    .collect::<DeserializationResult<Vec<_>>>()
+                    .with_context("rerun.archetypes.Boxes2D#rotations")?
+            })
+        } else {
+            None
+        };
         let colors = if let Some(array) = arrays_by_name.get("rerun.components.Color") {
             Some({
                 <crate::components::Color>::from_arrow_opt(&**array)
@@ -258,6 +284,18 @@ impl ::re_types_core::Archetype for Boxes2D {
         } else {
             None
         };
+        let tooltips = if let Some(array) = arrays_by_name.get("rerun.components.Tooltip") {
+            Some({
+                <crate::components::Tooltip>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.Boxes2D#tooltips")?
+                    .into_iter()
+                    .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                    .collect::<DeserializationResult<Vec<_>>>()
+                    .with_context("rerun.archetypes.Boxes2D#tooltips")?
+            })
+        } else {
+            None
+        };
         let draw_order = if let Some(array) = arrays_by_name.get("rerun.components.DrawOrder") {
             <crate::components::DrawOrder>::from_arrow_opt(&**array)
                 .with_context("rerun.archetypes.Boxes2D#draw_order")?
@@ -295,9 +333,11 @@ impl ::re_types_core::Archetype for Boxes2D {
         Ok(Self {
             half_sizes,
             centers,
+            rotations,
             colors,
             radii,
             labels,
+            tooltips,
             draw_order,
             class_ids,
             instance_keys,
@@ -315,6 +355,9 @@ impl ::re_types_core::AsComponents for Boxes2D {
             self.centers
                 .as_ref()
                 .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
+            self.rotations
+                .as_ref()
+                .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
             self.colors
                 .as_ref()
                 .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
@@ -324,6 +367,9 @@ impl ::re_types_core::AsComponents for Boxes2D {
             self.labels
                 .as_ref()
                 .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
+            self.tooltips
+                .as_ref()
+                .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
             self.draw_order
                 .as_ref()
                 .map(|comp| (comp as &dyn ComponentBatch).into()),
@@ -352,9 +398,11 @@ impl Boxes2D {
         Self {
             half_sizes: half_sizes.into_iter().map(Into::into).collect(),
             centers: None,
+            rotations: None,
             colors: None,
             radii: None,
             labels: None,
+            tooltips: None,
             draw_order: None,
             class_ids: None,
             instance_keys: None,
@@ -370,6 +418,15 @@ impl Boxes2D {
         self
     }
 
+    #[inline]
+    pub fn with_rotations(
+        mut self,
+        rotations: impl IntoIterator<Item = impl Into<crate::components::Rotation2D>>,
+    ) -> Self {
+        self.rotations = Some(rotations.into_iter().map(Into::into).collect());
+        self
+    }
+
     #[inline]
     pub fn with_colors(
         mut self,
@@ -397,6 +454,15 @@ impl Boxes2D {
         self
     }
 
+    #[inline]
+    pub fn with_tooltips(
+        mut self,
+        tooltips: impl IntoIterator<Item = impl Into<crate::components::Tooltip>>,
+    ) -> Self {
+        self.tooltips = Some(tooltips.into_iter().map(Into::into).collect());
+        self
+    }
+
     #[inline]
     pub fn with_draw_order(mut self, draw_order: impl Into<crate::components::DrawOrder>) -> Self {
         self.draw_order = Some(draw_order.into());
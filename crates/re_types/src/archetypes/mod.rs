@@ -26,13 +26,17 @@ mod pinhole_ext;
 mod points2d;
 mod points3d;
 mod points3d_ext;
+mod polygons2d;
 mod scalar;
 mod segmentation_image;
 mod segmentation_image_ext;
 mod series_line;
 mod series_point;
+mod svg_overlay;
+mod svg_overlay_ext;
 mod tensor;
 mod tensor_ext;
+mod text2d;
 mod text_document;
 mod text_document_ext;
 mod text_log;
@@ -58,11 +62,14 @@ pub use self::mesh3d::Mesh3D;
 pub use self::pinhole::Pinhole;
 pub use self::points2d::Points2D;
 pub use self::points3d::Points3D;
+pub use self::polygons2d::Polygons2D;
 pub use self::scalar::Scalar;
 pub use self::segmentation_image::SegmentationImage;
 pub use self::series_line::SeriesLine;
 pub use self::series_point::SeriesPoint;
+pub use self::svg_overlay::SvgOverlay;
 pub use self::tensor::Tensor;
+pub use self::text2d::Text2D;
 pub use self::text_document::TextDocument;
 pub use self::text_log::TextLog;
 pub use self::transform3d::Transform3D;
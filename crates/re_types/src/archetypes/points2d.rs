@@ -71,6 +71,9 @@ pub struct Points2D {
     /// Optional text labels for the points.
     pub labels: Option<Vec<crate::components::Text>>,
 
+    /// Optional tooltips for the points, shown when hovering them instead of their instance paths.
+    pub tooltips: Option<Vec<crate::components::Tooltip>>,
+
     /// An optional floating point value that specifies the 2D drawing order.
     ///
     /// Objects with higher values are drawn on top of those with lower values.
@@ -102,6 +105,7 @@ impl ::re_types_core::SizeBytes for Points2D {
             + self.radii.heap_size_bytes()
             + self.colors.heap_size_bytes()
             + self.labels.heap_size_bytes()
+            + self.tooltips.heap_size_bytes()
             + self.draw_order.heap_size_bytes()
             + self.class_ids.heap_size_bytes()
             + self.keypoint_ids.heap_size_bytes()
@@ -114,6 +118,7 @@ impl ::re_types_core::SizeBytes for Points2D {
             && <Option<Vec<crate::components::Radius>>>::is_pod()
             && <Option<Vec<crate::components::Color>>>::is_pod()
             && <Option<Vec<crate::components::Text>>>::is_pod()
+            && <Option<Vec<crate::components::Tooltip>>>::is_pod()
             && <Option<crate::components::DrawOrder>>::is_pod()
             && <Option<Vec<crate::components::ClassId>>>::is_pod()
             && <Option<Vec<crate::components::KeypointId>>>::is_pod()
@@ -133,7 +138,7 @@ static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 3usize]> =
         ]
     });
 
-static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 5usize]> =
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 6usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             "rerun.components.ClassId".into(),
@@ -141,10 +146,11 @@ static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 5usize]> =
             "rerun.components.InstanceKey".into(),
             "rerun.components.KeypointId".into(),
             "rerun.components.Text".into(),
+            "rerun.components.Tooltip".into(),
         ]
     });
 
-static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 9usize]> =
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 10usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             "rerun.components.Position2D".into(),
@@ -156,11 +162,12 @@ static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 9usize]> =
             "rerun.components.InstanceKey".into(),
             "rerun.components.KeypointId".into(),
             "rerun.components.Text".into(),
+            "rerun.components.Tooltip".into(),
         ]
     });
 
 impl Points2D {
-    pub const NUM_COMPONENTS: usize = 9usize;
+    pub const NUM_COMPONENTS: usize = 10usize;
 }
 
 /// Indicator component for the [`Points2D`] [`::re_types_core::Archetype`]
@@ -258,6 +265,18 @@ impl ::re_types_core::Archetype for Points2D {
         } else {
             None
         };
+        let tooltips = if let Some(array) = arrays_by_name.get("rerun.components.Tooltip") {
+            Some({
+                <crate::components::Tooltip>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.Points2D#tooltips")?
+                    .into_iter()
+                    .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                    .collect::<DeserializationResult<Vec<_>>>()
+                    .with_context("rerun.archetypes.Points2D#tooltips")?
+            })
+        } else {
+            None
+        };
         let draw_order = if let Some(array) = arrays_by_name.get("rerun.components.DrawOrder") {
             <crate::components::DrawOrder>::from_arrow_opt(&**array)
                 .with_context("rerun.archetypes.Points2D#draw_order")?
@@ -309,6 +328,7 @@ impl ::re_types_core::Archetype for Points2D {
             radii,
             colors,
             labels,
+            tooltips,
             draw_order,
             class_ids,
             keypoint_ids,
@@ -333,6 +353,9 @@ impl ::re_types_core::AsComponents for Points2D {
             self.labels
                 .as_ref()
                 .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
+            self.tooltips
+                .as_ref()
+                .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
             self.draw_order
                 .as_ref()
                 .map(|comp| (comp as &dyn ComponentBatch).into()),
@@ -366,6 +389,7 @@ impl Points2D {
             radii: None,
             colors: None,
             labels: None,
+            tooltips: None,
             draw_order: None,
             class_ids: None,
             keypoint_ids: None,
@@ -400,6 +424,15 @@ impl Points2D {
         self
     }
 
+    #[inline]
+    pub fn with_tooltips(
+        mut self,
+        tooltips: impl IntoIterator<Item = impl Into<crate::components::Tooltip>>,
+    ) -> Self {
+        self.tooltips = Some(tooltips.into_iter().map(Into::into).collect());
+        self
+    }
+
     #[inline]
     pub fn with_draw_order(mut self, draw_order: impl Into<crate::components::DrawOrder>) -> Self {
         self.draw_order = Some(draw_order.into());
@@ -0,0 +1,210 @@
+// DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/re_types/definitions/rerun/archetypes/svg_overlay.fbs".
+
+#![allow(trivial_numeric_casts)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::iter_on_single_items)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::match_wildcard_for_single_variants)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::unnecessary_cast)]
+
+use ::re_types_core::external::arrow2;
+use ::re_types_core::ComponentName;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, MaybeOwnedComponentBatch};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A 2D vector graphic in SVG format, e.g. an annotation overlay exported from a drawing tool.
+///
+/// One SVG user unit maps to one unit of the entity's space.
+/// The graphic is rasterized at the resolution it is shown at.
+///
+/// ## Example
+///
+/// ### Simple SVG overlay
+/// ```ignore
+/// const SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="3">
+///   <rect x="0.5" y="0.5" width="3" height="2" fill="none" stroke="red" stroke-width="0.1"/>
+///   <circle cx="2" cy="1.5" r="0.5" fill="blue"/>
+/// </svg>"#;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let rec = rerun::RecordingStreamBuilder::new("rerun_example_svg_overlay").spawn()?;
+///
+///     rec.log("overlay", &rerun::SvgOverlay::new(SVG.as_bytes().to_vec()))?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgOverlay {
+    /// The bytes of the SVG document.
+    pub blob: crate::components::Blob,
+
+    /// An out-of-tree transform.
+    ///
+    /// Applies a transformation to the graphic itself without impacting its children.
+    pub transform: Option<crate::components::OutOfTreeTransform3D>,
+}
+
+impl ::re_types_core::SizeBytes for SvgOverlay {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.blob.heap_size_bytes() + self.transform.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::components::Blob>::is_pod()
+            && <Option<crate::components::OutOfTreeTransform3D>>::is_pod()
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 1usize]> =
+    once_cell::sync::Lazy::new(|| ["rerun.components.Blob".into()]);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 1usize]> =
+    once_cell::sync::Lazy::new(|| ["rerun.components.SvgOverlayIndicator".into()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            "rerun.components.InstanceKey".into(),
+            "rerun.components.OutOfTreeTransform3D".into(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 4usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            "rerun.components.Blob".into(),
+            "rerun.components.SvgOverlayIndicator".into(),
+            "rerun.components.InstanceKey".into(),
+            "rerun.components.OutOfTreeTransform3D".into(),
+        ]
+    });
+
+impl SvgOverlay {
+    pub const NUM_COMPONENTS: usize = 4usize;
+}
+
+/// Indicator component for the [`SvgOverlay`] [`::re_types_core::Archetype`]
+pub type SvgOverlayIndicator = ::re_types_core::GenericIndicatorComponent<SvgOverlay>;
+
+impl ::re_types_core::Archetype for SvgOverlay {
+    type Indicator = SvgOverlayIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.SvgOverlay".into()
+    }
+
+    #[inline]
+    fn indicator() -> MaybeOwnedComponentBatch<'static> {
+        static INDICATOR: SvgOverlayIndicator = SvgOverlayIndicator::DEFAULT;
+        MaybeOwnedComponentBatch::Ref(&INDICATOR)
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentName, Box<dyn arrow2::array::Array>)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_name: ::std::collections::HashMap<_, _> = arrow_data
+            .into_iter()
+            .map(|(name, array)| (name.full_name(), array))
+            .collect();
+        let blob = {
+            let array = arrays_by_name
+                .get("rerun.components.Blob")
+                .ok_or_else(DeserializationError::missing_data)
+                .with_context("rerun.archetypes.SvgOverlay#blob")?;
+            <crate::components::Blob>::from_arrow_opt(&**array)
+                .with_context("rerun.archetypes.SvgOverlay#blob")?
+                .into_iter()
+                .next()
+                .flatten()
+                .ok_or_else(DeserializationError::missing_data)
+                .with_context("rerun.archetypes.SvgOverlay#blob")?
+        };
+        let transform =
+            if let Some(array) = arrays_by_name.get("rerun.components.OutOfTreeTransform3D") {
+                <crate::components::OutOfTreeTransform3D>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.SvgOverlay#transform")?
+                    .into_iter()
+                    .next()
+                    .flatten()
+            } else {
+                None
+            };
+        Ok(Self { blob, transform })
+    }
+}
+
+impl ::re_types_core::AsComponents for SvgOverlay {
+    fn as_component_batches(&self) -> Vec<MaybeOwnedComponentBatch<'_>> {
+        re_tracing::profile_function!();
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            Some((&self.blob as &dyn ComponentBatch).into()),
+            self.transform
+                .as_ref()
+                .map(|comp| (comp as &dyn ComponentBatch).into()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    #[inline]
+    fn num_instances(&self) -> usize {
+        1
+    }
+}
+
+impl SvgOverlay {
+    pub fn new(blob: impl Into<crate::components::Blob>) -> Self {
+        Self {
+            blob: blob.into(),
+            transform: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_transform(
+        mut self,
+        transform: impl Into<crate::components::OutOfTreeTransform3D>,
+    ) -> Self {
+        self.transform = Some(transform.into());
+        self
+    }
+}
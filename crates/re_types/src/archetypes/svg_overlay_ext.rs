@@ -0,0 +1,14 @@
+use super::SvgOverlay;
+
+impl SvgOverlay {
+    /// Creates a new [`SvgOverlay`] from the contents of the SVG file at `path`.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    pub fn from_file(filepath: impl AsRef<std::path::Path>) -> anyhow::Result<Self> {
+        use anyhow::Context as _;
+        let filepath = filepath.as_ref();
+        let contents = std::fs::read(filepath)
+            .with_context(|| format!("could not read file contents: {filepath:?}"))?;
+        Ok(Self::new(contents))
+    }
+}
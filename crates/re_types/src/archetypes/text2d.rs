@@ -0,0 +1,390 @@
+// DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/re_types/definitions/rerun/archetypes/text2d.fbs".
+
+#![allow(trivial_numeric_casts)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::iter_on_single_items)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::match_wildcard_for_single_variants)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::unnecessary_cast)]
+
+use ::re_types_core::external::arrow2;
+use ::re_types_core::ComponentName;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, MaybeOwnedComponentBatch};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Standalone 2D text annotations, e.g. timestamps, event markers or calibration printouts.
+///
+/// Unlike labels, the texts don't annotate any other geometry.
+///
+/// ## Example
+///
+/// ### Simple 2D texts
+/// ```ignore
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let rec = rerun::RecordingStreamBuilder::new("rerun_example_text2d").spawn()?;
+///
+///     rec.log(
+///         "texts",
+///         &rerun::Text2D::new([(0.0, 0.0), (2.0, 1.0)], ["origin", "t = 1.5 s"])
+///             .with_colors([[255, 0, 0], [0, 0, 255]])
+///             .with_font_sizes([14.0, 24.0]),
+///     )?;
+///
+///     // Log an extra rect to set the view bounds
+///     rec.log(
+///         "bounds",
+///         &rerun::Boxes2D::from_centers_and_half_sizes([(1.0, 0.5)], [(2.0, 1.5)]),
+///     )?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Text2D {
+    /// The 2D positions at which the texts are placed.
+    pub positions: Vec<crate::components::Position2D>,
+
+    /// The texts to show.
+    pub texts: Vec<crate::components::Text>,
+
+    /// Optional colors for the texts.
+    pub colors: Option<Vec<crate::components::Color>>,
+
+    /// Optional font sizes for the texts, in ui points.
+    ///
+    /// The size of the viewer's body text is used if not specified.
+    pub font_sizes: Option<Vec<crate::components::FontSize>>,
+
+    /// Optional anchors of the texts, i.e. which point of each text is placed at its position.
+    ///
+    /// Texts are centered on their positions if not specified.
+    pub anchors: Option<Vec<crate::components::TextAnchor>>,
+
+    /// Optional widths at which the texts are wrapped onto multiple lines.
+    ///
+    /// Texts that don't fit into a few lines of that width are cut short.
+    /// Texts are not wrapped if not specified.
+    pub wrap_widths: Option<Vec<crate::components::TextWrapWidth>>,
+
+    /// Unique identifiers for each individual text in the batch.
+    pub instance_keys: Option<Vec<crate::components::InstanceKey>>,
+}
+
+impl ::re_types_core::SizeBytes for Text2D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.positions.heap_size_bytes()
+            + self.texts.heap_size_bytes()
+            + self.colors.heap_size_bytes()
+            + self.font_sizes.heap_size_bytes()
+            + self.anchors.heap_size_bytes()
+            + self.wrap_widths.heap_size_bytes()
+            + self.instance_keys.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <Vec<crate::components::Position2D>>::is_pod()
+            && <Vec<crate::components::Text>>::is_pod()
+            && <Option<Vec<crate::components::Color>>>::is_pod()
+            && <Option<Vec<crate::components::FontSize>>>::is_pod()
+            && <Option<Vec<crate::components::TextAnchor>>>::is_pod()
+            && <Option<Vec<crate::components::TextWrapWidth>>>::is_pod()
+            && <Option<Vec<crate::components::InstanceKey>>>::is_pod()
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            "rerun.components.Position2D".into(),
+            "rerun.components.Text".into(),
+        ]
+    });
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            "rerun.components.Color".into(),
+            "rerun.components.Text2DIndicator".into(),
+        ]
+    });
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 4usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            "rerun.components.FontSize".into(),
+            "rerun.components.InstanceKey".into(),
+            "rerun.components.TextAnchor".into(),
+            "rerun.components.TextWrapWidth".into(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 8usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            "rerun.components.Position2D".into(),
+            "rerun.components.Text".into(),
+            "rerun.components.Color".into(),
+            "rerun.components.Text2DIndicator".into(),
+            "rerun.components.FontSize".into(),
+            "rerun.components.InstanceKey".into(),
+            "rerun.components.TextAnchor".into(),
+            "rerun.components.TextWrapWidth".into(),
+        ]
+    });
+
+impl Text2D {
+    pub const NUM_COMPONENTS: usize = 8usize;
+}
+
+/// Indicator component for the [`Text2D`] [`::re_types_core::Archetype`]
+pub type Text2DIndicator = ::re_types_core::GenericIndicatorComponent<Text2D>;
+
+impl ::re_types_core::Archetype for Text2D {
+    type Indicator = Text2DIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.Text2D".into()
+    }
+
+    #[inline]
+    fn indicator() -> MaybeOwnedComponentBatch<'static> {
+        static INDICATOR: Text2DIndicator = Text2DIndicator::DEFAULT;
+        MaybeOwnedComponentBatch::Ref(&INDICATOR)
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentName, Box<dyn arrow2::array::Array>)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_name: ::std::collections::HashMap<_, _> = arrow_data
+            .into_iter()
+            .map(|(name, array)| (name.full_name(), array))
+            .collect();
+        let positions = {
+            let array = arrays_by_name
+                .get("rerun.components.Position2D")
+                .ok_or_else(DeserializationError::missing_data)
+                .with_context("rerun.archetypes.Text2D#positions")?;
+            <crate::components::Position2D>::from_arrow_opt(&**array)
+                .with_context("rerun.archetypes.Text2D#positions")?
+                .into_iter()
+                .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                .collect::<DeserializationResult<Vec<_>>>()
+                .with_context("rerun.archetypes.Text2D#positions")?
+        };
+        let texts = {
+            let array = arrays_by_name
+                .get("rerun.components.Text")
+                .ok_or_else(DeserializationError::missing_data)
+                .with_context("rerun.archetypes.Text2D#texts")?;
+            <crate::components::Text>::from_arrow_opt(&**array)
+                .with_context("rerun.archetypes.Text2D#texts")?
+                .into_iter()
+                .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                .collect::<DeserializationResult<Vec<_>>>()
+                .with_context("rerun.archetypes.Text2D#texts")?
+        };
+        let colors = if let Some(array) = arrays_by_name.get("rerun.components.Color") {
+            Some({
+                <crate::components::Color>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.Text2D#colors")?
+                    .into_iter()
+                    .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                    .collect::<DeserializationResult<Vec<_>>>()
+                    .with_context("rerun.archetypes.Text2D#colors")?
+            })
+        } else {
+            None
+        };
+        let font_sizes = if let Some(array) = arrays_by_name.get("rerun.components.FontSize") {
+            Some({
+                <crate::components::FontSize>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.Text2D#font_sizes")?
+                    .into_iter()
+                    .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                    .collect::<DeserializationResult<Vec<_>>>()
+                    .with_context("rerun.archetypes.Text2D#font_sizes")?
+            })
+        } else {
+            None
+        };
+        let anchors = if let Some(array) = arrays_by_name.get("rerun.components.TextAnchor") {
+            Some({
+                <crate::components::TextAnchor>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.Text2D#anchors")?
+                    .into_iter()
+                    .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                    .collect::<DeserializationResult<Vec<_>>>()
+                    .with_context("rerun.archetypes.Text2D#anchors")?
+            })
+        } else {
+            None
+        };
+        let wrap_widths = if let Some(array) = arrays_by_name.get("rerun.components.TextWrapWidth")
+        {
+            Some({
+                <crate::components::TextWrapWidth>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.Text2D#wrap_widths")?
+                    .into_iter()
+                    .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                    .collect::<DeserializationResult<Vec<_>>>()
+                    .with_context("rerun.archetypes.Text2D#wrap_widths")?
+            })
+        } else {
+            None
+        };
+        let instance_keys = if let Some(array) = arrays_by_name.get("rerun.components.InstanceKey")
+        {
+            Some({
+                <crate::components::InstanceKey>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.Text2D#instance_keys")?
+                    .into_iter()
+                    .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                    .collect::<DeserializationResult<Vec<_>>>()
+                    .with_context("rerun.archetypes.Text2D#instance_keys")?
+            })
+        } else {
+            None
+        };
+        Ok(Self {
+            positions,
+            texts,
+            colors,
+            font_sizes,
+            anchors,
+            wrap_widths,
+            instance_keys,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for Text2D {
+    fn as_component_batches(&self) -> Vec<MaybeOwnedComponentBatch<'_>> {
+        re_tracing::profile_function!();
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            Some((&self.positions as &dyn ComponentBatch).into()),
+            Some((&self.texts as &dyn ComponentBatch).into()),
+            self.colors
+                .as_ref()
+                .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
+            self.font_sizes
+                .as_ref()
+                .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
+            self.anchors
+                .as_ref()
+                .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
+            self.wrap_widths
+                .as_ref()
+                .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
+            self.instance_keys
+                .as_ref()
+                .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    #[inline]
+    fn num_instances(&self) -> usize {
+        self.positions.len()
+    }
+}
+
+impl Text2D {
+    pub fn new(
+        positions: impl IntoIterator<Item = impl Into<crate::components::Position2D>>,
+        texts: impl IntoIterator<Item = impl Into<crate::components::Text>>,
+    ) -> Self {
+        Self {
+            positions: positions.into_iter().map(Into::into).collect(),
+            texts: texts.into_iter().map(Into::into).collect(),
+            colors: None,
+            font_sizes: None,
+            anchors: None,
+            wrap_widths: None,
+            instance_keys: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_colors(
+        mut self,
+        colors: impl IntoIterator<Item = impl Into<crate::components::Color>>,
+    ) -> Self {
+        self.colors = Some(colors.into_iter().map(Into::into).collect());
+        self
+    }
+
+    #[inline]
+    pub fn with_font_sizes(
+        mut self,
+        font_sizes: impl IntoIterator<Item = impl Into<crate::components::FontSize>>,
+    ) -> Self {
+        self.font_sizes = Some(font_sizes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    #[inline]
+    pub fn with_anchors(
+        mut self,
+        anchors: impl IntoIterator<Item = impl Into<crate::components::TextAnchor>>,
+    ) -> Self {
+        self.anchors = Some(anchors.into_iter().map(Into::into).collect());
+        self
+    }
+
+    #[inline]
+    pub fn with_wrap_widths(
+        mut self,
+        wrap_widths: impl IntoIterator<Item = impl Into<crate::components::TextWrapWidth>>,
+    ) -> Self {
+        self.wrap_widths = Some(wrap_widths.into_iter().map(Into::into).collect());
+        self
+    }
+
+    #[inline]
+    pub fn with_instance_keys(
+        mut self,
+        instance_keys: impl IntoIterator<Item = impl Into<crate::components::InstanceKey>>,
+    ) -> Self {
+        self.instance_keys = Some(instance_keys.into_iter().map(Into::into).collect());
+        self
+    }
+}
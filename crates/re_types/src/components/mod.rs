@@ -13,6 +13,7 @@ mod disconnected_space;
 mod disconnected_space_ext;
 mod draw_order;
 mod draw_order_ext;
+mod font_size;
 mod half_sizes2d;
 mod half_sizes2d_ext;
 mod half_sizes3d;
@@ -45,6 +46,7 @@ mod radius;
 mod radius_ext;
 mod range1d;
 mod resolution;
+mod rotation2d;
 mod rotation3d;
 mod rotation3d_ext;
 mod scalar;
@@ -55,9 +57,15 @@ mod tensor_data;
 mod texcoord2d;
 mod texcoord2d_ext;
 mod text;
+mod text_anchor;
+mod text_anchor_ext;
 mod text_ext;
 mod text_log_level;
 mod text_log_level_ext;
+mod text_wrap_width;
+mod text_wrap_width_ext;
+mod tooltip;
+mod tooltip_ext;
 mod transform3d;
 mod transform3d_ext;
 mod vector2d;
@@ -74,6 +82,7 @@ pub use self::color::Color;
 pub use self::depth_meter::DepthMeter;
 pub use self::disconnected_space::DisconnectedSpace;
 pub use self::draw_order::DrawOrder;
+pub use self::font_size::FontSize;
 pub use self::half_sizes2d::HalfSizes2D;
 pub use self::half_sizes3d::HalfSizes3D;
 pub use self::keypoint_id::KeypointId;
@@ -92,6 +101,7 @@ pub use self::position3d::Position3D;
 pub use self::radius::Radius;
 pub use self::range1d::Range1D;
 pub use self::resolution::Resolution;
+pub use self::rotation2d::Rotation2D;
 pub use self::rotation3d::Rotation3D;
 pub use self::scalar::Scalar;
 pub use self::scalar_scattering::ScalarScattering;
@@ -99,7 +109,10 @@ pub use self::stroke_width::StrokeWidth;
 pub use self::tensor_data::TensorData;
 pub use self::texcoord2d::Texcoord2D;
 pub use self::text::Text;
+pub use self::text_anchor::TextAnchor;
 pub use self::text_log_level::TextLogLevel;
+pub use self::text_wrap_width::TextWrapWidth;
+pub use self::tooltip::Tooltip;
 pub use self::transform3d::Transform3D;
 pub use self::vector2d::Vector2D;
 pub use self::vector3d::Vector3D;
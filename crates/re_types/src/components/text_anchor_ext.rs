@@ -0,0 +1,33 @@
+use crate::datatypes::Vec2D;
+
+use super::TextAnchor;
+
+// ---
+
+impl TextAnchor {
+    pub const TOP_LEFT: Self = Self::new(-1.0, -1.0);
+    pub const CENTER: Self = Self::new(0.0, 0.0);
+    pub const BOTTOM_RIGHT: Self = Self::new(1.0, 1.0);
+
+    #[inline]
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self(Vec2D::new(x, y))
+    }
+
+    #[inline]
+    pub fn x(&self) -> f32 {
+        self.0.x()
+    }
+
+    #[inline]
+    pub fn y(&self) -> f32 {
+        self.0.y()
+    }
+}
+
+impl Default for TextAnchor {
+    #[inline]
+    fn default() -> Self {
+        Self::CENTER
+    }
+}
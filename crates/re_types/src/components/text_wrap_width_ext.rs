@@ -0,0 +1,27 @@
+use super::TextWrapWidth;
+
+impl TextWrapWidth {
+    /// Wrap at a width in scene units.
+    #[inline]
+    pub fn new_scene(width: f32) -> Self {
+        Self(width.abs())
+    }
+
+    /// Wrap at a width in ui points.
+    #[inline]
+    pub fn new_points(width: f32) -> Self {
+        Self(-width.abs())
+    }
+
+    /// The wrap width in scene units, if positive.
+    #[inline]
+    pub fn scene(&self) -> Option<f32> {
+        (self.0 > 0.0).then_some(self.0)
+    }
+
+    /// The wrap width in ui points, if negative.
+    #[inline]
+    pub fn points(&self) -> Option<f32> {
+        (self.0 < 0.0).then_some(-self.0)
+    }
+}
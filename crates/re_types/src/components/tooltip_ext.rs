@@ -0,0 +1,30 @@
+use super::Tooltip;
+
+// TODO(#4536): These should come for free
+impl Tooltip {
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl From<Tooltip> for String {
+    #[inline]
+    fn from(value: Tooltip) -> Self {
+        value.as_str().to_owned()
+    }
+}
+
+impl AsRef<str> for Tooltip {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::borrow::Borrow<str> for Tooltip {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
@@ -3,6 +3,8 @@ use crate::tensor_data::{TensorCastError, TensorDataType, TensorElement};
 #[cfg(feature = "image")]
 use crate::tensor_data::{DecodedTensor, TensorImageLoadError, TensorImageSaveError};
 
+use re_types_core::ArrowBuffer;
+
 use super::{TensorBuffer, TensorData, TensorDimension};
 
 // Much of the following duplicates code from: `crates/re_components/src/tensor.rs`, which
@@ -119,6 +121,46 @@ impl TensorData {
         }
     }
 
+    /// Assembles a `[height, width, 3]` image from three channels of a `[height, width, channels]` tensor.
+    ///
+    /// Useful for tensors with more channels than an image can have, e.g. hyperspectral images.
+    /// Returns `None` if the tensor isn't shaped like this, a channel is out of bounds,
+    /// or the tensor is encoded.
+    pub fn select_image_channels(&self, channels: [u64; 3]) -> Option<Self> {
+        let [height, width, num_channels] = self.shape_short() else {
+            return None;
+        };
+        if channels.iter().any(|&channel| channel >= num_channels.size) {
+            return None;
+        }
+
+        fn select<T: Copy>(buf: &[T], num_channels: u64, channels: [u64; 3]) -> ArrowBuffer<T> {
+            buf.chunks_exact(num_channels as usize)
+                .flat_map(|pixel| channels.map(|channel| pixel[channel as usize]))
+                .collect()
+        }
+
+        let buffer = match &self.buffer {
+            TensorBuffer::U8(buf) => TensorBuffer::U8(select(buf, num_channels.size, channels)),
+            TensorBuffer::U16(buf) => TensorBuffer::U16(select(buf, num_channels.size, channels)),
+            TensorBuffer::U32(buf) => TensorBuffer::U32(select(buf, num_channels.size, channels)),
+            TensorBuffer::U64(buf) => TensorBuffer::U64(select(buf, num_channels.size, channels)),
+            TensorBuffer::I8(buf) => TensorBuffer::I8(select(buf, num_channels.size, channels)),
+            TensorBuffer::I16(buf) => TensorBuffer::I16(select(buf, num_channels.size, channels)),
+            TensorBuffer::I32(buf) => TensorBuffer::I32(select(buf, num_channels.size, channels)),
+            TensorBuffer::I64(buf) => TensorBuffer::I64(select(buf, num_channels.size, channels)),
+            TensorBuffer::F16(buf) => TensorBuffer::F16(select(buf, num_channels.size, channels)),
+            TensorBuffer::F32(buf) => TensorBuffer::F32(select(buf, num_channels.size, channels)),
+            TensorBuffer::F64(buf) => TensorBuffer::F64(select(buf, num_channels.size, channels)),
+            TensorBuffer::Jpeg(_) | TensorBuffer::Nv12(_) | TensorBuffer::Yuy2(_) => return None,
+        };
+
+        Some(Self::new(
+            vec![height.clone(), width.clone(), TensorDimension::depth(3)],
+            buffer,
+        ))
+    }
+
     /// Query with x, y, channel indices.
     ///
     /// Allows to query values for any image like tensor even if it has more or less dimensions than 3.
@@ -230,6 +272,25 @@ impl TensorData {
         }
     }
 
+    /// Values of all channels of the pixel at the given image coordinates.
+    ///
+    /// NV12 and YUY2 images are decoded to RGB8.
+    pub fn get_pixel_with_image_coords(&self, x: u64, y: u64) -> Option<Vec<TensorElement>> {
+        match &self.buffer {
+            TensorBuffer::Nv12(_) => Some(self.get_nv12_pixel(x, y)?.to_vec()),
+            TensorBuffer::Yuy2(_) => Some(self.get_yuy2_pixel(x, y)?.to_vec()),
+            _ => {
+                let num_channels = match self.shape_short() {
+                    [_, _, channels] => channels.size,
+                    _ => 1,
+                };
+                (0..num_channels)
+                    .map(|channel| self.get_with_image_coords(x, y, channel))
+                    .collect()
+            }
+        }
+    }
+
     /// Returns decoded RGB8 value at the given image coordinates if this tensor is a valid NV12 image.
     pub fn get_nv12_pixel(&self, x: u64, y: u64) -> Option<[TensorElement; 3]> {
         let TensorBuffer::Nv12(buf) = &self.buffer else {
@@ -320,6 +381,27 @@ impl TensorData {
     pub fn size_in_bytes(&self) -> usize {
         self.buffer.size_in_bytes()
     }
+
+    /// Calls `f` with every value of the tensor in buffer order, converted to `f64`.
+    ///
+    /// Returns `false` without calling `f` for encoded tensors.
+    pub fn for_each_value_as_f64(&self, mut f: impl FnMut(f64)) -> bool {
+        match &self.buffer {
+            TensorBuffer::U8(buf) => buf.iter().for_each(|&v| f(v as f64)),
+            TensorBuffer::U16(buf) => buf.iter().for_each(|&v| f(v as f64)),
+            TensorBuffer::U32(buf) => buf.iter().for_each(|&v| f(v as f64)),
+            TensorBuffer::U64(buf) => buf.iter().for_each(|&v| f(v as f64)),
+            TensorBuffer::I8(buf) => buf.iter().for_each(|&v| f(v as f64)),
+            TensorBuffer::I16(buf) => buf.iter().for_each(|&v| f(v as f64)),
+            TensorBuffer::I32(buf) => buf.iter().for_each(|&v| f(v as f64)),
+            TensorBuffer::I64(buf) => buf.iter().for_each(|&v| f(v as f64)),
+            TensorBuffer::F16(buf) => buf.iter().for_each(|v| f(v.to_f32() as f64)),
+            TensorBuffer::F32(buf) => buf.iter().for_each(|&v| f(v as f64)),
+            TensorBuffer::F64(buf) => buf.iter().for_each(|&v| f(v)),
+            TensorBuffer::Jpeg(_) | TensorBuffer::Nv12(_) | TensorBuffer::Yuy2(_) => return false,
+        }
+        true
+    }
 }
 
 // ----------------------------------------------------------------------------
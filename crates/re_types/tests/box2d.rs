@@ -13,6 +13,10 @@ fn roundtrip() {
             components::Position2D::new(1.0, 2.0), //
             components::Position2D::new(3.0, 4.0),
         ]),
+        rotations: Some(vec![
+            components::Rotation2D(0.5), //
+            components::Rotation2D(-1.0),
+        ]),
         colors: Some(vec![
             components::Color::from_unmultiplied_rgba(0xAA, 0x00, 0x00, 0xCC), //
             components::Color::from_unmultiplied_rgba(0x00, 0xBB, 0x00, 0xDD),
@@ -25,6 +29,10 @@ fn roundtrip() {
             "hello".into(),  //
             "friend".into(), //
         ]),
+        tooltips: Some(vec![
+            "**score:** 0.9".into(), //
+            "*occluded*".into(),     //
+        ]),
         draw_order: Some(components::DrawOrder(300.0)),
         class_ids: Some(vec![
             components::ClassId::from(126), //
@@ -38,9 +46,11 @@ fn roundtrip() {
 
     let arch = Boxes2D::from_half_sizes([(1.0, 2.0), (3.0, 4.0)])
         .with_centers([(1.0, 2.0), (3.0, 4.0)])
+        .with_rotations([0.5, -1.0])
         .with_colors([0xAA0000CC, 0x00BB00DD])
         .with_radii([42.0, 43.0])
         .with_labels(["hello", "friend"])
+        .with_tooltips(["**score:** 0.9", "*occluded*"])
         .with_draw_order(300.0)
         .with_class_ids([126, 127])
         .with_instance_keys([u64::MAX - 1, u64::MAX]);
@@ -49,9 +59,11 @@ fn roundtrip() {
     let expected_extensions: HashMap<_, _> = [
         ("half_sizes", vec!["rerun.components.HalfSize2D"]),
         ("centers", vec!["rerun.components.Position2D"]),
+        ("rotations", vec!["rerun.components.Rotation2D"]),
         ("colors", vec!["rerun.components.Color"]),
         ("radii", vec!["rerun.components.Radius"]),
         ("labels", vec!["rerun.components.Label"]),
+        ("tooltips", vec!["rerun.components.Tooltip"]),
         ("draw_order", vec!["rerun.components.DrawOrder"]),
         ("class_ids", vec!["rerun.components.ClassId"]),
         ("instance_keys", vec!["rerun.components.InstanceKey"]),
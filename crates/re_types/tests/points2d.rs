@@ -21,6 +21,10 @@ fn roundtrip() {
             "hello".into(),  //
             "friend".into(), //
         ]),
+        tooltips: Some(vec![
+            "**score:** 0.9".into(), //
+            "*occluded*".into(),     //
+        ]),
         draw_order: Some(components::DrawOrder(300.0)),
         class_ids: Some(vec![
             components::ClassId::from(126), //
@@ -40,6 +44,7 @@ fn roundtrip() {
         .with_radii([42.0, 43.0])
         .with_colors([0xAA0000CC, 0x00BB00DD])
         .with_labels(["hello", "friend"])
+        .with_tooltips(["**score:** 0.9", "*occluded*"])
         .with_draw_order(300.0)
         .with_class_ids([126, 127])
         .with_keypoint_ids([2, 3])
@@ -51,6 +56,7 @@ fn roundtrip() {
         ("radii", vec!["rerun.components.Radius"]),
         ("colors", vec!["rerun.components.Color"]),
         ("labels", vec!["rerun.components.Label"]),
+        ("tooltips", vec!["rerun.components.Tooltip"]),
         ("draw_order", vec!["rerun.components.DrawOrder"]),
         ("class_ids", vec!["rerun.components.ClassId"]),
         ("keypoint_ids", vec!["rerun.components.KeypointId"]),
@@ -0,0 +1,61 @@
+use re_types::{
+    archetypes::Polygons2D,
+    components::{ClassId, Color, DrawOrder, InstanceKey, LineStrip2D, Radius},
+    Archetype as _, AsComponents as _,
+};
+
+#[test]
+fn roundtrip() {
+    let expected = Polygons2D {
+        polygons: vec![
+            LineStrip2D::from_iter([[0., 0.], [4., 0.], [2., 3.]]), //
+            LineStrip2D::from_iter([[5., 0.], [8., 0.], [8., 2.], [5., 2.]]), //
+        ],
+        radii: Some(vec![
+            Radius(42.0), //
+            Radius(43.0),
+        ]),
+        colors: Some(vec![
+            Color::from_unmultiplied_rgba(0xAA, 0x00, 0x00, 0xCC), //
+            Color::from_unmultiplied_rgba(0x00, 0xBB, 0x00, 0xDD),
+        ]),
+        labels: Some(vec![
+            "hello".into(),  //
+            "friend".into(), //
+        ]),
+        draw_order: Some(DrawOrder(300.0)),
+        class_ids: Some(vec![
+            ClassId::from(126), //
+            ClassId::from(127), //
+        ]),
+        instance_keys: Some(vec![
+            InstanceKey(u64::MAX - 1), //
+            InstanceKey(u64::MAX),
+        ]),
+    };
+
+    let polygons = [
+        [[0., 0.], [4., 0.], [2., 3.]].to_vec(),
+        [[5., 0.], [8., 0.], [8., 2.], [5., 2.]].to_vec(),
+    ];
+    let arch = Polygons2D::new(polygons)
+        .with_radii([42.0, 43.0])
+        .with_colors([0xAA0000CC, 0x00BB00DD])
+        .with_labels(["hello", "friend"])
+        .with_draw_order(300.0)
+        .with_class_ids([126, 127])
+        .with_instance_keys([u64::MAX - 1, u64::MAX]);
+    similar_asserts::assert_eq!(expected, arch);
+
+    eprintln!("arch = {arch:#?}");
+    let serialized = arch.to_arrow().unwrap();
+    for (field, array) in &serialized {
+        // NOTE: Keep those around please, very useful when debugging.
+        // eprintln!("field = {field:#?}");
+        // eprintln!("array = {array:#?}");
+        eprintln!("{} = {array:#?}", field.name);
+    }
+
+    let deserialized = Polygons2D::from_arrow(serialized).unwrap();
+    similar_asserts::assert_eq!(expected, deserialized);
+}
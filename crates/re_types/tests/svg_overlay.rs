@@ -0,0 +1,38 @@
+use re_types::{
+    archetypes::SvgOverlay,
+    components::{Blob, OutOfTreeTransform3D},
+    datatypes::{Scale3D, Transform3D, TranslationRotationScale3D, Vec3D},
+    Archetype as _, AsComponents as _,
+};
+
+#[test]
+fn roundtrip() {
+    const BYTES: &[u8] = br#"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="2"/>"#;
+
+    let transform = Transform3D::TranslationRotationScale(TranslationRotationScale3D {
+        translation: Some(Vec3D([1.0, 2.0, 0.0])),
+        rotation: None,
+        scale: Some(Scale3D::Uniform(0.5)),
+        from_parent: false,
+    });
+
+    let expected = SvgOverlay {
+        blob: Blob(BYTES.to_vec().into()),
+        transform: Some(OutOfTreeTransform3D(transform)), //
+    };
+
+    let arch = SvgOverlay::new(BYTES.to_vec()).with_transform(transform);
+    similar_asserts::assert_eq!(expected, arch);
+
+    eprintln!("arch = {arch:#?}");
+    let serialized = arch.to_arrow().unwrap();
+    for (field, array) in &serialized {
+        // NOTE: Keep those around please, very useful when debugging.
+        // eprintln!("field = {field:#?}");
+        // eprintln!("array = {array:#?}");
+        eprintln!("{} = {array:#?}", field.name);
+    }
+
+    let deserialized = SvgOverlay::from_arrow(serialized).unwrap();
+    similar_asserts::assert_eq!(expected, deserialized);
+}
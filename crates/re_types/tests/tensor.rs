@@ -200,3 +200,24 @@ fn check_tensor_type_error() {
 
     assert_eq!(n, Err(TensorCastError::TypeMismatch));
 }
+
+#[test]
+fn select_image_channels() {
+    let t = TensorData::new(
+        vec![
+            TensorDimension::height(1),
+            TensorDimension::width(2),
+            TensorDimension::depth(5),
+        ],
+        TensorBuffer::U16((0..10).collect::<Vec<u16>>().into()),
+    );
+
+    let selected = t.select_image_channels([4, 0, 2]).unwrap();
+    assert_eq!(selected.image_height_width_channels(), Some([1, 2, 3]));
+    assert_eq!(
+        selected.buffer,
+        TensorBuffer::U16(vec![4, 0, 2, 9, 5, 7].into())
+    );
+
+    assert!(t.select_image_channels([0, 1, 5]).is_none());
+}
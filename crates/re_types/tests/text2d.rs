@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use re_types::{archetypes::Text2D, components, Archetype as _, AsComponents as _};
+
+#[test]
+fn roundtrip() {
+    let expected = Text2D {
+        positions: vec![
+            components::Position2D::new(1.0, 2.0), //
+            components::Position2D::new(3.0, 4.0),
+        ],
+        texts: vec![
+            "hello".into(),  //
+            "friend".into(), //
+        ],
+        colors: Some(vec![
+            components::Color::from_unmultiplied_rgba(0xAA, 0x00, 0x00, 0xCC), //
+            components::Color::from_unmultiplied_rgba(0x00, 0xBB, 0x00, 0xDD),
+        ]),
+        font_sizes: Some(vec![
+            components::FontSize(12.0), //
+            components::FontSize(24.0),
+        ]),
+        anchors: Some(vec![
+            components::TextAnchor::TOP_LEFT, //
+            components::TextAnchor::new(1.0, 0.0),
+        ]),
+        wrap_widths: Some(vec![
+            components::TextWrapWidth::new_scene(100.0), //
+            components::TextWrapWidth::new_points(200.0),
+        ]),
+        instance_keys: Some(vec![
+            components::InstanceKey(u64::MAX - 1), //
+            components::InstanceKey(u64::MAX),
+        ]),
+    };
+
+    let arch = Text2D::new([(1.0, 2.0), (3.0, 4.0)], ["hello", "friend"])
+        .with_colors([0xAA0000CC, 0x00BB00DD])
+        .with_font_sizes([12.0, 24.0])
+        .with_anchors([(-1.0, -1.0), (1.0, 0.0)])
+        .with_wrap_widths([100.0, -200.0])
+        .with_instance_keys([u64::MAX - 1, u64::MAX]);
+    similar_asserts::assert_eq!(expected, arch);
+
+    let expected_extensions: HashMap<_, _> = [
+        ("positions", vec!["rerun.components.Position2D"]),
+        ("texts", vec!["rerun.components.Text"]),
+        ("colors", vec!["rerun.components.Color"]),
+        ("font_sizes", vec!["rerun.components.FontSize"]),
+        ("anchors", vec!["rerun.components.TextAnchor"]),
+        ("wrap_widths", vec!["rerun.components.TextWrapWidth"]),
+        ("instance_keys", vec!["rerun.components.InstanceKey"]),
+    ]
+    .into();
+
+    eprintln!("arch = {arch:#?}");
+    let serialized = arch.to_arrow().unwrap();
+    for (field, array) in &serialized {
+        // NOTE: Keep those around please, very useful when debugging.
+        // eprintln!("field = {field:#?}");
+        // eprintln!("array = {array:#?}");
+        eprintln!("{} = {array:#?}", field.name);
+
+        // TODO(cmc): Re-enable extensions and these assertions once `arrow2-convert`
+        // has been fully replaced.
+        if false {
+            util::assert_extensions(
+                &**array,
+                expected_extensions[field.name.as_str()].as_slice(),
+            );
+        }
+    }
+
+    let deserialized = Text2D::from_arrow(serialized).unwrap();
+    similar_asserts::assert_eq!(expected, deserialized);
+}
+
+mod util;
@@ -1,13 +1,13 @@
 use re_data_source::{DataSource, FileContents};
-use re_entity_db::entity_db::EntityDb;
+use re_entity_db::{entity_db::EntityDb, EntityPath};
 use re_log_types::{FileSource, LogMsg, StoreKind};
 use re_renderer::WgpuResourcePoolStatistics;
 use re_smart_channel::{ReceiveSet, SmartChannelSource};
 use re_ui::{toasts, UICommand, UICommandSender};
 use re_viewer_context::{
-    command_channel, AppOptions, CommandReceiver, CommandSender, ComponentUiRegistry,
-    DynSpaceViewClass, PlayState, SpaceViewClassRegistry, SpaceViewClassRegistryError,
-    StoreContext, SystemCommand, SystemCommandSender,
+    command_channel, AppOptions, Click2DCallback, CommandReceiver, CommandSender,
+    ComponentUiRegistry, DynSpaceViewClass, PlayState, SpaceViewClassRegistry,
+    SpaceViewClassRegistryError, StoreContext, SystemCommand, SystemCommandSender,
 };
 
 use crate::{
@@ -142,6 +142,9 @@ pub struct App {
 
     /// All known space view types.
     space_view_class_registry: SpaceViewClassRegistry,
+
+    /// Called on clicks on empty space in 2D views.
+    on_2d_click_callbacks: Vec<Click2DCallback>,
 }
 
 impl App {
@@ -249,6 +252,7 @@ impl App {
             cmd_palette: Default::default(),
 
             space_view_class_registry,
+            on_2d_click_callbacks: Vec::new(),
 
             analytics,
         }
@@ -303,6 +307,17 @@ impl App {
         self.space_view_class_registry.add_class::<T>()
     }
 
+    /// Registers a callback for primary clicks on empty space in 2D views.
+    ///
+    /// The callback gets the origin of the view's space and the clicked position in the
+    /// coordinates of that space. Clicks that select something don't fire it.
+    pub fn add_2d_click_callback(
+        &mut self,
+        callback: impl Fn(&EntityPath, egui::Pos2) + Send + Sync + 'static,
+    ) {
+        self.on_2d_click_callbacks.push(Box::new(callback));
+    }
+
     fn check_keyboard_shortcuts(&self, egui_ctx: &egui::Context) {
         if let Some(cmd) = UICommand::listen_for_kb_shortcut(egui_ctx) {
             self.command_sender.send_ui(cmd);
@@ -397,6 +412,14 @@ impl App {
                     }
                 }
             }
+            SystemCommand::UpdateRecording(store_id, rows) => {
+                let entity_db = store_hub.entity_db_mut(&store_id);
+                for row in rows {
+                    if let Err(err) = entity_db.add_data_row(row) {
+                        re_log::warn_once!("Failed to add data to recording: {err}");
+                    }
+                }
+            }
             #[cfg(debug_assertions)]
             SystemCommand::EnableInspectBlueprintTimeline(show) => {
                 self.app_options_mut().inspect_blueprint_timeline = show;
@@ -876,6 +899,7 @@ impl App {
                             &self.space_view_class_registry,
                             &self.rx,
                             &self.command_sender,
+                            &self.on_2d_click_callbacks,
                         );
 
                         render_ctx.before_submit();
@@ -6,9 +6,9 @@ use re_log_types::{LogMsg, StoreId, TimeRangeF};
 use re_smart_channel::ReceiveSet;
 use re_space_view::{determine_visualizable_entities, DataQuery as _, PropertyResolver as _};
 use re_viewer_context::{
-    blueprint_timeline, AppOptions, ApplicationSelectionState, Caches, CommandSender,
-    ComponentUiRegistry, PlayState, RecordingConfig, SpaceViewClassRegistry, StoreContext,
-    SystemCommandSender as _, ViewerContext,
+    blueprint_timeline, AppOptions, ApplicationSelectionState, Caches, Click2DCallback,
+    CommandSender, ComponentUiRegistry, PlayState, RecordingConfig, SpaceViewClassRegistry,
+    StoreContext, SystemCommandSender as _, ViewerContext,
 };
 use re_viewport::{Viewport, ViewportBlueprint, ViewportState};
 
@@ -117,6 +117,7 @@ impl AppState {
         space_view_class_registry: &SpaceViewClassRegistry,
         rx: &ReceiveSet<LogMsg>,
         command_sender: &CommandSender,
+        on_2d_click_callbacks: &[Click2DCallback],
     ) {
         re_tracing::profile_function!();
 
@@ -233,6 +234,7 @@ impl AppState {
             render_ctx,
             command_sender,
             focused_item,
+            on_2d_click_callbacks,
         };
 
         // First update the viewport and thus all active space views.
@@ -291,6 +293,7 @@ impl AppState {
             render_ctx,
             command_sender,
             focused_item,
+            on_2d_click_callbacks,
         };
 
         if app_options.inspect_blueprint_timeline {
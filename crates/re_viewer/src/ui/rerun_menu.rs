@@ -4,7 +4,7 @@ use egui::NumExt as _;
 
 use re_log_types::TimeZone;
 use re_ui::{ReUi, UICommand};
-use re_viewer_context::{StoreContext, SystemCommand, SystemCommandSender};
+use re_viewer_context::{DepthUnit, StoreContext, SystemCommand, SystemCommandSender};
 
 use crate::App;
 
@@ -326,6 +326,87 @@ fn options_menu_ui(
             .on_hover_text("Display timestamps in the local timezone");
     });
 
+    ui.horizontal(|ui| {
+        ui.label("Depth unit:");
+        for depth_unit in DepthUnit::ALL {
+            re_ui.radio_value(
+                ui,
+                &mut app_options.depth_unit,
+                depth_unit,
+                depth_unit.suffix(),
+            );
+        }
+    })
+    .response
+    .on_hover_text("Unit depth values are shown in");
+
+    {
+        ui.add_space(SPACING);
+        ui.label("2D views:");
+        re_ui
+            .checkbox(ui, &mut app_options.invert_zoom_2d, "Invert zoom direction")
+            .on_hover_text("Scroll up to zoom out instead of in");
+        re_ui
+            .checkbox(
+                ui,
+                &mut app_options.zoom_2d_requires_modifier,
+                "Hold modifier to zoom",
+            )
+            .on_hover_text(
+                "If enabled, scrolling pans and scrolling while holding the zoom modifier zooms.\n\
+                If disabled, scrolling always zooms.",
+            );
+        ui.horizontal(|ui| {
+            let mut lod_enabled = app_options.points_2d_lod_threshold.is_some();
+            re_ui
+                .checkbox(ui, &mut lod_enabled, "Subsample point clouds above")
+                .on_hover_text(
+                    "Draw at most one point per screen pixel for point clouds \
+                    with more visible points than this",
+                );
+            let mut threshold = app_options.points_2d_lod_threshold.unwrap_or(100_000);
+            ui.add_enabled(
+                lod_enabled,
+                egui::DragValue::new(&mut threshold)
+                    .clamp_range(1_000..=100_000_000)
+                    .speed(1_000.0),
+            );
+            app_options.points_2d_lod_threshold = lod_enabled.then_some(threshold);
+        });
+        ui.horizontal(|ui| {
+            ui.label("Hover tooltip delay:");
+            ui.add(
+                egui::DragValue::new(&mut app_options.hover_tooltip_delay)
+                    .clamp_range(0.0..=5.0)
+                    .speed(0.01)
+                    .suffix(" s"),
+            );
+        })
+        .response
+        .on_hover_text("How long the pointer has to rest on an entity before its tooltip appears");
+        ui.horizontal(|ui| {
+            ui.label("Hover tooltip max size:");
+            ui.add(
+                egui::DragValue::new(&mut app_options.hover_tooltip_max_width)
+                    .clamp_range(100.0..=2000.0)
+                    .suffix(" pt"),
+            );
+            ui.label("×");
+            let mut limit_height = app_options.hover_tooltip_max_height.is_some();
+            re_ui.checkbox(ui, &mut limit_height, "");
+            let mut max_height = app_options.hover_tooltip_max_height.unwrap_or(400.0);
+            ui.add_enabled(
+                limit_height,
+                egui::DragValue::new(&mut max_height)
+                    .clamp_range(100.0..=2000.0)
+                    .suffix(" pt"),
+            );
+            app_options.hover_tooltip_max_height = limit_height.then_some(max_height);
+        })
+        .response
+        .on_hover_text("Maximum width and, if enabled, height of hover tooltips");
+    }
+
     {
         ui.add_space(SPACING);
         ui.label("Experimental features:");
@@ -472,6 +553,11 @@ fn debug_menu_options_ui(
         "Picking Debug Overlay",
     ).on_hover_text("Show a debug overlay that renders the picking layer information using the `debug_overlay.wgsl` shader.");
 
+    re_ui.checkbox(ui,
+        &mut app_options.show_2d_data_density_overlay,
+        "2D Data Density Overlay",
+    ).on_hover_text("Show how many images, boxes, lines and points each 2D view draws, how many it culled and the size of its point & line buffers.");
+
     ui.menu_button("Crash", |ui| {
         #[allow(clippy::manual_assert)]
         if ui.button("panic!").clicked() {
@@ -2,12 +2,13 @@ use egui::{NumExt as _, Ui};
 
 use re_data_ui::{image_meaning_for_entity, item_ui, DataUi};
 use re_entity_db::{
-    ColorMapper, Colormap, EditableAutoValue, EntityPath, EntityProperties, InstancePath,
+    AutoLabelMode, BoxAutoLabel, ColorMapper, Colormap, EditableAutoValue, EntityPath,
+    EntityProperties, InstancePath, NormalizationMode,
 };
 use re_log_types::{DataRow, EntityPathFilter, RowId};
 use re_space_view_time_series::TimeSeriesSpaceView;
 use re_types::{
-    components::{PinholeProjection, Transform3D},
+    components::{PinholeProjection, TensorData, Transform3D},
     tensor_data::TensorDataMeaning,
 };
 use re_types_core::components::InstanceKey;
@@ -1140,6 +1141,38 @@ The last rule matching `/world/house` is `+ /world/**`, so it is included.
     }
 }
 
+fn box_auto_label_ui(ui: &mut egui::Ui, auto_label: &mut BoxAutoLabel) {
+    ui.horizontal(|ui| {
+        ui.label("Box labels")
+            .on_hover_text("Label generated for 2D boxes that were logged without a label");
+        egui::ComboBox::from_id_source("box_auto_label")
+            .selected_text(auto_label.mode.to_string())
+            .show_ui(ui, |ui| {
+                for mode in [
+                    AutoLabelMode::None,
+                    AutoLabelMode::Dimensions,
+                    AutoLabelMode::Area,
+                ] {
+                    ui.selectable_value(&mut auto_label.mode, mode, mode.to_string());
+                }
+            });
+
+        if auto_label.mode != AutoLabelMode::None {
+            ui.add(
+                egui::DragValue::new(&mut auto_label.decimals)
+                    .clamp_range(0..=6)
+                    .suffix(" decimals"),
+            );
+            ui.add(
+                egui::TextEdit::singleline(&mut auto_label.unit_suffix)
+                    .hint_text("unit")
+                    .desired_width(40.0),
+            )
+            .on_hover_text("Unit appended to the numbers, e.g. m or px");
+        }
+    });
+}
+
 fn entity_props_ui(
     ctx: &ViewerContext<'_>,
     ui: &mut egui::Ui,
@@ -1153,6 +1186,117 @@ fn entity_props_ui(
     re_ui
         .checkbox(ui, &mut entity_props.interactive, "Interactive")
         .on_hover_text("If disabled, the entity will not react to any mouse interaction");
+    ui.horizontal(|ui| {
+        ui.label("Alpha");
+        ui.add(egui::Slider::new(&mut entity_props.alpha, 0..=255))
+            .on_hover_text("Opacity of points, lines & boxes of the entity");
+    });
+    ui.horizontal(|ui| {
+        let mut fill_boxes = entity_props.box_fill_color.is_some();
+        if re_ui
+            .checkbox(ui, &mut fill_boxes, "Fill boxes")
+            .on_hover_text("Fill the interior of 2D boxes with a color")
+            .changed()
+        {
+            entity_props.box_fill_color = fill_boxes.then_some([255, 255, 255, 64]);
+        }
+        if let Some(fill_color) = &mut entity_props.box_fill_color {
+            let [r, g, b, a] = *fill_color;
+            let mut color = egui::Color32::from_rgba_unmultiplied(r, g, b, a);
+            if egui::color_picker::color_edit_button_srgba(
+                ui,
+                &mut color,
+                egui::color_picker::Alpha::OnlyBlend,
+            )
+            .changed()
+            {
+                *fill_color = color.to_srgba_unmultiplied();
+            }
+        }
+    });
+    box_auto_label_ui(ui, &mut entity_props.box_auto_label);
+    ui.horizontal(|ui| {
+        let mut override_draw_order = entity_props.draw_order.is_some();
+        if re_ui
+            .checkbox(ui, &mut override_draw_order, "Draw order")
+            .on_hover_text(
+                "Override the draw order of the entity. \
+                Entities with a higher draw order are drawn on top in 2D views",
+            )
+            .changed()
+        {
+            entity_props.draw_order = override_draw_order.then_some(0.0);
+        }
+        if let Some(draw_order) = &mut entity_props.draw_order {
+            ui.add(egui::DragValue::new(draw_order).speed(0.1));
+        }
+    });
+    ui.horizontal(|ui| {
+        let mut preview_nms = entity_props.box_nms_iou_threshold.is_some();
+        if re_ui
+            .checkbox(ui, &mut preview_nms, "NMS preview")
+            .on_hover_text(
+                "Preview non-maximum suppression of overlapping 2D boxes. \
+                Boxes are assumed to be logged in order of descending score. \
+                Kept boxes are shown in green, the boxes they suppress in faded red",
+            )
+            .changed()
+        {
+            entity_props.box_nms_iou_threshold = preview_nms.then_some(0.5);
+        }
+        if let Some(iou_threshold) = &mut entity_props.box_nms_iou_threshold {
+            ui.add(egui::Slider::new(iou_threshold, 0.0..=1.0).text("IoU"));
+        }
+    });
+    ui.horizontal(|ui| {
+        let mut fill_polygons = entity_props.polygon_fill_opacity.is_some();
+        if re_ui
+            .checkbox(ui, &mut fill_polygons, "Fill polygons")
+            .on_hover_text(
+                "Fill the interior of 2D polygons with a translucent version of their color",
+            )
+            .changed()
+        {
+            entity_props.polygon_fill_opacity = fill_polygons.then_some(0.3);
+        }
+        if let Some(opacity) = &mut entity_props.polygon_fill_opacity {
+            ui.add(egui::Slider::new(opacity, 0.0..=1.0).text("Opacity"));
+        }
+    });
+    ui.horizontal(|ui| {
+        let mut fixed_arrow_heads = entity_props.arrow_head_size.is_some();
+        if re_ui
+            .checkbox(ui, &mut fixed_arrow_heads, "Fixed size arrowheads")
+            .on_hover_text(
+                "Draw the heads of 2D arrows with a fixed size on screen, \
+                so that they stay readable at any zoom level",
+            )
+            .changed()
+        {
+            entity_props.arrow_head_size = fixed_arrow_heads.then_some(8.0);
+        }
+        if let Some(arrow_head_size) = &mut entity_props.arrow_head_size {
+            ui.add(
+                egui::DragValue::new(arrow_head_size)
+                    .clamp_range(1.0..=64.0)
+                    .suffix(" pt"),
+            );
+        }
+    });
+
+    {
+        let mut connect_points = *entity_props.connect_points.get();
+        if re_ui
+            .checkbox(ui, &mut connect_points, "Connect points")
+            .on_hover_text(
+                "Connect the 2D points of the entity with lines in the order they were logged, \
+                e.g. to show a trajectory",
+            )
+            .changed()
+        {
+            entity_props.connect_points = EditableAutoValue::UserEdited(connect_points);
+        }
+    }
 
     visible_history_ui(
         ctx,
@@ -1172,6 +1316,9 @@ fn entity_props_ui(
             if let Some(entity_path) = entity_path {
                 pinhole_props_ui(ctx, ui, entity_path, entity_props);
                 depth_props_ui(ctx, ui, entity_path, entity_props);
+                image_channels_props_ui(ctx, ui, entity_path, entity_props);
+                image_normalization_props_ui(ctx, ui, entity_path, entity_props);
+                segmentation_opacity_props_ui(ctx, ui, entity_path, entity_props);
                 transform3d_visualization_ui(ctx, ui, entity_path, entity_props);
             }
         });
@@ -1236,6 +1383,153 @@ fn pinhole_props_ui(
     }
 }
 
+fn image_channels_props_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    entity_path: &EntityPath,
+    entity_props: &mut EntityProperties,
+) -> Option<()> {
+    let (query, store) = guess_query_and_store_for_selected_entity(ctx, entity_path);
+
+    if image_meaning_for_entity(entity_path, &query, store) != TensorDataMeaning::Unknown {
+        return Some(());
+    }
+    let tensor = store.query_latest_component::<TensorData>(entity_path, &query)?;
+    let [_, _, num_channels] = tensor.shape_short() else {
+        return Some(());
+    };
+    let max_channel = (num_channels.size as usize).checked_sub(1)?;
+
+    let mut select_channels = entity_props.channel_selector.is_some();
+    if ctx
+        .re_ui
+        .checkbox(ui, &mut select_channels, "Select channels")
+        .on_hover_text(
+            "Show three channels of the image as red, green and blue.\n\
+            Required for images with more than 4 channels.",
+        )
+        .changed()
+    {
+        entity_props.channel_selector =
+            select_channels.then(|| [0, 1, 2].map(|channel: usize| channel.min(max_channel)));
+    }
+    if let Some(channels) = &mut entity_props.channel_selector {
+        ui.horizontal(|ui| {
+            for (channel, name) in channels.iter_mut().zip(["R", "G", "B"]) {
+                ui.add(
+                    egui::DragValue::new(channel)
+                        .clamp_range(0..=max_channel)
+                        .prefix(format!("{name}: ")),
+                );
+            }
+        });
+    }
+    ui.end_row();
+
+    Some(())
+}
+
+fn image_normalization_props_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    entity_path: &EntityPath,
+    entity_props: &mut EntityProperties,
+) {
+    let (query, store) = guess_query_and_store_for_selected_entity(ctx, entity_path);
+
+    // Class ids are looked up in the annotation context, normalizing them makes no sense.
+    if store
+        .query_latest_component::<TensorData>(entity_path, &query)
+        .is_none()
+        || image_meaning_for_entity(entity_path, &query, store) == TensorDataMeaning::ClassId
+    {
+        return;
+    }
+
+    ui.label("Normalization");
+    ui.horizontal(|ui| {
+        let normalization = &mut entity_props.image_normalization;
+        egui::ComboBox::from_id_source("image_normalization")
+            .selected_text(match normalization {
+                NormalizationMode::None => "Automatic",
+                NormalizationMode::MinMax => "Min-max",
+                NormalizationMode::Percentile(..) => "Percentile",
+            })
+            .show_ui(ui, |ui| {
+                ui.selectable_value(normalization, NormalizationMode::None, "Automatic")
+                    .on_hover_text("Guess the range from the data type and values of the image");
+                ui.selectable_value(normalization, NormalizationMode::MinMax, "Min-max")
+                    .on_hover_text("Stretch the smallest to the largest value over the full range");
+                if ui
+                    .selectable_label(
+                        matches!(normalization, NormalizationMode::Percentile(..)),
+                        "Percentile",
+                    )
+                    .on_hover_text(
+                        "Stretch the values between two percentiles over the full range, \
+                        clamping outliers",
+                    )
+                    .clicked()
+                    && !matches!(normalization, NormalizationMode::Percentile(..))
+                {
+                    *normalization = NormalizationMode::DEFAULT_PERCENTILE;
+                }
+            });
+
+        if let NormalizationMode::Percentile(low, high) = normalization {
+            ui.add(
+                egui::DragValue::new(low)
+                    .clamp_range(0.0..=100.0)
+                    .speed(0.1)
+                    .suffix("%"),
+            )
+            .on_hover_text("Values below this percentile are clamped");
+            ui.add(
+                egui::DragValue::new(high)
+                    .clamp_range(0.0..=100.0)
+                    .speed(0.1)
+                    .suffix("%"),
+            )
+            .on_hover_text("Values above this percentile are clamped");
+        }
+    });
+    ui.end_row();
+}
+
+fn segmentation_opacity_props_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    entity_path: &EntityPath,
+    entity_props: &mut EntityProperties,
+) {
+    let (query, store) = guess_query_and_store_for_selected_entity(ctx, entity_path);
+
+    if store
+        .query_latest_component::<TensorData>(entity_path, &query)
+        .is_none()
+        || image_meaning_for_entity(entity_path, &query, store) != TensorDataMeaning::ClassId
+    {
+        return;
+    }
+
+    ui.label("Opacity");
+    ui.horizontal(|ui| {
+        let mut is_auto = entity_props.segmentation_opacity.is_none();
+        if ui
+            .checkbox(&mut is_auto, "Auto")
+            .on_hover_text("Share the opacity evenly between all images stacked in the same plane")
+            .changed()
+        {
+            entity_props.segmentation_opacity = if is_auto { None } else { Some(0.5) };
+        }
+        if let Some(opacity) = &mut entity_props.segmentation_opacity {
+            ui.add(egui::Slider::new(opacity, 0.0..=1.0))
+                .on_hover_text("Opacity of the segmentation image on top of other images");
+        }
+    });
+    ui.end_row();
+}
+
 fn depth_props_ui(
     ctx: &ViewerContext<'_>,
     ui: &mut egui::Ui,
@@ -1251,6 +1545,9 @@ fn depth_props_ui(
     if meaning != TensorDataMeaning::Depth {
         return Some(());
     }
+
+    colormap_props_ui(ctx, ui, entity_props);
+
     let image_projection_ent_path = store
         .query_latest_component_at_closest_ancestor::<PinholeProjection>(entity_path, &query)?
         .0;
@@ -1281,10 +1578,6 @@ fn depth_props_ui(
         depth_from_world_scale_ui(ui, &mut entity_props.depth_from_world_scale);
 
         backproject_radius_scale_ui(ui, &mut entity_props.backproject_radius_scale);
-
-        // TODO(cmc): This should apply to the depth map entity as a whole, but for that we
-        // need to get the current hardcoded colormapping out of the image cache first.
-        colormap_props_ui(ctx, ui, entity_props);
     }
 
     Some(())
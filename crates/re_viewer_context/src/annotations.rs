@@ -78,6 +78,11 @@ impl Annotations {
     pub fn row_id(&self) -> RowId {
         self.row_id
     }
+
+    /// Ids of all described classes, in no particular order.
+    pub fn class_ids(&self) -> impl Iterator<Item = ClassId> + '_ {
+        self.class_map.keys().copied()
+    }
 }
 
 #[derive(Clone, Debug)]
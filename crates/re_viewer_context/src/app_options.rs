@@ -22,9 +22,37 @@ pub struct AppOptions {
     /// Toggle query clamping for the plot visualizers.
     pub experimental_plot_query_clamping: bool,
 
+    /// Invert the direction in which scrolling zooms 2D views.
+    pub invert_zoom_2d: bool,
+
+    /// Require holding the zoom modifier to zoom 2D views by scrolling.
+    ///
+    /// If disabled, scrolling zooms and never pans.
+    pub zoom_2d_requires_modifier: bool,
+
+    /// Draw at most one point per screen pixel for 2D point clouds with more visible points than this.
+    ///
+    /// `None` always draws all points.
+    pub points_2d_lod_threshold: Option<usize>,
+
+    /// How long the pointer has to rest on an image or entity of a 2D or 3D view
+    /// before its hover tooltip appears, in seconds.
+    pub hover_tooltip_delay: f32,
+
+    /// Maximum width of hover tooltips in 2D and 3D views, in ui points.
+    pub hover_tooltip_max_width: f32,
+
+    /// Maximum height of hover tooltips in 2D and 3D views, in ui points.
+    ///
+    /// `None` doesn't limit the height.
+    pub hover_tooltip_max_height: Option<f32>,
+
     /// Displays an overlay for debugging picking.
     pub show_picking_debug_overlay: bool,
 
+    /// Displays how many objects 2D views draw and how many they culled.
+    pub show_2d_data_density_overlay: bool,
+
     /// Inspect the blueprint timeline.
     pub inspect_blueprint_timeline: bool,
 
@@ -34,6 +62,9 @@ pub struct AppOptions {
     /// What time zone to display timestamps in.
     #[serde(rename = "time_zone_for_timestamps")]
     pub time_zone: TimeZone,
+
+    /// What unit to display depth values in.
+    pub depth_unit: DepthUnit,
 }
 
 impl Default for AppOptions {
@@ -53,13 +84,95 @@ impl Default for AppOptions {
 
             experimental_plot_query_clamping: false,
 
+            invert_zoom_2d: false,
+
+            zoom_2d_requires_modifier: true,
+
+            points_2d_lod_threshold: None,
+
+            hover_tooltip_delay: 0.0,
+
+            hover_tooltip_max_width: 320.0,
+
+            hover_tooltip_max_height: None,
+
             show_picking_debug_overlay: false,
 
+            show_2d_data_density_overlay: false,
+
             inspect_blueprint_timeline: false,
 
             blueprint_gc: true,
 
             time_zone: TimeZone::Utc,
+
+            depth_unit: DepthUnit::Meters,
         }
     }
 }
+
+/// Unit depth values are displayed in.
+///
+/// Only affects presentation, depth values are always stored and computed in meters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum DepthUnit {
+    #[default]
+    Meters,
+    Centimeters,
+    Millimeters,
+}
+
+impl DepthUnit {
+    pub const ALL: [Self; 3] = [Self::Meters, Self::Centimeters, Self::Millimeters];
+
+    /// Short suffix of the unit, e.g. `mm`.
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Self::Meters => "m",
+            Self::Centimeters => "cm",
+            Self::Millimeters => "mm",
+        }
+    }
+
+    /// How many of this unit make up a meter.
+    pub fn per_meter(self) -> f64 {
+        match self {
+            Self::Meters => 1.0,
+            Self::Centimeters => 100.0,
+            Self::Millimeters => 1000.0,
+        }
+    }
+
+    /// Converts a depth given in meters to this unit.
+    pub fn convert(self, meters: f64) -> f64 {
+        meters * self.per_meter()
+    }
+
+    /// Formats a depth given in meters in this unit, with a precision of about a millimeter.
+    pub fn format(self, meters: f64) -> String {
+        let precision = match self {
+            Self::Meters => 3,
+            Self::Centimeters => 1,
+            Self::Millimeters => 0,
+        };
+        format!("{:.precision$} {}", self.convert(meters), self.suffix())
+    }
+}
+
+impl std::fmt::Display for DepthUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.suffix())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DepthUnit;
+
+    #[test]
+    fn format_depth() {
+        assert_eq!(DepthUnit::Meters.format(1.2346), "1.235 m");
+        assert_eq!(DepthUnit::Centimeters.format(1.2346), "123.5 cm");
+        assert_eq!(DepthUnit::Millimeters.format(1.2346), "1235 mm");
+    }
+}
@@ -33,6 +33,9 @@ pub enum SystemCommand {
     /// is both modified and changed in the same frame.
     UpdateBlueprint(StoreId, Vec<DataRow>),
 
+    /// Add data to a recording, e.g. annotations created interactively in a view.
+    UpdateRecording(StoreId, Vec<DataRow>),
+
     /// Show a timeline of the blueprint data.
     #[cfg(debug_assertions)]
     EnableInspectBlueprintTimeline(bool),
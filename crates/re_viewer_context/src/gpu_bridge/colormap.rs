@@ -59,6 +59,58 @@ fn colormap_preview_ui(
     Ok(response)
 }
 
+/// Paints a vertical bar showing the colors the given texture maps its range to, low values at the bottom.
+///
+/// Uses the color mapper and gamma of `colormapped_texture`, so the bar matches the texture exactly.
+pub fn paint_colormap_bar(
+    render_ctx: &re_renderer::RenderContext,
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    colormapped_texture: &re_renderer::renderer::ColormappedTexture,
+) -> anyhow::Result<()> {
+    re_tracing::profile_function!();
+
+    let vertical_gradient_id = egui::util::hash("vertical_gradient");
+    let vertical_gradient = get_or_create_texture(render_ctx, vertical_gradient_id, || {
+        let width = 1;
+        let height = 256;
+        let data: Vec<u8> = (0..height)
+            .flat_map(|y| {
+                let t = 1.0 - y as f32 / (height as f32 - 1.0);
+                half::f16::from_f32(t).to_le_bytes()
+            })
+            .collect();
+
+        re_renderer::resource_managers::Texture2DCreationDesc {
+            label: "vertical_gradient".into(),
+            data: data.into(),
+            format: wgpu::TextureFormat::R16Float,
+            width,
+            height,
+        }
+    })
+    .map_err(|err| anyhow::anyhow!("Failed to create vertical gradient texture: {err}"))?;
+
+    let gradient_texture = re_renderer::renderer::ColormappedTexture {
+        texture: vertical_gradient,
+        range: [0.0, 1.0],
+        decode_srgb: false,
+        multiply_rgb_with_alpha: false,
+        gamma: colormapped_texture.gamma,
+        shader_decoding: None,
+        color_mapper: colormapped_texture.color_mapper.clone(),
+    };
+
+    render_image(
+        render_ctx,
+        painter,
+        rect,
+        gradient_texture,
+        egui::TextureOptions::LINEAR,
+        "colormap_bar",
+    )
+}
+
 pub fn colormap_dropdown_button_ui(
     render_ctx: &re_renderer::RenderContext,
     ui: &mut egui::Ui,
@@ -4,11 +4,11 @@ mod colormap;
 mod re_renderer_callback;
 mod tensor_to_gpu;
 
-pub use colormap::colormap_dropdown_button_ui;
-pub use re_renderer_callback::new_renderer_callback;
+pub use colormap::{colormap_dropdown_button_ui, paint_colormap_bar};
+pub use re_renderer_callback::{new_offscreen_renderer_callback, new_renderer_callback};
 pub use tensor_to_gpu::{
-    class_id_tensor_to_gpu, color_tensor_to_gpu, depth_tensor_to_gpu, tensor_to_gpu,
-    texture_height_width_channels,
+    apply_data_range, class_id_tensor_to_gpu, color_tensor_channels_to_gpu, color_tensor_to_gpu,
+    depth_tensor_to_gpu, tensor_to_gpu, texture_height_width_channels,
 };
 
 use crate::TensorStats;
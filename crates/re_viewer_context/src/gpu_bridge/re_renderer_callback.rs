@@ -14,6 +14,24 @@ pub fn new_renderer_callback(
     )
 }
 
+/// Draws a view builder without showing it anywhere on screen.
+///
+/// Useful for views which are only read back, e.g. for rendering screenshots at a higher
+/// resolution than what is shown.
+/// The callback still needs to be added to a painter with a non-empty clip rect for it to run.
+pub fn new_offscreen_renderer_callback(
+    view_builder: re_renderer::ViewBuilder,
+    position: egui::Pos2,
+    clear_color: re_renderer::Rgba,
+) -> egui::PaintCallback {
+    // egui skips painting callbacks with an empty viewport, but still prepares them.
+    new_renderer_callback(
+        view_builder,
+        egui::Rect::from_min_size(position, egui::Vec2::ZERO),
+        clear_color,
+    )
+}
+
 struct ReRendererCallback {
     view_builder: re_renderer::ViewBuilder,
     clear_color: re_renderer::Rgba,
@@ -1,6 +1,6 @@
 //! Upload tensors to [`re_renderer`].
 
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeSet};
 
 use anyhow::Context;
 use bytemuck::{allocation::pod_collect_to_vec, cast_slice, Pod};
@@ -11,7 +11,7 @@ use re_log_types::RowId;
 use re_renderer::{
     pad_rgb_to_rgba,
     renderer::{ColorMapper, ColormappedTexture, ShaderDecoding},
-    resource_managers::Texture2DCreationDesc,
+    resource_managers::{GpuTexture2D, Texture2DCreationDesc},
     RenderContext,
 };
 use re_types::tensor_data::DecodedTensor;
@@ -32,7 +32,9 @@ use super::{get_or_create_texture, try_get_or_create_texture};
 /// This will only upload the tensor if it isn't on the GPU already.
 ///
 /// `tensor_stats` is used for determining the range of the texture.
+/// Pixels of segmentation images with one of the `hidden_class_ids` are transparent.
 // TODO(#2341): allow user to specify the range in ui.
+#[allow(clippy::too_many_arguments)]
 pub fn tensor_to_gpu(
     render_ctx: &RenderContext,
     debug_name: &str,
@@ -41,6 +43,7 @@ pub fn tensor_to_gpu(
     meaning: TensorDataMeaning,
     tensor_stats: &TensorStats,
     annotations: &Annotations,
+    hidden_class_ids: &BTreeSet<ClassId>,
 ) -> anyhow::Result<ColormappedTexture> {
     re_tracing::profile_function!(format!(
         "meaning: {:?}, dtype: {}, shape: {:?}",
@@ -64,6 +67,7 @@ pub fn tensor_to_gpu(
             tensor,
             tensor_stats,
             annotations,
+            hidden_class_ids,
         ),
         TensorDataMeaning::Depth => depth_tensor_to_gpu(
             render_ctx,
@@ -75,6 +79,37 @@ pub fn tensor_to_gpu(
     }
 }
 
+/// Overrides the range a texture created by [`tensor_to_gpu`] is mapped with.
+///
+/// `data_range` is given in the values of the tensor, independent of the texture format.
+/// Textures that are mapped via a lookup texture (segmentation images) or decoded in the
+/// shader (NV12, YUY2) are left untouched.
+pub fn apply_data_range(colormapped_texture: &mut ColormappedTexture, data_range: (f64, f64)) {
+    if matches!(colormapped_texture.color_mapper, ColorMapper::Texture(_))
+        || colormapped_texture.shader_decoding.is_some()
+    {
+        return;
+    }
+
+    let (mut min, mut max) = data_range;
+    if min == max {
+        // Uniform range. This can explode the colormapping, so let's map all colors to the middle:
+        min -= 1.0;
+        max += 1.0;
+    }
+
+    // Normalized textures see the tensor values divided by the maximum of their integer type.
+    let scale = match colormapped_texture.texture.format() {
+        TextureFormat::R8Unorm | TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => {
+            1.0 / u8::MAX as f64
+        }
+        TextureFormat::R8Snorm => 1.0 / i8::MAX as f64,
+        _ => 1.0,
+    };
+
+    colormapped_texture.range = [(min * scale) as f32, (max * scale) as f32];
+}
+
 // ----------------------------------------------------------------------------
 // Color textures:
 
@@ -120,6 +155,59 @@ pub fn color_tensor_to_gpu(
     })
     .map_err(|err| anyhow::anyhow!("{err}"))?;
 
+    colormapped_color_texture(texture_handle, debug_name, tensor, depth, tensor_stats)
+}
+
+/// Like [`color_tensor_to_gpu`], but maps the given channels of a `[height, width, channels]`
+/// tensor to RGB.
+///
+/// This allows showing tensors with more channels than fit into a texture,
+/// e.g. hyperspectral images.
+pub fn color_tensor_channels_to_gpu(
+    render_ctx: &RenderContext,
+    debug_name: &str,
+    tensor_data_row_id: RowId,
+    tensor: &DecodedTensor,
+    channels: [u64; 3],
+    tensor_stats: &TensorStats,
+) -> anyhow::Result<ColormappedTexture> {
+    re_tracing::profile_function!();
+
+    let texture_key = hash((tensor_data_row_id, channels));
+
+    let texture_handle = try_get_or_create_texture(render_ctx, texture_key, || {
+        let selected = tensor
+            .select_image_channels(channels)
+            .and_then(|selected| DecodedTensor::try_from(selected).ok())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Cannot select channels {channels:?} of tensor with shape {:?}",
+                    tensor.shape
+                )
+            })?;
+        let desc = general_texture_creation_desc_from_tensor(debug_name, &selected)?;
+        // The selected channels only live in this closure, so the data needs to be owned.
+        anyhow::Ok(Texture2DCreationDesc {
+            label: desc.label,
+            data: desc.data.into_owned().into(),
+            format: desc.format,
+            width: desc.width,
+            height: desc.height,
+        })
+    })
+    .map_err(|err| anyhow::anyhow!("{err}"))?;
+
+    colormapped_color_texture(texture_handle, debug_name, tensor, 3, tensor_stats)
+}
+
+/// Determines how to interpret a color texture uploaded from `tensor`.
+fn colormapped_color_texture(
+    texture_handle: GpuTexture2D,
+    debug_name: &str,
+    tensor: &DecodedTensor,
+    depth: u32,
+    tensor_stats: &TensorStats,
+) -> anyhow::Result<ColormappedTexture> {
     let texture_format = texture_handle.format();
     let shader_decoding = match tensor.buffer {
         TensorBuffer::Nv12(_) => Some(ShaderDecoding::Nv12),
@@ -203,6 +291,7 @@ pub fn class_id_tensor_to_gpu(
     tensor: &DecodedTensor,
     tensor_stats: &TensorStats,
     annotations: &Annotations,
+    hidden_class_ids: &BTreeSet<ClassId>,
 ) -> anyhow::Result<ColormappedTexture> {
     re_tracing::profile_function!();
     let texture_key = hash(tensor_data_row_id);
@@ -228,27 +317,31 @@ pub fn class_id_tensor_to_gpu(
     let colormap_width = 256;
     let colormap_height = (num_colors + colormap_width - 1) / colormap_width;
 
-    let colormap_texture_handle =
-        get_or_create_texture(render_ctx, hash(annotations.row_id()), || {
-            let data: Vec<u8> = (0..(colormap_width * colormap_height))
-                .flat_map(|id| {
-                    let color = annotations
-                        .resolved_class_description(Some(ClassId::from(id as u16)))
-                        .annotation_info()
-                        .color(None, DefaultColor::TransparentBlack);
-                    color.to_array() // premultiplied!
-                })
-                .collect();
-
-            Texture2DCreationDesc {
-                label: "class_id_colormap".into(),
-                data: data.into(),
-                format: TextureFormat::Rgba8UnormSrgb,
-                width: colormap_width as u32,
-                height: colormap_height as u32,
-            }
-        })
-        .context("Failed to create class_id_colormap.")?;
+    let colormap_key = hash((annotations.row_id(), hidden_class_ids));
+    let colormap_texture_handle = get_or_create_texture(render_ctx, colormap_key, || {
+        let data: Vec<u8> = (0..(colormap_width * colormap_height))
+            .flat_map(|id| {
+                let class_id = ClassId::from(id as u16);
+                if hidden_class_ids.contains(&class_id) {
+                    return [0; 4];
+                }
+                let color = annotations
+                    .resolved_class_description(Some(class_id))
+                    .annotation_info()
+                    .color(None, DefaultColor::TransparentBlack);
+                color.to_array() // premultiplied!
+            })
+            .collect();
+
+        Texture2DCreationDesc {
+            label: "class_id_colormap".into(),
+            data: data.into(),
+            format: TextureFormat::Rgba8UnormSrgb,
+            width: colormap_width as u32,
+            height: colormap_height as u32,
+        }
+    })
+    .context("Failed to create class_id_colormap.")?;
 
     let main_texture_handle = try_get_or_create_texture(render_ctx, texture_key, || {
         general_texture_creation_desc_from_tensor(debug_name, tensor)
@@ -27,7 +27,7 @@ pub mod gpu_bridge;
 pub use annotations::{
     AnnotationMap, Annotations, ResolvedAnnotationInfo, ResolvedAnnotationInfos,
 };
-pub use app_options::AppOptions;
+pub use app_options::{AppOptions, DepthUnit};
 pub use blueprint_helpers::{blueprint_timeline, blueprint_timepoint_for_writes};
 pub use blueprint_id::{BlueprintId, BlueprintIdRegistry, ContainerId, DataQueryId, SpaceViewId};
 pub use caches::{Cache, Caches};
@@ -39,8 +39,8 @@ pub use item::Item;
 pub use query_context::{DataQueryResult, DataResultHandle, DataResultNode, DataResultTree};
 pub use selection_history::SelectionHistory;
 pub use selection_state::{
-    ApplicationSelectionState, HoverHighlight, InteractionHighlight, SelectedSpaceContext,
-    Selection, SelectionHighlight,
+    ApplicationSelectionState, HoverHighlight, InteractionHighlight, ProjectedRay,
+    SelectedSpaceContext, Selection, SelectionHighlight,
 };
 pub use space_view::{
     DataResult, DynSpaceViewClass, IdentifiedViewSystem, PerSystemDataResults, PerSystemEntities,
@@ -54,13 +54,16 @@ pub use space_view::{
     VisualizerSystem,
 };
 pub use store_context::StoreContext;
-pub use tensor::{TensorDecodeCache, TensorStats, TensorStatsCache};
+pub use tensor::{
+    TensorDecodeCache, TensorHistogram, TensorHistogramCache, TensorPercentileCache, TensorStats,
+    TensorStatsCache,
+};
 pub use time_control::{Looping, PlayState, TimeControl, TimeView};
 pub use typed_entity_collections::{
     ApplicableEntities, IndicatedEntities, PerVisualizer, VisualizableEntities,
 };
 pub use utils::{auto_color, level_to_rich_text, DefaultColor};
-pub use viewer_context::{RecordingConfig, ViewerContext};
+pub use viewer_context::{Click2DCallback, RecordingConfig, ViewerContext};
 
 #[cfg(not(target_arch = "wasm32"))]
 mod clipboard;
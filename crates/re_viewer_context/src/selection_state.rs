@@ -15,6 +15,9 @@ pub enum SelectedSpaceContext {
 
         /// Where in this 2D space (+ depth)?
         pos: glam::Vec3,
+
+        /// Integer pixel coordinates in the topmost hovered image, if the position is within one.
+        pixel: Option<[u32; 2]>,
     },
 
     /// Hovering/Selecting in a 3D space.
@@ -31,9 +34,70 @@ pub enum SelectedSpaceContext {
 
         /// Corresponding 2D spaces and pixel coordinates (with Z=depth)
         point_in_space_cameras: Vec<(EntityPath, Option<glam::Vec3>)>,
+
+        /// Epipolar lines of the hovered ray in the 2D spaces of all other cameras,
+        /// if the eye is looking through (i.e. tracking) a camera.
+        ///
+        /// Each line is given by two distinct points on it, in pixel coordinates.
+        epipolar_lines_in_space_cameras: Vec<(EntityPath, [glam::Vec2; 2])>,
+
+        /// The part of the hovered ray in front of each camera other than the tracked one,
+        /// projected into the camera's 2D space.
+        ray_in_space_cameras: Vec<(EntityPath, ProjectedRay)>,
     },
 }
 
+/// The part of a ray in front of a camera, projected into the camera's 2D space.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ProjectedRay {
+    /// Projected start of the ray, in pixel coordinates.
+    pub start: glam::Vec2,
+
+    /// Projected end of the ray, in pixel coordinates.
+    ///
+    /// If the ray never leaves the space in front of the camera, this is its vanishing point.
+    pub end: glam::Vec2,
+
+    /// Inverse of the depth of [`Self::start`] in front of the camera.
+    pub start_inv_depth: f32,
+
+    /// Inverse of the depth of [`Self::end`] in front of the camera, zero for a vanishing point.
+    pub end_inv_depth: f32,
+}
+
+impl ProjectedRay {
+    /// The part of the ray that is at most `max_depth` in front of the camera.
+    ///
+    /// Returns `None` if all of the ray is further away.
+    pub fn up_to_depth(&self, max_depth: f32) -> Option<Self> {
+        let max_inv_depth = max_depth.recip();
+        if self.start_inv_depth.max(self.end_inv_depth) <= max_inv_depth {
+            return None;
+        }
+        if self.start_inv_depth.min(self.end_inv_depth) >= max_inv_depth {
+            return Some(*self);
+        }
+
+        // Projected points along a line are affine in the inverse of their depth.
+        let t =
+            (max_inv_depth - self.start_inv_depth) / (self.end_inv_depth - self.start_inv_depth);
+        let cut = self.start.lerp(self.end, t);
+        Some(if self.start_inv_depth > self.end_inv_depth {
+            Self {
+                end: cut,
+                end_inv_depth: max_inv_depth,
+                ..*self
+            }
+        } else {
+            Self {
+                start: cut,
+                start_inv_depth: max_inv_depth,
+                ..*self
+            }
+        })
+    }
+}
+
 /// Selection highlight, sorted from weakest to strongest.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
 pub enum SelectionHighlight {
@@ -369,3 +433,36 @@ impl ApplicationSelectionState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use re_entity_db::InstancePath;
+    use re_types::components::InstanceKey;
+
+    use super::{ApplicationSelectionState, Item};
+
+    #[test]
+    fn toggling_adds_to_and_removes_from_the_selection() {
+        let instance = |key| {
+            Item::InstancePath(
+                None,
+                InstancePath::instance("points".into(), InstanceKey(key)),
+            )
+        };
+        let selected_items = |state: &ApplicationSelectionState| {
+            state.current().iter_items().cloned().collect::<Vec<_>>()
+        };
+
+        let mut state = ApplicationSelectionState::default();
+        state.set_selection(instance(0));
+        state.on_frame_start(|_| true);
+
+        state.toggle_selection(instance(1).into());
+        state.on_frame_start(|_| true);
+        assert!(selected_items(&state) == [instance(0), instance(1)]);
+
+        state.toggle_selection(instance(0).into());
+        state.on_frame_start(|_| true);
+        assert!(selected_items(&state) == [instance(1)]);
+    }
+}
@@ -1,9 +1,13 @@
 // TODO(andreas): Move tensor utilities to a tensor specific crate?
 
 mod tensor_decode_cache;
+mod tensor_histogram_cache;
+mod tensor_percentile_cache;
 mod tensor_stats;
 mod tensor_stats_cache;
 
 pub use tensor_decode_cache::TensorDecodeCache;
+pub use tensor_histogram_cache::{TensorHistogram, TensorHistogramCache};
+pub use tensor_percentile_cache::TensorPercentileCache;
 pub use tensor_stats::TensorStats;
 pub use tensor_stats_cache::TensorStatsCache;
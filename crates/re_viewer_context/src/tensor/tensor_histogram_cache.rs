@@ -0,0 +1,166 @@
+use re_log_types::RowId;
+use re_types::datatypes::TensorData;
+
+use crate::Cache;
+
+/// How many values of each channel of an image fall into evenly sized buckets spanning all its values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TensorHistogram {
+    /// Smallest and largest finite value of the image, spanned by the buckets.
+    pub range: (f64, f64),
+
+    /// Value counts per bucket, for each of the (up to three) color channels of the image.
+    ///
+    /// Alpha channels are not counted.
+    pub channels: Vec<Vec<u64>>,
+}
+
+impl TensorHistogram {
+    pub const NUM_BUCKETS: usize = 64;
+
+    /// The range of values covered by the bucket with the given index.
+    pub fn bucket_range(&self, bucket: usize) -> (f64, f64) {
+        let (min, max) = self.range;
+        let bucket_size = (max - min) / Self::NUM_BUCKETS as f64;
+        (
+            min + bucket as f64 * bucket_size,
+            min + (bucket + 1) as f64 * bucket_size,
+        )
+    }
+}
+
+/// Caches the histograms of image tensors, keyed by the [`RowId`] of the tensor.
+///
+/// Counting requires a pass over all values of the tensor that is too slow to do every frame.
+/// Images that weren't used for [`Self::MAX_UNUSED_FRAMES`] frames are evicted.
+#[derive(Default)]
+pub struct TensorHistogramCache {
+    histograms: ahash::HashMap<RowId, CachedHistogram>,
+    frame: u64,
+}
+
+struct CachedHistogram {
+    histogram: Option<TensorHistogram>,
+    last_used_frame: u64,
+}
+
+impl TensorHistogramCache {
+    /// Number of frames an image may go unused before its histogram is evicted.
+    const MAX_UNUSED_FRAMES: u64 = 60;
+
+    /// The histogram of an image tensor.
+    ///
+    /// The key should be the `RowId` of the `TensorData`.
+    /// Returns `None` for encoded tensors, tensors that aren't images and tensors without any finite values.
+    pub fn entry(&mut self, key: RowId, tensor: &TensorData) -> Option<&TensorHistogram> {
+        let cached = self
+            .histograms
+            .entry(key)
+            .or_insert_with(|| CachedHistogram {
+                histogram: tensor_histogram(tensor),
+                last_used_frame: self.frame,
+            });
+        cached.last_used_frame = self.frame;
+        cached.histogram.as_ref()
+    }
+}
+
+impl Cache for TensorHistogramCache {
+    fn begin_frame(&mut self) {
+        self.frame += 1;
+
+        let frame = self.frame;
+        self.histograms
+            .retain(|_, cached| frame - cached.last_used_frame <= Self::MAX_UNUSED_FRAMES);
+    }
+
+    fn purge_memory(&mut self) {
+        self.histograms.clear();
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Histogram of the interleaved channels of an image tensor.
+///
+/// Only the (up to three) color channels are counted, so an alpha channel doesn't affect the range either.
+fn tensor_histogram(tensor: &TensorData) -> Option<TensorHistogram> {
+    re_tracing::profile_function!();
+
+    let [_, _, num_channels] = tensor.image_height_width_channels()?;
+    let num_channels = (num_channels as usize).max(1);
+    let is_counted = |index: usize, value: f64| index % num_channels < 3 && value.is_finite();
+
+    let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+    let mut index = 0;
+    let is_decoded = tensor.for_each_value_as_f64(|value| {
+        if is_counted(index, value) {
+            min = min.min(value);
+            max = max.max(value);
+        }
+        index += 1;
+    });
+    if !is_decoded || min > max {
+        return None;
+    }
+
+    let mut channels = vec![vec![0; TensorHistogram::NUM_BUCKETS]; num_channels.min(3)];
+    let mut index = 0;
+    tensor.for_each_value_as_f64(|value| {
+        if is_counted(index, value) {
+            let bucket = if max > min {
+                ((value - min) / (max - min) * TensorHistogram::NUM_BUCKETS as f64) as usize
+            } else {
+                0
+            };
+            channels[index % num_channels][bucket.min(TensorHistogram::NUM_BUCKETS - 1)] += 1;
+        }
+        index += 1;
+    });
+
+    Some(TensorHistogram {
+        range: (min, max),
+        channels,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::datatypes::{TensorBuffer, TensorData, TensorDimension};
+
+    use super::{tensor_histogram, TensorHistogram};
+
+    #[test]
+    fn test_histogram() {
+        // Two RGBA pixels, the alpha channel isn't counted.
+        let rgba = TensorData::new(
+            vec![
+                TensorDimension::height(1),
+                TensorDimension::width(2),
+                TensorDimension::depth(4),
+            ],
+            TensorBuffer::F64(vec![0.0, 64.0, 64.0, 255.0, 32.0, 32.0, f64::NAN, 0.0].into()),
+        );
+        let histogram = tensor_histogram(&rgba).unwrap();
+        assert_eq!(histogram.range, (0.0, 64.0));
+        assert_eq!(histogram.channels.len(), 3);
+
+        let last = TensorHistogram::NUM_BUCKETS - 1;
+        assert_eq!(histogram.channels[0][0], 1);
+        assert_eq!(histogram.channels[0][32], 1);
+        assert_eq!(histogram.channels[1][32], 1);
+        assert_eq!(histogram.channels[1][last], 1);
+        assert_eq!(histogram.channels[2][last], 1);
+        assert_eq!(histogram.channels[2].iter().sum::<u64>(), 1);
+
+        assert_eq!(histogram.bucket_range(32), (32.0, 33.0));
+
+        let nan = TensorData::new(
+            vec![TensorDimension::height(1), TensorDimension::width(1)],
+            TensorBuffer::F64(vec![f64::NAN].into()),
+        );
+        assert_eq!(tensor_histogram(&nan), None);
+    }
+}
@@ -0,0 +1,153 @@
+use re_log_types::RowId;
+use re_types::datatypes::TensorData;
+
+use crate::Cache;
+
+/// Caches the sorted finite values of tensors, keyed by the [`RowId`] of the tensor.
+///
+/// Sorting requires a pass over all values of the tensor that is too slow to do every frame,
+/// whereas looking up the values at any two percentiles in the sorted values is cheap,
+/// e.g. while the percentiles are being dragged.
+/// Tensors that weren't used for [`Self::MAX_UNUSED_FRAMES`] frames are evicted.
+#[derive(Default)]
+pub struct TensorPercentileCache {
+    sorted_values: ahash::HashMap<RowId, CachedSortedValues>,
+    frame: u64,
+}
+
+struct CachedSortedValues {
+    /// Sorted finite values of the tensor, or an evenly spread sample of them for large tensors.
+    values: Vec<f64>,
+    last_used_frame: u64,
+}
+
+impl TensorPercentileCache {
+    /// Number of frames a tensor may go unused before its values are evicted.
+    const MAX_UNUSED_FRAMES: u64 = 60;
+
+    /// Tensors with more values than this are sampled.
+    const MAX_SAMPLE_SIZE: usize = 1 << 18;
+
+    /// The values at the `low` and `high` percentile (`0.0..=100.0`) of all finite values of the tensor.
+    ///
+    /// The key should be the `RowId` of the `TensorData`.
+    /// For tensors with many values, the percentiles are estimated from an evenly spread sample.
+    /// Returns `None` for encoded tensors and tensors without any finite values.
+    pub fn entry(
+        &mut self,
+        key: RowId,
+        tensor: &TensorData,
+        low: f32,
+        high: f32,
+    ) -> Option<(f64, f64)> {
+        let cached = self
+            .sorted_values
+            .entry(key)
+            .or_insert_with(|| CachedSortedValues {
+                values: sorted_sample(tensor, Self::MAX_SAMPLE_SIZE),
+                last_used_frame: self.frame,
+            });
+        cached.last_used_frame = self.frame;
+        percentiles(&cached.values, low, high)
+    }
+}
+
+impl Cache for TensorPercentileCache {
+    fn begin_frame(&mut self) {
+        self.frame += 1;
+
+        let frame = self.frame;
+        self.sorted_values
+            .retain(|_, cached| frame - cached.last_used_frame <= Self::MAX_UNUSED_FRAMES);
+    }
+
+    fn purge_memory(&mut self) {
+        self.sorted_values.clear();
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Sorted finite values of the tensor, keeping at most about `max_sample_size` of them.
+fn sorted_sample(tensor: &TensorData, max_sample_size: usize) -> Vec<f64> {
+    re_tracing::profile_function!();
+
+    let num_values = tensor
+        .shape
+        .iter()
+        .map(|dim| dim.size as usize)
+        .product::<usize>();
+    let innermost_size = tensor.shape.last().map_or(1, |dim| dim.size as usize);
+    let step = sample_step(num_values, innermost_size, max_sample_size);
+
+    let mut values = Vec::new();
+    let mut index = 0;
+    tensor.for_each_value_as_f64(|v| {
+        if index % step == 0 && v.is_finite() {
+            values.push(v);
+        }
+        index += 1;
+    });
+    values.sort_unstable_by(f64::total_cmp);
+    values
+}
+
+/// Distance between sampled values such that at most about `max_sample_size` of `num_values` are kept.
+///
+/// The step has no common divisor with the size of the innermost dimension,
+/// so that e.g. all channels of an image are sampled, not just one of them.
+fn sample_step(num_values: usize, innermost_size: usize, max_sample_size: usize) -> usize {
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    let max_sample_size = max_sample_size.max(1);
+    let mut step = ((num_values + max_sample_size - 1) / max_sample_size).max(1);
+    while step > 1 && gcd(step, innermost_size) > 1 {
+        step += 1;
+    }
+    step
+}
+
+/// The values at the `low` and `high` percentile of the sorted `values`.
+fn percentiles(values: &[f64], low: f32, high: f32) -> Option<(f64, f64)> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let value_at = |percentile: f32| {
+        let rank = (percentile.clamp(0.0, 100.0) as f64 / 100.0 * (values.len() - 1) as f64).round()
+            as usize;
+        values[rank]
+    };
+    Some((value_at(low.min(high)), value_at(low.max(high))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{percentiles, sample_step};
+
+    #[test]
+    fn test_percentiles() {
+        let values = (0..=100).map(|v| v as f64).collect::<Vec<_>>();
+        assert_eq!(percentiles(&values, 0.0, 100.0), Some((0.0, 100.0)));
+        assert_eq!(percentiles(&values, 2.0, 98.0), Some((2.0, 98.0)));
+        assert_eq!(percentiles(&values, 98.0, 2.0), Some((2.0, 98.0)));
+        assert_eq!(percentiles(&[], 2.0, 98.0), None);
+    }
+
+    #[test]
+    fn test_sample_step() {
+        assert_eq!(sample_step(100, 3, 1000), 1);
+        assert_eq!(sample_step(4000, 1, 1000), 4);
+        // An RGB image: a step of 3 would only sample the red channel.
+        assert_eq!(sample_step(3000, 3, 1000), 4);
+        assert_eq!(sample_step(8000, 4, 1000), 9);
+    }
+}
@@ -105,6 +105,23 @@ pub struct TimeControl {
     /// This is used during UI interactions. E.g. to show visual history range that's highlighted.
     #[serde(skip)]
     pub highlighted_range: Option<TimeRange>,
+
+    /// Pause requested by [`Self::pause_while_hovered`].
+    ///
+    /// `None` if playback isn't paused because of hovering.
+    #[serde(skip)]
+    hover_pause: Option<HoverPause>,
+}
+
+/// Playback paused while the pointer is over a view, see [`TimeControl::pause_while_hovered`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum HoverPause {
+    /// Paused by hovering, remembering whether we were following before.
+    Paused { following: bool },
+
+    /// Playback was started or paused explicitly while hovering,
+    /// so it is left as is once the pointer leaves the view.
+    Overridden,
 }
 
 impl Default for TimeControl {
@@ -117,6 +134,7 @@ impl Default for TimeControl {
             speed: 1.0,
             looping: Looping::Off,
             highlighted_range: None,
+            hover_pause: None,
         }
     }
 }
@@ -241,6 +259,8 @@ impl TimeControl {
     }
 
     pub fn set_play_state(&mut self, times_per_timeline: &TimesPerTimeline, play_state: PlayState) {
+        self.override_hover_pause();
+
         match play_state {
             PlayState::Paused => {
                 self.playing = false;
@@ -282,6 +302,39 @@ impl TimeControl {
 
     pub fn pause(&mut self) {
         self.playing = false;
+        self.override_hover_pause();
+    }
+
+    /// Pause playback while the pointer is over a view, until [`Self::resume_after_hover`] is called.
+    ///
+    /// Does nothing if playback was already paused because of hovering,
+    /// so that starting playback while hovering isn't immediately undone.
+    pub fn pause_while_hovered(&mut self) {
+        if self.hover_pause.is_none() && self.playing {
+            self.hover_pause = Some(HoverPause::Paused {
+                following: self.following,
+            });
+            self.playing = false;
+        }
+    }
+
+    /// Resume playback paused by [`Self::pause_while_hovered`].
+    ///
+    /// If playback was started or paused explicitly in the meantime, it is left as is.
+    pub fn resume_after_hover(&mut self) {
+        if let Some(HoverPause::Paused { following }) = self.hover_pause.take() {
+            if !self.playing {
+                self.playing = true;
+                self.following = following;
+            }
+        }
+    }
+
+    /// Explicit changes to playback win over resuming it once the pointer leaves the view.
+    fn override_hover_pause(&mut self) {
+        if self.hover_pause.is_some() {
+            self.hover_pause = Some(HoverPause::Overridden);
+        }
     }
 
     pub fn step_time_back(&mut self, times_per_timeline: &TimesPerTimeline) {
@@ -330,6 +383,8 @@ impl TimeControl {
     }
 
     pub fn toggle_play_pause(&mut self, times_per_timeline: &TimesPerTimeline) {
+        self.override_hover_pause();
+
         #[allow(clippy::collapsible_else_if)]
         if self.playing {
             self.pause();
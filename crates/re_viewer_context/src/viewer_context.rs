@@ -2,7 +2,7 @@ use ahash::HashMap;
 use parking_lot::RwLock;
 
 use re_data_store::LatestAtQuery;
-use re_entity_db::entity_db::EntityDb;
+use re_entity_db::{entity_db::EntityDb, EntityPath};
 
 use crate::{
     query_context::DataQueryResult, AppOptions, ApplicableEntities, ApplicationSelectionState,
@@ -10,6 +10,11 @@ use crate::{
     Selection, SpaceViewClassRegistry, StoreContext, SystemCommandSender as _, TimeControl,
 };
 
+/// Called when empty space in a 2D view is clicked, i.e. a click that didn't select anything.
+///
+/// Gets the origin of the view's space and the clicked position in the coordinates of that space.
+pub type Click2DCallback = Box<dyn Fn(&EntityPath, egui::Pos2) + Send + Sync>;
+
 /// Common things needed by many parts of the viewer.
 pub struct ViewerContext<'a> {
     /// Global options for the whole viewer.
@@ -70,6 +75,9 @@ pub struct ViewerContext<'a> {
     /// The focused item is cleared every frame, but views may react with side-effects
     /// that last several frames.
     pub focused_item: &'a Option<crate::Item>,
+
+    /// Callbacks registered by code embedding the viewer, fired on clicks on empty space in 2D views.
+    pub on_2d_click_callbacks: &'a [Click2DCallback],
 }
 
 impl<'a> ViewerContext<'a> {
@@ -92,6 +100,18 @@ impl<'a> ViewerContext<'a> {
         self.rec_cfg.time_ctrl.read().current_query()
     }
 
+    /// Pauses playback while the pointer is over a view.
+    ///
+    /// Call [`Self::release_pause`] once the pointer left the view again.
+    pub fn request_pause(&self) {
+        self.rec_cfg.time_ctrl.write().pause_while_hovered();
+    }
+
+    /// Resumes playback paused by [`Self::request_pause`].
+    pub fn release_pause(&self) {
+        self.rec_cfg.time_ctrl.write().resume_after_hover();
+    }
+
     /// Set hover/select/focus for a given selection based on an egui response.
     pub fn select_hovered_on_click(
         &self,
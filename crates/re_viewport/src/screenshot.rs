@@ -8,9 +8,11 @@ pub fn handle_pending_space_view_screenshots(
 ) {
     // Set to clipboard.
     #[cfg(not(target_arch = "wasm32"))]
-    re_viewer_context::Clipboard::with(|clipboard| {
-        clipboard.set_image([extent.x as _, extent.y as _], data);
-    });
+    if mode != ScreenshotMode::SaveToDisk {
+        re_viewer_context::Clipboard::with(|clipboard| {
+            clipboard.set_image([extent.x as _, extent.y as _], data);
+        });
+    }
     if mode == ScreenshotMode::CopyToClipboard {
         return;
     }
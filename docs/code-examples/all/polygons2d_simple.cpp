@@ -0,0 +1,21 @@
+// Log a batch of 2d polygons.
+
+#include <rerun.hpp>
+
+int main() {
+    const auto rec = rerun::RecordingStream("rerun_example_polygons2d");
+    rec.spawn().exit_on_failure();
+
+    rerun::Collection<rerun::Vec2D> triangle = {{0.f, 0.f}, {4.f, 0.f}, {2.f, 3.f}};
+    rerun::Collection<rerun::Vec2D> pentagon =
+        {{5.f, 0.f}, {8.f, 0.f}, {8.f, 3.f}, {6.f, 4.f}, {5.f, 3.f}};
+    rec.log(
+        "polygons",
+        rerun::Polygons2D({triangle, pentagon})
+            .with_colors({0xFF0000FF, 0x0000FFFF})
+            .with_labels({"triangle", "pentagon"})
+    );
+
+    // Log an extra rect to set the view bounds
+    rec.log("bounds", rerun::Boxes2D::from_centers_and_half_sizes({{4.0f, 2.0f}}, {{5.0f, 3.0f}}));
+}
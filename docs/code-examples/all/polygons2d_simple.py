@@ -0,0 +1,19 @@
+"""Log a batch of 2d polygons."""
+import rerun as rr
+
+rr.init("rerun_example_polygons2d", spawn=True)
+
+rr.log(
+    "polygons",
+    rr.Polygons2D(
+        [
+            [[0, 0], [4, 0], [2, 3]],
+            [[5, 0], [8, 0], [8, 3], [6, 4], [5, 3]],
+        ],
+        colors=[[255, 0, 0], [0, 0, 255]],
+        labels=["triangle", "pentagon"],
+    ),
+)
+
+# Log an extra rect to set the view bounds
+rr.log("bounds", rr.Boxes2D(centers=[4, 2], half_sizes=[5.0, 3.0]))
@@ -0,0 +1,22 @@
+//! Log a batch of 2d polygons.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rec = rerun::RecordingStreamBuilder::new("rerun_example_polygons2d").spawn()?;
+
+    let triangle = [[0., 0.], [4., 0.], [2., 3.]];
+    let pentagon = [[5., 0.], [8., 0.], [8., 3.], [6., 4.], [5., 3.]];
+    rec.log(
+        "polygons",
+        &rerun::Polygons2D::new([triangle.to_vec(), pentagon.to_vec()])
+            .with_colors([0xFF0000FF, 0x0000FFFF])
+            .with_labels(["triangle", "pentagon"]),
+    )?;
+
+    // Log an extra rect to set the view bounds
+    rec.log(
+        "bounds",
+        &rerun::Boxes2D::from_centers_and_half_sizes([(4.0, 2.0)], [(5.0, 3.0)]),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+// Log a simple SVG overlay.
+
+#include <rerun.hpp>
+
+#include <string>
+
+static const std::string SVG = R"(<svg xmlns="http://www.w3.org/2000/svg" width="4" height="3">
+  <rect x="0.5" y="0.5" width="3" height="2" fill="none" stroke="red" stroke-width="0.1"/>
+  <circle cx="2" cy="1.5" r="0.5" fill="blue"/>
+</svg>)";
+
+int main() {
+    const auto rec = rerun::RecordingStream("rerun_example_svg_overlay");
+    rec.spawn().exit_on_failure();
+
+    rec.log(
+        "overlay",
+        rerun::SvgOverlay(rerun::Collection<uint8_t>::borrow(SVG.data(), SVG.size()))
+    );
+}
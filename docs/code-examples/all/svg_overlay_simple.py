@@ -0,0 +1,13 @@
+"""Log a simple SVG overlay."""
+import rerun as rr
+
+SVG = (
+    b'<svg xmlns="http://www.w3.org/2000/svg" width="4" height="3">'
+    b'<rect x="0.5" y="0.5" width="3" height="2" fill="none" stroke="red" stroke-width="0.1"/>'
+    b'<circle cx="2" cy="1.5" r="0.5" fill="blue"/>'
+    b"</svg>"
+)
+
+rr.init("rerun_example_svg_overlay", spawn=True)
+
+rr.log("overlay", rr.SvgOverlay(SVG))
@@ -0,0 +1,14 @@
+//! Log a simple SVG overlay.
+
+const SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="3">
+  <rect x="0.5" y="0.5" width="3" height="2" fill="none" stroke="red" stroke-width="0.1"/>
+  <circle cx="2" cy="1.5" r="0.5" fill="blue"/>
+</svg>"#;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rec = rerun::RecordingStreamBuilder::new("rerun_example_svg_overlay").spawn()?;
+
+    rec.log("overlay", &rerun::SvgOverlay::new(SVG.as_bytes().to_vec()))?;
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+// Log some standalone 2D texts.
+
+#include <rerun.hpp>
+
+int main() {
+    const auto rec = rerun::RecordingStream("rerun_example_text2d");
+    rec.spawn().exit_on_failure();
+
+    rec.log(
+        "texts",
+        rerun::Text2D({{0.0f, 0.0f}, {2.0f, 1.0f}}, {"origin", "t = 1.5 s"})
+            .with_colors({{255, 0, 0}, {0, 0, 255}})
+            .with_font_sizes({14.0f, 24.0f})
+    );
+
+    // Log an extra rect to set the view bounds
+    rec.log(
+        "bounds",
+        rerun::Boxes2D::from_centers_and_half_sizes({{1.0f, 0.5f}}, {{2.0f, 1.5f}})
+    );
+}
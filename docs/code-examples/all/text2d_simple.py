@@ -0,0 +1,17 @@
+"""Log some standalone 2D texts."""
+import rerun as rr
+
+rr.init("rerun_example_text2d", spawn=True)
+
+rr.log(
+    "texts",
+    rr.Text2D(
+        positions=[[0.0, 0.0], [2.0, 1.0]],
+        texts=["origin", "t = 1.5 s"],
+        colors=[[255, 0, 0], [0, 0, 255]],
+        font_sizes=[14.0, 24.0],
+    ),
+)
+
+# Log an extra rect to set the view bounds
+rr.log("bounds", rr.Boxes2D(centers=[1.0, 0.5], half_sizes=[2.0, 1.5]))
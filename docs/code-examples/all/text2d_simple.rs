@@ -0,0 +1,20 @@
+//! Log some standalone 2D texts.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rec = rerun::RecordingStreamBuilder::new("rerun_example_text2d").spawn()?;
+
+    rec.log(
+        "texts",
+        &rerun::Text2D::new([(0.0, 0.0), (2.0, 1.0)], ["origin", "t = 1.5 s"])
+            .with_colors([[255, 0, 0], [0, 0, 255]])
+            .with_font_sizes([14.0, 24.0]),
+    )?;
+
+    // Log an extra rect to set the view bounds
+    rec.log(
+        "bounds",
+        &rerun::Boxes2D::from_centers_and_half_sizes([(1.0, 0.5)], [(2.0, 1.5)]),
+    )?;
+
+    Ok(())
+}
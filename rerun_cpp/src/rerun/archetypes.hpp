@@ -19,11 +19,14 @@
 #include "archetypes/pinhole.hpp"
 #include "archetypes/points2d.hpp"
 #include "archetypes/points3d.hpp"
+#include "archetypes/polygons2d.hpp"
 #include "archetypes/scalar.hpp"
 #include "archetypes/segmentation_image.hpp"
 #include "archetypes/series_line.hpp"
 #include "archetypes/series_point.hpp"
+#include "archetypes/svg_overlay.hpp"
 #include "archetypes/tensor.hpp"
+#include "archetypes/text2d.hpp"
 #include "archetypes/text_document.hpp"
 #include "archetypes/text_log.hpp"
 #include "archetypes/time_series_scalar.hpp"
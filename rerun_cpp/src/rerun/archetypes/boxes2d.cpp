@@ -14,7 +14,7 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<DataCell> cells;
-        cells.reserve(9);
+        cells.reserve(11);
 
         {
             auto result = DataCell::from_loggable(archetype.half_sizes);
@@ -26,6 +26,11 @@ namespace rerun {
             RR_RETURN_NOT_OK(result.error);
             cells.push_back(std::move(result.value));
         }
+        if (archetype.rotations.has_value()) {
+            auto result = DataCell::from_loggable(archetype.rotations.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
         if (archetype.colors.has_value()) {
             auto result = DataCell::from_loggable(archetype.colors.value());
             RR_RETURN_NOT_OK(result.error);
@@ -41,6 +46,11 @@ namespace rerun {
             RR_RETURN_NOT_OK(result.error);
             cells.push_back(std::move(result.value));
         }
+        if (archetype.tooltips.has_value()) {
+            auto result = DataCell::from_loggable(archetype.tooltips.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
         if (archetype.draw_order.has_value()) {
             auto result = DataCell::from_loggable(archetype.draw_order.value());
             RR_RETURN_NOT_OK(result.error);
@@ -12,7 +12,9 @@
 #include "../components/instance_key.hpp"
 #include "../components/position2d.hpp"
 #include "../components/radius.hpp"
+#include "../components/rotation2d.hpp"
 #include "../components/text.hpp"
+#include "../components/tooltip.hpp"
 #include "../data_cell.hpp"
 #include "../indicator_component.hpp"
 #include "../result.hpp"
@@ -50,6 +52,9 @@ namespace rerun::archetypes {
         /// Optional center positions of the boxes.
         std::optional<Collection<rerun::components::Position2D>> centers;
 
+        /// Optional rotations of the boxes around their centers.
+        std::optional<Collection<rerun::components::Rotation2D>> rotations;
+
         /// Optional colors for the boxes.
         std::optional<Collection<rerun::components::Color>> colors;
 
@@ -59,6 +64,9 @@ namespace rerun::archetypes {
         /// Optional text labels for the boxes.
         std::optional<Collection<rerun::components::Text>> labels;
 
+        /// Optional tooltips for the boxes, shown when hovering them instead of their instance paths.
+        std::optional<Collection<rerun::components::Tooltip>> tooltips;
+
         /// An optional floating point value that specifies the 2D drawing order.
         ///
         /// Objects with higher values are drawn on top of those with lower values.
@@ -140,6 +148,13 @@ namespace rerun::archetypes {
             RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
         }
 
+        /// Optional rotations of the boxes around their centers.
+        Boxes2D with_rotations(Collection<rerun::components::Rotation2D> _rotations) && {
+            rotations = std::move(_rotations);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
         /// Optional colors for the boxes.
         Boxes2D with_colors(Collection<rerun::components::Color> _colors) && {
             colors = std::move(_colors);
@@ -161,6 +176,13 @@ namespace rerun::archetypes {
             RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
         }
 
+        /// Optional tooltips for the boxes, shown when hovering them instead of their instance paths.
+        Boxes2D with_tooltips(Collection<rerun::components::Tooltip> _tooltips) && {
+            tooltips = std::move(_tooltips);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
         /// An optional floating point value that specifies the 2D drawing order.
         ///
         /// Objects with higher values are drawn on top of those with lower values.
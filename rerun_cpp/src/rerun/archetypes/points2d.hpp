@@ -13,6 +13,7 @@
 #include "../components/position2d.hpp"
 #include "../components/radius.hpp"
 #include "../components/text.hpp"
+#include "../components/tooltip.hpp"
 #include "../data_cell.hpp"
 #include "../indicator_component.hpp"
 #include "../result.hpp"
@@ -81,6 +82,9 @@ namespace rerun::archetypes {
         /// Optional text labels for the points.
         std::optional<Collection<rerun::components::Text>> labels;
 
+        /// Optional tooltips for the points, shown when hovering them instead of their instance paths.
+        std::optional<Collection<rerun::components::Tooltip>> tooltips;
+
         /// An optional floating point value that specifies the 2D drawing order.
         ///
         /// Objects with higher values are drawn on top of those with lower values.
@@ -138,6 +142,13 @@ namespace rerun::archetypes {
             RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
         }
 
+        /// Optional tooltips for the points, shown when hovering them instead of their instance paths.
+        Points2D with_tooltips(Collection<rerun::components::Tooltip> _tooltips) && {
+            tooltips = std::move(_tooltips);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
         /// An optional floating point value that specifies the 2D drawing order.
         ///
         /// Objects with higher values are drawn on top of those with lower values.
@@ -0,0 +1,63 @@
+// DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/re_types/definitions/rerun/archetypes/polygons2d.fbs".
+
+#include "polygons2d.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {}
+
+namespace rerun {
+
+    Result<std::vector<DataCell>> AsComponents<archetypes::Polygons2D>::serialize(
+        const archetypes::Polygons2D& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<DataCell> cells;
+        cells.reserve(8);
+
+        {
+            auto result = DataCell::from_loggable(archetype.polygons);
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.radii.has_value()) {
+            auto result = DataCell::from_loggable(archetype.radii.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.colors.has_value()) {
+            auto result = DataCell::from_loggable(archetype.colors.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.labels.has_value()) {
+            auto result = DataCell::from_loggable(archetype.labels.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.draw_order.has_value()) {
+            auto result = DataCell::from_loggable(archetype.draw_order.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.class_ids.has_value()) {
+            auto result = DataCell::from_loggable(archetype.class_ids.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.instance_keys.has_value()) {
+            auto result = DataCell::from_loggable(archetype.instance_keys.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        {
+            auto indicator = Polygons2D::IndicatorComponent();
+            auto result = DataCell::from_loggable(indicator);
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
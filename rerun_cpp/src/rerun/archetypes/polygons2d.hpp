@@ -0,0 +1,160 @@
+// DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/re_types/definitions/rerun/archetypes/polygons2d.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../compiler_utils.hpp"
+#include "../components/class_id.hpp"
+#include "../components/color.hpp"
+#include "../components/draw_order.hpp"
+#include "../components/instance_key.hpp"
+#include "../components/line_strip2d.hpp"
+#include "../components/radius.hpp"
+#include "../components/text.hpp"
+#include "../data_cell.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: 2D closed polygons with optional colors, radii, labels, etc.
+    ///
+    /// The last vertex of each polygon is implicitly connected back to its first vertex.
+    /// Polygons are drawn as outlines and may be filled translucently by the viewer.
+    ///
+    /// ## Example
+    ///
+    /// ### Simple 2D polygons
+    /// ```cpp
+    /// #include <rerun.hpp>
+    ///
+    /// int main() {
+    ///     const auto rec = rerun::RecordingStream("rerun_example_polygons2d");
+    ///     rec.spawn().exit_on_failure();
+    ///
+    ///     rerun::Collection<rerun::Vec2D> triangle = {{0.f, 0.f}, {4.f, 0.f}, {2.f, 3.f}};
+    ///     rerun::Collection<rerun::Vec2D> pentagon =
+    ///         {{5.f, 0.f}, {8.f, 0.f}, {8.f, 3.f}, {6.f, 4.f}, {5.f, 3.f}};
+    ///     rec.log(
+    ///         "polygons",
+    ///         rerun::Polygons2D({triangle, pentagon})
+    ///             .with_colors({0xFF0000FF, 0x0000FFFF})
+    ///             .with_labels({"triangle", "pentagon"})
+    ///     );
+    ///
+    ///     // Log an extra rect to set the view bounds
+    ///     rec.log("bounds", rerun::Boxes2D::from_centers_and_half_sizes({{4.0f, 2.0f}}, {{5.0f, 3.0f}}));
+    /// }
+    /// ```
+    struct Polygons2D {
+        /// All the 2D polygons that make up the batch, as lists of vertices.
+        Collection<rerun::components::LineStrip2D> polygons;
+
+        /// Optional radii for the polygons.
+        std::optional<Collection<rerun::components::Radius>> radii;
+
+        /// Optional colors for the polygons.
+        std::optional<Collection<rerun::components::Color>> colors;
+
+        /// Optional text labels for the polygons.
+        std::optional<Collection<rerun::components::Text>> labels;
+
+        /// An optional floating point value that specifies the 2D drawing order of each polygon.
+        ///
+        /// Objects with higher values are drawn on top of those with lower values.
+        std::optional<rerun::components::DrawOrder> draw_order;
+
+        /// Optional `ClassId`s for the polygons.
+        ///
+        /// The class ID provides colors and labels if not specified explicitly.
+        std::optional<Collection<rerun::components::ClassId>> class_ids;
+
+        /// Unique identifiers for each individual polygon in the batch.
+        std::optional<Collection<rerun::components::InstanceKey>> instance_keys;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.components.Polygons2DIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+
+      public:
+        Polygons2D() = default;
+        Polygons2D(Polygons2D&& other) = default;
+
+        explicit Polygons2D(Collection<rerun::components::LineStrip2D> _polygons)
+            : polygons(std::move(_polygons)) {}
+
+        /// Optional radii for the polygons.
+        Polygons2D with_radii(Collection<rerun::components::Radius> _radii) && {
+            radii = std::move(_radii);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// Optional colors for the polygons.
+        Polygons2D with_colors(Collection<rerun::components::Color> _colors) && {
+            colors = std::move(_colors);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// Optional text labels for the polygons.
+        Polygons2D with_labels(Collection<rerun::components::Text> _labels) && {
+            labels = std::move(_labels);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// An optional floating point value that specifies the 2D drawing order of each polygon.
+        ///
+        /// Objects with higher values are drawn on top of those with lower values.
+        Polygons2D with_draw_order(rerun::components::DrawOrder _draw_order) && {
+            draw_order = std::move(_draw_order);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// Optional `ClassId`s for the polygons.
+        ///
+        /// The class ID provides colors and labels if not specified explicitly.
+        Polygons2D with_class_ids(Collection<rerun::components::ClassId> _class_ids) && {
+            class_ids = std::move(_class_ids);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// Unique identifiers for each individual polygon in the batch.
+        Polygons2D with_instance_keys(Collection<rerun::components::InstanceKey> _instance_keys
+        ) && {
+            instance_keys = std::move(_instance_keys);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// Returns the number of primary instances of this archetype.
+        size_t num_instances() const {
+            return polygons.size();
+        }
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::Polygons2D> {
+        /// Serialize all set component batches.
+        static Result<std::vector<DataCell>> serialize(const archetypes::Polygons2D& archetype);
+    };
+} // namespace rerun
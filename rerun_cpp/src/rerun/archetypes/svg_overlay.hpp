@@ -0,0 +1,98 @@
+// DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/re_types/definitions/rerun/archetypes/svg_overlay.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../compiler_utils.hpp"
+#include "../components/blob.hpp"
+#include "../components/out_of_tree_transform3d.hpp"
+#include "../data_cell.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A 2D vector graphic in SVG format, e.g. an annotation overlay exported from a drawing tool.
+    ///
+    /// One SVG user unit maps to one unit of the entity's space.
+    /// The graphic is rasterized at the resolution it is shown at.
+    ///
+    /// ## Example
+    ///
+    /// ### Simple SVG overlay
+    /// ```cpp
+    /// #include <rerun.hpp>
+    ///
+    /// #include <string>
+    ///
+    /// static const std::string SVG = R"(<svg xmlns="http://www.w3.org/2000/svg" width="4" height="3">
+    ///   <rect x="0.5" y="0.5" width="3" height="2" fill="none" stroke="red" stroke-width="0.1"/>
+    ///   <circle cx="2" cy="1.5" r="0.5" fill="blue"/>
+    /// </svg>)";
+    ///
+    /// int main() {
+    ///     const auto rec = rerun::RecordingStream("rerun_example_svg_overlay");
+    ///     rec.spawn().exit_on_failure();
+    ///
+    ///     rec.log(
+    ///         "overlay",
+    ///         rerun::SvgOverlay(rerun::Collection<uint8_t>::borrow(SVG.data(), SVG.size()))
+    ///     );
+    /// }
+    /// ```
+    struct SvgOverlay {
+        /// The bytes of the SVG document.
+        rerun::components::Blob blob;
+
+        /// An out-of-tree transform.
+        ///
+        /// Applies a transformation to the graphic itself without impacting its children.
+        std::optional<rerun::components::OutOfTreeTransform3D> transform;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.components.SvgOverlayIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+
+      public:
+        SvgOverlay() = default;
+        SvgOverlay(SvgOverlay&& other) = default;
+
+        explicit SvgOverlay(rerun::components::Blob _blob) : blob(std::move(_blob)) {}
+
+        /// An out-of-tree transform.
+        ///
+        /// Applies a transformation to the graphic itself without impacting its children.
+        SvgOverlay with_transform(rerun::components::OutOfTreeTransform3D _transform) && {
+            transform = std::move(_transform);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// Returns the number of primary instances of this archetype.
+        size_t num_instances() const {
+            return 1;
+        }
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::SvgOverlay> {
+        /// Serialize all set component batches.
+        static Result<std::vector<DataCell>> serialize(const archetypes::SvgOverlay& archetype);
+    };
+} // namespace rerun
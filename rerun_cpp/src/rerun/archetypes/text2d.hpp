@@ -0,0 +1,159 @@
+// DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/re_types/definitions/rerun/archetypes/text2d.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../compiler_utils.hpp"
+#include "../components/color.hpp"
+#include "../components/font_size.hpp"
+#include "../components/instance_key.hpp"
+#include "../components/position2d.hpp"
+#include "../components/text.hpp"
+#include "../components/text_anchor.hpp"
+#include "../components/text_wrap_width.hpp"
+#include "../data_cell.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: Standalone 2D text annotations, e.g. timestamps, event markers or calibration printouts.
+    ///
+    /// Unlike labels, the texts don't annotate any other geometry.
+    ///
+    /// ## Example
+    ///
+    /// ### Simple 2D texts
+    /// ```cpp
+    /// #include <rerun.hpp>
+    ///
+    /// int main() {
+    ///     const auto rec = rerun::RecordingStream("rerun_example_text2d");
+    ///     rec.spawn().exit_on_failure();
+    ///
+    ///     rec.log(
+    ///         "texts",
+    ///         rerun::Text2D({{0.0f, 0.0f}, {2.0f, 1.0f}}, {"origin", "t = 1.5 s"})
+    ///             .with_colors({{255, 0, 0}, {0, 0, 255}})
+    ///             .with_font_sizes({14.0f, 24.0f})
+    ///     );
+    ///
+    ///     // Log an extra rect to set the view bounds
+    ///     rec.log(
+    ///         "bounds",
+    ///         rerun::Boxes2D::from_centers_and_half_sizes({{1.0f, 0.5f}}, {{2.0f, 1.5f}})
+    ///     );
+    /// }
+    /// ```
+    struct Text2D {
+        /// The 2D positions at which the texts are placed.
+        Collection<rerun::components::Position2D> positions;
+
+        /// The texts to show.
+        Collection<rerun::components::Text> texts;
+
+        /// Optional colors for the texts.
+        std::optional<Collection<rerun::components::Color>> colors;
+
+        /// Optional font sizes for the texts, in ui points.
+        ///
+        /// The size of the viewer's body text is used if not specified.
+        std::optional<Collection<rerun::components::FontSize>> font_sizes;
+
+        /// Optional anchors of the texts, i.e. which point of each text is placed at its position.
+        ///
+        /// Texts are centered on their positions if not specified.
+        std::optional<Collection<rerun::components::TextAnchor>> anchors;
+
+        /// Optional widths at which the texts are wrapped onto multiple lines.
+        ///
+        /// Texts that don't fit into a few lines of that width are cut short.
+        /// Texts are not wrapped if not specified.
+        std::optional<Collection<rerun::components::TextWrapWidth>> wrap_widths;
+
+        /// Unique identifiers for each individual text in the batch.
+        std::optional<Collection<rerun::components::InstanceKey>> instance_keys;
+
+      public:
+        static constexpr const char IndicatorComponentName[] = "rerun.components.Text2DIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+
+      public:
+        Text2D() = default;
+        Text2D(Text2D&& other) = default;
+
+        explicit Text2D(
+            Collection<rerun::components::Position2D> _positions,
+            Collection<rerun::components::Text> _texts
+        )
+            : positions(std::move(_positions)), texts(std::move(_texts)) {}
+
+        /// Optional colors for the texts.
+        Text2D with_colors(Collection<rerun::components::Color> _colors) && {
+            colors = std::move(_colors);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// Optional font sizes for the texts, in ui points.
+        ///
+        /// The size of the viewer's body text is used if not specified.
+        Text2D with_font_sizes(Collection<rerun::components::FontSize> _font_sizes) && {
+            font_sizes = std::move(_font_sizes);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// Optional anchors of the texts, i.e. which point of each text is placed at its position.
+        ///
+        /// Texts are centered on their positions if not specified.
+        Text2D with_anchors(Collection<rerun::components::TextAnchor> _anchors) && {
+            anchors = std::move(_anchors);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// Optional widths at which the texts are wrapped onto multiple lines.
+        ///
+        /// Texts that don't fit into a few lines of that width are cut short.
+        /// Texts are not wrapped if not specified.
+        Text2D with_wrap_widths(Collection<rerun::components::TextWrapWidth> _wrap_widths) && {
+            wrap_widths = std::move(_wrap_widths);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// Unique identifiers for each individual text in the batch.
+        Text2D with_instance_keys(Collection<rerun::components::InstanceKey> _instance_keys) && {
+            instance_keys = std::move(_instance_keys);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// Returns the number of primary instances of this archetype.
+        size_t num_instances() const {
+            return positions.size();
+        }
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::Text2D> {
+        /// Serialize all set component batches.
+        static Result<std::vector<DataCell>> serialize(const archetypes::Text2D& archetype);
+    };
+} // namespace rerun
@@ -10,6 +10,7 @@
 #include "components/depth_meter.hpp"
 #include "components/disconnected_space.hpp"
 #include "components/draw_order.hpp"
+#include "components/font_size.hpp"
 #include "components/half_sizes2d.hpp"
 #include "components/half_sizes3d.hpp"
 #include "components/instance_key.hpp"
@@ -29,6 +30,7 @@
 #include "components/radius.hpp"
 #include "components/range1d.hpp"
 #include "components/resolution.hpp"
+#include "components/rotation2d.hpp"
 #include "components/rotation3d.hpp"
 #include "components/scalar.hpp"
 #include "components/scalar_scattering.hpp"
@@ -36,7 +38,10 @@
 #include "components/tensor_data.hpp"
 #include "components/texcoord2d.hpp"
 #include "components/text.hpp"
+#include "components/text_anchor.hpp"
 #include "components/text_log_level.hpp"
+#include "components/text_wrap_width.hpp"
+#include "components/tooltip.hpp"
 #include "components/transform3d.hpp"
 #include "components/vector2d.hpp"
 #include "components/vector3d.hpp"
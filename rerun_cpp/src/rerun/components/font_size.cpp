@@ -0,0 +1,57 @@
+// DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/re_types/definitions/rerun/components/font_size.fbs".
+
+#include "font_size.hpp"
+
+#include <arrow/builder.h>
+#include <arrow/type_fwd.h>
+
+namespace rerun::components {}
+
+namespace rerun {
+    const std::shared_ptr<arrow::DataType>& Loggable<components::FontSize>::arrow_datatype() {
+        static const auto datatype = arrow::float32();
+        return datatype;
+    }
+
+    rerun::Error Loggable<components::FontSize>::fill_arrow_array_builder(
+        arrow::FloatBuilder* builder, const components::FontSize* elements, size_t num_elements
+    ) {
+        if (builder == nullptr) {
+            return rerun::Error(ErrorCode::UnexpectedNullArgument, "Passed array builder is null.");
+        }
+        if (elements == nullptr) {
+            return rerun::Error(
+                ErrorCode::UnexpectedNullArgument,
+                "Cannot serialize null pointer to arrow array."
+            );
+        }
+
+        static_assert(sizeof(*elements) == sizeof(elements->points));
+        ARROW_RETURN_NOT_OK(
+            builder->AppendValues(&elements->points, static_cast<int64_t>(num_elements))
+        );
+
+        return Error::ok();
+    }
+
+    Result<std::shared_ptr<arrow::Array>> Loggable<components::FontSize>::to_arrow(
+        const components::FontSize* instances, size_t num_instances
+    ) {
+        // TODO(andreas): Allow configuring the memory pool.
+        arrow::MemoryPool* pool = arrow::default_memory_pool();
+        auto datatype = arrow_datatype();
+
+        ARROW_ASSIGN_OR_RAISE(auto builder, arrow::MakeBuilder(datatype, pool))
+        if (instances && num_instances > 0) {
+            RR_RETURN_NOT_OK(Loggable<components::FontSize>::fill_arrow_array_builder(
+                static_cast<arrow::FloatBuilder*>(builder.get()),
+                instances,
+                num_instances
+            ));
+        }
+        std::shared_ptr<arrow::Array> array;
+        ARROW_RETURN_NOT_OK(builder->Finish(&array));
+        return array;
+    }
+} // namespace rerun
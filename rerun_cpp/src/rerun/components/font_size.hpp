@@ -0,0 +1,61 @@
+// DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/re_types/definitions/rerun/components/font_size.fbs".
+
+#pragma once
+
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class FloatType;
+    using FloatBuilder = NumericBuilder<FloatType>;
+} // namespace arrow
+
+namespace rerun::components {
+    /// **Component**: The size of a font, in ui points.
+    struct FontSize {
+        float points;
+
+      public:
+        FontSize() = default;
+
+        FontSize(float points_) : points(points_) {}
+
+        FontSize& operator=(float points_) {
+            points = points_;
+            return *this;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<components::FontSize> {
+        static constexpr const char Name[] = "rerun.components.FontSize";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::FloatBuilder* builder, const components::FontSize* elements, size_t num_elements
+        );
+
+        /// Serializes an array of `rerun::components::FontSize` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::FontSize* instances, size_t num_instances
+        );
+    };
+} // namespace rerun
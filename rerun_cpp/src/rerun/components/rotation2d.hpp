@@ -0,0 +1,64 @@
+// DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/re_types/definitions/rerun/components/rotation2d.fbs".
+
+#pragma once
+
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class FloatType;
+    using FloatBuilder = NumericBuilder<FloatType>;
+} // namespace arrow
+
+namespace rerun::components {
+    /// **Component**: A rotation of a 2D shape around its center, expressed as an angle in radians.
+    ///
+    /// Positive angles rotate from the positive X axis towards the positive Y axis.
+    struct Rotation2D {
+        float radians;
+
+      public:
+        Rotation2D() = default;
+
+        Rotation2D(float radians_) : radians(radians_) {}
+
+        Rotation2D& operator=(float radians_) {
+            radians = radians_;
+            return *this;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<components::Rotation2D> {
+        static constexpr const char Name[] = "rerun.components.Rotation2D";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::FloatBuilder* builder, const components::Rotation2D* elements,
+            size_t num_elements
+        );
+
+        /// Serializes an array of `rerun::components::Rotation2D` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::Rotation2D* instances, size_t num_instances
+        );
+    };
+} // namespace rerun
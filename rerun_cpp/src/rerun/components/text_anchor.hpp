@@ -0,0 +1,75 @@
+// DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/re_types/definitions/rerun/components/text_anchor.fbs".
+
+#pragma once
+
+#include "../datatypes/vec2d.hpp"
+#include "../result.hpp"
+
+#include <array>
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    class Array;
+    class DataType;
+    class FixedSizeListBuilder;
+} // namespace arrow
+
+namespace rerun::components {
+    /// **Component**: Which point of a text's bounding box is placed at the text's position.
+    ///
+    /// Given as `[x, y]`, each in `[-1, 1]`: `[-1, -1]` is the top left corner of the text,
+    /// `[0, 0]` its center and `[1, 1]` its bottom right corner.
+    /// Values in between are rounded to the nearest of `-1`, `0` and `1`.
+    struct TextAnchor {
+        rerun::datatypes::Vec2D xy;
+
+      public:
+        TextAnchor() = default;
+
+        TextAnchor(rerun::datatypes::Vec2D xy_) : xy(xy_) {}
+
+        TextAnchor& operator=(rerun::datatypes::Vec2D xy_) {
+            xy = xy_;
+            return *this;
+        }
+
+        TextAnchor(std::array<float, 2> xy_) : xy(xy_) {}
+
+        TextAnchor& operator=(std::array<float, 2> xy_) {
+            xy = xy_;
+            return *this;
+        }
+
+        /// Cast to the underlying Vec2D datatype
+        operator rerun::datatypes::Vec2D() const {
+            return xy;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<components::TextAnchor> {
+        static constexpr const char Name[] = "rerun.components.TextAnchor";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::FixedSizeListBuilder* builder, const components::TextAnchor* elements,
+            size_t num_elements
+        );
+
+        /// Serializes an array of `rerun::components::TextAnchor` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::TextAnchor* instances, size_t num_instances
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,57 @@
+// DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/re_types/definitions/rerun/components/text_wrap_width.fbs".
+
+#include "text_wrap_width.hpp"
+
+#include <arrow/builder.h>
+#include <arrow/type_fwd.h>
+
+namespace rerun::components {}
+
+namespace rerun {
+    const std::shared_ptr<arrow::DataType>& Loggable<components::TextWrapWidth>::arrow_datatype() {
+        static const auto datatype = arrow::float32();
+        return datatype;
+    }
+
+    rerun::Error Loggable<components::TextWrapWidth>::fill_arrow_array_builder(
+        arrow::FloatBuilder* builder, const components::TextWrapWidth* elements, size_t num_elements
+    ) {
+        if (builder == nullptr) {
+            return rerun::Error(ErrorCode::UnexpectedNullArgument, "Passed array builder is null.");
+        }
+        if (elements == nullptr) {
+            return rerun::Error(
+                ErrorCode::UnexpectedNullArgument,
+                "Cannot serialize null pointer to arrow array."
+            );
+        }
+
+        static_assert(sizeof(*elements) == sizeof(elements->value));
+        ARROW_RETURN_NOT_OK(
+            builder->AppendValues(&elements->value, static_cast<int64_t>(num_elements))
+        );
+
+        return Error::ok();
+    }
+
+    Result<std::shared_ptr<arrow::Array>> Loggable<components::TextWrapWidth>::to_arrow(
+        const components::TextWrapWidth* instances, size_t num_instances
+    ) {
+        // TODO(andreas): Allow configuring the memory pool.
+        arrow::MemoryPool* pool = arrow::default_memory_pool();
+        auto datatype = arrow_datatype();
+
+        ARROW_ASSIGN_OR_RAISE(auto builder, arrow::MakeBuilder(datatype, pool))
+        if (instances && num_instances > 0) {
+            RR_RETURN_NOT_OK(Loggable<components::TextWrapWidth>::fill_arrow_array_builder(
+                static_cast<arrow::FloatBuilder*>(builder.get()),
+                instances,
+                num_instances
+            ));
+        }
+        std::shared_ptr<arrow::Array> array;
+        ARROW_RETURN_NOT_OK(builder->Finish(&array));
+        return array;
+    }
+} // namespace rerun
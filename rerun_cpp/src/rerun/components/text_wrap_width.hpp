@@ -0,0 +1,65 @@
+// DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/re_types/definitions/rerun/components/text_wrap_width.fbs".
+
+#pragma once
+
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class FloatType;
+    using FloatBuilder = NumericBuilder<FloatType>;
+} // namespace arrow
+
+namespace rerun::components {
+    /// **Component**: The width at which a text is wrapped onto multiple lines.
+    ///
+    /// Positive values are in scene units, so the wrap width scales with the view.
+    /// Negative values are in ui points, so the wrap width stays the same on screen.
+    struct TextWrapWidth {
+        float value;
+
+      public:
+        TextWrapWidth() = default;
+
+        TextWrapWidth(float value_) : value(value_) {}
+
+        TextWrapWidth& operator=(float value_) {
+            value = value_;
+            return *this;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<components::TextWrapWidth> {
+        static constexpr const char Name[] = "rerun.components.TextWrapWidth";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::FloatBuilder* builder, const components::TextWrapWidth* elements,
+            size_t num_elements
+        );
+
+        /// Serializes an array of `rerun::components::TextWrapWidth` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::TextWrapWidth* instances, size_t num_instances
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,51 @@
+// DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/re_types/definitions/rerun/components/tooltip.fbs".
+
+#include "tooltip.hpp"
+
+#include "../datatypes/utf8.hpp"
+
+#include <arrow/builder.h>
+#include <arrow/type_fwd.h>
+
+namespace rerun::components {}
+
+namespace rerun {
+    const std::shared_ptr<arrow::DataType>& Loggable<components::Tooltip>::arrow_datatype() {
+        static const auto datatype = Loggable<rerun::datatypes::Utf8>::arrow_datatype();
+        return datatype;
+    }
+
+    rerun::Error Loggable<components::Tooltip>::fill_arrow_array_builder(
+        arrow::StringBuilder* builder, const components::Tooltip* elements, size_t num_elements
+    ) {
+        static_assert(sizeof(rerun::datatypes::Utf8) == sizeof(components::Tooltip));
+        RR_RETURN_NOT_OK(Loggable<rerun::datatypes::Utf8>::fill_arrow_array_builder(
+            builder,
+            reinterpret_cast<const rerun::datatypes::Utf8*>(elements),
+            num_elements
+        ));
+
+        return Error::ok();
+    }
+
+    Result<std::shared_ptr<arrow::Array>> Loggable<components::Tooltip>::to_arrow(
+        const components::Tooltip* instances, size_t num_instances
+    ) {
+        // TODO(andreas): Allow configuring the memory pool.
+        arrow::MemoryPool* pool = arrow::default_memory_pool();
+        auto datatype = arrow_datatype();
+
+        ARROW_ASSIGN_OR_RAISE(auto builder, arrow::MakeBuilder(datatype, pool))
+        if (instances && num_instances > 0) {
+            RR_RETURN_NOT_OK(Loggable<components::Tooltip>::fill_arrow_array_builder(
+                static_cast<arrow::StringBuilder*>(builder.get()),
+                instances,
+                num_instances
+            ));
+        }
+        std::shared_ptr<arrow::Array> array;
+        ARROW_RETURN_NOT_OK(builder->Finish(&array));
+        return array;
+    }
+} // namespace rerun
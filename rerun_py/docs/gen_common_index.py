@@ -169,6 +169,9 @@ SECTION_TABLE: Final[list[Section]] = [
             "archetypes.Mesh3D",
             "archetypes.Points2D",
             "archetypes.Points3D",
+            "archetypes.Polygons2D",
+            "archetypes.SvgOverlay",
+            "archetypes.Text2D",
         ],
         gen_page=False,
     ),
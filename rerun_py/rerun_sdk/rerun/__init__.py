@@ -43,6 +43,7 @@ __all__ = [
     "Pinhole",
     "Points2D",
     "Points3D",
+    "Polygons2D",
     "Quaternion",
     "RecordingStream",
     "RotationAxisAngle",
@@ -51,8 +52,10 @@ __all__ = [
     "SegmentationImage",
     "SeriesLine",
     "SeriesPoint",
+    "SvgOverlay",
     "Tensor",
     "TensorData",
+    "Text2D",
     "TextDocument",
     "TextLog",
     "TextLogLevel",
@@ -125,11 +128,14 @@ from .archetypes import (
     Pinhole,
     Points2D,
     Points3D,
+    Polygons2D,
     Scalar,
     SegmentationImage,
     SeriesLine,
     SeriesPoint,
+    SvgOverlay,
     Tensor,
+    Text2D,
     TextDocument,
     TextLog,
     TimeSeriesScalar,
@@ -19,11 +19,14 @@ from .mesh3d import Mesh3D
 from .pinhole import Pinhole
 from .points2d import Points2D
 from .points3d import Points3D
+from .polygons2d import Polygons2D
 from .scalar import Scalar
 from .segmentation_image import SegmentationImage
 from .series_line import SeriesLine
 from .series_point import SeriesPoint
+from .svg_overlay import SvgOverlay
 from .tensor import Tensor
+from .text2d import Text2D
 from .text_document import TextDocument
 from .text_log import TextLog
 from .time_series_scalar import TimeSeriesScalar
@@ -48,11 +51,14 @@ __all__ = [
     "Pinhole",
     "Points2D",
     "Points3D",
+    "Polygons2D",
     "Scalar",
     "SegmentationImage",
     "SeriesLine",
     "SeriesPoint",
+    "SvgOverlay",
     "Tensor",
+    "Text2D",
     "TextDocument",
     "TextLog",
     "TimeSeriesScalar",
@@ -50,9 +50,11 @@ class Boxes2D(Boxes2DExt, Archetype):
         self.__attrs_init__(
             half_sizes=None,  # type: ignore[arg-type]
             centers=None,  # type: ignore[arg-type]
+            rotations=None,  # type: ignore[arg-type]
             colors=None,  # type: ignore[arg-type]
             radii=None,  # type: ignore[arg-type]
             labels=None,  # type: ignore[arg-type]
+            tooltips=None,  # type: ignore[arg-type]
             draw_order=None,  # type: ignore[arg-type]
             class_ids=None,  # type: ignore[arg-type]
             instance_keys=None,  # type: ignore[arg-type]
@@ -82,6 +84,15 @@ class Boxes2D(Boxes2DExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    rotations: components.Rotation2DBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.Rotation2DBatch._optional,  # type: ignore[misc]
+    )
+    # Optional rotations of the boxes around their centers.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     colors: components.ColorBatch | None = field(
         metadata={"component": "optional"},
         default=None,
@@ -109,6 +120,15 @@ class Boxes2D(Boxes2DExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    tooltips: components.TooltipBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.TooltipBatch._optional,  # type: ignore[misc]
+    )
+    # Optional tooltips for the boxes, shown when hovering them instead of their instance paths.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     draw_order: components.DrawOrderBatch | None = field(
         metadata={"component": "optional"},
         default=None,
@@ -44,9 +44,11 @@ class Boxes2DExt:
         centers: datatypes.Vec2DArrayLike | None = None,
         array: npt.ArrayLike | None = None,
         array_format: Box2DFormat | None = None,
+        rotations: components.Rotation2DArrayLike | None = None,
         radii: components.RadiusArrayLike | None = None,
         colors: datatypes.Rgba32ArrayLike | None = None,
         labels: datatypes.Utf8ArrayLike | None = None,
+        tooltips: datatypes.Utf8ArrayLike | None = None,
         draw_order: components.DrawOrderLike | None = None,
         class_ids: datatypes.ClassIdArrayLike | None = None,
         instance_keys: components.InstanceKeyArrayLike | None = None,
@@ -74,12 +76,16 @@ class Boxes2DExt:
             How to interpret the data in `array`.
         centers:
             Optional center positions of the boxes.
+        rotations:
+            Optional rotations of the boxes around their centers, in radians.
         colors:
             Optional colors for the boxes.
         radii:
             Optional radii for the lines that make up the boxes.
         labels:
             Optional text labels for the boxes.
+        tooltips:
+            Optional tooltips for the boxes, shown when hovering them instead of their instance paths.
         draw_order:
             An optional floating point value that specifies the 2D drawing order.
             Objects with higher values are drawn on top of those with lower values.
@@ -163,9 +169,11 @@ class Boxes2DExt:
             self.__attrs_init__(
                 half_sizes=half_sizes,
                 centers=centers,
+                rotations=rotations,
                 radii=radii,
                 colors=colors,
                 labels=labels,
+                tooltips=tooltips,
                 draw_order=draw_order,
                 class_ids=class_ids,
                 instance_keys=instance_keys,
@@ -58,6 +58,7 @@ class Points2D(Points2DExt, Archetype):
             radii=None,  # type: ignore[arg-type]
             colors=None,  # type: ignore[arg-type]
             labels=None,  # type: ignore[arg-type]
+            tooltips=None,  # type: ignore[arg-type]
             draw_order=None,  # type: ignore[arg-type]
             class_ids=None,  # type: ignore[arg-type]
             keypoint_ids=None,  # type: ignore[arg-type]
@@ -109,6 +110,15 @@ class Points2D(Points2DExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    tooltips: components.TooltipBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.TooltipBatch._optional,  # type: ignore[misc]
+    )
+    # Optional tooltips for the points, shown when hovering them instead of their instance paths.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     draw_order: components.DrawOrderBatch | None = field(
         metadata={"component": "optional"},
         default=None,
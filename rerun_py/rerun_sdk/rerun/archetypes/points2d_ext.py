@@ -16,6 +16,7 @@ class Points2DExt:
         radii: components.RadiusArrayLike | None = None,
         colors: datatypes.Rgba32ArrayLike | None = None,
         labels: datatypes.Utf8ArrayLike | None = None,
+        tooltips: datatypes.Utf8ArrayLike | None = None,
         draw_order: components.DrawOrderLike | None = None,
         class_ids: datatypes.ClassIdArrayLike | None = None,
         keypoint_ids: datatypes.KeypointIdArrayLike | None = None,
@@ -37,6 +38,8 @@ class Points2DExt:
              As either 0-1 floats or 0-255 integers, with separate alpha.
         labels:
              Optional text labels for the points.
+        tooltips:
+             Optional tooltips for the points, shown when hovering them instead of their instance paths.
         draw_order:
              An optional floating point value that specifies the 2D drawing order.
              Objects with higher values are drawn on top of those with lower values.
@@ -66,6 +69,7 @@ class Points2DExt:
                 radii=radii,
                 colors=colors,
                 labels=labels,
+                tooltips=tooltips,
                 draw_order=draw_order,
                 class_ids=class_ids,
                 keypoint_ids=keypoint_ids,
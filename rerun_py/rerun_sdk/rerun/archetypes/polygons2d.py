@@ -0,0 +1,188 @@
+# DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/python.rs
+# Based on "crates/re_types/definitions/rerun/archetypes/polygons2d.fbs".
+
+# You can extend this class by creating a "Polygons2DExt" class in "polygons2d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import Archetype
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["Polygons2D"]
+
+
+@define(str=False, repr=False, init=False)
+class Polygons2D(Archetype):
+    """
+    **Archetype**: 2D closed polygons with optional colors, radii, labels, etc.
+
+    The last vertex of each polygon is implicitly connected back to its first vertex.
+    Polygons are drawn as outlines and may be filled translucently by the viewer.
+
+    Example
+    -------
+    ### Simple 2D polygons:
+    ```python
+    import rerun as rr
+
+    rr.init("rerun_example_polygons2d", spawn=True)
+
+    rr.log(
+        "polygons",
+        rr.Polygons2D(
+            [
+                [[0, 0], [4, 0], [2, 3]],
+                [[5, 0], [8, 0], [8, 3], [6, 4], [5, 3]],
+            ],
+            colors=[[255, 0, 0], [0, 0, 255]],
+            labels=["triangle", "pentagon"],
+        ),
+    )
+
+    # Log an extra rect to set the view bounds
+    rr.log("bounds", rr.Boxes2D(centers=[4, 2], half_sizes=[5.0, 3.0]))
+    ```
+    """
+
+    def __init__(
+        self: Any,
+        polygons: components.LineStrip2DArrayLike,
+        *,
+        radii: components.RadiusArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        labels: datatypes.Utf8ArrayLike | None = None,
+        draw_order: components.DrawOrderLike | None = None,
+        class_ids: datatypes.ClassIdArrayLike | None = None,
+        instance_keys: components.InstanceKeyArrayLike | None = None,
+    ):
+        """
+        Create a new instance of the Polygons2D archetype.
+
+        Parameters
+        ----------
+        polygons:
+            All the 2D polygons that make up the batch, as lists of vertices.
+        radii:
+            Optional radii for the polygons.
+        colors:
+            Optional colors for the polygons.
+        labels:
+            Optional text labels for the polygons.
+        draw_order:
+            An optional floating point value that specifies the 2D drawing order of each polygon.
+
+            Objects with higher values are drawn on top of those with lower values.
+        class_ids:
+            Optional `ClassId`s for the polygons.
+
+            The class ID provides colors and labels if not specified explicitly.
+        instance_keys:
+            Unique identifiers for each individual polygon in the batch.
+        """
+
+        # You can define your own __init__ function as a member of Polygons2DExt in polygons2d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                polygons=polygons,
+                radii=radii,
+                colors=colors,
+                labels=labels,
+                draw_order=draw_order,
+                class_ids=class_ids,
+                instance_keys=instance_keys,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            polygons=None,  # type: ignore[arg-type]
+            radii=None,  # type: ignore[arg-type]
+            colors=None,  # type: ignore[arg-type]
+            labels=None,  # type: ignore[arg-type]
+            draw_order=None,  # type: ignore[arg-type]
+            class_ids=None,  # type: ignore[arg-type]
+            instance_keys=None,  # type: ignore[arg-type]
+        )
+
+    @classmethod
+    def _clear(cls) -> Polygons2D:
+        """Produce an empty Polygons2D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    polygons: components.LineStrip2DBatch = field(
+        metadata={"component": "required"},
+        converter=components.LineStrip2DBatch._required,  # type: ignore[misc]
+    )
+    # All the 2D polygons that make up the batch, as lists of vertices.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    radii: components.RadiusBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.RadiusBatch._optional,  # type: ignore[misc]
+    )
+    # Optional radii for the polygons.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colors: components.ColorBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.ColorBatch._optional,  # type: ignore[misc]
+    )
+    # Optional colors for the polygons.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    labels: components.TextBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.TextBatch._optional,  # type: ignore[misc]
+    )
+    # Optional text labels for the polygons.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    draw_order: components.DrawOrderBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.DrawOrderBatch._optional,  # type: ignore[misc]
+    )
+    # An optional floating point value that specifies the 2D drawing order of each polygon.
+    #
+    # Objects with higher values are drawn on top of those with lower values.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    class_ids: components.ClassIdBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.ClassIdBatch._optional,  # type: ignore[misc]
+    )
+    # Optional `ClassId`s for the polygons.
+    #
+    # The class ID provides colors and labels if not specified explicitly.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    instance_keys: components.InstanceKeyBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.InstanceKeyBatch._optional,  # type: ignore[misc]
+    )
+    # Unique identifiers for each individual polygon in the batch.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__
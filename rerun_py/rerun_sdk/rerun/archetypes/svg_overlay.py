@@ -0,0 +1,100 @@
+# DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/python.rs
+# Based on "crates/re_types/definitions/rerun/archetypes/svg_overlay.fbs".
+
+# You can extend this class by creating a "SvgOverlayExt" class in "svg_overlay_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import Archetype
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["SvgOverlay"]
+
+
+@define(str=False, repr=False, init=False)
+class SvgOverlay(Archetype):
+    """
+    **Archetype**: A 2D vector graphic in SVG format, e.g. an annotation overlay exported from a drawing tool.
+
+    One SVG user unit maps to one unit of the entity's space.
+    The graphic is rasterized at the resolution it is shown at.
+
+    Example
+    -------
+    ### Simple SVG overlay:
+    ```python
+    import rerun as rr
+
+    SVG = (
+        b'<svg xmlns="http://www.w3.org/2000/svg" width="4" height="3">'
+        b'<rect x="0.5" y="0.5" width="3" height="2" fill="none" stroke="red" stroke-width="0.1"/>'
+        b'<circle cx="2" cy="1.5" r="0.5" fill="blue"/>'
+        b"</svg>"
+    )
+
+    rr.init("rerun_example_svg_overlay", spawn=True)
+
+    rr.log("overlay", rr.SvgOverlay(SVG))
+    ```
+    """
+
+    def __init__(self: Any, blob: components.BlobLike, *, transform: datatypes.Transform3DLike | None = None):
+        """
+        Create a new instance of the SvgOverlay archetype.
+
+        Parameters
+        ----------
+        blob:
+            The bytes of the SVG document.
+        transform:
+            An out-of-tree transform.
+
+            Applies a transformation to the graphic itself without impacting its children.
+        """
+
+        # You can define your own __init__ function as a member of SvgOverlayExt in svg_overlay_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(blob=blob, transform=transform)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            blob=None,  # type: ignore[arg-type]
+            transform=None,  # type: ignore[arg-type]
+        )
+
+    @classmethod
+    def _clear(cls) -> SvgOverlay:
+        """Produce an empty SvgOverlay, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    blob: components.BlobBatch = field(
+        metadata={"component": "required"},
+        converter=components.BlobBatch._required,  # type: ignore[misc]
+    )
+    # The bytes of the SVG document.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    transform: components.OutOfTreeTransform3DBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.OutOfTreeTransform3DBatch._optional,  # type: ignore[misc]
+    )
+    # An out-of-tree transform.
+    #
+    # Applies a transformation to the graphic itself without impacting its children.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__
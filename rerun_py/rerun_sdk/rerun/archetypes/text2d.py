@@ -0,0 +1,196 @@
+# DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/python.rs
+# Based on "crates/re_types/definitions/rerun/archetypes/text2d.fbs".
+
+# You can extend this class by creating a "Text2DExt" class in "text2d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import Archetype
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["Text2D"]
+
+
+@define(str=False, repr=False, init=False)
+class Text2D(Archetype):
+    """
+    **Archetype**: Standalone 2D text annotations, e.g. timestamps, event markers or calibration printouts.
+
+    Unlike labels, the texts don't annotate any other geometry.
+
+    Example
+    -------
+    ### Simple 2D texts:
+    ```python
+    import rerun as rr
+
+    rr.init("rerun_example_text2d", spawn=True)
+
+    rr.log(
+        "texts",
+        rr.Text2D(
+            positions=[[0.0, 0.0], [2.0, 1.0]],
+            texts=["origin", "t = 1.5 s"],
+            colors=[[255, 0, 0], [0, 0, 255]],
+            font_sizes=[14.0, 24.0],
+        ),
+    )
+
+    # Log an extra rect to set the view bounds
+    rr.log("bounds", rr.Boxes2D(centers=[1.0, 0.5], half_sizes=[2.0, 1.5]))
+    ```
+    """
+
+    def __init__(
+        self: Any,
+        positions: datatypes.Vec2DArrayLike,
+        texts: datatypes.Utf8ArrayLike,
+        *,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        font_sizes: components.FontSizeArrayLike | None = None,
+        anchors: datatypes.Vec2DArrayLike | None = None,
+        wrap_widths: components.TextWrapWidthArrayLike | None = None,
+        instance_keys: components.InstanceKeyArrayLike | None = None,
+    ):
+        """
+        Create a new instance of the Text2D archetype.
+
+        Parameters
+        ----------
+        positions:
+            The 2D positions at which the texts are placed.
+        texts:
+            The texts to show.
+        colors:
+            Optional colors for the texts.
+
+            The colors are interpreted as RGB or RGBA in sRGB gamma-space,
+            As either 0-1 floats or 0-255 integers, with separate alpha.
+        font_sizes:
+            Optional font sizes for the texts, in ui points.
+
+            The size of the viewer's body text is used if not specified.
+        anchors:
+            Optional anchors of the texts, i.e. which point of each text is placed at its position.
+
+            Texts are centered on their positions if not specified.
+        wrap_widths:
+            Optional widths at which the texts are wrapped onto multiple lines.
+
+            Texts that don't fit into a few lines of that width are cut short.
+            Texts are not wrapped if not specified.
+        instance_keys:
+            Unique identifiers for each individual text in the batch.
+        """
+
+        # You can define your own __init__ function as a member of Text2DExt in text2d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                positions=positions,
+                texts=texts,
+                colors=colors,
+                font_sizes=font_sizes,
+                anchors=anchors,
+                wrap_widths=wrap_widths,
+                instance_keys=instance_keys,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            positions=None,  # type: ignore[arg-type]
+            texts=None,  # type: ignore[arg-type]
+            colors=None,  # type: ignore[arg-type]
+            font_sizes=None,  # type: ignore[arg-type]
+            anchors=None,  # type: ignore[arg-type]
+            wrap_widths=None,  # type: ignore[arg-type]
+            instance_keys=None,  # type: ignore[arg-type]
+        )
+
+    @classmethod
+    def _clear(cls) -> Text2D:
+        """Produce an empty Text2D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    positions: components.Position2DBatch = field(
+        metadata={"component": "required"},
+        converter=components.Position2DBatch._required,  # type: ignore[misc]
+    )
+    # The 2D positions at which the texts are placed.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    texts: components.TextBatch = field(
+        metadata={"component": "required"},
+        converter=components.TextBatch._required,  # type: ignore[misc]
+    )
+    # The texts to show.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colors: components.ColorBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.ColorBatch._optional,  # type: ignore[misc]
+    )
+    # Optional colors for the texts.
+    #
+    # The colors are interpreted as RGB or RGBA in sRGB gamma-space,
+    # As either 0-1 floats or 0-255 integers, with separate alpha.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    font_sizes: components.FontSizeBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.FontSizeBatch._optional,  # type: ignore[misc]
+    )
+    # Optional font sizes for the texts, in ui points.
+    #
+    # The size of the viewer's body text is used if not specified.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    anchors: components.TextAnchorBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.TextAnchorBatch._optional,  # type: ignore[misc]
+    )
+    # Optional anchors of the texts, i.e. which point of each text is placed at its position.
+    #
+    # Texts are centered on their positions if not specified.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    wrap_widths: components.TextWrapWidthBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.TextWrapWidthBatch._optional,  # type: ignore[misc]
+    )
+    # Optional widths at which the texts are wrapped onto multiple lines.
+    #
+    # Texts that don't fit into a few lines of that width are cut short.
+    # Texts are not wrapped if not specified.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    instance_keys: components.InstanceKeyBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.InstanceKeyBatch._optional,  # type: ignore[misc]
+    )
+    # Unique identifiers for each individual text in the batch.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__
@@ -28,6 +28,7 @@ from .disconnected_space import (
     DisconnectedSpaceType,
 )
 from .draw_order import DrawOrder, DrawOrderArrayLike, DrawOrderBatch, DrawOrderLike, DrawOrderType
+from .font_size import FontSize, FontSizeArrayLike, FontSizeBatch, FontSizeLike, FontSizeType
 from .half_sizes2d import HalfSizes2D, HalfSizes2DBatch, HalfSizes2DType
 from .half_sizes3d import HalfSizes3D, HalfSizes3DBatch, HalfSizes3DType
 from .instance_key import InstanceKey, InstanceKeyArrayLike, InstanceKeyBatch, InstanceKeyLike, InstanceKeyType
@@ -47,6 +48,7 @@ from .position3d import Position3D, Position3DBatch, Position3DType
 from .radius import Radius, RadiusArrayLike, RadiusBatch, RadiusLike, RadiusType
 from .range1d import Range1D, Range1DArrayLike, Range1DBatch, Range1DLike, Range1DType
 from .resolution import Resolution, ResolutionBatch, ResolutionType
+from .rotation2d import Rotation2D, Rotation2DArrayLike, Rotation2DBatch, Rotation2DLike, Rotation2DType
 from .rotation3d import Rotation3D, Rotation3DBatch, Rotation3DType
 from .scalar import Scalar, ScalarArrayLike, ScalarBatch, ScalarLike, ScalarType
 from .scalar_scattering import (
@@ -60,7 +62,16 @@ from .stroke_width import StrokeWidth, StrokeWidthArrayLike, StrokeWidthBatch, S
 from .tensor_data import TensorData, TensorDataBatch, TensorDataType
 from .texcoord2d import Texcoord2D, Texcoord2DBatch, Texcoord2DType
 from .text import Text, TextBatch, TextType
+from .text_anchor import TextAnchor, TextAnchorBatch, TextAnchorType
 from .text_log_level import TextLogLevel, TextLogLevelBatch, TextLogLevelType
+from .text_wrap_width import (
+    TextWrapWidth,
+    TextWrapWidthArrayLike,
+    TextWrapWidthBatch,
+    TextWrapWidthLike,
+    TextWrapWidthType,
+)
+from .tooltip import Tooltip, TooltipBatch, TooltipType
 from .transform3d import Transform3D, Transform3DBatch, Transform3DType
 from .vector2d import Vector2D, Vector2DBatch, Vector2DType
 from .vector3d import Vector3D, Vector3DBatch, Vector3DType
@@ -116,6 +127,11 @@ __all__ = [
     "DrawOrderBatch",
     "DrawOrderLike",
     "DrawOrderType",
+    "FontSize",
+    "FontSizeArrayLike",
+    "FontSizeBatch",
+    "FontSizeLike",
+    "FontSizeType",
     "HalfSizes2D",
     "HalfSizes2DBatch",
     "HalfSizes2DType",
@@ -187,6 +203,11 @@ __all__ = [
     "Resolution",
     "ResolutionBatch",
     "ResolutionType",
+    "Rotation2D",
+    "Rotation2DArrayLike",
+    "Rotation2DBatch",
+    "Rotation2DLike",
+    "Rotation2DType",
     "Rotation3D",
     "Rotation3DBatch",
     "Rotation3DType",
@@ -212,11 +233,22 @@ __all__ = [
     "Texcoord2DBatch",
     "Texcoord2DType",
     "Text",
+    "TextAnchor",
+    "TextAnchorBatch",
+    "TextAnchorType",
     "TextBatch",
     "TextLogLevel",
     "TextLogLevelBatch",
     "TextLogLevelType",
     "TextType",
+    "TextWrapWidth",
+    "TextWrapWidthArrayLike",
+    "TextWrapWidthBatch",
+    "TextWrapWidthLike",
+    "TextWrapWidthType",
+    "Tooltip",
+    "TooltipBatch",
+    "TooltipType",
     "Transform3D",
     "Transform3DBatch",
     "Transform3DType",
@@ -0,0 +1,61 @@
+# DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/python.rs
+# Based on "crates/re_types/definitions/rerun/components/font_size.fbs".
+
+# You can extend this class by creating a "FontSizeExt" class in "font_size_ext.py".
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any, Sequence, Union
+
+import numpy as np
+import numpy.typing as npt
+import pyarrow as pa
+from attrs import define, field
+
+from .._baseclasses import BaseBatch, BaseExtensionType, ComponentBatchMixin
+from .font_size_ext import FontSizeExt
+
+__all__ = ["FontSize", "FontSizeArrayLike", "FontSizeBatch", "FontSizeLike", "FontSizeType"]
+
+
+@define(init=False)
+class FontSize(FontSizeExt):
+    """**Component**: The size of a font, in ui points."""
+
+    def __init__(self: Any, points: FontSizeLike):
+        """Create a new instance of the FontSize component."""
+
+        # You can define your own __init__ function as a member of FontSizeExt in font_size_ext.py
+        self.__attrs_init__(points=points)
+
+    points: float = field(converter=float)
+
+    def __array__(self, dtype: npt.DTypeLike = None) -> npt.NDArray[Any]:
+        # You can define your own __array__ function as a member of FontSizeExt in font_size_ext.py
+        return np.asarray(self.points, dtype=dtype)
+
+    def __float__(self) -> float:
+        return float(self.points)
+
+
+if TYPE_CHECKING:
+    FontSizeLike = Union[FontSize, float]
+else:
+    FontSizeLike = Any
+
+FontSizeArrayLike = Union[FontSize, Sequence[FontSizeLike], float, npt.NDArray[np.float32]]
+
+
+class FontSizeType(BaseExtensionType):
+    _TYPE_NAME: str = "rerun.components.FontSize"
+
+    def __init__(self) -> None:
+        pa.ExtensionType.__init__(self, pa.float32(), self._TYPE_NAME)
+
+
+class FontSizeBatch(BaseBatch[FontSizeArrayLike], ComponentBatchMixin):
+    _ARROW_TYPE = FontSizeType()
+
+    @staticmethod
+    def _native_to_pa_array(data: FontSizeArrayLike, data_type: pa.DataType) -> pa.Array:
+        return FontSizeExt.native_to_pa_array_override(data, data_type)
@@ -0,0 +1,65 @@
+# DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/python.rs
+# Based on "crates/re_types/definitions/rerun/components/rotation2d.fbs".
+
+# You can extend this class by creating a "Rotation2DExt" class in "rotation2d_ext.py".
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any, Sequence, Union
+
+import numpy as np
+import numpy.typing as npt
+import pyarrow as pa
+from attrs import define, field
+
+from .._baseclasses import BaseBatch, BaseExtensionType, ComponentBatchMixin
+from .rotation2d_ext import Rotation2DExt
+
+__all__ = ["Rotation2D", "Rotation2DArrayLike", "Rotation2DBatch", "Rotation2DLike", "Rotation2DType"]
+
+
+@define(init=False)
+class Rotation2D(Rotation2DExt):
+    """
+    **Component**: A rotation of a 2D shape around its center, expressed as an angle in radians.
+
+    Positive angles rotate from the positive X axis towards the positive Y axis.
+    """
+
+    def __init__(self: Any, radians: Rotation2DLike):
+        """Create a new instance of the Rotation2D component."""
+
+        # You can define your own __init__ function as a member of Rotation2DExt in rotation2d_ext.py
+        self.__attrs_init__(radians=radians)
+
+    radians: float = field(converter=float)
+
+    def __array__(self, dtype: npt.DTypeLike = None) -> npt.NDArray[Any]:
+        # You can define your own __array__ function as a member of Rotation2DExt in rotation2d_ext.py
+        return np.asarray(self.radians, dtype=dtype)
+
+    def __float__(self) -> float:
+        return float(self.radians)
+
+
+if TYPE_CHECKING:
+    Rotation2DLike = Union[Rotation2D, float]
+else:
+    Rotation2DLike = Any
+
+Rotation2DArrayLike = Union[Rotation2D, Sequence[Rotation2DLike], float, npt.NDArray[np.float32]]
+
+
+class Rotation2DType(BaseExtensionType):
+    _TYPE_NAME: str = "rerun.components.Rotation2D"
+
+    def __init__(self) -> None:
+        pa.ExtensionType.__init__(self, pa.float32(), self._TYPE_NAME)
+
+
+class Rotation2DBatch(BaseBatch[Rotation2DArrayLike], ComponentBatchMixin):
+    _ARROW_TYPE = Rotation2DType()
+
+    @staticmethod
+    def _native_to_pa_array(data: Rotation2DArrayLike, data_type: pa.DataType) -> pa.Array:
+        return Rotation2DExt.native_to_pa_array_override(data, data_type)
@@ -0,0 +1,34 @@
+# DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/python.rs
+# Based on "crates/re_types/definitions/rerun/components/text_anchor.fbs".
+
+# You can extend this class by creating a "TextAnchorExt" class in "text_anchor_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import ComponentBatchMixin
+
+__all__ = ["TextAnchor", "TextAnchorBatch", "TextAnchorType"]
+
+
+class TextAnchor(datatypes.Vec2D):
+    """
+    **Component**: Which point of a text's bounding box is placed at the text's position.
+
+    Given as `[x, y]`, each in `[-1, 1]`: `[-1, -1]` is the top left corner of the text,
+    `[0, 0]` its center and `[1, 1]` its bottom right corner.
+    Values in between are rounded to the nearest of `-1`, `0` and `1`.
+    """
+
+    # You can define your own __init__ function as a member of TextAnchorExt in text_anchor_ext.py
+
+    # Note: there are no fields here because TextAnchor delegates to datatypes.Vec2D
+    pass
+
+
+class TextAnchorType(datatypes.Vec2DType):
+    _TYPE_NAME: str = "rerun.components.TextAnchor"
+
+
+class TextAnchorBatch(datatypes.Vec2DBatch, ComponentBatchMixin):
+    _ARROW_TYPE = TextAnchorType()
@@ -0,0 +1,66 @@
+# DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/python.rs
+# Based on "crates/re_types/definitions/rerun/components/text_wrap_width.fbs".
+
+# You can extend this class by creating a "TextWrapWidthExt" class in "text_wrap_width_ext.py".
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any, Sequence, Union
+
+import numpy as np
+import numpy.typing as npt
+import pyarrow as pa
+from attrs import define, field
+
+from .._baseclasses import BaseBatch, BaseExtensionType, ComponentBatchMixin
+from .text_wrap_width_ext import TextWrapWidthExt
+
+__all__ = ["TextWrapWidth", "TextWrapWidthArrayLike", "TextWrapWidthBatch", "TextWrapWidthLike", "TextWrapWidthType"]
+
+
+@define(init=False)
+class TextWrapWidth(TextWrapWidthExt):
+    """
+    **Component**: The width at which a text is wrapped onto multiple lines.
+
+    Positive values are in scene units, so the wrap width scales with the view.
+    Negative values are in ui points, so the wrap width stays the same on screen.
+    """
+
+    def __init__(self: Any, value: TextWrapWidthLike):
+        """Create a new instance of the TextWrapWidth component."""
+
+        # You can define your own __init__ function as a member of TextWrapWidthExt in text_wrap_width_ext.py
+        self.__attrs_init__(value=value)
+
+    value: float = field(converter=float)
+
+    def __array__(self, dtype: npt.DTypeLike = None) -> npt.NDArray[Any]:
+        # You can define your own __array__ function as a member of TextWrapWidthExt in text_wrap_width_ext.py
+        return np.asarray(self.value, dtype=dtype)
+
+    def __float__(self) -> float:
+        return float(self.value)
+
+
+if TYPE_CHECKING:
+    TextWrapWidthLike = Union[TextWrapWidth, float]
+else:
+    TextWrapWidthLike = Any
+
+TextWrapWidthArrayLike = Union[TextWrapWidth, Sequence[TextWrapWidthLike], float, npt.NDArray[np.float32]]
+
+
+class TextWrapWidthType(BaseExtensionType):
+    _TYPE_NAME: str = "rerun.components.TextWrapWidth"
+
+    def __init__(self) -> None:
+        pa.ExtensionType.__init__(self, pa.float32(), self._TYPE_NAME)
+
+
+class TextWrapWidthBatch(BaseBatch[TextWrapWidthArrayLike], ComponentBatchMixin):
+    _ARROW_TYPE = TextWrapWidthType()
+
+    @staticmethod
+    def _native_to_pa_array(data: TextWrapWidthArrayLike, data_type: pa.DataType) -> pa.Array:
+        return TextWrapWidthExt.native_to_pa_array_override(data, data_type)
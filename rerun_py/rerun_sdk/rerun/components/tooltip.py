@@ -0,0 +1,32 @@
+# DO NOT EDIT! This file was auto-generated by crates/re_types_builder/src/codegen/python.rs
+# Based on "crates/re_types/definitions/rerun/components/tooltip.fbs".
+
+# You can extend this class by creating a "TooltipExt" class in "tooltip_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import ComponentBatchMixin
+
+__all__ = ["Tooltip", "TooltipBatch", "TooltipType"]
+
+
+class Tooltip(datatypes.Utf8):
+    """
+    **Component**: Text shown when hovering an instance in a view, instead of its instance path.
+
+    Supports a small markup subset: `**bold**`, `*italic*` and line breaks.
+    """
+
+    # You can define your own __init__ function as a member of TooltipExt in tooltip_ext.py
+
+    # Note: there are no fields here because Tooltip delegates to datatypes.Utf8
+    pass
+
+
+class TooltipType(datatypes.Utf8Type):
+    _TYPE_NAME: str = "rerun.components.Tooltip"
+
+
+class TooltipBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _ARROW_TYPE = TooltipType()